@@ -4,6 +4,11 @@ use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 
+/// Headcount at which a team reads as a department
+const DEPARTMENT_HEADCOUNT: u32 = 20;
+/// Headcount at which a team reads as the whole organization
+const ORGANIZATION_HEADCOUNT: u32 = 100;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AchievementAnalysis {
     pub strong_achievements: Vec<BulletAnalysis>,
@@ -31,6 +36,44 @@ pub struct BulletAnalysis {
     pub xyz_components: XYZComponents,
     pub strength_score: f64,
     pub improvement_suggestions: Vec<String>,
+    /// Phrase that sets the scale of the achievement, such as "team of 10" or "company-wide"
+    #[serde(default)]
+    pub scope: Option<String>,
+    #[serde(default)]
+    pub impact_tier: ImpactTier,
+}
+
+/// Scale an achievement reaches, from the bullet's scope phrases; larger scopes weigh more
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImpactTier {
+    #[default]
+    Individual,
+    Team,
+    Department,
+    Organization,
+}
+
+impl ImpactTier {
+    /// Points added to a bullet's strength score for reaching this scale
+    pub fn strength_bonus(self) -> f64 {
+        match self {
+            ImpactTier::Individual => 0.0,
+            ImpactTier::Team => 5.0,
+            ImpactTier::Department => 10.0,
+            ImpactTier::Organization => 15.0,
+        }
+    }
+
+    fn from_headcount(headcount: u32) -> Self {
+        if headcount >= ORGANIZATION_HEADCOUNT {
+            ImpactTier::Organization
+        } else if headcount >= DEPARTMENT_HEADCOUNT {
+            ImpactTier::Department
+        } else {
+            ImpactTier::Team
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -53,6 +96,10 @@ pub struct XYZSuggestion {
     pub improvement_impact: f64,
     pub implementation_difficulty: String, // "easy", "medium", "hard"
     pub explanation: String,
+    #[serde(default)]
+    pub scope: Option<String>,
+    #[serde(default)]
+    pub impact_tier: ImpactTier,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -73,6 +120,10 @@ pub struct AchievementAnalyzer {
     quantification_patterns: Vec<Regex>,
     achievement_patterns: Vec<Regex>,
     outcome_patterns: Vec<Regex>,
+    /// Phrases with a fixed scale, such as "company-wide" or "across 3 regions"
+    scope_patterns: Vec<(Regex, ImpactTier)>,
+    /// Phrases whose scale depends on the headcount they capture, such as "team of 10"
+    headcount_patterns: Vec<Regex>,
     stop_words: HashSet<String>,
 }
 
@@ -85,6 +136,8 @@ impl AchievementAnalyzer {
             quantification_patterns: Vec::new(),
             achievement_patterns: Vec::new(),
             outcome_patterns: Vec::new(),
+            scope_patterns: Vec::new(),
+            headcount_patterns: Vec::new(),
             stop_words: HashSet::new(),
         };
 
@@ -174,13 +227,15 @@ impl AchievementAnalyzer {
         let xyz_components = self.analyze_xyz_components(&cleaned_bullet);
         let has_xyz_formula = xyz_components.completeness_score >= 0.8;
 
-        // Calculate strength score
-        let strength_score = self.calculate_bullet_strength(
+        // Calculate strength score, weighted by the scale the achievement reaches
+        let (scope, impact_tier) = self.analyze_scope(&cleaned_bullet);
+        let strength_score = (self.calculate_bullet_strength(
             &action_verb_strength,
             has_quantification,
             has_outcome,
             xyz_components.completeness_score,
-        );
+        ) + impact_tier.strength_bonus())
+        .min(100.0);
 
         // Generate improvement suggestions
         let improvement_suggestions = self.generate_bullet_improvements(
@@ -205,9 +260,19 @@ impl AchievementAnalyzer {
             xyz_components,
             strength_score,
             improvement_suggestions,
+            scope,
+            impact_tier,
         }
     }
 
+    /// True for any known action verb, whatever its strength
+    pub fn is_action_verb(&self, word: &str) -> bool {
+        let word = word.to_lowercase();
+        self.strong_action_verbs.contains(&word)
+            || self.medium_action_verbs.contains(&word)
+            || self.weak_action_verbs.contains(&word)
+    }
+
     fn analyze_action_verbs(&self, text: &str) -> (bool, Option<String>, String) {
         let words: Vec<&str> = text.split_whitespace().collect();
 
@@ -244,6 +309,34 @@ impl AchievementAnalyzer {
         (!quantifications.is_empty(), quantifications)
     }
 
+    /// Widest scope the bullet mentions and the tier it implies
+    fn analyze_scope(&self, text: &str) -> (Option<String>, ImpactTier) {
+        let fixed = self
+            .scope_patterns
+            .iter()
+            .filter_map(|(pattern, tier)| pattern.find(text).map(|mat| (mat.as_str(), *tier)));
+        let counted = self.headcount_patterns.iter().filter_map(|pattern| {
+            let captures = pattern.captures(text)?;
+            let headcount = captures[1].replace(',', "").parse::<u32>().ok()?;
+            Some((
+                captures.get(0)?.as_str(),
+                ImpactTier::from_headcount(headcount),
+            ))
+        });
+
+        // On a tie the first phrase wins; headcounts are the more specific phrases
+        counted.chain(fixed).fold(
+            (None, ImpactTier::Individual),
+            |(best, best_tier), (phrase, tier)| {
+                if best.is_none() || tier > best_tier {
+                    (Some(phrase.to_string()), tier)
+                } else {
+                    (best, best_tier)
+                }
+            },
+        )
+    }
+
     fn analyze_outcomes(&self, text: &str) -> (bool, Option<String>) {
         for pattern in &self.outcome_patterns {
             if let Some(mat) = pattern.find(text) {
@@ -471,6 +564,8 @@ impl AchievementAnalyzer {
             improvement_impact,
             implementation_difficulty,
             explanation,
+            scope: analysis.scope.clone(),
+            impact_tier: analysis.impact_tier,
         }
     }
 
@@ -943,6 +1038,37 @@ impl AchievementAnalyzer {
             .into_iter()
             .filter_map(|pattern| Regex::new(pattern).ok())
             .collect();
+
+        // Scope patterns (how far the achievement reached)
+        let scope_patterns = vec![
+            (
+                r"(?i)\b(?:company|organization|organisation|org|enterprise|firm|business)[\s-]wide\b|\b(?:global(?:ly)?|worldwide|nationwide)\b|\bacross\s+(?:\d+|all|multiple|several)\s+(?:regions|countries|markets|offices|sites|business\s+units|subsidiaries)\b",
+                ImpactTier::Organization,
+            ),
+            (
+                r"(?i)\b(?:department|division|team)[\s-]wide\b|\bcross[\s-]functional\b|\bacross\s+(?:\d+|all|multiple|several)\s+(?:teams|departments|squads|groups)\b",
+                ImpactTier::Department,
+            ),
+            (
+                r"(?i)\b(?:my|our|the)\s+team\b|\bteam(?:mates)?\b",
+                ImpactTier::Team,
+            ),
+        ];
+
+        self.scope_patterns = scope_patterns
+            .into_iter()
+            .filter_map(|(pattern, tier)| Regex::new(pattern).ok().map(|regex| (regex, tier)))
+            .collect();
+
+        let headcount_patterns = vec![
+            r"(?i)\b(?:team|group|staff|organization|department)\s+of\s+(\d[\d,]*)\+?",
+            r"(?i)\b(\d[\d,]*)\+?\s+(?:engineers|developers|people|employees|reports|staff|members|analysts|designers)\b",
+        ];
+
+        self.headcount_patterns = headcount_patterns
+            .into_iter()
+            .filter_map(|pattern| Regex::new(pattern).ok())
+            .collect();
     }
 
     fn initialize_stop_words(&mut self) {
@@ -1063,4 +1189,27 @@ mod tests {
         assert!(!analysis.improvement_opportunities.is_empty());
         assert!(analysis.xyz_formula_compliance >= 0.0);
     }
+
+    #[test]
+    fn test_large_scope_bullet_outranks_small_one() {
+        let analyzer = AchievementAnalyzer::new();
+
+        let large = analyzer.analyze_single_bullet(
+            "Improved a company-wide deployment system used across 3 regions",
+            "Experience",
+        );
+        let small = analyzer.analyze_single_bullet("Fixed a bug in the login form", "Experience");
+        let team = analyzer.analyze_single_bullet(
+            "Led a team of 10 engineers through a platform migration",
+            "Experience",
+        );
+
+        assert_eq!(large.impact_tier, ImpactTier::Organization);
+        assert_eq!(large.scope.as_deref(), Some("company-wide"));
+        assert_eq!(small.impact_tier, ImpactTier::Individual);
+        assert_eq!(small.scope, None);
+        assert_eq!(team.impact_tier, ImpactTier::Team);
+        assert_eq!(team.scope.as_deref(), Some("team of 10"));
+        assert!(large.strength_score > small.strength_score);
+    }
 }
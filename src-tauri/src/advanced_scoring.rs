@@ -1,16 +1,47 @@
 use anyhow::{anyhow, Result};
-use log::{debug, info};
+use chrono::{Datelike, NaiveDate, Utc};
+use log::{debug, info, warn};
+use once_cell::sync::Lazy;
 use regex::Regex;
-use rust_stemmers::{Algorithm, Stemmer};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::sync::Arc;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, PoisonError};
 use tokio::sync::Mutex;
 use unicode_normalization::UnicodeNormalization;
 use unicode_segmentation::UnicodeSegmentation;
 
-use crate::database::Database;
-use crate::models::AnalysisResult;
+use crate::achievement_analyzer::AchievementAnalyzer;
+use crate::contact_confidence::{
+    apply_contact_confidence, ContactConfidence, UnconfirmedContactField,
+    DEFAULT_MIN_CONTACT_CONFIDENCE,
+};
+use crate::database::{score_distribution, Database, MIN_DISTRIBUTION_SAMPLES};
+use crate::employment_gaps::{
+    check_experience_dates, date_range_start, find_date_range, parse_month, ExperienceDateIssue,
+    DEFAULT_GAP_THRESHOLD_MONTHS,
+};
+use crate::errors::ATSError;
+use crate::functional_resume::{apply_functional_layout, is_functional_resume, SkillGroup};
+use crate::language::{detect_language, Language};
+use crate::level_fit::{
+    assess_level_fit_with, career_span_years, validate_experience_level_thresholds, LevelFit,
+    SeniorityLevel,
+};
+use crate::linkedin_export::{is_linkedin_export, parse_linkedin_export};
+use crate::models::{
+    Analysis, AnalysisConfig, AnalysisResult, CategoryConfidence, ExperienceLevelThresholds,
+    FormatPenalties, IndustryDefinition, JobDescription, KeywordPositionWeighting, KeywordRule,
+    MatchTypeWeights, OptimizationChange, OptimizationResult, RecencyDecay, ResumeRegion,
+    ScoringProfile, SectionPolicy, TonePhrases,
+};
+use crate::ollama::OllamaClient;
+use crate::readability_analyzer::{ReadabilityAnalyzer, ReadabilityMetrics};
+use crate::section_classifier::{classify_unrecognized_sections, EmbeddingSectionClassifier};
+use crate::tone_analyzer::{ToneAnalysis, ToneAnalyzer};
+use crate::utils::{find_whole_words, tokenize, Token};
 
 /// Advanced scoring engine for Jobscan-level accuracy
 #[allow(dead_code)]
@@ -19,8 +50,14 @@ pub struct AdvancedScoringEngine {
     ats_simulator: ATSSimulator,
     industry_weights: Arc<Mutex<IndustryWeights>>,
     format_analyzer: FormatAnalyzer,
-    #[allow(dead_code)]
+    tone_analyzer: ToneAnalyzer,
     db: Arc<Mutex<Database>>,
+    settings: Arc<ScoringSettings>,
+    /// Places blocks under creative headings by embedding, when an embedding model is set
+    section_classifier: Option<EmbeddingSectionClassifier>,
+    /// Stage a test forces to fail
+    #[cfg(test)]
+    failing_stage: Option<AnalysisStage>,
 }
 
 /// Multi-layered keyword analysis system
@@ -30,11 +67,13 @@ pub struct KeywordAnalyzer {
     stemmed_matcher: StemmedMatcher,
     contextual_matcher: ContextualMatcher,
     synonym_matcher: SynonymMatcher,
+    settings: Arc<ScoringSettings>,
 }
 
 /// ATS system simulation for parsing behavior
 pub struct ATSSimulator {
     parsers: Vec<Box<dyn ATSParser + Send + Sync>>,
+    settings: Arc<ScoringSettings>,
     #[allow(dead_code)]
     format_rules: Vec<FormatRule>,
     #[allow(dead_code)]
@@ -52,7 +91,7 @@ pub struct IndustryWeights {
 }
 
 /// Scoring weights for different components
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ScoringWeights {
     pub keyword_match: f64,        // 40% weight
     pub format_compatibility: f64, // 20% weight
@@ -71,18 +110,550 @@ pub struct KeywordMatch {
     pub overall_score: f64,
     pub match_density: f64,
     pub section_distribution: HashMap<String, f64>,
+    /// Keyword occurrences per 100 words within each detected section
+    pub section_density: HashMap<String, f64>,
+    /// Sections whose keyword density is high enough to read as keyword stuffing
+    pub stuffed_sections: Vec<String>,
+    /// Detected resume language, which picks the stemmer
+    #[serde(default)]
+    pub language: Language,
+    /// Match weight that earned a full keyword score under the scoring profile used
+    #[serde(default = "default_keyword_score_match_cap")]
+    pub keyword_score_match_cap: f64,
+    /// Match type weights in effect when the score was calculated
+    #[serde(default)]
+    pub match_type_weights: MatchTypeWeights,
+    /// Whole-word occurrences of each target keyword found in the resume, keyed in lowercase
+    #[serde(default)]
+    pub keyword_occurrences: HashMap<String, usize>,
+    /// Matches of the user-defined keyword rules, grouped by rule category and reported under
+    /// the rule name
+    #[serde(default)]
+    pub rule_matches: BTreeMap<String, Vec<MatchResult>>,
+    /// How well each matched keyword is used, keyed in lowercase: the best sentence it appears
+    /// in, from a bare list mention up to 1.0 for a quantified, action-led sentence
+    #[serde(default)]
+    pub context_quality: HashMap<String, f64>,
 }
 
-/// Individual match result
+fn default_keyword_score_match_cap() -> f64 {
+    KEYWORD_SCORE_MATCH_CAP
+}
+
+impl KeywordMatch {
+    /// Consolidated per-keyword view of every matcher's results, sorted by keyword
+    pub fn explain(&self) -> Vec<KeywordExplanation> {
+        let stemmer = self.language.stemmer();
+        let mut by_keyword: BTreeMap<String, Vec<MatchExplanation>> = BTreeMap::new();
+
+        let typed_matches = [
+            (MatchType::Exact, self.exact_matches.as_slice()),
+            (MatchType::Stemmed, self.stemmed_matches.as_slice()),
+            (MatchType::Contextual, self.contextual_matches.as_slice()),
+            (MatchType::Synonym, self.synonym_matches.as_slice()),
+        ];
+        let scored = scored_matches(&typed_matches);
+        for (match_type, matches) in typed_matches {
+            for (index, result) in matches.iter().enumerate() {
+                let stemmed_form = (match_type == MatchType::Stemmed)
+                    .then(|| stemmer.stem(&result.keyword.to_lowercase()).to_string());
+                let counted =
+                    scored.get(&result.keyword.to_lowercase()) == Some(&(match_type, index));
+                by_keyword
+                    .entry(result.keyword.clone())
+                    .or_default()
+                    .push(MatchExplanation {
+                        match_type,
+                        matched_text: result.matched_text.clone(),
+                        stemmed_form,
+                        section: result.section.clone(),
+                        context: result.context.clone(),
+                        confidence: result.confidence,
+                        weight: result.weight,
+                        score_contribution: if counted {
+                            match_type.score_factor(&self.match_type_weights)
+                                * result.position_factor
+                                / self.keyword_score_match_cap
+                                * 100.0
+                        } else {
+                            0.0
+                        },
+                    });
+            }
+        }
+
+        by_keyword
+            .into_iter()
+            .map(|(keyword, matches)| KeywordExplanation {
+                total_contribution: matches.iter().map(|m| m.score_contribution).sum(),
+                context_quality: self
+                    .context_quality
+                    .get(&keyword.to_lowercase())
+                    .copied()
+                    .unwrap_or(0.0),
+                keyword,
+                matches,
+            })
+            .collect()
+    }
+
+    /// Distinct target keywords found by any matcher, in sorted order
+    pub fn matched_keywords(&self) -> Vec<String> {
+        let mut keywords: Vec<String> = self
+            .exact_matches
+            .iter()
+            .chain(&self.stemmed_matches)
+            .chain(&self.contextual_matches)
+            .chain(&self.synonym_matches)
+            .chain(self.rule_matches.values().flatten())
+            .map(|m| m.keyword.to_lowercase())
+            .collect();
+        keywords.sort();
+        keywords.dedup();
+        keywords
+    }
+}
+
+/// Which matcher produced a keyword match
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MatchType {
+    Exact,
+    Stemmed,
+    Contextual,
+    Synonym,
+    Fuzzy,
+}
+
+impl MatchType {
+    /// Share of a full keyword hit this kind of match is worth in the overall keyword score
+    pub fn score_factor(self, weights: &MatchTypeWeights) -> f64 {
+        match self {
+            MatchType::Exact => weights.exact,
+            MatchType::Stemmed => weights.stemmed,
+            MatchType::Synonym => weights.synonym,
+            MatchType::Contextual => weights.contextual,
+            MatchType::Fuzzy => weights.fuzzy,
+        }
+    }
+}
+
+/// Keyword matches that earn a full keyword score
+const KEYWORD_SCORE_MATCH_CAP: f64 = 20.0;
+
+/// Experience alignment added when the resume's tenure reaches the level the role targets
+const LEVEL_MET_BONUS: f64 = 25.0;
+/// Experience alignment added when the resume's tenure is one level short of the target
+const LEVEL_NEARLY_MET_BONUS: f64 = 10.0;
+
+/// Source text behind a category score before it counts as fully backed
+const CONFIDENT_SKILL_COUNT: f64 = 5.0;
+const CONFIDENT_EXPERIENCE_WORDS: f64 = 60.0;
+const CONFIDENT_RESUME_WORDS: f64 = 150.0;
+
+/// Impact of a missing section the role's [`SectionPolicy`] marks as required
+const REQUIRED_SECTION_IMPACT: f64 = 85.0;
+
+/// Word count range for a professional summary that is neither a throwaway line nor a wall
+const SUMMARY_MIN_WORDS: usize = 15;
+const SUMMARY_MAX_WORDS: usize = 80;
+/// Job description keywords a summary should mention
+const SUMMARY_MIN_KEYWORDS: usize = 2;
+/// Job title nouns that show a summary names the role being targeted
+const SUMMARY_ROLE_TITLES: &[&str] = &[
+    "engineer",
+    "developer",
+    "architect",
+    "analyst",
+    "scientist",
+    "manager",
+    "director",
+    "designer",
+    "consultant",
+    "specialist",
+    "administrator",
+    "accountant",
+    "nurse",
+    "physician",
+    "marketer",
+    "strategist",
+    "coordinator",
+    "lead",
+    "officer",
+    "attorney",
+    "teacher",
+];
+
+static YEARS_OF_EXPERIENCE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b(?:\d+|one|two|three|four|five|six|seven|eight|nine|ten)\+?\s*(?:years?|yrs?)\b")
+        .expect("valid years of experience pattern")
+});
+
+/// Whether `word` appears in `text` with no letters or digits directly either side
+fn contains_word(text: &str, word: &str) -> bool {
+    let is_word_char = |c: Option<char>| c.is_some_and(char::is_alphanumeric);
+    !word.is_empty()
+        && text.match_indices(word).any(|(start, _)| {
+            !is_word_char(text[..start].chars().next_back())
+                && !is_word_char(text[start + word.len()..].chars().next())
+        })
+}
+
+/// The one match each keyword is scored by, as its type and index within that type's
+/// matches, keyed by lowercase keyword, so a keyword found several times or by several
+/// matchers counts once. That is its most confident match, with ties going to the stricter
+/// type and then to the better-placed match.
+fn scored_matches(
+    typed_matches: &[(MatchType, &[MatchResult])],
+) -> HashMap<String, (MatchType, usize)> {
+    let mut best: HashMap<String, (MatchType, usize, &MatchResult)> = HashMap::new();
+    for &(match_type, matches) in typed_matches {
+        for (index, result) in matches.iter().enumerate() {
+            let candidate = (match_type, index, result);
+            best.entry(result.keyword.to_lowercase())
+                .and_modify(|current| {
+                    let better = result
+                        .confidence
+                        .total_cmp(&current.2.confidence)
+                        .then_with(|| current.0.cmp(&match_type))
+                        .then_with(|| result.position_factor.total_cmp(&current.2.position_factor))
+                        .is_gt();
+                    if better {
+                        *current = candidate;
+                    }
+                })
+                .or_insert(candidate);
+        }
+    }
+    best.into_iter()
+        .map(|(keyword, (match_type, index, _))| (keyword, (match_type, index)))
+        .collect()
+}
+
+/// Share of a missing keyword's priority driven by market demand; the rest is industry weight
+const MARKET_DEMAND_BLEND: f64 = 0.5;
+/// Demand assumed for keywords without market data, leaving their industry weight unchanged
+const NEUTRAL_MARKET_DEMAND: f64 = 0.5;
+
+/// Why one occurrence of a keyword matched
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchExplanation {
+    pub match_type: MatchType,
+    /// Text as it appears in the resume
+    pub matched_text: String,
+    /// Common stem of the keyword and the matched text, for stemmed matches
+    pub stemmed_form: Option<String>,
+    pub section: String,
+    pub context: String,
+    pub confidence: f64,
+    /// Keyword importance assigned by the matcher
+    pub weight: f64,
+    /// Points this match adds to the keyword score before the 100 cap
+    pub score_contribution: f64,
+}
+
+/// Every match found for one job description keyword
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeywordExplanation {
+    pub keyword: String,
+    pub matches: Vec<MatchExplanation>,
+    pub total_contribution: f64,
+    /// How well the resume uses the keyword, from a bare list mention up to 1.0 for a
+    /// quantified, action-led sentence
+    #[serde(default)]
+    pub context_quality: f64,
+}
+
+/// A keyword's standing across several job descriptions for the same kind of role
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AggregatedKeyword {
+    pub keyword: String,
+    /// Number of job descriptions that ask for the keyword
+    pub job_count: usize,
+    /// Share of the job descriptions that ask for the keyword, 0.0-1.0
+    pub job_share: f64,
+    /// Mean importance across the job descriptions that mention it, where a keyword's
+    /// importance in one description is its mentions relative to the most repeated keyword
+    pub average_importance: f64,
+}
+
+/// Job descriptions ranked by fit when the caller sets no limit
+pub const DEFAULT_SIMILAR_ROLES: usize = 5;
+
+/// How well a resume suits one of the user's stored job descriptions
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JobFit {
+    pub job_id: String,
+    pub title: String,
+    pub company: String,
+    /// 0-100: the mean of the keyword score and the keyword coverage as a percentage
+    pub fit_score: f64,
+    /// Share of the job description's keywords the resume mentions, 0.0-1.0
+    pub keyword_coverage: f64,
+    pub matched_keywords: Vec<String>,
+}
+
+/// Individual match result
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "StoredMatchResult")]
 pub struct MatchResult {
     pub keyword: String,
     pub matched_text: String,
     pub section: String,
-    pub position: usize,
+    /// Start of the match in the original resume text, counted in characters (Unicode scalar
+    /// values, not bytes), so `start..end` covers exactly `matched_text`
+    pub start: usize,
+    /// End of the match in characters, exclusive
+    pub end: usize,
     pub context: String,
     pub confidence: f64,
     pub weight: f64,
+    /// Score multiplier for where the match appears, set by `KeywordPositionWeighting`
+    pub position_factor: f64,
+}
+
+/// A match as stored, including results saved before matches carried an end offset
+#[derive(Deserialize)]
+struct StoredMatchResult {
+    keyword: String,
+    matched_text: String,
+    section: String,
+    #[serde(alias = "position")]
+    start: usize,
+    end: Option<usize>,
+    context: String,
+    confidence: f64,
+    weight: f64,
+    #[serde(default = "default_position_factor")]
+    position_factor: f64,
+}
+
+impl From<StoredMatchResult> for MatchResult {
+    fn from(stored: StoredMatchResult) -> Self {
+        // Older results only recorded where the match starts; it spans the text it matched
+        let end = stored
+            .end
+            .unwrap_or(stored.start + stored.matched_text.chars().count());
+        MatchResult {
+            keyword: stored.keyword,
+            matched_text: stored.matched_text,
+            section: stored.section,
+            start: stored.start,
+            end,
+            context: stored.context,
+            confidence: stored.confidence,
+            weight: stored.weight,
+            position_factor: stored.position_factor,
+        }
+    }
+}
+
+fn default_position_factor() -> f64 {
+    1.0
+}
+
+/// Slice `text` by character offsets, clamped to its length
+fn char_slice(text: &str, start: usize, end: usize) -> &str {
+    let byte_offset = |chars: usize| {
+        text.char_indices()
+            .nth(chars)
+            .map_or(text.len(), |(offset, _)| offset)
+    };
+    let start = byte_offset(start);
+    &text[start..byte_offset(end).max(start)]
+}
+
+/// Bumped whenever config that feeds scoring changes. Every cache remembers the generation its
+/// entries were computed under, so one bump invalidates all of them together.
+static CACHE_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+pub fn cache_generation() -> u64 {
+    CACHE_GENERATION.load(Ordering::SeqCst)
+}
+
+/// Drop every cached keyword match and stemmed resume so the next analysis sees the new config
+pub fn invalidate_caches() {
+    CACHE_GENERATION.fetch_add(1, Ordering::SeqCst);
+}
+
+/// Least-recently-used cache keyed by an input hash whose entries all belong to one cache
+/// generation; the first access after [`invalidate_caches`] empties it
+struct GenerationCache<T> {
+    generation: u64,
+    capacity: usize,
+    entries: VecDeque<(u64, T)>,
+}
+
+impl<T: Clone> GenerationCache<T> {
+    fn new(capacity: usize) -> Self {
+        Self {
+            generation: cache_generation(),
+            capacity,
+            entries: VecDeque::new(),
+        }
+    }
+
+    fn sync_generation(&mut self) {
+        let current = cache_generation();
+        if self.generation != current {
+            self.entries.clear();
+            self.generation = current;
+        }
+    }
+
+    fn get(&mut self, key: u64) -> Option<T> {
+        self.sync_generation();
+        let index = self.entries.iter().position(|(cached, _)| *cached == key)?;
+        let entry = self.entries.remove(index).expect("index from position");
+        let value = entry.1.clone();
+        self.entries.push_back(entry);
+        Some(value)
+    }
+
+    /// Store a value computed under `generation`; one computed before the latest invalidation
+    /// is already stale and is dropped
+    fn insert(&mut self, key: u64, value: T, generation: u64) {
+        self.sync_generation();
+        if generation != self.generation {
+            return;
+        }
+        if self.entries.len() >= self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back((key, value));
+    }
+}
+
+/// Words shown either side of a match when no context window is configured
+pub const DEFAULT_CONTEXT_WINDOW_WORDS: usize = 5;
+
+/// Context score a contextual match must exceed when no threshold is configured
+pub const DEFAULT_MIN_CONTEXTUAL_CONFIDENCE: f64 = 0.3;
+
+/// Verbs that show a keyword being put to work rather than listed
+const CONTEXT_ACTION_VERBS: &[&str] = &[
+    "developed",
+    "implemented",
+    "built",
+    "created",
+    "designed",
+    "managed",
+    "led",
+    "architected",
+    "optimized",
+];
+
+/// Words that tie a keyword to an outcome
+const CONTEXT_ACHIEVEMENT_WORDS: &[&str] = &[
+    "achieved",
+    "improved",
+    "increased",
+    "reduced",
+    "delivered",
+    "completed",
+    "successful",
+];
+
+/// Context quality of a keyword that only appears in a list or a fragment
+const BARE_MENTION_CONTEXT_QUALITY: f64 = 0.1;
+
+/// Longest list item, in words, still read as a bare mention rather than a phrase
+const LIST_ITEM_MAX_WORDS: usize = 3;
+
+/// Words a sentence needs before it reads as a description of work rather than a fragment
+const NARRATIVE_MIN_WORDS: usize = 6;
+
+/// Words either side of a match the matchers read to place it in a section; fixed so the
+/// configured snippet size never changes a match's section or weight
+const SECTION_CONTEXT_WORDS: usize = 5;
+
+/// The words overlapping the character span `start..end` plus up to `window` words either
+/// side, from the text's [`tokenize`] output
+fn context_around(tokens: &[Token], start: usize, end: usize, window: usize) -> String {
+    // A match starting or ending inside a word keeps that whole word
+    let first_word = tokens.partition_point(|token| token.end <= start);
+    let last_word = tokens
+        .partition_point(|token| token.start < end)
+        .max(first_word + 1);
+
+    let from = first_word.saturating_sub(window);
+    let to = (last_word + window).min(tokens.len());
+    tokens[from.min(to)..to]
+        .iter()
+        .map(|token| token.text)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// How much parsed text backs each category score: skills listed, words describing roles,
+/// recognizable degrees, the resume's length for keywords, and the parser's own confidence
+fn category_confidence(resume_content: &str, parsed_resume: &ParsedResume) -> CategoryConfidence {
+    let backed = |amount: f64, expected: f64| (amount / expected).min(1.0);
+    let experience_words: usize = parsed_resume
+        .experience
+        .iter()
+        .map(|entry| tokenize(&entry.title).len() + tokenize(&entry.description).len())
+        .sum();
+    let recognized_degrees = parsed_resume
+        .education
+        .iter()
+        .filter(|entry| entry.degree_level().is_some())
+        .count();
+
+    CategoryConfidence {
+        skills: backed(parsed_resume.skills.len() as f64, CONFIDENT_SKILL_COUNT),
+        experience: backed(experience_words as f64, CONFIDENT_EXPERIENCE_WORDS),
+        education: backed(recognized_degrees as f64, 1.0),
+        keywords: backed(
+            tokenize(resume_content).len() as f64,
+            CONFIDENT_RESUME_WORDS,
+        ),
+        format: parsed_resume.parsing_confidence.clamp(0.0, 1.0),
+    }
+}
+
+/// Text folded for matching (NFC, lowercase), remembering the original character range each
+/// folded character came from so spans survive case and normalization changes in length
+struct FoldedText {
+    chars: Vec<char>,
+    origins: Vec<(usize, usize)>,
+}
+
+impl FoldedText {
+    fn new(text: &str) -> Self {
+        let mut chars = Vec::new();
+        let mut origins = Vec::new();
+        let mut offset = 0;
+        for grapheme in text.graphemes(true) {
+            let length = grapheme.chars().count();
+            for folded in grapheme.nfc().flat_map(char::to_lowercase) {
+                chars.push(folded);
+                origins.push((offset, offset + length));
+            }
+            offset += length;
+        }
+        Self { chars, origins }
+    }
+
+    /// Original character spans of every occurrence of `needle`, optionally only where it
+    /// stands as a whole word
+    fn find_all(&self, needle: &str, whole_word: bool) -> Vec<(usize, usize)> {
+        let needle: Vec<char> = needle.nfc().flat_map(char::to_lowercase).collect();
+        if needle.is_empty() || needle.len() > self.chars.len() {
+            return Vec::new();
+        }
+        let is_word_char = |c: Option<&char>| c.is_some_and(|c| c.is_alphanumeric() || *c == '_');
+
+        (0..=self.chars.len() - needle.len())
+            .filter(|&i| self.chars[i..i + needle.len()] == needle[..])
+            .filter(|&i| {
+                !whole_word
+                    || (!is_word_char(i.checked_sub(1).and_then(|j| self.chars.get(j)))
+                        && !is_word_char(self.chars.get(i + needle.len())))
+            })
+            .map(|i| (self.origins[i].0, self.origins[i + needle.len() - 1].1))
+            .collect()
+    }
 }
 
 /// Format analysis results
@@ -118,6 +689,8 @@ pub enum FormatIssueType {
     TableFormatting,
     ImageText,
     SpecialCharacters,
+    KeywordStuffing,
+    FunctionalLayout,
 }
 
 /// Severity levels for issues
@@ -166,6 +739,81 @@ pub trait ATSParser {
     fn parse_resume(&self, content: &str) -> Result<ParsedResume>;
     fn get_system_type(&self) -> ATSSystem;
     fn get_compatibility_score(&self, resume: &ParsedResume) -> f64;
+
+    /// Plain-language reasons this system may have scored the resume down, based on what its
+    /// own parse of `content` extracted
+    fn explain_issues(&self, _content: &str, resume: &ParsedResume) -> Vec<String> {
+        common_parsing_issues(&format!("{:?}", self.get_system_type()), resume)
+    }
+}
+
+/// What one ATS extracted from a resume and why it scored the way it did
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ATSSystemReport {
+    pub score: f64,
+    pub parsing_confidence: f64,
+    pub sections_found: Vec<String>,
+    pub issues: Vec<String>,
+}
+
+/// Lines where text continues after a wide gap, which is how side-by-side columns survive
+/// text extraction
+static COLUMN_GUTTER: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\S(?: {4,}|\t+)\S").expect("valid column gutter pattern"));
+
+fn multi_column_lines(content: &str) -> usize {
+    content
+        .lines()
+        .filter(|line| COLUMN_GUTTER.is_match(line))
+        .count()
+}
+
+/// Extraction gaps every parser reports: missing sections, empty entries and contact details
+fn common_parsing_issues(system: &str, resume: &ParsedResume) -> Vec<String> {
+    let mut issues = Vec::new();
+
+    for section in ["Experience", "Education", "Skills"] {
+        if !resume.sections.contains_key(section) {
+            issues.push(format!(
+                "{} did not find a {} section; use a standard \"{}\" header on its own line",
+                system, section, section
+            ));
+        }
+    }
+    if resume.sections.contains_key("Experience") {
+        if resume.experience.is_empty() {
+            issues.push(format!(
+                "{} found the Experience section but could not split it into roles",
+                system
+            ));
+        } else if resume
+            .experience
+            .iter()
+            .all(|role| date_range_start(&role.duration).is_none())
+        {
+            issues.push(format!(
+                "{} could not parse dates for any role; use ranges like \"Jan 2020 - Present\"",
+                system
+            ));
+        }
+    }
+    if resume.sections.contains_key("Education") && resume.education.is_empty() {
+        issues.push(format!(
+            "{} found the Education section but extracted no degrees",
+            system
+        ));
+    }
+    if resume.contact_info.email.is_none() {
+        issues.push(format!("{} could not find an email address", system));
+    }
+    if resume.contact_info.name.is_none() {
+        issues.push(format!(
+            "{} could not read your name from the top of the resume",
+            system
+        ));
+    }
+
+    issues
 }
 
 /// Parsed resume structure
@@ -176,9 +824,75 @@ pub struct ParsedResume {
     pub experience: Vec<ExperienceEntry>,
     pub education: Vec<EducationEntry>,
     pub skills: Vec<String>,
+    /// Proficiency written beside a skill, keyed by the lowercase skill name
+    #[serde(default)]
+    pub skill_proficiencies: HashMap<String, SkillProficiency>,
+    #[serde(default)]
+    pub certifications: Vec<CertificationEntry>,
+    /// Accomplishments grouped by skill area, when the resume uses a functional layout
+    #[serde(default)]
+    pub skill_groups: Vec<SkillGroup>,
     pub parsing_confidence: f64,
 }
 
+/// Self-reported skill level, as in "Python (expert)" or "Java – intermediate"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SkillProficiency {
+    Beginner,
+    Familiar,
+    Intermediate,
+    Advanced,
+    Expert,
+}
+
+impl SkillProficiency {
+    /// Recognize a proficiency annotation such as "expert" or "working knowledge"
+    pub fn parse(annotation: &str) -> Option<Self> {
+        let annotation = annotation
+            .trim()
+            .trim_matches(|c: char| !c.is_alphanumeric())
+            .to_lowercase();
+        match annotation.as_str() {
+            "expert" | "expertise" | "master" => Some(Self::Expert),
+            "advanced" | "proficient" | "strong" => Some(Self::Advanced),
+            "intermediate" | "competent" | "working knowledge" => Some(Self::Intermediate),
+            "familiar" | "basic" | "basic knowledge" | "exposure" => Some(Self::Familiar),
+            "beginner" | "novice" | "learning" => Some(Self::Beginner),
+            _ => None,
+        }
+    }
+
+    /// Share of a skill requirement's importance a match at this level earns
+    pub fn weight(self) -> f64 {
+        match self {
+            Self::Expert => 1.0,
+            Self::Advanced => 0.9,
+            Self::Intermediate => 0.75,
+            Self::Familiar => 0.5,
+            Self::Beginner => 0.35,
+        }
+    }
+}
+
+/// Split a skills-list entry into the bare skill name and any proficiency annotated beside it
+pub fn split_proficiency(entry: &str) -> (String, Option<SkillProficiency>) {
+    let entry = entry.trim();
+    if let Some((skill, annotation)) = entry.split_once('(') {
+        if let Some(level) = SkillProficiency::parse(annotation.trim_end_matches(')')) {
+            return (skill.trim().to_string(), Some(level));
+        }
+    }
+    for separator in [" – ", " — ", " - ", ":"] {
+        if let Some((skill, annotation)) = entry.rsplit_once(separator) {
+            if let Some(level) = SkillProficiency::parse(annotation) {
+                return (skill.trim().to_string(), Some(level));
+            }
+        }
+    }
+    (entry.to_string(), None)
+}
+
 /// Contact information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContactInfo {
@@ -186,6 +900,12 @@ pub struct ContactInfo {
     pub email: Option<String>,
     pub phone: Option<String>,
     pub location: Option<String>,
+    /// How sure the parser is of each field
+    #[serde(default)]
+    pub confidence: ContactConfidence,
+    /// Fields withheld for falling below the confidence threshold, for the user to confirm
+    #[serde(default)]
+    pub unconfirmed: Vec<UnconfirmedContactField>,
 }
 
 /// Experience entry
@@ -198,6 +918,254 @@ pub struct ExperienceEntry {
     pub achievements: Vec<String>,
 }
 
+impl ExperienceEntry {
+    /// Years from the end of the role to `today`: 0.0 for a current role, `None` when the
+    /// duration has no date range
+    pub fn years_since_end(&self, today: NaiveDate) -> Option<f64> {
+        let range = find_date_range(&self.duration)?;
+        let (_, end) = range
+            .split_once(['-', '–', '—'])
+            .or_else(|| range.split_once(" to "))?;
+        let end = end.trim();
+        if matches!(end.to_lowercase().as_str(), "present" | "current" | "now") {
+            return Some(0.0);
+        }
+        let end = parse_month(end, true)?;
+        Some((today - end).num_days().max(0) as f64 / 365.25)
+    }
+
+    fn mentions(&self, term_lower: &str) -> bool {
+        self.title.to_lowercase().contains(term_lower)
+            || self.description.to_lowercase().contains(term_lower)
+            || self
+                .achievements
+                .iter()
+                .any(|achievement| achievement.to_lowercase().contains(term_lower))
+    }
+}
+
+/// An older role needs at least this many bullets before it can outweigh the recent one
+const BULLET_IMBALANCE_MIN_BULLETS: usize = 6;
+/// How many times the recent role's bullets an older role needs to read as imbalanced
+const BULLET_IMBALANCE_RATIO: f64 = 3.0;
+/// Bullets an older role is trimmed to when rebalancing
+const OLDER_ROLE_MAX_BULLETS: usize = 4;
+
+/// Bullets listed under one experience entry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoleBulletCount {
+    pub title: String,
+    pub company: String,
+    pub duration: String,
+    pub bullet_count: usize,
+}
+
+/// How bullets are spread across roles
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BulletBalance {
+    /// In resume order
+    pub roles: Vec<RoleBulletCount>,
+    /// Index into `roles` of the role that ended last
+    pub most_recent: Option<usize>,
+    /// Index into `roles` of the older role with the most bullets
+    pub heaviest: Option<usize>,
+    /// Whether an older role has far more bullets than the most recent one
+    pub imbalanced: bool,
+}
+
+impl BulletBalance {
+    /// Bullet counts per role; roles without parseable dates are taken as listed newest first
+    pub fn analyze(experience: &[ExperienceEntry], today: NaiveDate) -> Self {
+        let roles: Vec<RoleBulletCount> = experience
+            .iter()
+            .map(|role| RoleBulletCount {
+                title: role.title.clone(),
+                company: role.company.clone(),
+                duration: role.duration.clone(),
+                bullet_count: role.achievements.len(),
+            })
+            .collect();
+        if roles.is_empty() {
+            return Self::default();
+        }
+
+        let most_recent = experience
+            .iter()
+            .enumerate()
+            .min_by(|(i, a), (j, b)| {
+                let ended = |role: &ExperienceEntry, index: usize| {
+                    role.years_since_end(today).unwrap_or(index as f64 * 1e-6)
+                };
+                ended(a, *i)
+                    .partial_cmp(&ended(b, *j))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|(index, _)| index)
+            .unwrap_or(0);
+        let heaviest = roles
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| *index != most_recent)
+            .max_by_key(|(index, role)| (role.bullet_count, std::cmp::Reverse(*index)))
+            .map(|(index, _)| index);
+
+        let recent_bullets = roles[most_recent].bullet_count;
+        let imbalanced = heaviest.is_some_and(|index| {
+            let bullets = roles[index].bullet_count;
+            bullets >= BULLET_IMBALANCE_MIN_BULLETS
+                && bullets as f64 >= recent_bullets.max(1) as f64 * BULLET_IMBALANCE_RATIO
+        });
+
+        Self {
+            roles,
+            most_recent: Some(most_recent),
+            heaviest,
+            imbalanced,
+        }
+    }
+}
+
+/// Recency weight of the most recent role mentioning `term`, or `None` when no role does;
+/// roles without parseable dates count at full weight
+fn most_recent_mention(
+    experience: &[ExperienceEntry],
+    term: &str,
+    decay: &RecencyDecay,
+    today: NaiveDate,
+) -> Option<f64> {
+    let term_lower = term.to_lowercase();
+    experience
+        .iter()
+        .filter(|role| role.mentions(&term_lower))
+        .map(|role| {
+            role.years_since_end(today)
+                .map_or(1.0, |years| decay.weight(years))
+        })
+        .reduce(f64::max)
+}
+
+/// Direction of title seniority across roles in chronological order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProgressionTrend {
+    Upward,
+    Flat,
+    Declining,
+    /// Fewer than two roles to compare
+    Insufficient,
+}
+
+/// Career trajectory read from job titles, oldest role first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CareerProgression {
+    /// Seniority level of each role, see [`title_seniority`]
+    pub levels: Vec<u8>,
+    pub trend: ProgressionTrend,
+    /// 50 for a flat career, higher for each level gained and lower for each step down
+    pub score: f64,
+    /// Set when the progression is flat or declining
+    pub flag: Option<String>,
+}
+
+/// Seniority implied by a job title: 0 intern, 1 junior, 2 individual contributor, 3 senior,
+/// 4 lead/staff/principal, 5 manager, 6 director, 7 executive
+pub fn title_seniority(title: &str) -> u8 {
+    const LEVELS: &[(u8, &[&str])] = &[
+        (
+            7,
+            &[
+                "vp",
+                "vice",
+                "chief",
+                "cto",
+                "ceo",
+                "cfo",
+                "cio",
+                "president",
+            ],
+        ),
+        (6, &["director", "head"]),
+        (5, &["manager"]),
+        (4, &["lead", "principal", "staff", "architect"]),
+        (3, &["senior", "sr"]),
+        (1, &["junior", "jr"]),
+        (0, &["intern", "trainee", "apprentice"]),
+    ];
+
+    let title_lower = title.to_lowercase();
+    let words: Vec<&str> = title_lower
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect();
+    LEVELS
+        .iter()
+        .find(|(_, terms)| words.iter().any(|word| terms.contains(word)))
+        .map_or(2, |(level, _)| *level)
+}
+
+/// Order roles by their parsed start dates and check whether titles grow in seniority. Roles
+/// without a readable date keep resume order, which is assumed to list the newest role first.
+pub fn analyze_career_progression(experience: &[ExperienceEntry]) -> CareerProgression {
+    let starts: Option<Vec<_>> = experience
+        .iter()
+        .map(|entry| date_range_start(&entry.duration))
+        .collect();
+    let mut roles: Vec<&ExperienceEntry> = experience.iter().collect();
+    match starts {
+        Some(starts) => {
+            let mut dated: Vec<_> = starts.into_iter().zip(roles).collect();
+            dated.sort_by_key(|(start, _)| *start);
+            roles = dated.into_iter().map(|(_, entry)| entry).collect();
+        }
+        None => roles.reverse(),
+    }
+
+    let levels: Vec<u8> = roles
+        .iter()
+        .map(|entry| title_seniority(&entry.title))
+        .collect();
+    if levels.len() < 2 {
+        return CareerProgression {
+            levels,
+            trend: ProgressionTrend::Insufficient,
+            score: 50.0,
+            flag: None,
+        };
+    }
+
+    let net = levels[levels.len() - 1] as f64 - levels[0] as f64;
+    let step_downs = levels.windows(2).filter(|pair| pair[1] < pair[0]).count();
+    let score = (50.0 + 12.5 * net - 5.0 * step_downs as f64).clamp(0.0, 100.0);
+    let (trend, flag) = if net > 0.0 {
+        (ProgressionTrend::Upward, None)
+    } else if net == 0.0 {
+        (
+            ProgressionTrend::Flat,
+            Some(format!(
+                "Titles stay at the same level across {} roles; show growth in scope, team size \
+                 or ownership",
+                levels.len()
+            )),
+        )
+    } else {
+        (
+            ProgressionTrend::Declining,
+            Some(
+                "Most recent title is less senior than earlier ones; explain the move or \
+                 emphasize leadership scope in the current role"
+                    .to_string(),
+            ),
+        )
+    };
+
+    CareerProgression {
+        levels,
+        trend,
+        score,
+        flag,
+    }
+}
+
 /// Education entry
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EducationEntry {
@@ -207,6 +1175,167 @@ pub struct EducationEntry {
     pub gpa: Option<f64>,
 }
 
+impl EducationEntry {
+    pub fn degree_level(&self) -> Option<DegreeLevel> {
+        DegreeLevel::from_degree(&self.degree)
+    }
+}
+
+/// Certification entry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CertificationEntry {
+    pub name: String,
+    pub issuer: Option<String>,
+    /// When the certification was earned, as written on the resume
+    pub date: Option<String>,
+    /// When the certification lapses, as written on the resume
+    pub expiry: Option<String>,
+    /// The expiry date has passed
+    pub expired: bool,
+}
+
+static CERTIFICATION_EXPIRY: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)[(,|]?\s*\b(?:expires?|expired|expiry|exp\.|valid\s+(?:until|through|thru))\s*:?\s*((?:[A-Za-z]{3,9}\.?\s+)?\d{4}|\d{1,2}/\d{4})\)?",
+    )
+    .expect("valid certification expiry pattern")
+});
+
+/// Parse each line of a certifications section, e.g. "AWS Certified Solutions Architect,
+/// Amazon, 2022" or "CKA | CNCF | Jan 2020 - Jan 2023", flagging those whose expiry is before
+/// `today`
+pub fn parse_certifications(section: &str, today: NaiveDate) -> Vec<CertificationEntry> {
+    section
+        .lines()
+        .map(|line| line.trim_start_matches(['•', '-', '*']).trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| parse_certification_line(line, today))
+        .collect()
+}
+
+fn parse_certification_line(line: &str, today: NaiveDate) -> CertificationEntry {
+    let mut rest = line.to_string();
+    let mut date = None;
+    let mut expiry = None;
+
+    if let Some(cap) = CERTIFICATION_EXPIRY.captures(line) {
+        expiry = Some(cap[1].to_string());
+        rest = rest.replace(&cap[0], "");
+    } else if let Some(range) = find_date_range(line) {
+        // An issued-to-expiry range such as "Jan 2020 - Jan 2023"
+        let (issued, until) = range
+            .split_once(['-', '–', '—'])
+            .or_else(|| range.split_once(" to "))
+            .unwrap_or((range, ""));
+        date = Some(issued.trim().to_string());
+        let until = until.trim();
+        if !matches!(
+            until.to_lowercase().as_str(),
+            "" | "present" | "current" | "now"
+        ) {
+            expiry = Some(until.to_string());
+        }
+        rest = rest.replace(range, "");
+    }
+
+    let mut parts = rest
+        .split(['|', ',', '•', '(', ')'])
+        .flat_map(|part| part.split(" - "))
+        .map(|part| part.trim().trim_start_matches("Issued").trim())
+        .filter(|part| !part.is_empty());
+    let name = parts.next().unwrap_or(line).to_string();
+    let mut issuer = None;
+    for part in parts {
+        if date.is_none() && parse_month(part, false).is_some() {
+            date = Some(part.to_string());
+        } else if issuer.is_none() {
+            issuer = Some(part.to_string());
+        }
+    }
+
+    let expired = expiry
+        .as_deref()
+        .and_then(|expiry| parse_month(expiry, true))
+        .is_some_and(|expiry| expiry < today);
+
+    CertificationEntry {
+        name,
+        issuer,
+        date,
+        expiry,
+        expired,
+    }
+}
+
+impl CertificationEntry {
+    /// The expiry as a date, taking the first of the month when only a month is written
+    pub fn expiry_date(&self) -> Option<NaiveDate> {
+        self.expiry
+            .as_deref()
+            .and_then(|expiry| parse_month(expiry, true))
+    }
+}
+
+/// Days ahead of a certification's expiry that it is flagged when no window is configured
+pub const DEFAULT_CERT_EXPIRY_WARNING_DAYS: u32 = 60;
+
+/// Credential level of a degree, ordered from lowest to highest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DegreeLevel {
+    Associate,
+    Bachelor,
+    Master,
+    Doctorate,
+}
+
+impl DegreeLevel {
+    /// Recognize the level from degree text such as "Ph.D.", "M.S. Computer Science" or
+    /// "Bachelor of Arts"
+    pub fn from_degree(degree: &str) -> Option<Self> {
+        let normalized = degree.to_lowercase().replace('.', "");
+        let words: Vec<&str> = normalized
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .collect();
+        let has_any = |terms: &[&str]| words.iter().any(|word| terms.contains(word));
+
+        if has_any(&["phd", "doctorate", "doctor", "dphil", "edd", "dba"]) {
+            Some(DegreeLevel::Doctorate)
+        } else if has_any(&[
+            "master", "masters", "ms", "msc", "ma", "mba", "meng", "mfa", "mph", "mtech",
+        ]) {
+            Some(DegreeLevel::Master)
+        } else if has_any(&[
+            "bachelor",
+            "bachelors",
+            "bs",
+            "bsc",
+            "ba",
+            "beng",
+            "bba",
+            "bfa",
+            "btech",
+        ]) {
+            Some(DegreeLevel::Bachelor)
+        } else if has_any(&["associate", "associates", "aa", "aas"]) {
+            Some(DegreeLevel::Associate)
+        } else {
+            None
+        }
+    }
+
+    /// Education score for the level before field relevance is applied
+    pub fn base_score(self) -> f64 {
+        match self {
+            DegreeLevel::Associate => 55.0,
+            DegreeLevel::Bachelor => 65.0,
+            DegreeLevel::Master => 75.0,
+            DegreeLevel::Doctorate => 85.0,
+        }
+    }
+}
+
 /// Experience pattern for industry matching
 #[derive(Debug, Clone)]
 pub struct ExperiencePattern {
@@ -215,7 +1344,7 @@ pub struct ExperiencePattern {
 
 /// Industry benchmark data
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct IndustryBenchmark {
     pub average_score: f64,
     pub median_score: f64,
@@ -285,6 +1414,37 @@ impl Default for ExperienceLevelBenchmark {
     }
 }
 
+/// Analysis of a stored resume against a stored job description, and the record it was saved as
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedAnalysis {
+    pub analysis: Analysis,
+    pub result: EnhancedAnalysisResult,
+}
+
+/// Full score distribution for an industry and experience level, for charting
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndustryBenchmarkReport {
+    pub industry: String,
+    pub experience_level: String,
+    /// Whether the industry figures come from stored analyses rather than built-in benchmarks
+    pub data_driven: bool,
+    pub sample_size: usize,
+    pub score_distribution: Vec<(f64, f64)>, // (score_threshold, percentile)
+    pub average_score: f64,
+    pub median_score: f64,
+    pub top_10_percent_score: f64,
+    pub bottom_10_percent_score: f64,
+    pub experience_level_distribution: Vec<(f64, f64)>,
+    pub experience_level_average: f64,
+    pub experience_level_median: f64,
+}
+
+/// Score at `percentile` (0-100) of ascending `sorted` scores, by nearest rank
+fn nearest_rank(sorted: &[f64], percentile: f64) -> f64 {
+    let rank = (percentile / 100.0 * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
 /// Enhanced analysis result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EnhancedAnalysisResult {
@@ -295,20 +1455,186 @@ pub struct EnhancedAnalysisResult {
     pub industry_alignment: f64,
     pub benchmark_comparison: BenchmarkComparison,
     pub improvement_suggestions: Vec<OptimizationSuggestion>,
+    /// How easy the resume is for a human reader, alongside the ATS-facing scores
+    #[serde(default)]
+    pub readability: ReadabilityMetrics,
+    /// Per-keyword match explanations, only filled in when explain mode is requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keyword_explanations: Option<Vec<KeywordExplanation>>,
+    /// Band the overall score plausibly falls in given how reliably the resume was read
+    #[serde(default)]
+    pub score_range: ScoreRange,
+    /// Whether the seniority the resume demonstrates suits the level the job targets
+    #[serde(default)]
+    pub level_fit: LevelFit,
+    /// Optional stages that failed; their fields hold placeholders rather than results
+    #[serde(default)]
+    pub failed_stages: Vec<StageFailure>,
+    /// Each weighted component behind the overall score
+    #[serde(default)]
+    pub score_breakdown: ScoreBreakdown,
+    /// Overall score blended with the readability score at the configured balance
+    #[serde(default)]
+    pub quality_blend: QualityBlend,
+    /// Fewest suggestions expected to lift the overall score to a target, when one was set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_plan: Option<TargetScorePlan>,
+    /// Bullet counts per role and whether older roles outweigh the most recent one
+    #[serde(default)]
+    pub bullet_balance: BulletBalance,
+    /// Hedging versus confident language, with rewrites for each hedging phrase
+    #[serde(default)]
+    pub tone: ToneAnalysis,
+    /// Experience entries with no dates or dates that cannot be parsed
+    #[serde(default)]
+    pub experience_date_issues: Vec<ExperienceDateIssue>,
 }
 
-/// Benchmark comparison
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct BenchmarkComparison {
-    pub industry_percentile: f64,
-    pub experience_level_percentile: f64,
-    pub overall_percentile: f64,
-    pub top_performers_gap: f64,
+/// Single "overall quality" figure balancing how an ATS and a human reader see the resume
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct QualityBlend {
+    pub ats_score: f64,
+    pub readability_score: f64,
+    /// Share of the blend taken from `ats_score`; the rest comes from `readability_score`
+    pub ats_weight: f64,
+    pub blended_score: f64,
 }
 
-/// Optimization suggestion
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl QualityBlend {
+    pub fn new(ats_score: f64, readability_score: f64, ats_weight: f64) -> Self {
+        let ats_weight = ats_weight.clamp(0.0, 1.0);
+        Self {
+            ats_score,
+            readability_score,
+            ats_weight,
+            blended_score: ats_score * ats_weight + readability_score * (1.0 - ats_weight),
+        }
+    }
+}
+
+/// One weighted component of the overall score
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScoreComponent {
+    pub name: String,
+    /// Component score out of 100, before weighting
+    pub raw_score: f64,
+    pub weight: f64,
+    /// Points the component adds to the overall score
+    pub contribution: f64,
+    /// Points a perfect component score would have added on top of `contribution`
+    pub points_lost: f64,
+}
+
+impl ScoreComponent {
+    fn new(name: &str, raw_score: f64, weight: f64) -> Self {
+        let contribution = raw_score * weight;
+        Self {
+            name: name.to_string(),
+            raw_score,
+            weight,
+            contribution,
+            points_lost: (100.0 * weight - contribution).max(0.0),
+        }
+    }
+}
+
+/// How the overall score adds up: the contributions sum to `total` before it is clamped to
+/// 0-100
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScoreBreakdown {
+    pub components: Vec<ScoreComponent>,
+    pub total: f64,
+}
+
+/// Analysis stages that can fail without discarding the keyword and format results
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AnalysisStage {
+    AtsCompatibility,
+    IndustryAlignment,
+    Benchmark,
+    Suggestions,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StageFailure {
+    pub stage: AnalysisStage,
+    pub error: String,
+}
+
+/// Industry alignment scored when its stage fails: neither credit nor penalty
+const FAILED_STAGE_ALIGNMENT: f64 = 50.0;
+
+/// Smallest margin either side of the score, even for a perfectly parsed resume
+const MIN_SCORE_MARGIN: f64 = 2.0;
+/// Margin added when the parsers have no confidence at all in what they extracted
+const PARSING_UNCERTAINTY_MARGIN: f64 = 15.0;
+/// Margin per unit of standard deviation in confidence between the simulated parsers
+const PARSER_DISAGREEMENT_MARGIN: f64 = 20.0;
+/// Margin per unit of standard deviation in keyword match confidence
+const MATCH_SPREAD_MARGIN: f64 = 10.0;
+
+/// Range around the overall score, wider when parsing was shaky or the parsers disagreed and
+/// when keyword matches mix certain and tentative evidence
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScoreRange {
+    pub lower: f64,
+    pub upper: f64,
+    /// Mean parsing confidence across the simulated ATS parsers, 0.0 to 1.0
+    pub parsing_confidence: f64,
+}
+
+impl ScoreRange {
+    pub fn estimate(score: f64, parsing_confidences: &[f64], match_confidences: &[f64]) -> Self {
+        let parsing: Vec<f64> = parsing_confidences
+            .iter()
+            .map(|confidence| confidence.clamp(0.0, 1.0))
+            .collect();
+        let (parsing_mean, parsing_spread) = mean_and_std_dev(&parsing);
+        let (_, match_spread) = mean_and_std_dev(match_confidences);
+
+        let margin = MIN_SCORE_MARGIN
+            + (1.0 - parsing_mean) * PARSING_UNCERTAINTY_MARGIN
+            + parsing_spread * PARSER_DISAGREEMENT_MARGIN
+            + match_spread * MATCH_SPREAD_MARGIN;
+
+        Self {
+            lower: (score - margin).max(0.0),
+            upper: (score + margin).min(100.0),
+            parsing_confidence: parsing_mean,
+        }
+    }
+}
+
+/// Mean and population standard deviation; an empty slice counts as fully confident
+fn mean_and_std_dev(values: &[f64]) -> (f64, f64) {
+    if values.is_empty() {
+        return (1.0, 0.0);
+    }
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+    let variance = values
+        .iter()
+        .map(|value| (value - mean).powi(2))
+        .sum::<f64>()
+        / values.len() as f64;
+    (mean, variance.sqrt())
+}
+
+/// Benchmark comparison
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BenchmarkComparison {
+    pub industry_percentile: f64,
+    pub experience_level_percentile: f64,
+    pub overall_percentile: f64,
+    pub top_performers_gap: f64,
+}
+
+/// Optimization suggestion
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct OptimizationSuggestion {
+    /// Same for the same advice across analyses, so a resolution follows it to re-analyses
+    #[serde(default)]
+    pub id: String,
     pub category: String,
     pub title: String,
     pub description: String,
@@ -317,6 +1643,342 @@ pub struct OptimizationSuggestion {
     pub specific_actions: Vec<SuggestionAction>,
     pub before_example: String,
     pub after_example: String,
+    /// Whether the user marked this advice done on the analysis it is linked to
+    #[serde(default)]
+    pub resolved: bool,
+}
+
+impl OptimizationSuggestion {
+    /// Stable identifier built from the suggestion's category and title
+    pub fn stable_id(category: &str, title: &str) -> String {
+        let slug = |text: &str| {
+            text.to_lowercase()
+                .split(|c: char| !c.is_alphanumeric())
+                .filter(|word| !word.is_empty())
+                .collect::<Vec<_>>()
+                .join("-")
+        };
+        format!("{}:{}", slug(category), slug(title))
+    }
+}
+
+/// How many suggestions and recommendations an analysis returns; omitted fields keep their
+/// defaults
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SuggestionLimits {
+    pub max_suggestions: usize,
+    pub max_recommendations: usize,
+    /// Suggestions below this impact score are dropped as noise
+    pub min_impact_score: f64,
+}
+
+impl Default for SuggestionLimits {
+    fn default() -> Self {
+        Self {
+            max_suggestions: 15,
+            max_recommendations: 5,
+            min_impact_score: 0.0,
+        }
+    }
+}
+
+impl SuggestionLimits {
+    /// Drop low-impact suggestions and keep the highest-impact ones, ties broken by title
+    pub fn apply(&self, suggestions: &mut Vec<OptimizationSuggestion>) {
+        suggestions.retain(|s| s.impact_score >= self.min_impact_score);
+        suggestions.sort_by(|a, b| {
+            b.impact_score
+                .partial_cmp(&a.impact_score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.title.cmp(&b.title))
+        });
+        suggestions.truncate(self.max_suggestions);
+    }
+}
+
+/// Prioritized plan for reaching a target overall score
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TargetScorePlan {
+    pub current_score: f64,
+    pub target_score: f64,
+    /// Points still needed; zero once the target is met
+    pub gap: f64,
+    /// Highest-impact unresolved suggestions first, stopping once they are expected to close
+    /// the gap
+    pub steps: Vec<OptimizationSuggestion>,
+    /// Points the steps are expected to add together
+    pub estimated_gain: f64,
+    /// False when even every suggestion together is not expected to close the gap
+    pub reachable: bool,
+}
+
+impl TargetScorePlan {
+    /// A suggestion is expected to win back `impact_score` percent of the points its score
+    /// component has lost, so several suggestions aimed at one component share its headroom.
+    /// Suggestions are taken greedily by the points they would still add.
+    pub fn new(
+        suggestions: &[OptimizationSuggestion],
+        breakdown: &ScoreBreakdown,
+        target_score: f64,
+    ) -> Self {
+        let current_score = breakdown.total;
+        let gap = (target_score - current_score).max(0.0);
+        let mut headroom: HashMap<&str, f64> = breakdown
+            .components
+            .iter()
+            .map(|component| (component.name.as_str(), component.points_lost))
+            .collect();
+        let mut candidates: Vec<&OptimizationSuggestion> =
+            suggestions.iter().filter(|s| !s.resolved).collect();
+        candidates.sort_by(|a, b| a.title.cmp(&b.title));
+
+        let expected_gain = |suggestion: &OptimizationSuggestion, headroom: &HashMap<&str, f64>| {
+            let lost = headroom
+                .get(score_component_for(&suggestion.category))
+                .copied()
+                .unwrap_or(0.0);
+            suggestion.impact_score.clamp(0.0, 100.0) / 100.0 * lost
+        };
+
+        let mut steps = Vec::new();
+        let mut estimated_gain = 0.0;
+        while estimated_gain < gap {
+            let best = candidates
+                .iter()
+                .enumerate()
+                .map(|(index, suggestion)| (index, expected_gain(suggestion, &headroom)))
+                .filter(|(_, gain)| *gain > 0.0)
+                .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+            let Some((index, gain)) = best else {
+                break;
+            };
+            let suggestion = candidates.remove(index);
+            if let Some(lost) = headroom.get_mut(score_component_for(&suggestion.category)) {
+                *lost -= gain;
+            }
+            estimated_gain += gain;
+            steps.push(suggestion.clone());
+        }
+
+        Self {
+            current_score,
+            target_score,
+            gap,
+            steps,
+            estimated_gain,
+            reachable: estimated_gain >= gap,
+        }
+    }
+}
+
+/// The score component a suggestion of `category` works on
+fn score_component_for(category: &str) -> &'static str {
+    match category {
+        "Keywords" => "keyword_match",
+        "Format" | "ATS" => "format_compatibility",
+        "Sections" => "section_completeness",
+        "Content" => "achievement_quality",
+        _ => "industry_alignment",
+    }
+}
+
+/// Per-call settings for [`AdvancedScoringEngine::analyze_comprehensive_with_options`]
+#[derive(Debug, Clone, Default)]
+pub struct AnalysisOptions {
+    pub scoring_profile: ScoringProfile,
+    pub suggestion_limits: SuggestionLimits,
+}
+
+/// Scoring inputs an engine works from for its whole life. [`ScoringSettings::from_config`]
+/// builds them from the analysis config; the default is the built-in values. Tests and
+/// callers that need other inputs build their own and hand them to
+/// [`AdvancedScoringEngine::with_settings`].
+#[derive(Debug, Clone)]
+pub struct ScoringSettings {
+    pub context_window_words: usize,
+    pub min_contextual_confidence: f64,
+    pub keyword_position_weighting: KeywordPositionWeighting,
+    pub match_type_weights: MatchTypeWeights,
+    pub keyword_rules: Arc<[CompiledKeywordRule]>,
+    pub format_penalties: FormatPenalties,
+    pub recency_decay: RecencyDecay,
+    pub ats_score_weight: f64,
+    pub cert_expiry_warning_days: u32,
+    /// Keyed by lowercase role or industry name
+    pub section_policies: HashMap<String, SectionPolicy>,
+    /// Keyed by lowercase industry name
+    pub custom_industries: HashMap<String, IndustryDefinition>,
+    pub experience_level_thresholds: ExperienceLevelThresholds,
+    pub section_header_synonyms: SectionHeaderSynonyms,
+    /// Presets plus custom profiles, keyed by lowercase profile name
+    pub scoring_profiles: HashMap<String, ScoringProfile>,
+    /// Lowercase name of the profile used when a call does not pick one
+    pub default_scoring_profile: String,
+    /// Canonical skill name to the variants that mean the same skill
+    pub skill_taxonomy: HashMap<String, Vec<String>>,
+    pub min_contact_confidence: f64,
+    pub employment_gap_threshold_months: u32,
+    pub tone_phrases: TonePhrases,
+    pub resume_region: ResumeRegion,
+    /// Ollama embedding model that places blocks under creative headings, if any
+    pub section_embedding_model: Option<String>,
+}
+
+impl Default for ScoringSettings {
+    fn default() -> Self {
+        Self {
+            context_window_words: DEFAULT_CONTEXT_WINDOW_WORDS,
+            min_contextual_confidence: DEFAULT_MIN_CONTEXTUAL_CONFIDENCE,
+            keyword_position_weighting: KeywordPositionWeighting::default(),
+            match_type_weights: MatchTypeWeights::default(),
+            keyword_rules: Arc::from(Vec::new()),
+            format_penalties: FormatPenalties::default(),
+            recency_decay: RecencyDecay::default(),
+            ats_score_weight: DEFAULT_ATS_SCORE_WEIGHT,
+            cert_expiry_warning_days: DEFAULT_CERT_EXPIRY_WARNING_DAYS,
+            section_policies: SectionPolicy::presets(),
+            custom_industries: HashMap::new(),
+            experience_level_thresholds: ExperienceLevelThresholds::default(),
+            section_header_synonyms: SectionHeaderSynonyms::default(),
+            scoring_profiles: ScoringProfile::presets(),
+            default_scoring_profile: ScoringProfile::DEFAULT_NAME.to_string(),
+            skill_taxonomy: default_skill_taxonomy(),
+            min_contact_confidence: DEFAULT_MIN_CONTACT_CONFIDENCE,
+            employment_gap_threshold_months: DEFAULT_GAP_THRESHOLD_MONTHS,
+            tone_phrases: TonePhrases::default(),
+            resume_region: ResumeRegion::default(),
+            section_embedding_model: None,
+        }
+    }
+}
+
+impl ScoringSettings {
+    /// Settings for `config`, failing on the first keyword rule whose pattern does not
+    /// compile. Thresholds that do not increase fall back to the built-in ones.
+    pub fn from_config(config: &AnalysisConfig) -> Result<Self> {
+        let keyword_rules = compile_keyword_rules(&config.keyword_rules)?;
+        let experience_level_thresholds =
+            match validate_experience_level_thresholds(&config.experience_level_thresholds) {
+                Ok(()) => config.experience_level_thresholds.clone(),
+                Err(e) => {
+                    warn!("Using the default experience level thresholds: {}", e);
+                    ExperienceLevelThresholds::default()
+                }
+            };
+
+        let mut scoring_profiles = ScoringProfile::presets();
+        scoring_profiles.extend(
+            config
+                .scoring_profiles
+                .iter()
+                .map(|(name, profile)| (name.to_lowercase(), profile.clone())),
+        );
+
+        Ok(Self {
+            context_window_words: config.context_window_words,
+            min_contextual_confidence: config.min_contextual_confidence,
+            keyword_position_weighting: config.keyword_position_weighting.clone(),
+            match_type_weights: config.match_type_weights.clone(),
+            keyword_rules: Arc::from(keyword_rules),
+            format_penalties: config.format_penalties.clone(),
+            recency_decay: config.recency_decay.clone(),
+            ats_score_weight: config.ats_score_weight.clamp(0.0, 1.0),
+            cert_expiry_warning_days: config.cert_expiry_warning_days,
+            section_policies: config
+                .section_policies
+                .iter()
+                .map(|(name, policy)| (name.to_lowercase(), policy.clone()))
+                .collect(),
+            custom_industries: config
+                .custom_industries
+                .iter()
+                .map(|(name, definition)| (name.to_lowercase(), definition.clone()))
+                .collect(),
+            experience_level_thresholds,
+            section_header_synonyms: SectionHeaderSynonyms::with_custom(
+                &config.section_header_synonyms,
+            ),
+            scoring_profiles,
+            default_scoring_profile: config.default_scoring_profile.to_lowercase(),
+            skill_taxonomy: config.skill_taxonomy.clone(),
+            min_contact_confidence: config.min_contact_confidence,
+            employment_gap_threshold_months: config.employment_gap_threshold_months,
+            tone_phrases: config.tone_phrases.clone(),
+            resume_region: config.resume_region,
+            section_embedding_model: config.section_embedding_model.clone(),
+        })
+    }
+
+    /// Look up a scoring profile by name, or the default profile when `name` is `None`
+    pub fn scoring_profile(&self, name: Option<&str>) -> Result<ScoringProfile> {
+        let name = name.map_or_else(|| self.default_scoring_profile.clone(), str::to_lowercase);
+        self.scoring_profiles.get(&name).cloned().ok_or_else(|| {
+            ATSError::validation(format!("Unknown scoring profile '{}'", name)).into()
+        })
+    }
+
+    /// The default profile, falling back to "balanced" if the default name is unknown
+    pub fn default_scoring_profile(&self) -> ScoringProfile {
+        self.scoring_profile(None).unwrap_or_default()
+    }
+
+    /// Canonical name of `skill` when the taxonomy knows it under any of its variants
+    pub fn canonical_skill(&self, skill: &str) -> Option<String> {
+        let needle = skill.trim().to_lowercase();
+        self.skill_taxonomy
+            .iter()
+            .find(|(canonical, aliases)| {
+                canonical.to_lowercase() == needle
+                    || aliases.iter().any(|alias| alias.to_lowercase() == needle)
+            })
+            .map(|(canonical, _)| canonical.clone())
+    }
+
+    fn custom_industry(&self, name: &str) -> Option<&IndustryDefinition> {
+        self.custom_industries.get(&name.to_lowercase())
+    }
+
+    fn section_policy(&self, name: &str) -> Option<&SectionPolicy> {
+        self.section_policies.get(&name.to_lowercase())
+    }
+
+    /// Feed the settings keyword matching depends on into a cache key
+    fn hash_keyword_inputs(&self, hasher: &mut impl Hasher) {
+        self.context_window_words.hash(hasher);
+        self.min_contextual_confidence.to_bits().hash(hasher);
+
+        let weighting = &self.keyword_position_weighting;
+        weighting.top_boost.to_bits().hash(hasher);
+        weighting.decay_exponent.to_bits().hash(hasher);
+        let section_boosts: BTreeMap<_, _> = weighting.section_boosts.iter().collect();
+        for (section, boost) in section_boosts {
+            section.hash(hasher);
+            boost.to_bits().hash(hasher);
+        }
+
+        let weights = &self.match_type_weights;
+        for weight in [
+            weights.exact,
+            weights.stemmed,
+            weights.contextual,
+            weights.synonym,
+            weights.fuzzy,
+        ] {
+            weight.to_bits().hash(hasher);
+        }
+
+        for rule in self.keyword_rules.iter() {
+            rule.name.hash(hasher);
+            rule.category.hash(hasher);
+            rule.weight.to_bits().hash(hasher);
+            rule.regex.as_str().hash(hasher);
+        }
+
+        let taxonomy: BTreeMap<_, _> = self.skill_taxonomy.iter().collect();
+        taxonomy.hash(hasher);
+        self.section_header_synonyms.hash(hasher);
+    }
 }
 
 /// Difficulty levels
@@ -344,37 +2006,110 @@ pub struct SuggestionAction {
     pub action: String,
     pub section: String,
     pub reasoning: String,
+    /// Set when the action places a missing keyword; rank 1 is the best section for it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub placement: Option<KeywordPlacement>,
+}
+
+/// What kind of term a keyword is, which decides where on a resume it reads naturally
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeywordType {
+    Tool,
+    Certification,
+    Education,
+    SoftSkill,
+    Accomplishment,
+    General,
+}
+
+/// One ranked spot for a missing keyword, with a sentence showing it in use
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeywordPlacement {
+    pub rank: usize,
+    pub keyword_type: KeywordType,
+    pub example: String,
 }
 
+/// Section keyword density (occurrences per 100 words) above which a section reads as stuffed
+pub const KEYWORD_STUFFING_DENSITY_THRESHOLD: f64 = 50.0;
+
+/// A single keyword repeated more often than this per 100 words reads as repetition abuse
+pub const KEYWORD_REPETITION_THRESHOLD: f64 = 4.0;
+
+/// Fewer repetitions than this are never flagged, however short the resume
+const MIN_FLAGGED_REPETITIONS: usize = 6;
+
+/// A comma-separated run of at least this many terms on one line is a keyword wall
+const KEYWORD_WALL_MIN_TERMS: usize = 30;
+
+/// Shorter term lists still count as a wall when this share of terms are repeats
+const KEYWORD_WALL_DUPLICATE_SHARE: f64 = 0.3;
+const KEYWORD_WALL_MIN_TERMS_WITH_DUPLICATES: usize = 10;
+
+/// Sections shorter than this are too small for density to be meaningful
+const MIN_SECTION_WORDS_FOR_DENSITY: usize = 10;
+
+/// A dense section only reads as stuffed once some keyword appears in it more often than this;
+/// a skills list naming each skill once is dense but not stuffed
+const MAX_SECTION_KEYWORD_REPEATS: usize = 2;
+
 // Matcher implementations
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct ExactMatcher;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct StemmedMatcher;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct ContextualMatcher;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct SynonymMatcher;
 
 #[derive(Debug)]
-pub struct FormatAnalyzer;
+pub struct FormatAnalyzer {
+    penalties: FormatPenalties,
+}
 
 impl AdvancedScoringEngine {
+    /// Engine scoring with the built-in settings
+    #[allow(dead_code)]
     pub fn new(db: Arc<Mutex<Database>>) -> Self {
-        let keyword_analyzer = KeywordAnalyzer::new();
-        let ats_simulator = ATSSimulator::new();
+        Self::with_settings(db, Arc::new(ScoringSettings::default()))
+    }
+
+    /// Engine scoring with `settings`, usually those built from the analysis config
+    pub fn with_settings(db: Arc<Mutex<Database>>, settings: Arc<ScoringSettings>) -> Self {
+        let keyword_analyzer = KeywordAnalyzer::with_settings(Arc::clone(&settings));
+        let ats_simulator = ATSSimulator::with_settings(Arc::clone(&settings));
+        let tone_analyzer = ToneAnalyzer::with_phrases(&settings.tone_phrases);
         let industry_weights = Arc::new(Mutex::new(IndustryWeights::default()));
-        let format_analyzer = FormatAnalyzer::new();
+        let format_analyzer = FormatAnalyzer::with_penalties(settings.format_penalties.clone());
+        let section_classifier = settings.section_embedding_model.as_ref().and_then(|model| {
+            match OllamaClient::new(None) {
+                Ok(client) => Some(EmbeddingSectionClassifier::new(client, model.as_str())),
+                Err(e) => {
+                    warn!(
+                        "Section embeddings disabled, using vocabulary matching: {}",
+                        e
+                    );
+                    None
+                }
+            }
+        });
 
         Self {
             keyword_analyzer,
             ats_simulator,
             industry_weights,
             format_analyzer,
+            tone_analyzer,
             db,
+            settings,
+            section_classifier,
+            #[cfg(test)]
+            failing_stage: None,
         }
     }
 
@@ -386,72 +2121,243 @@ impl AdvancedScoringEngine {
         industry: &str,
         experience_level: &str,
     ) -> Result<EnhancedAnalysisResult> {
+        let options = AnalysisOptions {
+            scoring_profile: self.settings.default_scoring_profile(),
+            ..AnalysisOptions::default()
+        };
+        self.analyze_comprehensive_with_options(
+            resume_content,
+            job_description,
+            industry,
+            experience_level,
+            &options,
+        )
+        .await
+    }
+
+    /// Re-run a stored analysis's resume and job description as if targeting another industry
+    /// or seniority; the stored analysis is left as it was
+    pub async fn reanalyze_stored(
+        &self,
+        analysis_id: &str,
+        industry: &str,
+        experience_level: &str,
+    ) -> Result<EnhancedAnalysisResult> {
+        let (resume, job_description) = {
+            let db = self.db.lock().await;
+            let analysis = db
+                .get_analysis(analysis_id)
+                .await?
+                .ok_or_else(|| anyhow!("Analysis '{}' not found", analysis_id))?;
+            let resume = db
+                .get_resume(&analysis.resume_id)
+                .await?
+                .ok_or_else(|| anyhow!("Resume for analysis '{}' no longer exists", analysis_id))?;
+            let job_description = db
+                .get_job_description(&analysis.job_description_id)
+                .await?
+                .ok_or_else(|| {
+                    anyhow!(
+                        "Job description for analysis '{}' was not saved",
+                        analysis_id
+                    )
+                })?;
+            (resume, job_description)
+        };
+
+        let mut result = self
+            .analyze_comprehensive(
+                &resume.content,
+                &job_description.content,
+                industry,
+                experience_level,
+            )
+            .await?;
+        self.apply_suggestion_resolutions(analysis_id, &mut result)
+            .await?;
+        Ok(result)
+    }
+
+    /// Analyze a saved resume against a saved job description and store the analysis under
+    /// both IDs. The job's own industry and experience level drive the benchmarks.
+    pub async fn analyze_saved(
+        &self,
+        resume_id: &str,
+        job_description_id: &str,
+    ) -> Result<SavedAnalysis> {
+        let (resume, job_description) = {
+            let db = self.db.lock().await;
+            let resume = db
+                .get_resume(resume_id)
+                .await?
+                .ok_or_else(|| anyhow!("Resume '{}' not found", resume_id))?;
+            let job_description = db
+                .get_job_description(job_description_id)
+                .await?
+                .ok_or_else(|| anyhow!("Job description '{}' not found", job_description_id))?;
+            (resume, job_description)
+        };
+
+        let industry = job_description
+            .industry
+            .as_deref()
+            .map(str::trim)
+            .filter(|industry| !industry.is_empty())
+            .map_or_else(|| "general".to_string(), str::to_lowercase);
+        let result = self
+            .analyze_comprehensive(
+                &resume.content,
+                &job_description.content,
+                &industry,
+                job_description.experience_level.scoring_level(),
+            )
+            .await?;
+
+        let analysis = Analysis::new(
+            resume.id,
+            job_description.id,
+            "advanced".to_string(),
+            &result.base_analysis,
+        );
+        self.db.lock().await.save_analysis(&analysis).await?;
+        Ok(SavedAnalysis { analysis, result })
+    }
+
+    /// Mark the suggestions in `result` the user already resolved on `analysis_id`; resolved
+    /// suggestions that no longer apply simply do not appear
+    pub async fn apply_suggestion_resolutions(
+        &self,
+        analysis_id: &str,
+        result: &mut EnhancedAnalysisResult,
+    ) -> Result<()> {
+        let resolved = self
+            .db
+            .lock()
+            .await
+            .get_resolved_suggestions(analysis_id)
+            .await?;
+        for suggestion in &mut result.improvement_suggestions {
+            suggestion.resolved = resolved.contains(&suggestion.id);
+        }
+        Ok(())
+    }
+
+    /// Comprehensive analysis under a specific scoring profile and suggestion limits
+    pub async fn analyze_comprehensive_with_options(
+        &self,
+        resume_content: &str,
+        job_description: &str,
+        industry: &str,
+        experience_level: &str,
+        options: &AnalysisOptions,
+    ) -> Result<EnhancedAnalysisResult> {
+        let profile = &options.scoring_profile;
+        let limits = &options.suggestion_limits;
         info!("Starting comprehensive analysis for {} industry", industry);
 
         // Parse resume with ATS simulation
-        let parsed_resume = self
-            .ats_simulator
-            .parse_with_multiple_systems(resume_content)?;
+        let parsed_resume = match &self.section_classifier {
+            Some(classifier) => {
+                self.ats_simulator
+                    .parse_with_section_embeddings(resume_content, classifier)
+                    .await?
+            }
+            None => self
+                .ats_simulator
+                .parse_with_multiple_systems(resume_content)?,
+        };
 
         // Perform keyword analysis
         let keyword_analysis = self
             .keyword_analyzer
-            .analyze_comprehensive(resume_content, job_description, industry)
+            .analyze_with_profile(resume_content, job_description, industry, profile)
             .await?;
 
         // Analyze format compatibility
-        let format_analysis = self
-            .format_analyzer
-            .analyze_comprehensive(resume_content, &parsed_resume)?;
+        let mut format_analysis =
+            self.format_analyzer
+                .analyze_with_profile(resume_content, &parsed_resume, profile)?;
+        format_analysis.parsing_issues.extend(
+            self.format_analyzer
+                .keyword_stuffing_issues(resume_content, &keyword_analysis),
+        );
 
         // Get industry-specific weights
         let weights = self.get_industry_weights(industry).await?;
 
+        // The remaining stages are optional: a failure is recorded and the keyword and format
+        // results above are still returned
+        let mut failed_stages = Vec::new();
+
         // Calculate ATS compatibility scores
-        let ats_compatibility = self
-            .ats_simulator
-            .calculate_compatibility_scores(&parsed_resume)?;
+        let ats_compatibility = self.stage_or(
+            AnalysisStage::AtsCompatibility,
+            self.ats_simulator
+                .calculate_compatibility_scores(&parsed_resume),
+            HashMap::new(),
+            &mut failed_stages,
+        );
 
         // Calculate industry alignment
-        let industry_alignment = self
-            .calculate_industry_alignment(&parsed_resume, industry, experience_level)
-            .await?;
+        let industry_alignment = self.stage_or(
+            AnalysisStage::IndustryAlignment,
+            self.calculate_industry_alignment(&parsed_resume, industry, experience_level)
+                .await,
+            FAILED_STAGE_ALIGNMENT,
+            &mut failed_stages,
+        );
 
         // Get benchmark comparison
-        let benchmark_comparison = self
-            .get_benchmark_comparison(
+        let benchmark_comparison = self.stage_or(
+            AnalysisStage::Benchmark,
+            self.get_benchmark_comparison(
                 &keyword_analysis,
                 &format_analysis,
                 industry,
                 experience_level,
             )
-            .await?;
+            .await,
+            BenchmarkComparison::default(),
+            &mut failed_stages,
+        );
 
         // Generate optimization suggestions
-        let improvement_suggestions = self
-            .generate_optimization_suggestions(
+        let improvement_suggestions = self.stage_or(
+            AnalysisStage::Suggestions,
+            self.generate_optimization_suggestions(
+                resume_content,
                 &parsed_resume,
                 &keyword_analysis,
                 &format_analysis,
                 job_description,
                 industry,
+                experience_level,
+                limits,
             )
-            .await?;
+            .await,
+            Vec::new(),
+            &mut failed_stages,
+        );
 
         // Calculate overall enhanced score
-        let overall_score = self.calculate_weighted_score(
+        let score_breakdown = self.calculate_weighted_score(
             &keyword_analysis,
+            &parsed_resume,
             &format_analysis,
             industry_alignment,
             &weights,
         )?;
+        let overall_score = score_breakdown.total;
 
         // Create base analysis result for compatibility
         let base_analysis = AnalysisResult {
             overall_score,
             category_scores: self.create_category_scores(
+                resume_content,
                 &keyword_analysis,
                 &format_analysis,
+                &parsed_resume,
+                industry,
                 industry_alignment,
             ),
             detailed_feedback: self.generate_detailed_feedback(
@@ -460,10 +2366,35 @@ impl AdvancedScoringEngine {
                 &improvement_suggestions,
             ),
             missing_keywords: self.extract_missing_keywords(&keyword_analysis),
-            recommendations: self.extract_recommendations(&improvement_suggestions),
+            recommendations: self
+                .extract_recommendations(&improvement_suggestions, limits.max_recommendations),
             processing_time_ms: 0, // Will be set by caller
+            model_used: None,
         };
 
+        let match_confidences: Vec<f64> = [
+            &keyword_analysis.exact_matches,
+            &keyword_analysis.stemmed_matches,
+            &keyword_analysis.contextual_matches,
+            &keyword_analysis.synonym_matches,
+        ]
+        .into_iter()
+        .flatten()
+        .map(|m| m.confidence)
+        .collect();
+        let score_range = ScoreRange::estimate(
+            overall_score,
+            &self.ats_simulator.parsing_confidences(resume_content)?,
+            &match_confidences,
+        );
+
+        let readability = ReadabilityAnalyzer::new().analyze(resume_content);
+        let quality_blend = QualityBlend::new(
+            overall_score,
+            readability.score,
+            self.settings.ats_score_weight,
+        );
+
         Ok(EnhancedAnalysisResult {
             base_analysis,
             keyword_analysis,
@@ -472,10 +2403,103 @@ impl AdvancedScoringEngine {
             industry_alignment,
             benchmark_comparison,
             improvement_suggestions,
+            readability,
+            keyword_explanations: None,
+            score_range,
+            level_fit: assess_level_fit_with(
+                resume_content,
+                job_description,
+                Utc::now().date_naive(),
+                &self.settings.experience_level_thresholds,
+            ),
+            failed_stages,
+            score_breakdown,
+            quality_blend,
+            target_plan: None,
+            bullet_balance: BulletBalance::analyze(
+                &parsed_resume.experience,
+                Utc::now().date_naive(),
+            ),
+            tone: self.tone_analyzer.analyze(resume_content),
+            experience_date_issues: check_experience_dates(&parsed_resume.experience),
+        })
+    }
+
+    /// The value of an optional stage, or `fallback` with the failure recorded in `failures`
+    fn stage_or<T>(
+        &self,
+        stage: AnalysisStage,
+        outcome: Result<T>,
+        fallback: T,
+        failures: &mut Vec<StageFailure>,
+    ) -> T {
+        #[cfg(test)]
+        let outcome = match self.failing_stage {
+            Some(failing) if failing == stage => Err(anyhow!("{:?} stage forced to fail", stage)),
+            _ => outcome,
+        };
+        match outcome {
+            Ok(value) => value,
+            Err(e) => {
+                warn!(
+                    "{:?} stage failed, returning partial results: {:#}",
+                    stage, e
+                );
+                failures.push(StageFailure {
+                    stage,
+                    error: format!("{:#}", e),
+                });
+                fallback
+            }
+        }
+    }
+
+    /// Rule-based optimization used when the LLM is unavailable: the resume is left as is and
+    /// the suggestions from the scoring engine are returned as proposed changes
+    pub async fn offline_optimization(
+        &self,
+        resume_content: &str,
+        job_description: &str,
+    ) -> Result<OptimizationResult> {
+        let analysis = self
+            .analyze_comprehensive(resume_content, job_description, "general", "mid")
+            .await?;
+
+        let changes_made = analysis
+            .improvement_suggestions
+            .iter()
+            .map(|suggestion| OptimizationChange {
+                section: suggestion
+                    .specific_actions
+                    .first()
+                    .map(|action| action.section.clone())
+                    .unwrap_or_else(|| suggestion.category.clone()),
+                change_type: format!("suggestion: {}", suggestion.title),
+                original: suggestion.before_example.clone(),
+                optimized: suggestion.after_example.clone(),
+                impact_score: suggestion.impact_score,
+            })
+            .collect();
+
+        let score = analysis.base_analysis.overall_score;
+        Ok(OptimizationResult {
+            optimized_content: resume_content.to_string(),
+            changes_made,
+            before_score: score,
+            after_score: score,
+            improvement_percentage: 0.0,
+            offline_mode: true,
         })
     }
 
     async fn get_industry_weights(&self, industry: &str) -> Result<ScoringWeights> {
+        if let Some(weights) = self
+            .settings
+            .custom_industry(industry)
+            .and_then(|d| d.scoring_weights.clone())
+        {
+            return Ok(weights);
+        }
         let weights = self.industry_weights.lock().await;
         let industry_weights = match industry.to_lowercase().as_str() {
             "technology" | "tech" | "software" => &weights.tech,
@@ -490,29 +2514,61 @@ impl AdvancedScoringEngine {
     fn calculate_weighted_score(
         &self,
         keyword_analysis: &KeywordMatch,
+        parsed_resume: &ParsedResume,
         format_analysis: &FormatAnalysis,
         industry_alignment: f64,
         weights: &ScoringWeights,
-    ) -> Result<f64> {
-        let keyword_score = keyword_analysis.overall_score * weights.keyword_match;
-        let format_score = format_analysis.ats_compatibility_score * weights.format_compatibility;
-        let section_score = format_analysis.section_detection_score * weights.section_completeness;
-        let achievement_score =
-            self.calculate_achievement_score(keyword_analysis) * weights.achievement_quality;
-        let industry_score = industry_alignment * weights.industry_alignment;
+    ) -> Result<ScoreBreakdown> {
+        let components = vec![
+            ScoreComponent::new(
+                "keyword_match",
+                keyword_analysis.overall_score,
+                weights.keyword_match,
+            ),
+            ScoreComponent::new(
+                "format_compatibility",
+                format_analysis.ats_compatibility_score,
+                weights.format_compatibility,
+            ),
+            ScoreComponent::new(
+                "section_completeness",
+                format_analysis.section_detection_score,
+                weights.section_completeness,
+            ),
+            ScoreComponent::new(
+                "achievement_quality",
+                self.calculate_achievement_score(keyword_analysis, parsed_resume),
+                weights.achievement_quality,
+            ),
+            ScoreComponent::new(
+                "industry_alignment",
+                industry_alignment,
+                weights.industry_alignment,
+            ),
+        ];
 
-        let total_score =
-            keyword_score + format_score + section_score + achievement_score + industry_score;
-        Ok(total_score.clamp(0.0, 100.0))
+        let total_score: f64 = components.iter().map(|c| c.contribution).sum();
+        Ok(ScoreBreakdown {
+            total: total_score.clamp(0.0, 100.0),
+            components,
+        })
     }
 
-    fn calculate_achievement_score(&self, keyword_analysis: &KeywordMatch) -> f64 {
-        // Calculate achievement quality based on contextual matches and positioning
+    fn calculate_achievement_score(
+        &self,
+        keyword_analysis: &KeywordMatch,
+        parsed_resume: &ParsedResume,
+    ) -> f64 {
+        // Contextual matches backed by a role count by how recently that role was held
+        let decay = &self.settings.recency_decay;
+        let today = Utc::now().date_naive();
         let achievement_matches = keyword_analysis
             .contextual_matches
             .iter()
-            .filter(|m| m.section.contains("experience") || m.section.contains("work"))
-            .count();
+            .filter_map(|m| {
+                most_recent_mention(&parsed_resume.experience, &m.matched_text, decay, today)
+            })
+            .fold(0.0, |total, weight| total + weight);
 
         let total_matches = keyword_analysis.exact_matches.len()
             + keyword_analysis.stemmed_matches.len()
@@ -522,37 +2578,49 @@ impl AdvancedScoringEngine {
             return 0.0;
         }
 
-        ((achievement_matches as f64 / total_matches as f64) * 100.0).min(100.0)
+        ((achievement_matches / total_matches as f64) * 100.0).min(100.0)
     }
 
     fn create_category_scores(
         &self,
+        resume_content: &str,
         keyword_analysis: &KeywordMatch,
         format_analysis: &FormatAnalysis,
+        parsed_resume: &ParsedResume,
+        industry: &str,
         _industry_alignment: f64,
     ) -> crate::models::CategoryScores {
         crate::models::CategoryScores {
             skills: keyword_analysis.overall_score,
-            experience: self.calculate_achievement_score(keyword_analysis),
-            education: self.calculate_education_score(keyword_analysis),
+            experience: self.calculate_achievement_score(keyword_analysis, parsed_resume),
+            education: self.calculate_education_score(&parsed_resume.education, industry),
             keywords: keyword_analysis.overall_score,
             format: format_analysis.ats_compatibility_score,
+            confidence: Some(category_confidence(resume_content, parsed_resume)),
         }
     }
 
-    fn calculate_education_score(&self, keyword_analysis: &KeywordMatch) -> f64 {
-        // Calculate education relevance based on education section matches
-        let education_matches = keyword_analysis
-            .exact_matches
-            .iter()
-            .filter(|m| m.section.contains("education") || m.section.contains("degree"))
-            .count();
-
-        if education_matches == 0 {
-            return 50.0; // Neutral score if no education matches
-        }
+    /// Score the strongest credential: its degree level sets the base, then a field the
+    /// industry prefers adds that field's weight while an unrelated field costs a flat penalty
+    fn calculate_education_score(&self, education: &[EducationEntry], industry: &str) -> f64 {
+        const UNRELATED_FIELD_PENALTY: f64 = 10.0;
 
-        ((education_matches as f64 / 5.0) * 100.0).min(100.0) // Assume 5 max relevant education keywords
+        let preferred_degrees = self.get_preferred_degrees(industry);
+        education
+            .iter()
+            .filter_map(|entry| {
+                let level = entry.degree_level()?;
+                let degree_lower = entry.degree.to_lowercase();
+                let field_weight = preferred_degrees
+                    .iter()
+                    .filter(|(field, _)| degree_lower.contains(field.as_str()))
+                    .map(|(_, weight)| *weight)
+                    .reduce(f64::max);
+                let adjustment = field_weight.unwrap_or(-UNRELATED_FIELD_PENALTY);
+                Some((level.base_score() + adjustment).clamp(0.0, 100.0))
+            })
+            .reduce(f64::max)
+            .unwrap_or(50.0) // Neutral score if no recognizable degree
     }
 
     fn generate_detailed_feedback(
@@ -598,10 +2666,14 @@ impl AdvancedScoringEngine {
         Vec::new()
     }
 
-    fn extract_recommendations(&self, suggestions: &[OptimizationSuggestion]) -> Vec<String> {
+    fn extract_recommendations(
+        &self,
+        suggestions: &[OptimizationSuggestion],
+        max_recommendations: usize,
+    ) -> Vec<String> {
         suggestions
             .iter()
-            .take(5) // Top 5 recommendations
+            .take(max_recommendations)
             .map(|s| {
                 format!(
                     "{}: {}",
@@ -626,7 +2698,9 @@ impl AdvancedScoringEngine {
 
         // Get industry-specific keywords and weights
         let empty_map = HashMap::new();
-        let industry_keywords = industry_db.get(industry).unwrap_or(&empty_map);
+        let industry_keywords = industry_db
+            .get(&industry.to_lowercase())
+            .unwrap_or(&empty_map);
 
         // Calculate alignment score based on multiple factors
         let keyword_alignment =
@@ -1079,6 +3153,15 @@ impl AdvancedScoringEngine {
 
         db.insert("general".to_string(), general_keywords);
 
+        for (name, definition) in &self.settings.custom_industries {
+            let keywords = definition
+                .keywords
+                .iter()
+                .map(|(keyword, weight)| (keyword.to_lowercase(), *weight))
+                .collect();
+            db.insert(name.clone(), keywords);
+        }
+
         db
     }
 
@@ -1094,6 +3177,8 @@ impl AdvancedScoringEngine {
 
         let mut total_weight = 0.0;
         let mut matched_weight = 0.0;
+        let decay = &self.settings.recency_decay;
+        let today = Utc::now().date_naive();
 
         // Check each industry keyword against resume content
         for (keyword, weight) in industry_keywords {
@@ -1113,18 +3198,11 @@ impl AdvancedScoringEngine {
             }
 
             if !found {
-                // Check in experience descriptions
-                for exp in &parsed_resume.experience {
-                    if exp.title.to_lowercase().contains(&keyword_lower)
-                        || exp.description.to_lowercase().contains(&keyword_lower)
-                        || exp
-                            .achievements
-                            .iter()
-                            .any(|a| a.to_lowercase().contains(&keyword_lower))
-                    {
-                        matched_weight += weight * 0.8; // Slightly lower weight for experience mentions
-                        break;
-                    }
+                // Check in experience descriptions, counting recent roles more
+                if let Some(recency) =
+                    most_recent_mention(&parsed_resume.experience, &keyword_lower, decay, today)
+                {
+                    matched_weight += weight * 0.8 * recency; // Slightly lower weight for experience mentions
                 }
             }
 
@@ -1155,10 +3233,17 @@ impl AdvancedScoringEngine {
         industry: &str,
     ) -> Result<f64> {
         let industry_skill_requirements = self.get_industry_skill_requirements(industry);
-        let resume_skills: Vec<String> = parsed_resume
+        let resume_skills: Vec<(String, f64)> = parsed_resume
             .skills
             .iter()
-            .map(|s| s.to_lowercase())
+            .map(|s| {
+                let skill = s.to_lowercase();
+                let proficiency = parsed_resume
+                    .skill_proficiencies
+                    .get(&skill)
+                    .map_or(1.0, |level| level.weight());
+                (skill, proficiency)
+            })
             .collect();
 
         if industry_skill_requirements.is_empty() {
@@ -1172,13 +3257,18 @@ impl AdvancedScoringEngine {
             for (skill, importance) in skills_and_importance {
                 total_importance += importance;
 
-                // Check if resume contains this skill (fuzzy matching)
+                // Check if resume contains this skill (fuzzy matching), at the strongest
+                // proficiency claimed; unannotated skills count in full
                 let skill_lower = skill.to_lowercase();
-                if resume_skills
+                if let Some(proficiency) = resume_skills
                     .iter()
-                    .any(|rs| rs.contains(&skill_lower) || skill_lower.contains(rs))
+                    .filter(|(rs, _)| {
+                        rs.contains(&skill_lower) || skill_lower.contains(rs.as_str())
+                    })
+                    .map(|(_, proficiency)| *proficiency)
+                    .reduce(f64::max)
                 {
-                    matched_importance += importance;
+                    matched_importance += importance * proficiency;
                 }
             }
         }
@@ -1197,6 +3287,10 @@ impl AdvancedScoringEngine {
         &self,
         industry: &str,
     ) -> HashMap<String, Vec<(String, f64)>> {
+        if let Some(definition) = self.settings.custom_industry(industry) {
+            return definition.skill_requirements.clone();
+        }
+
         let mut requirements = HashMap::new();
 
         match industry {
@@ -1314,31 +3408,24 @@ impl AdvancedScoringEngine {
         let expected_experience = self.get_expected_experience_patterns(industry, experience_level);
         let mut alignment_score = 50.0; // Base score
 
-        // Check experience count
-        let experience_count = parsed_resume.experience.len();
-        match experience_level {
-            "entry" => {
-                if experience_count >= 1 {
-                    alignment_score += 20.0;
-                }
-            }
-            "mid" => {
-                if experience_count >= 2 {
-                    alignment_score += 15.0;
-                }
-                if experience_count >= 3 {
-                    alignment_score += 10.0;
-                }
-            }
-            "senior" => {
-                if experience_count >= 3 {
-                    alignment_score += 10.0;
-                }
-                if experience_count >= 5 {
-                    alignment_score += 15.0;
+        // Bucket the career span with the configured thresholds and compare it with the target
+        let career_years = career_span_years(
+            parsed_resume
+                .experience
+                .iter()
+                .map(|entry| entry.duration.as_str()),
+            Utc::now().date_naive(),
+        );
+        let resume_level =
+            SeniorityLevel::from_years(career_years, &self.settings.experience_level_thresholds);
+        if let Some(target_level) = SeniorityLevel::from_name(experience_level) {
+            if !parsed_resume.experience.is_empty() {
+                if resume_level >= target_level {
+                    alignment_score += LEVEL_MET_BONUS;
+                } else if resume_level as u8 + 1 == target_level as u8 {
+                    alignment_score += LEVEL_NEARLY_MET_BONUS;
                 }
             }
-            _ => {}
         }
 
         // Check for industry-relevant experience
@@ -1379,6 +3466,13 @@ impl AdvancedScoringEngine {
                     break;
                 }
             }
+
+            alignment_score += match analyze_career_progression(&parsed_resume.experience).trend {
+                ProgressionTrend::Upward => 10.0,
+                ProgressionTrend::Flat => -5.0,
+                ProgressionTrend::Declining => -10.0,
+                ProgressionTrend::Insufficient => 0.0,
+            };
         }
 
         Ok(alignment_score.clamp(0.0, 100.0))
@@ -1390,6 +3484,12 @@ impl AdvancedScoringEngine {
         industry: &str,
         _experience_level: &str,
     ) -> ExperiencePattern {
+        if let Some(definition) = self.settings.custom_industry(industry) {
+            return ExperiencePattern {
+                industry_keywords: definition.experience_keywords.clone(),
+            };
+        }
+
         let industry_keywords = match industry {
             "technology" => vec![
                 "software",
@@ -1516,6 +3616,10 @@ impl AdvancedScoringEngine {
 
     /// Get preferred degrees for each industry with weights
     fn get_preferred_degrees(&self, industry: &str) -> Vec<(String, f64)> {
+        if let Some(definition) = self.settings.custom_industry(industry) {
+            return definition.preferred_degrees.clone();
+        }
+
         match industry {
             "technology" => vec![
                 ("computer science".to_string(), 20.0),
@@ -1579,9 +3683,27 @@ impl AdvancedScoringEngine {
         // Get industry-specific benchmark data
         let default_industry = IndustryBenchmark::default();
         let industry_data = industry_benchmarks
-            .get(industry)
+            .get(&industry.to_lowercase())
             .unwrap_or(&default_industry);
 
+        // Prefer the distribution of the user's own stored analyses once there are enough
+        let stored_distribution = match self
+            .db
+            .lock()
+            .await
+            .compute_industry_distribution(industry)
+            .await
+        {
+            Ok(distribution) => distribution,
+            Err(e) => {
+                warn!("Falling back to built-in {} benchmarks: {}", industry, e);
+                None
+            }
+        };
+        let industry_distribution = stored_distribution
+            .as_deref()
+            .unwrap_or(&industry_data.score_distribution);
+
         // Get experience-level-specific benchmark data
         let default_experience = ExperienceLevelBenchmark::default();
         let experience_data = experience_benchmarks
@@ -1589,8 +3711,7 @@ impl AdvancedScoringEngine {
             .unwrap_or(&default_experience);
 
         // Calculate percentiles
-        let industry_percentile =
-            self.calculate_percentile(current_score, &industry_data.score_distribution);
+        let industry_percentile = self.calculate_percentile(current_score, industry_distribution);
         let experience_level_percentile =
             self.calculate_percentile(current_score, &experience_data.score_distribution);
 
@@ -1613,6 +3734,52 @@ impl AdvancedScoringEngine {
         })
     }
 
+    /// Full benchmark for an industry and experience level. Industry figures come from the
+    /// stored analyses for the industry once there are enough, else from the built-in benchmarks
+    pub async fn industry_benchmark(
+        &self,
+        industry: &str,
+        experience_level: &str,
+    ) -> Result<IndustryBenchmarkReport> {
+        let industry_data = self
+            .build_industry_benchmarks()
+            .remove(&industry.to_lowercase())
+            .unwrap_or_default();
+        let experience_data = self
+            .build_experience_level_benchmarks()
+            .remove(experience_level)
+            .unwrap_or_default();
+
+        let mut scores = self.db.lock().await.get_industry_scores(industry).await?;
+        let data_driven = scores.len() >= MIN_DISTRIBUTION_SAMPLES;
+
+        let mut report = IndustryBenchmarkReport {
+            industry: industry.to_string(),
+            experience_level: experience_level.to_string(),
+            data_driven,
+            sample_size: scores.len(),
+            score_distribution: industry_data.score_distribution,
+            average_score: industry_data.average_score,
+            median_score: industry_data.median_score,
+            top_10_percent_score: industry_data.top_10_percent_score,
+            bottom_10_percent_score: industry_data.bottom_10_percent_score,
+            experience_level_distribution: experience_data.score_distribution,
+            experience_level_average: experience_data.average_score,
+            experience_level_median: experience_data.median_score,
+        };
+
+        if data_driven {
+            scores.sort_by(|a, b| a.total_cmp(b));
+            report.score_distribution = score_distribution(&scores);
+            report.average_score = scores.iter().sum::<f64>() / scores.len() as f64;
+            report.median_score = nearest_rank(&scores, 50.0);
+            report.top_10_percent_score = nearest_rank(&scores, 90.0);
+            report.bottom_10_percent_score = nearest_rank(&scores, 10.0);
+        }
+
+        Ok(report)
+    }
+
     /// Build industry-specific benchmarks
     fn build_industry_benchmarks(&self) -> HashMap<String, IndustryBenchmark> {
         let mut benchmarks = HashMap::new();
@@ -1732,6 +3899,12 @@ impl AdvancedScoringEngine {
             },
         );
 
+        for (name, definition) in &self.settings.custom_industries {
+            if let Some(benchmark) = &definition.benchmark {
+                benchmarks.insert(name.clone(), benchmark.clone());
+            }
+        }
+
         benchmarks
     }
 
@@ -1854,13 +4027,17 @@ impl AdvancedScoringEngine {
         distribution.last().map(|(_, p)| *p).unwrap_or(95.0)
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn generate_optimization_suggestions(
         &self,
+        resume_content: &str,
         parsed_resume: &ParsedResume,
         keyword_analysis: &KeywordMatch,
         format_analysis: &FormatAnalysis,
         job_description: &str,
         industry: &str,
+        experience_level: &str,
+        limits: &SuggestionLimits,
     ) -> Result<Vec<OptimizationSuggestion>> {
         let mut suggestions = Vec::new();
 
@@ -1872,7 +4049,10 @@ impl AdvancedScoringEngine {
         // Get industry-specific recommendations
         let industry_db = self.build_industry_keyword_database();
         let empty_map = HashMap::new();
-        let industry_keywords = industry_db.get(industry).unwrap_or(&empty_map);
+        let industry_keywords = industry_db
+            .get(&industry.to_lowercase())
+            .unwrap_or(&empty_map);
+        let market_demand = self.market_demand_scores().await;
 
         // Generate keyword optimization suggestions
         suggestions.extend(self.generate_keyword_suggestions(
@@ -1880,13 +4060,19 @@ impl AdvancedScoringEngine {
             keyword_analysis,
             &target_keywords,
             industry_keywords,
+            &market_demand,
         )?);
 
         // Generate format optimization suggestions
         suggestions.extend(self.generate_format_suggestions(parsed_resume, format_analysis)?);
 
         // Generate section optimization suggestions
-        suggestions.extend(self.generate_section_suggestions(parsed_resume, industry)?);
+        suggestions.extend(self.generate_section_suggestions(
+            parsed_resume,
+            industry,
+            &target_keywords,
+        )?);
+        suggestions.extend(self.section_order_suggestion(resume_content, experience_level));
 
         // Generate content optimization suggestions
         suggestions.extend(self.generate_content_suggestions(
@@ -1894,19 +4080,27 @@ impl AdvancedScoringEngine {
             &target_keywords,
             industry,
         )?);
+        suggestions.extend(self.bullet_balance_suggestion(&BulletBalance::analyze(
+            &parsed_resume.experience,
+            Utc::now().date_naive(),
+        )));
+        suggestions.extend(
+            self.experience_date_suggestion(&check_experience_dates(&parsed_resume.experience)),
+        );
+        suggestions.extend(self.certification_expiry_suggestion(
+            &parsed_resume.certifications,
+            Utc::now().date_naive(),
+            self.settings.cert_expiry_warning_days,
+        ));
 
         // Generate ATS-specific suggestions
         suggestions.extend(self.generate_ats_suggestions(parsed_resume, format_analysis)?);
 
-        // Sort by impact score (highest first)
-        suggestions.sort_by(|a, b| {
-            b.impact_score
-                .partial_cmp(&a.impact_score)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
-
-        // Take top 15 suggestions to avoid overwhelming the user
-        suggestions.truncate(15);
+        limits.apply(&mut suggestions);
+        for suggestion in &mut suggestions {
+            suggestion.id =
+                OptimizationSuggestion::stable_id(&suggestion.category, &suggestion.title);
+        }
 
         Ok(suggestions)
     }
@@ -1918,36 +4112,41 @@ impl AdvancedScoringEngine {
         keyword_analysis: &KeywordMatch,
         target_keywords: &[String],
         industry_keywords: &HashMap<String, f64>,
+        market_demand: &HashMap<String, f64>,
     ) -> Result<Vec<OptimizationSuggestion>> {
         let mut suggestions = Vec::new();
 
         // Find missing high-value keywords
         let resume_text = self.get_resume_text(parsed_resume);
-        let missing_keywords =
-            self.find_missing_keywords(&resume_text, target_keywords, industry_keywords);
+        let missing_keywords = self.find_missing_keywords(
+            &resume_text,
+            target_keywords,
+            industry_keywords,
+            market_demand,
+        );
 
         // Suggest adding missing keywords
         for (keyword, importance) in missing_keywords.iter().take(5) {
+            let placements = self.keyword_analyzer.plan_keyword_placement(keyword);
+            let best = &placements[0];
             let suggestion = OptimizationSuggestion {
                 category: "Keywords".to_string(),
                 title: format!("Add '{}' keyword", keyword),
-                description: "This keyword appears in the job description and is highly valued in your industry. Consider adding it to your skills section or work experience descriptions.".to_string(),
+                description: format!("This keyword appears in the job description and is highly valued in your industry. It fits best in your {} section.", best.section.to_lowercase()),
                 impact_score: importance * 20.0,
                 difficulty: if parsed_resume.skills.is_empty() { "Medium".to_string() } else { "Easy".to_string() },
-                specific_actions: vec![
-                    SuggestionAction {
-                        action: format!("Add '{}' to your skills section", keyword),
-                        section: "Skills".to_string(),
-                        reasoning: "Skills section is the most direct place for keyword inclusion".to_string(),
-                    },
-                    SuggestionAction {
-                        action: format!("Incorporate '{}' into a work experience description", keyword),
-                        section: "Experience".to_string(),
-                        reasoning: "Contextual keyword usage in experience shows practical application".to_string(),
-                    },
-                ],
-                before_example: "Skills: Java, Python, SQL".to_string(),
-                after_example: format!("Skills: Java, Python, SQL, {}", keyword),
+                before_example: if best.section == "Skills" {
+                    "Skills: Python, SQL".to_string()
+                } else {
+                    String::new()
+                },
+                after_example: best
+                    .placement
+                    .as_ref()
+                    .map(|placement| placement.example.clone())
+                    .unwrap_or_default(),
+                specific_actions: placements,
+                ..Default::default()
             };
             suggestions.push(suggestion);
         }
@@ -1965,15 +4164,47 @@ impl AdvancedScoringEngine {
                         action: "Rewrite job descriptions to include more relevant keywords".to_string(),
                         section: "Experience".to_string(),
                         reasoning: "Natural keyword integration improves ATS parsing and relevance".to_string(),
+                        placement: None,
                     },
                     SuggestionAction {
                         action: "Add a 'Core Competencies' section with key skills".to_string(),
                         section: "Skills".to_string(),
                         reasoning: "Dedicated skills section increases keyword density effectively".to_string(),
+                        placement: None,
                     },
                 ],
                 before_example: "Worked on software projects".to_string(),
                 after_example: "Developed Python applications using React frontend and PostgreSQL database".to_string(),
+                ..Default::default()
+            };
+            suggestions.push(suggestion);
+        }
+
+        // Warn about keyword-stuffed sections
+        for section in &keyword_analysis.stuffed_sections {
+            let suggestion = OptimizationSuggestion {
+                category: "Keywords".to_string(),
+                title: format!("Reduce keyword stuffing in {}", section),
+                description: format!("Your {} section packs keywords too densely. Modern ATS systems penalize keyword stuffing and recruiters find it hard to read.", section),
+                impact_score: 80.0,
+                difficulty: "Easy".to_string(),
+                specific_actions: vec![
+                    SuggestionAction {
+                        action: format!("List each skill once in your {} section", section),
+                        section: section.clone(),
+                        reasoning: "Repeating a keyword does not increase its ATS weight but does trigger stuffing filters".to_string(),
+                        placement: None,
+                    },
+                    SuggestionAction {
+                        action: "Demonstrate key skills in experience bullets instead".to_string(),
+                        section: "Experience".to_string(),
+                        reasoning: "Keywords used in context carry more weight than bare lists".to_string(),
+                        placement: None,
+                    },
+                ],
+                before_example: "Skills: Python, Python developer, Python scripting, Python, SQL, SQL queries".to_string(),
+                after_example: "Skills: Python, SQL, Docker".to_string(),
+                ..Default::default()
             };
             suggestions.push(suggestion);
         }
@@ -1991,15 +4222,18 @@ impl AdvancedScoringEngine {
                         action: "Add a professional summary with key keywords".to_string(),
                         section: "Summary".to_string(),
                         reasoning: "Summary section is often the first section ATS systems parse".to_string(),
+                        placement: None,
                     },
                     SuggestionAction {
                         action: "Use keywords in job titles and descriptions".to_string(),
                         section: "Experience".to_string(),
                         reasoning: "Keywords in job titles and descriptions have high ATS weight".to_string(),
+                        placement: None,
                     },
                 ],
                 before_example: "Summary: Experienced professional with strong background".to_string(),
                 after_example: "Summary: Senior Software Engineer with 5+ years Python, React, and AWS experience".to_string(),
+                ..Default::default()
             };
             suggestions.push(suggestion);
         }
@@ -2028,15 +4262,18 @@ impl AdvancedScoringEngine {
                         action: "Use standard section headers (Experience, Education, Skills)".to_string(),
                         section: "Format".to_string(),
                         reasoning: "ATS systems are trained to recognize standard section headers".to_string(),
+                        placement: None,
                     },
                     SuggestionAction {
                         action: "Remove tables, columns, and complex formatting".to_string(),
                         section: "Format".to_string(),
                         reasoning: "Complex formatting can confuse ATS parsing algorithms".to_string(),
+                        placement: None,
                     },
                 ],
                 before_example: "║ PROFESSIONAL BACKGROUND ║".to_string(),
                 after_example: "EXPERIENCE".to_string(),
+                ..Default::default()
             };
             suggestions.push(suggestion);
         }
@@ -2054,15 +4291,18 @@ impl AdvancedScoringEngine {
                         action: "Change font to Arial, Calibri, or Times New Roman".to_string(),
                         section: "Format".to_string(),
                         reasoning: "These fonts are universally recognized by ATS systems".to_string(),
+                        placement: None,
                     },
                     SuggestionAction {
                         action: "Use font sizes between 10-12 points".to_string(),
                         section: "Format".to_string(),
                         reasoning: "Standard font sizes ensure proper text recognition".to_string(),
+                        placement: None,
                     },
                 ],
                 before_example: "Using decorative or script fonts".to_string(),
                 after_example: "Using Arial 11pt for body text".to_string(),
+                ..Default::default()
             };
             suggestions.push(suggestion);
         }
@@ -2080,15 +4320,18 @@ impl AdvancedScoringEngine {
                         action: "Convert to single-column layout".to_string(),
                         section: "Format".to_string(),
                         reasoning: "Single-column layouts are parsed most reliably by ATS systems".to_string(),
+                        placement: None,
                     },
                     SuggestionAction {
                         action: "Use consistent formatting for similar elements".to_string(),
                         section: "Format".to_string(),
                         reasoning: "Consistency helps ATS systems identify patterns and structure".to_string(),
+                        placement: None,
                     },
                 ],
                 before_example: "Two-column layout with sidebar".to_string(),
                 after_example: "Single-column layout with clear sections".to_string(),
+                ..Default::default()
             };
             suggestions.push(suggestion);
         }
@@ -2097,15 +4340,259 @@ impl AdvancedScoringEngine {
     }
 
     /// Generate section-related optimization suggestions
+    /// Flag a summary that is too short or long, or that leaves out years of experience, the
+    /// target role, or the job's keywords
+    fn summary_quality_suggestion(
+        &self,
+        summary: &str,
+        target_keywords: &[String],
+    ) -> Option<OptimizationSuggestion> {
+        let summary_lower = summary.to_lowercase();
+        let word_count = summary.split_whitespace().count();
+        let mut actions = Vec::new();
+        let mut action = |action: String, reasoning: &str| {
+            actions.push(SuggestionAction {
+                action,
+                section: "Summary".to_string(),
+                reasoning: reasoning.to_string(),
+                placement: None,
+            })
+        };
+
+        if word_count < SUMMARY_MIN_WORDS {
+            action(
+                format!(
+                    "Expand the summary from {} to at least {} words",
+                    word_count, SUMMARY_MIN_WORDS
+                ),
+                "A one-line summary gives ATS systems and recruiters little to go on",
+            );
+        } else if word_count > SUMMARY_MAX_WORDS {
+            action(
+                format!(
+                    "Trim the summary from {} to under {} words",
+                    word_count, SUMMARY_MAX_WORDS
+                ),
+                "Long summaries get skimmed past; keep it to 2-4 sentences",
+            );
+        }
+        if !YEARS_OF_EXPERIENCE.is_match(&summary_lower) {
+            action(
+                "State your years of experience (e.g. '6+ years')".to_string(),
+                "Experience length is one of the first things recruiters screen for",
+            );
+        }
+        if !SUMMARY_ROLE_TITLES
+            .iter()
+            .any(|title| contains_word(&summary_lower, title))
+        {
+            action(
+                "Name the role you are targeting (e.g. 'Backend Engineer')".to_string(),
+                "A job title in the summary matches the title recruiters search for",
+            );
+        }
+        let missing_keywords: Vec<&String> = target_keywords
+            .iter()
+            .filter(|keyword| !contains_word(&summary_lower, &keyword.to_lowercase()))
+            .collect();
+        let matched_keywords = target_keywords.len() - missing_keywords.len();
+        if !target_keywords.is_empty()
+            && matched_keywords < SUMMARY_MIN_KEYWORDS.min(target_keywords.len())
+        {
+            let examples: Vec<&str> = missing_keywords
+                .iter()
+                .take(3)
+                .map(|keyword| keyword.as_str())
+                .collect();
+            action(
+                format!(
+                    "Work in keywords from the job description, such as {}",
+                    examples.join(", ")
+                ),
+                "ATS systems weight keywords near the top of the resume more heavily",
+            );
+        }
+
+        if actions.is_empty() {
+            return None;
+        }
+        Some(OptimizationSuggestion {
+            category: "Sections".to_string(),
+            title: "Strengthen professional summary".to_string(),
+            description: "Your summary is present but generic. A strong summary names your role, your experience and the skills this job asks for.".to_string(),
+            impact_score: 70.0 + 5.0 * actions.len() as f64,
+            difficulty: "Easy".to_string(),
+            specific_actions: actions,
+            before_example: summary.trim().to_string(),
+            after_example: "Senior Software Engineer with 7+ years building Python and AWS services for high-traffic platforms".to_string(),
+            ..Default::default()
+        })
+    }
+
+    /// Suggestion to add a section the role's [`SectionPolicy`] expects
+    /// Advice to move sections into the conventional order for the experience level, when the
+    /// resume's order strays far enough from it
+    fn section_order_suggestion(
+        &self,
+        resume_content: &str,
+        experience_level: &str,
+    ) -> Option<OptimizationSuggestion> {
+        let conventional = conventional_section_order(experience_level);
+        // Only the sections both orders share can be compared
+        let detected: Vec<&str> =
+            detect_section_order(resume_content, &self.settings.section_header_synonyms)
+                .into_iter()
+                .filter_map(|(section, _)| {
+                    conventional
+                        .iter()
+                        .find(|name| name.eq_ignore_ascii_case(&section))
+                        .copied()
+                })
+                .collect();
+        if detected.len() < 2 {
+            return None;
+        }
+
+        let rank = |section: &str| conventional.iter().position(|name| *name == section);
+        let pairs = detected.len() * (detected.len() - 1) / 2;
+        let out_of_order = detected
+            .iter()
+            .enumerate()
+            .flat_map(|(i, earlier)| {
+                detected[i + 1..]
+                    .iter()
+                    .map(move |later| (*earlier, *later))
+            })
+            .filter(|&(earlier, later)| rank(earlier) > rank(later))
+            .count();
+        if (out_of_order as f64 / pairs as f64) < SECTION_ORDER_MAX_INVERSION_RATIO {
+            return None;
+        }
+
+        let mut expected = detected.clone();
+        expected.sort_by_key(|section| rank(section));
+        let moves = expected
+            .iter()
+            .zip(&detected)
+            .filter(|(expected, detected)| expected != detected)
+            .map(|(section, _)| SuggestionAction {
+                action: format!(
+                    "Move {} to position {} of {}",
+                    section,
+                    expected.iter().position(|s| s == section).unwrap_or(0) + 1,
+                    expected.len()
+                ),
+                section: section.to_string(),
+                reasoning: format!(
+                    "{} resumes conventionally follow {}",
+                    experience_level,
+                    conventional.join(" → ")
+                ),
+                placement: None,
+            })
+            .collect();
+
+        Some(OptimizationSuggestion {
+            category: "Sections".to_string(),
+            title: "Reorder resume sections".to_string(),
+            description: format!(
+                "Recruiters and ATS parsers expect sections in a conventional order for {} candidates. {} of {} section pairs are out of order.",
+                experience_level, out_of_order, pairs
+            ),
+            impact_score: 60.0,
+            difficulty: "Easy".to_string(),
+            specific_actions: moves,
+            before_example: detected.join(" → "),
+            after_example: expected.join(" → "),
+            ..Default::default()
+        })
+    }
+
+    fn missing_section_suggestion(
+        &self,
+        section: &str,
+        industry: &str,
+        required: bool,
+    ) -> OptimizationSuggestion {
+        let mut suggestion = match section.to_lowercase().as_str() {
+            "projects" => OptimizationSuggestion {
+                title: "Add technical projects section".to_string(),
+                description: format!("For {} roles, a projects section showcases your technical skills and experience with specific technologies.", industry),
+                impact_score: 75.0,
+                difficulty: "Medium".to_string(),
+                specific_actions: vec![
+                    SuggestionAction {
+                        action: "Add a 'Projects' or 'Technical Projects' section".to_string(),
+                        section: "Projects".to_string(),
+                        reasoning: format!("Projects section is highly valued in {} roles", industry),
+                        placement: None,
+                    },
+                    SuggestionAction {
+                        action: "Include 2-3 relevant projects with technologies used".to_string(),
+                        section: "Projects".to_string(),
+                        reasoning: "Specific project details demonstrate practical skills".to_string(),
+                        placement: None,
+                    },
+                ],
+                before_example: "Only Experience and Education sections".to_string(),
+                after_example: "Added Projects section with E-commerce Platform (React, Node.js, MongoDB)".to_string(),
+                ..Default::default()
+            },
+            "certifications" => OptimizationSuggestion {
+                title: "Add certifications section".to_string(),
+                description: format!("Employers in {} value certifications. Add a section for the licenses and certifications relevant to the role.", industry),
+                impact_score: 70.0,
+                difficulty: "Easy".to_string(),
+                specific_actions: vec![SuggestionAction {
+                    action: "Add 'Certifications' section".to_string(),
+                    section: "Certifications".to_string(),
+                    reasoning: format!("Certifications are highly valued in {} roles", industry),
+                    placement: None,
+                }],
+                before_example: "No certifications mentioned".to_string(),
+                after_example: "Certifications: CFA Level II Candidate, FRM Part I".to_string(),
+                ..Default::default()
+            },
+            _ => OptimizationSuggestion {
+                title: format!("Add {} section", section.to_lowercase()),
+                description: format!("Resumes for {} roles are expected to include a {} section.", industry, section),
+                impact_score: 65.0,
+                difficulty: "Medium".to_string(),
+                specific_actions: vec![SuggestionAction {
+                    action: format!("Add a '{}' section", section),
+                    section: section.to_string(),
+                    reasoning: format!("Recruiters for {} roles look for this section", industry),
+                    placement: None,
+                }],
+                before_example: format!("No {} section", section),
+                after_example: format!("Added a {} section listing the relevant entries", section),
+                ..Default::default()
+            },
+        };
+        suggestion.category = "Sections".to_string();
+        if required {
+            suggestion.impact_score = REQUIRED_SECTION_IMPACT;
+            suggestion.difficulty = "Medium".to_string();
+            suggestion.description.push_str(&format!(
+                " This section is required for {} roles.",
+                industry
+            ));
+        }
+        suggestion
+    }
+
     fn generate_section_suggestions(
         &self,
         parsed_resume: &ParsedResume,
         industry: &str,
+        target_keywords: &[String],
     ) -> Result<Vec<OptimizationSuggestion>> {
         let mut suggestions = Vec::new();
 
-        // Missing sections suggestions
-        if !parsed_resume.sections.contains_key("Summary") {
+        // Missing sections suggestions, or a summary that is there but says little
+        if let Some(summary) = parsed_resume.sections.get("Summary") {
+            suggestions.extend(self.summary_quality_suggestion(summary, target_keywords));
+        } else {
             let suggestion = OptimizationSuggestion {
                 category: "Sections".to_string(),
                 title: "Add professional summary".to_string(),
@@ -2117,69 +4604,38 @@ impl AdvancedScoringEngine {
                         action: "Write a 2-3 sentence professional summary".to_string(),
                         section: "Summary".to_string(),
                         reasoning: "Summary section is often the first section ATS systems parse".to_string(),
+                        placement: None,
                     },
                     SuggestionAction {
                         action: "Include your years of experience and key skills".to_string(),
                         section: "Summary".to_string(),
                         reasoning: "Key information in summary improves initial ATS scoring".to_string(),
+                        placement: None,
                     },
                 ],
                 before_example: "Resume starts with contact information".to_string(),
                 after_example: "Professional Summary: Senior Software Engineer with 5+ years developing scalable web applications using Python, React, and AWS".to_string(),
+                ..Default::default()
             };
             suggestions.push(suggestion);
         }
 
-        // Industry-specific section suggestions
-        match industry {
-            "technology" => {
-                if !parsed_resume.sections.contains_key("Projects") {
-                    let suggestion = OptimizationSuggestion {
-                        category: "Sections".to_string(),
-                        title: "Add technical projects section".to_string(),
-                        description: "For technology roles, a projects section showcases your technical skills and experience with specific technologies.".to_string(),
-                        impact_score: 75.0,
-                        difficulty: "Medium".to_string(),
-                        specific_actions: vec![
-                            SuggestionAction {
-                                action: "Add a 'Projects' or 'Technical Projects' section".to_string(),
-                                section: "Projects".to_string(),
-                                reasoning: "Projects section is highly valued in technology industry".to_string(),
-                            },
-                            SuggestionAction {
-                                action: "Include 2-3 relevant projects with technologies used".to_string(),
-                                section: "Projects".to_string(),
-                                reasoning: "Specific project details demonstrate practical skills".to_string(),
-                            },
-                        ],
-                        before_example: "Only Experience and Education sections".to_string(),
-                        after_example: "Added Projects section with E-commerce Platform (React, Node.js, MongoDB)".to_string(),
-                    };
-                    suggestions.push(suggestion);
-                }
-            }
-            "finance" => {
-                if !parsed_resume.sections.contains_key("Certifications") {
-                    let suggestion = OptimizationSuggestion {
-                        category: "Sections".to_string(),
-                        title: "Add certifications section".to_string(),
-                        description: "Financial industry values certifications. Add a section for CFA, FRM, or other relevant certifications.".to_string(),
-                        impact_score: 70.0,
-                        difficulty: "Easy".to_string(),
-                        specific_actions: vec![
-                            SuggestionAction {
-                                action: "Add 'Certifications' section".to_string(),
-                                section: "Certifications".to_string(),
-                                reasoning: "Certifications are highly valued in finance industry".to_string(),
-                            },
-                        ],
-                        before_example: "No certifications mentioned".to_string(),
-                        after_example: "Certifications: CFA Level II Candidate, FRM Part I".to_string(),
-                    };
-                    suggestions.push(suggestion);
+        // Sections the role or industry is expected to have
+        if let Some(policy) = self.settings.section_policy(industry) {
+            let expected = policy
+                .required
+                .iter()
+                .map(|section| (section, true))
+                .chain(policy.recommended.iter().map(|section| (section, false)));
+            for (section, required) in expected {
+                let present = parsed_resume
+                    .sections
+                    .keys()
+                    .any(|name| name.eq_ignore_ascii_case(section));
+                if !present {
+                    suggestions.push(self.missing_section_suggestion(section, industry, required));
                 }
             }
-            _ => {}
         }
 
         // Skills section optimization
@@ -2195,15 +4651,18 @@ impl AdvancedScoringEngine {
                         action: "Add 5-10 relevant technical and soft skills".to_string(),
                         section: "Skills".to_string(),
                         reasoning: "Comprehensive skills section improves ATS keyword matching".to_string(),
+                        placement: None,
                     },
                     SuggestionAction {
                         action: "Organize skills into categories (Technical, Tools, Languages)".to_string(),
                         section: "Skills".to_string(),
                         reasoning: "Organized skills are easier for ATS systems to parse".to_string(),
+                        placement: None,
                     },
                 ],
                 before_example: "Skills: Java, Python".to_string(),
                 after_example: "Technical Skills: Java, Python, JavaScript, React, SQL, AWS, Git, Docker".to_string(),
+                ..Default::default()
             };
             suggestions.push(suggestion);
         }
@@ -2211,6 +4670,171 @@ impl AdvancedScoringEngine {
         Ok(suggestions)
     }
 
+    /// Advice to move bullets from an older role that outweighs the most recent one
+    fn bullet_balance_suggestion(&self, balance: &BulletBalance) -> Option<OptimizationSuggestion> {
+        if !balance.imbalanced {
+            return None;
+        }
+        let recent = balance.roles.get(balance.most_recent?)?;
+        let heaviest = balance.roles.get(balance.heaviest?)?;
+        let role_name = |role: &RoleBulletCount| {
+            if role.company.is_empty() {
+                role.title.clone()
+            } else {
+                format!("{} at {}", role.title, role.company)
+            }
+        };
+
+        Some(OptimizationSuggestion {
+            category: "Content".to_string(),
+            title: "Rebalance bullets toward recent roles".to_string(),
+            description: format!(
+                "{} has {} bullet(s) while your most recent role, {}, has {}. Recruiters read recent roles most closely, so they should carry the most detail.",
+                role_name(heaviest),
+                heaviest.bullet_count,
+                role_name(recent),
+                recent.bullet_count
+            ),
+            impact_score: 65.0,
+            difficulty: "Medium".to_string(),
+            specific_actions: vec![
+                SuggestionAction {
+                    action: format!(
+                        "Trim {} to its {} strongest bullets",
+                        role_name(heaviest),
+                        OLDER_ROLE_MAX_BULLETS
+                    ),
+                    section: "Experience".to_string(),
+                    reasoning: "Older roles need only the achievements still relevant to the job"
+                        .to_string(),
+                    placement: None,
+                },
+                SuggestionAction {
+                    action: format!(
+                        "Expand {} with 3-5 quantified achievements",
+                        role_name(recent)
+                    ),
+                    section: "Experience".to_string(),
+                    reasoning: "Current work best shows what you can do in the next role"
+                        .to_string(),
+                    placement: None,
+                },
+            ],
+            before_example: format!(
+                "{}: {} bullets; {}: {} bullet(s)",
+                role_name(heaviest),
+                heaviest.bullet_count,
+                role_name(recent),
+                recent.bullet_count
+            ),
+            after_example: format!(
+                "{}: {} bullets; {}: 4-5 bullets",
+                role_name(heaviest),
+                OLDER_ROLE_MAX_BULLETS,
+                role_name(recent)
+            ),
+            ..Default::default()
+        })
+    }
+
+    fn experience_date_suggestion(
+        &self,
+        issues: &[ExperienceDateIssue],
+    ) -> Option<OptimizationSuggestion> {
+        let first = issues.first()?;
+        Some(OptimizationSuggestion {
+            category: "Format".to_string(),
+            title: "Add readable dates to every role".to_string(),
+            description: format!(
+                "{} experience entr{} lack a readable date range. ATS systems compute years of experience from role dates and may skip undated roles entirely.",
+                issues.len(),
+                if issues.len() == 1 { "y" } else { "ies" }
+            ),
+            impact_score: 70.0,
+            difficulty: "Easy".to_string(),
+            specific_actions: issues
+                .iter()
+                .map(|issue| SuggestionAction {
+                    action: issue.suggestion.clone(),
+                    section: "Experience".to_string(),
+                    reasoning: "Each role needs a start and end date in a standard format"
+                        .to_string(),
+                    placement: None,
+                })
+                .collect(),
+            before_example: if first.duration.is_empty() {
+                first.title.clone()
+            } else {
+                format!("{} | {}", first.title, first.duration)
+            },
+            after_example: format!("{} | Jan 2020 - Present", first.title),
+            ..Default::default()
+        })
+    }
+
+    fn certification_expiry_suggestion(
+        &self,
+        certifications: &[CertificationEntry],
+        today: NaiveDate,
+        warning_days: u32,
+    ) -> Option<OptimizationSuggestion> {
+        let horizon = today + chrono::Duration::days(i64::from(warning_days));
+        let lapsing: Vec<(&CertificationEntry, NaiveDate)> = certifications
+            .iter()
+            .filter_map(|cert| cert.expiry_date().map(|expiry| (cert, expiry)))
+            .filter(|(_, expiry)| *expiry <= horizon)
+            .collect();
+        let (first, first_expiry) = lapsing.first().copied()?;
+        let expired = lapsing.iter().filter(|(_, expiry)| *expiry < today).count();
+
+        Some(OptimizationSuggestion {
+            category: "Sections".to_string(),
+            title: "Renew or remove lapsing certifications".to_string(),
+            description: format!(
+                "{} certification{} expired or expire{} within {} days. Recruiters and ATS filters treat a lapsed credential as missing, and listing one can read as out of date.",
+                lapsing.len(),
+                if lapsing.len() == 1 { " has" } else { "s have" },
+                if lapsing.len() == 1 { "s" } else { "" },
+                warning_days
+            ),
+            impact_score: if expired > 0 { 90.0 } else { 85.0 },
+            difficulty: "Medium".to_string(),
+            specific_actions: lapsing
+                .iter()
+                .map(|(cert, expiry)| SuggestionAction {
+                    action: if *expiry < today {
+                        format!(
+                            "{} expired {}; renew it or remove it from the resume",
+                            cert.name,
+                            cert.expiry.as_deref().unwrap_or_default()
+                        )
+                    } else {
+                        format!(
+                            "{} expires {}; renew it and update the expiry date",
+                            cert.name,
+                            cert.expiry.as_deref().unwrap_or_default()
+                        )
+                    },
+                    section: "Certifications".to_string(),
+                    reasoning: "Only current certifications count toward required credentials"
+                        .to_string(),
+                    placement: None,
+                })
+                .collect(),
+            before_example: format!(
+                "{} (Expires {})",
+                first.name,
+                first.expiry.as_deref().unwrap_or_default()
+            ),
+            after_example: format!(
+                "{} (Expires {})",
+                first.name,
+                first_expiry.with_year(first_expiry.year() + 3).unwrap_or(first_expiry).format("%b %Y")
+            ),
+            ..Default::default()
+        })
+    }
+
     /// Generate content-related optimization suggestions
     fn generate_content_suggestions(
         &self,
@@ -2235,9 +4859,11 @@ impl AdvancedScoringEngine {
                     section: "Experience".to_string(),
                     reasoning: "Experience section is crucial for ATS systems and recruiters"
                         .to_string(),
+                    placement: None,
                 }],
                 before_example: "No experience section".to_string(),
                 after_example: "Experience: Software Engineer at Tech Corp (2020-2023)".to_string(),
+                ..Default::default()
             };
             suggestions.push(suggestion);
         } else {
@@ -2258,15 +4884,18 @@ impl AdvancedScoringEngine {
                             action: "Add 2-3 bullet points with quantified results for each role".to_string(),
                             section: "Experience".to_string(),
                             reasoning: "Quantified achievements demonstrate concrete value and impact".to_string(),
+                            placement: None,
                         },
                         SuggestionAction {
                             action: "Use action verbs and include numbers, percentages, or metrics".to_string(),
                             section: "Experience".to_string(),
                             reasoning: "Action verbs and metrics make achievements more compelling".to_string(),
+                            placement: None,
                         },
                     ],
                     before_example: "Worked on software development projects".to_string(),
                     after_example: "• Developed 5 web applications using React and Node.js, increasing user engagement by 25%".to_string(),
+                    ..Default::default()
                 };
                 suggestions.push(suggestion);
             }
@@ -2285,10 +4914,12 @@ impl AdvancedScoringEngine {
                         action: "Add degree, institution, and graduation year".to_string(),
                         section: "Education".to_string(),
                         reasoning: "Education section is required by most ATS systems".to_string(),
+                        placement: None,
                     },
                 ],
                 before_example: "No education section".to_string(),
                 after_example: "Education: Bachelor of Science in Computer Science, University of Technology, 2020".to_string(),
+                ..Default::default()
             };
             suggestions.push(suggestion);
         }
@@ -2310,10 +4941,12 @@ impl AdvancedScoringEngine {
                             action: "Add GitHub profile link to contact information".to_string(),
                             section: "Contact".to_string(),
                             reasoning: "GitHub profile demonstrates coding skills and project experience".to_string(),
+                            placement: None,
                         },
                     ],
                     before_example: "Contact: email@example.com, (555) 123-4567".to_string(),
                     after_example: "Contact: email@example.com, (555) 123-4567, github.com/username".to_string(),
+                    ..Default::default()
                 };
                 suggestions.push(suggestion);
             }
@@ -2342,9 +4975,11 @@ impl AdvancedScoringEngine {
                 action: "Save resume as PDF (preferred) or Word document".to_string(),
                 section: "Format".to_string(),
                 reasoning: "PDF preserves formatting while remaining ATS-readable".to_string(),
+                placement: None,
             }],
             before_example: "Resume saved as image or uncommon format".to_string(),
             after_example: "Resume saved as PDF with proper text encoding".to_string(),
+            ..Default::default()
         };
         suggestions.push(suggestion);
 
@@ -2361,15 +4996,18 @@ impl AdvancedScoringEngine {
                         action: "Remove headers, footers, and complex formatting elements".to_string(),
                         section: "Format".to_string(),
                         reasoning: "Simple formatting ensures reliable ATS parsing".to_string(),
+                        placement: None,
                     },
                     SuggestionAction {
                         action: "Use standard bullet points instead of custom symbols".to_string(),
                         section: "Format".to_string(),
                         reasoning: "Standard bullet points are universally recognized".to_string(),
+                        placement: None,
                     },
                 ],
                 before_example: "Using complex formatting with headers/footers".to_string(),
                 after_example: "Clean, simple formatting with standard elements".to_string(),
+                ..Default::default()
             };
             suggestions.push(suggestion);
         }
@@ -2377,12 +5015,26 @@ impl AdvancedScoringEngine {
         Ok(suggestions)
     }
 
-    /// Find missing keywords by comparing resume content with target keywords
+    /// Demand scores from the dynamic keyword database; empty when no market data has been
+    /// collected yet
+    async fn market_demand_scores(&self) -> HashMap<String, f64> {
+        let db = self.db.lock().await;
+        crate::dynamic_keyword_db::DynamicKeywordDatabase::load_market_demand_scores(&db)
+            .await
+            .unwrap_or_else(|e| {
+                debug!("No market demand data available: {}", e);
+                HashMap::new()
+            })
+    }
+
+    /// Find missing keywords by comparing resume content with target keywords, ranked by
+    /// industry importance blended with current market demand
     fn find_missing_keywords(
         &self,
         resume_text: &str,
         target_keywords: &[String],
         industry_keywords: &HashMap<String, f64>,
+        market_demand: &HashMap<String, f64>,
     ) -> Vec<(String, f64)> {
         let mut missing_keywords = Vec::new();
         let resume_lower = resume_text.to_lowercase();
@@ -2406,8 +5058,21 @@ impl AdvancedScoringEngine {
             }
         }
 
-        // Sort by importance
-        missing_keywords.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        for (keyword, importance) in missing_keywords.iter_mut() {
+            let demand = market_demand
+                .get(&keyword.to_lowercase())
+                .copied()
+                .unwrap_or(NEUTRAL_MARKET_DEMAND);
+            *importance *= 1.0 - MARKET_DEMAND_BLEND
+                + MARKET_DEMAND_BLEND * demand.clamp(0.0, 1.0) / NEUTRAL_MARKET_DEMAND;
+        }
+
+        // Sort by importance, breaking ties by name so hash map iteration order can't leak out
+        missing_keywords.sort_by(|a, b| {
+            b.1.partial_cmp(&a.1)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.0.cmp(&b.0))
+        });
 
         missing_keywords
     }
@@ -2454,19 +5119,101 @@ impl AdvancedScoringEngine {
     }
 }
 
+/// Output of the four keyword matchers
+#[derive(Debug, PartialEq)]
+struct MatcherResults {
+    exact_matches: Vec<MatchResult>,
+    stemmed_matches: Vec<MatchResult>,
+    contextual_matches: Vec<MatchResult>,
+    synonym_matches: Vec<MatchResult>,
+}
+
+impl MatcherResults {
+    /// Set each match's position factor from where its span starts in the resume
+    fn apply_position_weighting(
+        &mut self,
+        resume_content: &str,
+        weighting: &KeywordPositionWeighting,
+    ) {
+        let length = resume_content.chars().count();
+
+        let typed_matches = [
+            &mut self.exact_matches,
+            &mut self.stemmed_matches,
+            &mut self.contextual_matches,
+            &mut self.synonym_matches,
+        ];
+        for matches in typed_matches {
+            for result in matches.iter_mut() {
+                let relative_position = if length > 1 {
+                    result.start as f64 / (length - 1) as f64
+                } else {
+                    0.0
+                };
+                result.position_factor = weighting.factor(relative_position, &result.section);
+            }
+        }
+    }
+}
+
+fn spawn_matcher<F>(
+    content: &Arc<str>,
+    keywords: &Arc<[String]>,
+    matcher: F,
+) -> tokio::task::JoinHandle<Result<Vec<MatchResult>>>
+where
+    F: FnOnce(&str, &[String]) -> Result<Vec<MatchResult>> + Send + 'static,
+{
+    let content = Arc::clone(content);
+    let keywords = Arc::clone(keywords);
+    tokio::task::spawn_blocking(move || matcher(&content, &keywords))
+}
+
 impl Default for KeywordAnalyzer {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl KeywordAnalyzer {
-    pub fn new() -> Self {
+/// Keyword analyses kept for re-analysis of the same resume and job
+const KEYWORD_MATCH_CACHE_CAPACITY: usize = 32;
+
+static KEYWORD_MATCH_CACHE: Lazy<std::sync::Mutex<GenerationCache<KeywordMatch>>> =
+    Lazy::new(|| std::sync::Mutex::new(GenerationCache::new(KEYWORD_MATCH_CACHE_CAPACITY)));
+
+fn keyword_match_cache_key(
+    resume_content: &str,
+    job_description: &str,
+    industry: &str,
+    profile: &ScoringProfile,
+    settings: &ScoringSettings,
+) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    resume_content.hash(&mut hasher);
+    job_description.hash(&mut hasher);
+    industry.to_lowercase().hash(&mut hasher);
+    profile.penalty_multiplier.to_bits().hash(&mut hasher);
+    profile.threshold_multiplier.to_bits().hash(&mut hasher);
+    settings.hash_keyword_inputs(&mut hasher);
+    hasher.finish()
+}
+
+/// Shared action verb lists used to recognize accomplishment keywords
+static ACHIEVEMENT_ANALYZER: Lazy<AchievementAnalyzer> = Lazy::new(AchievementAnalyzer::new);
+
+impl KeywordAnalyzer {
+    /// Analyzer matching with the built-in settings
+    pub fn new() -> Self {
+        Self::with_settings(Arc::new(ScoringSettings::default()))
+    }
+
+    pub fn with_settings(settings: Arc<ScoringSettings>) -> Self {
         Self {
             exact_matcher: ExactMatcher,
             stemmed_matcher: StemmedMatcher,
             contextual_matcher: ContextualMatcher,
             synonym_matcher: SynonymMatcher,
+            settings,
         }
     }
 
@@ -2475,6 +5222,57 @@ impl KeywordAnalyzer {
         resume_content: &str,
         job_description: &str,
         industry: &str,
+    ) -> Result<KeywordMatch> {
+        self.analyze_with_profile(
+            resume_content,
+            job_description,
+            industry,
+            &self.settings.default_scoring_profile(),
+        )
+        .await
+    }
+
+    /// Keyword analysis under `profile`, reused while the same resume, job and industry are
+    /// analyzed again under unchanged config
+    pub async fn analyze_with_profile(
+        &self,
+        resume_content: &str,
+        job_description: &str,
+        industry: &str,
+        profile: &ScoringProfile,
+    ) -> Result<KeywordMatch> {
+        let key = keyword_match_cache_key(
+            resume_content,
+            job_description,
+            industry,
+            profile,
+            &self.settings,
+        );
+        let cached = KEYWORD_MATCH_CACHE
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(key);
+        if let Some(keyword_match) = cached {
+            return Ok(keyword_match);
+        }
+        let generation = cache_generation();
+
+        let keyword_match = self
+            .match_keywords(resume_content, job_description, industry, profile)
+            .await?;
+        KEYWORD_MATCH_CACHE
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key, keyword_match.clone(), generation);
+        Ok(keyword_match)
+    }
+
+    async fn match_keywords(
+        &self,
+        resume_content: &str,
+        job_description: &str,
+        industry: &str,
+        profile: &ScoringProfile,
     ) -> Result<KeywordMatch> {
         debug!(
             "Starting comprehensive keyword analysis for {} industry",
@@ -2485,27 +5283,50 @@ impl KeywordAnalyzer {
         let target_keywords = self.extract_keywords_from_job_description(job_description)?;
 
         // Perform different types of matching
-        let exact_matches = self
-            .exact_matcher
-            .find_matches(resume_content, &target_keywords)?;
-        let stemmed_matches = self
-            .stemmed_matcher
-            .find_matches(resume_content, &target_keywords)?;
-        let contextual_matches = self
-            .contextual_matcher
-            .find_matches(resume_content, &target_keywords)?;
-        let synonym_matches = self
-            .synonym_matcher
-            .find_matches(resume_content, &target_keywords)?;
+        let mut results = self
+            .run_matchers_concurrently(resume_content, &target_keywords)
+            .await?;
+        results.apply_position_weighting(resume_content, &self.settings.keyword_position_weighting);
+        let MatcherResults {
+            exact_matches,
+            stemmed_matches,
+            contextual_matches,
+            synonym_matches,
+        } = results;
+
+        // Judge the sentence each match sits in, not just that the keyword is present
+        let context_quality = self.contextual_matcher.keyword_context_quality(
+            resume_content,
+            &[
+                &exact_matches,
+                &stemmed_matches,
+                &contextual_matches,
+                &synonym_matches,
+            ],
+        );
 
         // Calculate overall score
-        let overall_score = self.calculate_overall_keyword_score(
+        let keyword_score_match_cap = KEYWORD_SCORE_MATCH_CAP * profile.threshold_multiplier;
+        let match_type_weights = self.settings.match_type_weights.clone();
+        let keyword_score = self.calculate_overall_keyword_score(
             &exact_matches,
             &stemmed_matches,
             &contextual_matches,
             &synonym_matches,
+            keyword_score_match_cap,
+            &match_type_weights,
         )?;
 
+        // User-defined rules add their weight on top of the job description's keywords
+        let rule_matches = find_rule_matches(
+            resume_content,
+            &self.settings.keyword_rules,
+            self.settings.context_window_words,
+        );
+        let rule_score: f64 = rule_matches.values().flatten().map(|m| m.weight).sum();
+        let overall_score =
+            (keyword_score + rule_score / keyword_score_match_cap * 100.0).min(100.0);
+
         // Calculate match density
         let match_density =
             self.calculate_match_density(resume_content, &exact_matches, &stemmed_matches)?;
@@ -2514,6 +5335,15 @@ impl KeywordAnalyzer {
         let section_distribution =
             self.calculate_section_distribution(&exact_matches, &stemmed_matches)?;
 
+        // Calculate keyword density within each section to spot stuffing
+        let section_density = self.calculate_section_density(resume_content, &target_keywords)?;
+        let stuffed_sections = self.detect_stuffed_sections(
+            resume_content,
+            &target_keywords,
+            &section_density,
+            KEYWORD_STUFFING_DENSITY_THRESHOLD / profile.threshold_multiplier,
+        )?;
+
         Ok(KeywordMatch {
             exact_matches,
             stemmed_matches,
@@ -2522,6 +5352,90 @@ impl KeywordAnalyzer {
             overall_score,
             match_density,
             section_distribution,
+            section_density,
+            stuffed_sections,
+            language: detect_language(resume_content),
+            keyword_score_match_cap,
+            match_type_weights,
+            keyword_occurrences: self
+                .exact_matcher
+                .count_occurrences(resume_content, &target_keywords),
+            rule_matches,
+            context_quality,
+        })
+    }
+
+    /// Run the four matchers in parallel on the blocking thread pool since they're CPU-bound and
+    /// independent. Each matcher keeps its own output order, so the result is identical to
+    /// `run_matchers_sequentially`.
+    async fn run_matchers_concurrently(
+        &self,
+        resume_content: &str,
+        keywords: &[String],
+    ) -> Result<MatcherResults> {
+        let content: Arc<str> = Arc::from(resume_content);
+        let keywords: Arc<[String]> = Arc::from(keywords);
+
+        let exact_matcher = self.exact_matcher;
+        let stemmed_matcher = self.stemmed_matcher;
+        let contextual_matcher = self.contextual_matcher;
+        let synonym_matcher = self.synonym_matcher;
+        let window = self.settings.context_window_words;
+        let min_confidence = self.settings.min_contextual_confidence;
+        let settings = Arc::clone(&self.settings);
+
+        let (exact_matches, stemmed_matches, contextual_matches, synonym_matches) =
+            tokio::try_join!(
+                spawn_matcher(&content, &keywords, move |c, k| exact_matcher
+                    .find_matches_with_context(c, k, window)),
+                spawn_matcher(&content, &keywords, move |c, k| stemmed_matcher
+                    .find_matches_with_context(c, k, window)),
+                spawn_matcher(&content, &keywords, move |c, k| contextual_matcher
+                    .find_matches_with_context(c, k, window, min_confidence)),
+                spawn_matcher(&content, &keywords, move |c, k| synonym_matcher
+                    .find_matches_with_context(c, k, window, &settings.skill_taxonomy)),
+            )
+            .map_err(|e| anyhow!("Keyword matcher task failed: {}", e))?;
+
+        Ok(MatcherResults {
+            exact_matches: exact_matches?,
+            stemmed_matches: stemmed_matches?,
+            contextual_matches: contextual_matches?,
+            synonym_matches: synonym_matches?,
+        })
+    }
+
+    /// Reference path for `run_matchers_concurrently`
+    #[cfg(test)]
+    fn run_matchers_sequentially(
+        &self,
+        resume_content: &str,
+        keywords: &[String],
+    ) -> Result<MatcherResults> {
+        let window = self.settings.context_window_words;
+        Ok(MatcherResults {
+            exact_matches: self.exact_matcher.find_matches_with_context(
+                resume_content,
+                keywords,
+                window,
+            )?,
+            stemmed_matches: self.stemmed_matcher.find_matches_with_context(
+                resume_content,
+                keywords,
+                window,
+            )?,
+            contextual_matches: self.contextual_matcher.find_matches_with_context(
+                resume_content,
+                keywords,
+                window,
+                self.settings.min_contextual_confidence,
+            )?,
+            synonym_matches: self.synonym_matcher.find_matches_with_context(
+                resume_content,
+                keywords,
+                window,
+                &self.settings.skill_taxonomy,
+            )?,
         })
     }
 
@@ -2534,6 +5448,7 @@ impl KeywordAnalyzer {
         // Normalize the job description
         let normalized = job_description.nfc().collect::<String>();
         let text_lower = normalized.to_lowercase();
+        let language = detect_language(&normalized);
 
         // Extract different types of keywords
         keywords.extend(self.extract_technical_skills(&text_lower));
@@ -2545,6 +5460,13 @@ impl KeywordAnalyzer {
         keywords.extend(self.extract_certification_requirements(&text_lower));
         keywords.extend(self.extract_business_keywords(&text_lower));
 
+        // Fold skill variants so "js" and "javascript" are one requirement, not two
+        for keyword in &mut keywords {
+            if let Some(canonical) = self.settings.canonical_skill(keyword) {
+                *keyword = canonical.to_lowercase();
+            }
+        }
+
         // Remove duplicates and sort
         keywords.sort();
         keywords.dedup();
@@ -2556,6 +5478,7 @@ impl KeywordAnalyzer {
                 word.len() >= 2
                     && word.len() <= 50
                     && !self.is_noise_word(word)
+                    && !language.is_stopword(word)
                     && !self.is_common_word(word)
             })
             .collect();
@@ -2563,6 +5486,217 @@ impl KeywordAnalyzer {
         Ok(filtered_keywords)
     }
 
+    /// Keywords from several job descriptions, most widely requested first and then by
+    /// average importance
+    pub fn aggregate_job_keywords(
+        &self,
+        job_descriptions: &[String],
+    ) -> Result<Vec<AggregatedKeyword>> {
+        let mut importances: HashMap<String, Vec<f64>> = HashMap::new();
+
+        for job_description in job_descriptions {
+            let text_lower = job_description.to_lowercase();
+            let mentions: Vec<(String, usize)> = self
+                .extract_keywords_from_job_description(job_description)?
+                .into_iter()
+                .map(|keyword| {
+                    let count = text_lower.matches(keyword.as_str()).count().max(1);
+                    (keyword, count)
+                })
+                .collect();
+            let most_mentions = mentions.iter().map(|(_, count)| *count).max().unwrap_or(1);
+
+            for (keyword, count) in mentions {
+                importances
+                    .entry(keyword)
+                    .or_default()
+                    .push(count as f64 / most_mentions as f64);
+            }
+        }
+
+        let mut aggregated: Vec<AggregatedKeyword> = importances
+            .into_iter()
+            .map(|(keyword, importances)| AggregatedKeyword {
+                keyword,
+                job_count: importances.len(),
+                job_share: importances.len() as f64 / job_descriptions.len() as f64,
+                average_importance: importances.iter().sum::<f64>() / importances.len() as f64,
+            })
+            .collect();
+        aggregated.sort_by(|a, b| {
+            b.job_count
+                .cmp(&a.job_count)
+                .then_with(|| {
+                    b.average_importance
+                        .partial_cmp(&a.average_importance)
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .then_with(|| a.keyword.cmp(&b.keyword))
+        });
+
+        Ok(aggregated)
+    }
+
+    /// Stored job descriptions ranked by how well the resume's keywords cover them, best fit
+    /// first, keeping at most `limit`
+    pub async fn rank_job_fit(
+        &self,
+        resume_content: &str,
+        jobs: &[JobDescription],
+        limit: usize,
+    ) -> Result<Vec<JobFit>> {
+        let mut fits = Vec::with_capacity(jobs.len());
+        for job in jobs {
+            let industry = job.industry.as_deref().unwrap_or("general");
+            let keyword_analysis = self
+                .analyze_comprehensive(resume_content, &job.content, industry)
+                .await?;
+            let target_count = self
+                .extract_keywords_from_job_description(&job.content)?
+                .len();
+            let matched_keywords = keyword_analysis.matched_keywords();
+            let keyword_coverage = if target_count == 0 {
+                0.0
+            } else {
+                (matched_keywords.len() as f64 / target_count as f64).min(1.0)
+            };
+
+            fits.push(JobFit {
+                job_id: job.id.clone(),
+                title: job.title.clone(),
+                company: job.company.clone(),
+                fit_score: (keyword_analysis.overall_score + keyword_coverage * 100.0) / 2.0,
+                keyword_coverage,
+                matched_keywords,
+            });
+        }
+
+        fits.sort_by(|a, b| {
+            b.fit_score
+                .partial_cmp(&a.fit_score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.title.cmp(&b.title))
+        });
+        fits.truncate(limit);
+        Ok(fits)
+    }
+
+    /// Classify a keyword by the extractor lists that recognize it
+    pub fn keyword_type(&self, keyword: &str) -> KeywordType {
+        let keyword = keyword.to_lowercase();
+        let recognized = |found: Vec<String>| found.contains(&keyword);
+
+        if recognized(self.extract_technical_skills(&keyword))
+            || recognized(self.extract_tools_and_technologies(&keyword))
+        {
+            KeywordType::Tool
+        } else if recognized(self.extract_certification_requirements(&keyword)) {
+            KeywordType::Certification
+        } else if recognized(self.extract_education_requirements(&keyword)) {
+            KeywordType::Education
+        } else if recognized(self.extract_soft_skills(&keyword)) {
+            KeywordType::SoftSkill
+        } else if recognized(self.extract_business_keywords(&keyword))
+            || recognized(self.extract_industry_terms(&keyword))
+            || ACHIEVEMENT_ANALYZER.is_action_verb(&keyword)
+        {
+            KeywordType::Accomplishment
+        } else {
+            KeywordType::General
+        }
+    }
+
+    /// Sections to add a missing keyword to, best first, each with an example sentence
+    pub fn plan_keyword_placement(&self, keyword: &str) -> Vec<SuggestionAction> {
+        let keyword_type = self.keyword_type(keyword);
+        let sections: &[(&str, &str, String)] = match keyword_type {
+            KeywordType::Tool => &[
+                (
+                    "Skills",
+                    "Tools are matched most reliably when listed in the skills section",
+                    format!("Skills: Python, SQL, {}", keyword),
+                ),
+                (
+                    "Experience",
+                    "Naming the tool in a bullet shows you have used it on real work",
+                    format!("Used {} to automate deployments, cutting release time by 30%", keyword),
+                ),
+            ],
+            KeywordType::Certification => &[
+                (
+                    "Certifications",
+                    "ATS look for certifications in their own section",
+                    format!("{}, Issuing Body, 2024", keyword),
+                ),
+                (
+                    "Skills",
+                    "Listing the certification with your skills repeats it where keywords are scanned",
+                    format!("Skills: {}, Python, SQL", keyword),
+                ),
+            ],
+            KeywordType::Education => &[(
+                "Education",
+                "Degree and field requirements are checked against the education section",
+                format!("{}, State University, 2020", keyword),
+            )],
+            KeywordType::SoftSkill => &[
+                (
+                    "Experience",
+                    "Soft skills are credible when a bullet shows them in action",
+                    format!("Showed {} by guiding a team of 5 through a platform migration", keyword),
+                ),
+                (
+                    "Summary",
+                    "The summary is read first and suits a short statement of strengths",
+                    format!("Engineer known for {} and shipping reliable systems", keyword),
+                ),
+            ],
+            KeywordType::Accomplishment => &[
+                (
+                    "Experience",
+                    "Accomplishment terms belong in bullets that show a measurable result",
+                    format!("Drove {} across the billing platform, saving $50K per year", keyword),
+                ),
+                (
+                    "Summary",
+                    "A summary line highlights the outcome before recruiters reach the details",
+                    format!("Track record of {} in fast-growing teams", keyword),
+                ),
+            ],
+            KeywordType::General => &[
+                (
+                    "Experience",
+                    "Contextual keyword usage in experience shows practical application",
+                    format!("Applied {} to deliver a project used by 10,000 customers", keyword),
+                ),
+                (
+                    "Skills",
+                    "The skills section is the most direct place for keyword inclusion",
+                    format!("Skills: Python, SQL, {}", keyword),
+                ),
+            ],
+        };
+
+        sections
+            .iter()
+            .enumerate()
+            .map(|(index, (section, reasoning, example))| SuggestionAction {
+                action: format!(
+                    "Add '{}' to your {} section",
+                    keyword,
+                    section.to_lowercase()
+                ),
+                section: section.to_string(),
+                reasoning: reasoning.to_string(),
+                placement: Some(KeywordPlacement {
+                    rank: index + 1,
+                    keyword_type,
+                    example: example.clone(),
+                }),
+            })
+            .collect()
+    }
+
     /// Extract technical skills from job description
     fn extract_technical_skills(&self, text: &str) -> Vec<String> {
         let mut skills = Vec::new();
@@ -3006,16 +6140,33 @@ impl KeywordAnalyzer {
         stemmed_matches: &[MatchResult],
         contextual_matches: &[MatchResult],
         synonym_matches: &[MatchResult],
+        match_cap: f64,
+        weights: &MatchTypeWeights,
     ) -> Result<f64> {
-        let exact_score = exact_matches.len() as f64 * 1.0;
-        let stemmed_score = stemmed_matches.len() as f64 * 0.85;
-        let contextual_score = contextual_matches.len() as f64 * 0.6;
-        let synonym_score = synonym_matches.len() as f64 * 0.7;
-
-        let total_score = exact_score + stemmed_score + contextual_score + synonym_score;
-        let max_possible = 20.0; // Assume 20 keywords max
+        let typed_matches = [
+            (MatchType::Exact, exact_matches),
+            (MatchType::Stemmed, stemmed_matches),
+            (MatchType::Contextual, contextual_matches),
+            (MatchType::Synonym, synonym_matches),
+        ];
+        // Each keyword only counts once, by its best match
+        let scored = scored_matches(&typed_matches);
+        let total_score: f64 = typed_matches
+            .iter()
+            .map(|&(match_type, matches)| {
+                matches
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, m)| {
+                        scored.get(&m.keyword.to_lowercase()) == Some(&(match_type, *index))
+                    })
+                    .map(|(_, m)| m.position_factor)
+                    .sum::<f64>()
+                    * match_type.score_factor(weights)
+            })
+            .sum();
 
-        Ok((total_score / max_possible * 100.0).min(100.0))
+        Ok((total_score / match_cap * 100.0).min(100.0))
     }
 
     fn calculate_match_density(
@@ -3024,7 +6175,7 @@ impl KeywordAnalyzer {
         exact_matches: &[MatchResult],
         stemmed_matches: &[MatchResult],
     ) -> Result<f64> {
-        let word_count = resume_content.split_whitespace().count();
+        let word_count = tokenize(resume_content).len();
         let match_count = exact_matches.len() + stemmed_matches.len();
 
         if word_count == 0 {
@@ -3060,6 +6211,71 @@ impl KeywordAnalyzer {
 
         Ok(distribution)
     }
+
+    /// Keyword occurrences per 100 words for each detected resume section
+    fn calculate_section_density(
+        &self,
+        resume_content: &str,
+        keywords: &[String],
+    ) -> Result<HashMap<String, f64>> {
+        let mut density = HashMap::new();
+        let sections = GenericParser::with_synonyms(&self.settings.section_header_synonyms)
+            .parse_sections_generic(resume_content)?;
+
+        for (section_name, section_content) in &sections {
+            let word_count = tokenize(section_content).len();
+            if word_count < MIN_SECTION_WORDS_FOR_DENSITY {
+                continue;
+            }
+
+            let occurrences: usize = self
+                .exact_matcher
+                .count_occurrences(section_content, keywords)
+                .values()
+                .sum();
+
+            density.insert(
+                section_name.clone(),
+                (occurrences as f64 / word_count as f64) * 100.0,
+            );
+        }
+
+        Ok(density)
+    }
+
+    /// Sections over the density threshold that also repeat a keyword, sorted by name. Density
+    /// alone would flag every plain skills list.
+    fn detect_stuffed_sections(
+        &self,
+        resume_content: &str,
+        keywords: &[String],
+        section_density: &HashMap<String, f64>,
+        threshold: f64,
+    ) -> Result<Vec<String>> {
+        if !section_density.values().any(|density| *density > threshold) {
+            return Ok(Vec::new());
+        }
+
+        let sections = GenericParser::with_synonyms(&self.settings.section_header_synonyms)
+            .parse_sections_generic(resume_content)?;
+        let mut stuffed: Vec<String> = sections
+            .iter()
+            .filter(|(section, _)| {
+                section_density
+                    .get(*section)
+                    .is_some_and(|d| *d > threshold)
+            })
+            .filter(|(_, content)| {
+                self.exact_matcher
+                    .count_occurrences(content, keywords)
+                    .values()
+                    .any(|count| *count > MAX_SECTION_KEYWORD_REPEATS)
+            })
+            .map(|(section, _)| section.clone())
+            .collect();
+        stuffed.sort();
+        Ok(stuffed)
+    }
 }
 
 impl Default for ATSSimulator {
@@ -3069,11 +6285,19 @@ impl Default for ATSSimulator {
 }
 
 impl ATSSimulator {
+    /// Simulator parsing with the built-in settings
     pub fn new() -> Self {
+        Self::with_settings(Arc::new(ScoringSettings::default()))
+    }
+
+    /// Simulator whose parsers use the section headers, skill taxonomy and contact threshold
+    /// in `settings`
+    pub fn with_settings(settings: Arc<ScoringSettings>) -> Self {
+        let synonyms = &settings.section_header_synonyms;
         let parsers: Vec<Box<dyn ATSParser + Send + Sync>> = vec![
-            Box::new(WorkdayParser::new()),
+            Box::new(WorkdayParser::with_synonyms(synonyms)),
             Box::new(TaleoParser::new()),
-            Box::new(GenericParser::new()),
+            Box::new(GenericParser::with_synonyms(synonyms)),
         ];
 
         let format_rules = vec![FormatRule {
@@ -3094,18 +6318,100 @@ impl ATSSimulator {
 
         Self {
             parsers,
+            settings,
             format_rules,
             section_detectors,
         }
     }
 
     pub fn parse_with_multiple_systems(&self, resume_content: &str) -> Result<ParsedResume> {
+        // Profile exports put contact details and skills in a sidebar the resume parsers misread
+        if is_linkedin_export(resume_content) {
+            let mut parsed = parse_linkedin_export(resume_content);
+            canonicalize_skills(&mut parsed, &self.settings);
+            return Ok(parsed);
+        }
+        let classified =
+            classify_unrecognized_sections(resume_content, &self.settings.section_header_synonyms);
+        self.parse_with_classified_sections(resume_content, classified)
+    }
+
+    /// As `parse_with_multiple_systems`, but blocks under creative headings are placed by
+    /// their nearest canonical section embedding
+    pub async fn parse_with_section_embeddings(
+        &self,
+        resume_content: &str,
+        classifier: &EmbeddingSectionClassifier,
+    ) -> Result<ParsedResume> {
+        if is_linkedin_export(resume_content) {
+            return self.parse_with_multiple_systems(resume_content);
+        }
+        let classified = classifier
+            .classify_unrecognized_sections(resume_content, &self.settings.section_header_synonyms)
+            .await;
+        self.parse_with_classified_sections(resume_content, classified)
+    }
+
+    fn parse_with_classified_sections(
+        &self,
+        resume_content: &str,
+        classified: HashMap<String, String>,
+    ) -> Result<ParsedResume> {
         // Use the first parser for now - in real implementation, would aggregate results
-        if let Some(parser) = self.parsers.first() {
-            parser.parse_resume(resume_content)
-        } else {
-            Err(anyhow!("No ATS parsers available"))
+        let mut parsed = match self.parsers.first() {
+            Some(parser) => parser.parse_resume(resume_content)?,
+            None => return Err(anyhow!("No ATS parsers available")),
+        };
+        // Blocks under creative headings the simulated parsers skip fill sections they missed
+        for (section_name, section_content) in classified {
+            parsed
+                .sections
+                .entry(section_name)
+                .or_insert(section_content);
+        }
+        // Skill-grouped accomplishments leave the chronological parsers with bare roles
+        apply_functional_layout(&mut parsed, resume_content);
+        canonicalize_skills(&mut parsed, &self.settings);
+        apply_contact_confidence(
+            &mut parsed.contact_info,
+            resume_content,
+            self.settings.min_contact_confidence,
+        );
+        Ok(parsed)
+    }
+
+    /// How confident each simulated system is in its own parse of the resume
+    pub fn parsing_confidences(&self, resume_content: &str) -> Result<Vec<f64>> {
+        self.parsers
+            .iter()
+            .map(|parser| Ok(parser.parse_resume(resume_content)?.parsing_confidence))
+            .collect()
+    }
+
+    /// Parse the resume with every simulated system and explain each one's score from its own
+    /// extraction
+    pub fn system_reports(
+        &self,
+        resume_content: &str,
+    ) -> Result<HashMap<ATSSystem, ATSSystemReport>> {
+        let mut reports = HashMap::new();
+
+        for parser in &self.parsers {
+            let parsed = parser.parse_resume(resume_content)?;
+            let mut sections_found: Vec<String> = parsed.sections.keys().cloned().collect();
+            sections_found.sort();
+            reports.insert(
+                parser.get_system_type(),
+                ATSSystemReport {
+                    score: parser.get_compatibility_score(&parsed),
+                    parsing_confidence: parsed.parsing_confidence,
+                    sections_found,
+                    issues: parser.explain_issues(resume_content, &parsed),
+                },
+            );
         }
+
+        Ok(reports)
     }
 
     pub fn calculate_compatibility_scores(
@@ -3123,6 +6429,59 @@ impl ATSSimulator {
     }
 }
 
+/// Characters that often come out garbled after copy-paste or a lossy conversion, with the
+/// ASCII text that replaces them; `None` marks sequences that can be flagged but not fixed
+const ENCODING_REPLACEMENTS: &[(&str, Option<&str>)] = &[
+    ("\u{2018}", Some("'")),    // Left single quotation mark
+    ("\u{2019}", Some("'")),    // Right single quotation mark (corrupted as â€™)
+    ("\u{201C}", Some("\"")),   // Left double quotation mark (corrupted as â€œ)
+    ("\u{201D}", Some("\"")),   // Right double quotation mark (corrupted as â€)
+    ("\u{2026}", Some("...")),  // Horizontal ellipsis (corrupted as â€¦)
+    ("\u{2013}", Some("-")),    // En dash (corrupted as â€")
+    ("\u{2014}", Some("-")),    // Em dash (corrupted as â€")
+    ("\u{00A0}", Some(" ")),    // Non-breaking space (corrupted as Â )
+    ("\u{00C3}", None),         // Latin capital letter A with tilde (corrupted as Ã)
+    ("\u{00A9}", Some("(c)")),  // Copyright sign (corrupted as Â©)
+    ("\u{00AE}", Some("(R)")),  // Registered sign (corrupted as Â®)
+    ("\u{2122}", Some("(TM)")), // Trade mark sign (corrupted as Â™)
+    ("\u{20AC}", Some("EUR")),  // Euro sign (corrupted as â‚¬)
+    ("\u{200B}", Some("")),     // Zero width space (corrupted as â€‹)
+    ("\u{FFFD}", Some("")),     // Replacement character (corrupted as ï¿½)
+];
+
+/// Replace the characters flagged by encoding-issue detection, plus other typographic dashes,
+/// quotes and spaces, with plain ASCII. Zero-width, direction-control and null characters and
+/// byte order marks are dropped; line structure is left untouched.
+pub fn normalize_resume_text(text: &str) -> String {
+    let mut normalized = String::with_capacity(text.len());
+    let mut buffer = [0u8; 4];
+
+    for c in text.chars() {
+        match c {
+            '\u{200B}'..='\u{200F}'
+            | '\u{202A}'..='\u{202E}'
+            | '\u{2060}'..='\u{2064}'
+            | '\u{2066}'..='\u{2069}'
+            | '\u{00AD}'
+            | '\u{FEFF}'
+            | '\0' => {}
+            '\u{2000}'..='\u{200A}' | '\u{202F}' | '\u{205F}' | '\u{3000}' => normalized.push(' '),
+            '\u{2010}'..='\u{2012}' | '\u{2015}' | '\u{2212}' => normalized.push('-'),
+            '\u{201A}' | '\u{201B}' | '\u{2032}' => normalized.push('\''),
+            '\u{201E}' | '\u{201F}' | '\u{2033}' => normalized.push('"'),
+            _ => {
+                let sequence = &*c.encode_utf8(&mut buffer);
+                match ENCODING_REPLACEMENTS.iter().find(|(s, _)| *s == sequence) {
+                    Some((_, Some(replacement))) => normalized.push_str(replacement),
+                    _ => normalized.push(c),
+                }
+            }
+        }
+    }
+
+    normalized
+}
+
 impl Default for FormatAnalyzer {
     fn default() -> Self {
         Self::new()
@@ -3131,19 +6490,26 @@ impl Default for FormatAnalyzer {
 
 impl FormatAnalyzer {
     pub fn new() -> Self {
-        Self
+        Self::with_penalties(FormatPenalties::default())
     }
 
-    pub fn analyze_comprehensive(
+    pub fn with_penalties(penalties: FormatPenalties) -> Self {
+        Self { penalties }
+    }
+
+    pub fn analyze_with_profile(
         &self,
         resume_content: &str,
         parsed_resume: &ParsedResume,
+        profile: &ScoringProfile,
     ) -> Result<FormatAnalysis> {
-        let ats_compatibility_score = self.calculate_ats_compatibility(resume_content)?;
+        let ats_compatibility_score =
+            profile.apply_penalty(self.calculate_ats_compatibility(resume_content)?);
         let parsing_issues = self.detect_parsing_issues(resume_content)?;
         let section_detection_score = parsed_resume.parsing_confidence;
-        let font_compatibility = self.analyze_font_compatibility(resume_content)?;
-        let layout_score = self.analyze_layout(resume_content)?;
+        let font_compatibility =
+            profile.apply_penalty(self.analyze_font_compatibility(resume_content)?);
+        let layout_score = profile.apply_penalty(self.analyze_layout(resume_content)?);
         let encoding_issues = self.detect_encoding_issues(resume_content)?;
 
         Ok(FormatAnalysis {
@@ -3156,20 +6522,137 @@ impl FormatAnalyzer {
         })
     }
 
+    /// Every kind of keyword stuffing: sections dense with repeated keywords, single keywords
+    /// repeated across the resume, and keyword walls
+    pub fn keyword_stuffing_issues(
+        &self,
+        resume_content: &str,
+        keyword_analysis: &KeywordMatch,
+    ) -> Vec<FormatIssue> {
+        let mut issues: Vec<FormatIssue> = keyword_analysis
+            .stuffed_sections
+            .iter()
+            .map(|section| {
+                let density = keyword_analysis
+                    .section_density
+                    .get(section)
+                    .copied()
+                    .unwrap_or(0.0);
+                FormatIssue {
+                    issue_type: FormatIssueType::KeywordStuffing,
+                    severity: IssueSeverity::High,
+                    location: section.clone(),
+                    description: format!(
+                        "{} section is {:.0}% job keywords and repeats them, which modern ATS systems treat as keyword stuffing",
+                        section, density
+                    ),
+                    fix_suggestion: "Remove repeated keywords and show each skill in context within your experience bullets".to_string(),
+                    ats_impact: 15.0,
+                }
+            })
+            .collect();
+        issues.extend(
+            self.keyword_repetition_issues(resume_content, &keyword_analysis.keyword_occurrences),
+        );
+        issues
+    }
+
+    /// Flag keywords repeated far more often than the resume length explains, and lines that
+    /// are walls of comma-separated terms with no prose around them
+    fn keyword_repetition_issues(
+        &self,
+        resume_content: &str,
+        keyword_occurrences: &HashMap<String, usize>,
+    ) -> Vec<FormatIssue> {
+        let word_count = tokenize(resume_content).len().max(1);
+        let mut issues = Vec::new();
+
+        let mut repeated: Vec<(&String, &usize)> = keyword_occurrences
+            .iter()
+            .filter(|(_, count)| {
+                **count >= MIN_FLAGGED_REPETITIONS
+                    && **count as f64 / word_count as f64 * 100.0 > KEYWORD_REPETITION_THRESHOLD
+            })
+            .collect();
+        repeated.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+        for (keyword, count) in repeated {
+            issues.push(FormatIssue {
+                issue_type: FormatIssueType::KeywordStuffing,
+                severity: IssueSeverity::High,
+                location: keyword.clone(),
+                description: format!(
+                    "'{}' appears {} times in {} words; modern ATS systems penalize this kind of repetition",
+                    keyword, count, word_count
+                ),
+                fix_suggestion: "Mention the keyword where it is relevant, ideally two or three times, each backed by a concrete result".to_string(),
+                ats_impact: 15.0,
+            });
+        }
+
+        for (index, line) in resume_content.lines().enumerate() {
+            if Self::is_keyword_wall(line) {
+                issues.push(FormatIssue {
+                    issue_type: FormatIssueType::KeywordStuffing,
+                    severity: IssueSeverity::High,
+                    location: format!("Line {}", index + 1),
+                    description: "Block of comma-separated keywords with no surrounding prose; modern ATS systems penalize keyword walls".to_string(),
+                    fix_suggestion: "Keep a focused skills list and work the rest of the keywords into experience bullets".to_string(),
+                    ats_impact: 15.0,
+                });
+            }
+        }
+
+        issues
+    }
+
+    fn is_keyword_wall(line: &str) -> bool {
+        let terms: Vec<String> = line
+            .split([',', ';', '|'])
+            .map(|term| term.trim().to_lowercase())
+            .filter(|term| !term.is_empty())
+            .collect();
+        // Prose in any term means the list is part of a sentence
+        if terms
+            .iter()
+            .any(|term| term.split_whitespace().count() > 3 || term.ends_with('.'))
+        {
+            return false;
+        }
+
+        let mut distinct = terms.clone();
+        distinct.sort();
+        distinct.dedup();
+        let duplicate_share = 1.0 - distinct.len() as f64 / terms.len().max(1) as f64;
+
+        terms.len() >= KEYWORD_WALL_MIN_TERMS
+            || (terms.len() >= KEYWORD_WALL_MIN_TERMS_WITH_DUPLICATES
+                && duplicate_share >= KEYWORD_WALL_DUPLICATE_SHARE)
+    }
+
     fn calculate_ats_compatibility(&self, resume_content: &str) -> Result<f64> {
-        let mut compatibility_score = 100.0;
+        let penalties = &self.penalties;
+        let mut total_penalty = 0.0;
+        let mut bonus = 0.0;
 
         // Check for ATS-unfriendly formatting elements
         let problematic_patterns = [
             (
                 r"[│║┌┐└┘├┤┬┴┼─━]",
-                15.0,
+                penalties.table_borders,
                 "Table borders and special characters",
             ),
-            (r"[★☆●○▪▫■□▲△▼▽◆◇]", 10.0, "Special symbols and bullets"),
-            (r"[①②③④⑤⑥⑦⑧⑨⑩]", 8.0, "Numbered circles"),
-            (r"[➤➢➣➤➥➦➧➨➩]", 8.0, "Arrow symbols"),
-            (r"[✓✔✗✘]", 5.0, "Checkmarks and crosses"),
+            (
+                r"[★☆●○▪▫■□▲△▼▽◆◇]",
+                penalties.special_symbols,
+                "Special symbols and bullets",
+            ),
+            (
+                r"[①②③④⑤⑥⑦⑧⑨⑩]",
+                penalties.numbered_circles,
+                "Numbered circles",
+            ),
+            (r"[➤➢➣➤➥➦➧➨➩]", penalties.arrow_symbols, "Arrow symbols"),
+            (r"[✓✔✗✘]", penalties.checkmarks, "Checkmarks and crosses"),
             (
                 r"@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}",
                 0.0,
@@ -3182,7 +6665,7 @@ impl FormatAnalyzer {
             let regex = Regex::new(pattern)?;
             let match_count = regex.find_iter(resume_content).count();
             if match_count > 0 && *penalty > 0.0 {
-                compatibility_score -= (match_count as f64 * penalty).min(penalty * 2.0);
+                total_penalty += (match_count as f64 * penalty).min(penalty * 2.0);
                 debug!(
                     "ATS compatibility penalty: {} for {} matches of {}",
                     penalty, match_count, description
@@ -3217,9 +6700,9 @@ impl FormatAnalyzer {
         }
 
         if found_sections < 3 {
-            compatibility_score -= 20.0;
+            total_penalty += penalties.missing_sections;
         } else if found_sections >= 4 {
-            compatibility_score += 5.0;
+            bonus += 5.0;
         }
 
         // Check for consistent formatting
@@ -3239,7 +6722,7 @@ impl FormatAnalyzer {
         }
 
         if bullet_consistency > 2 {
-            compatibility_score -= 10.0; // Inconsistent bullet usage
+            total_penalty += penalties.inconsistent_bullets;
         }
 
         // Check for proper contact information placement
@@ -3255,10 +6738,10 @@ impl FormatAnalyzer {
         let phone_regex = Regex::new(r"(\+?1[-.\s]?)?(\(?\d{3}\)?[-.\s]?\d{3}[-.\s]?\d{4})")?;
 
         if !email_regex.is_match(&first_section) {
-            compatibility_score -= 10.0;
+            total_penalty += penalties.missing_email;
         }
         if !phone_regex.is_match(&first_section) {
-            compatibility_score -= 5.0;
+            total_penalty += penalties.missing_phone;
         }
 
         // Check for excessive formatting
@@ -3272,14 +6755,14 @@ impl FormatAnalyzer {
             let regex = Regex::new(pattern)?;
             let matches = regex.find_iter(resume_content).count();
             if matches > 3 {
-                compatibility_score -= 5.0;
+                total_penalty += penalties.markup;
             }
         }
 
         // Check for reasonable line lengths
         let long_lines = lines.iter().filter(|line| line.len() > 150).count();
         if long_lines > lines.len() / 5 {
-            compatibility_score -= 10.0;
+            total_penalty += penalties.long_lines;
         }
 
         // Check for proper date formats
@@ -3299,10 +6782,18 @@ impl FormatAnalyzer {
         }
 
         if date_consistency > 2 {
-            compatibility_score -= 5.0; // Inconsistent date formatting
+            total_penalty += penalties.inconsistent_dates;
         }
 
-        Ok(compatibility_score.clamp(0.0, 100.0))
+        // Many minor issues should not add up to an unusable score
+        let capped_penalty = f64::min(total_penalty, penalties.max_total_penalty.max(0.0));
+        if capped_penalty < total_penalty {
+            debug!(
+                "ATS compatibility penalties capped at {} (uncapped {})",
+                capped_penalty, total_penalty
+            );
+        }
+        Ok((100.0 - capped_penalty + bonus).clamp(0.0, 100.0))
     }
 
     fn detect_parsing_issues(&self, resume_content: &str) -> Result<Vec<FormatIssue>> {
@@ -3498,6 +6989,18 @@ impl FormatAnalyzer {
             });
         }
 
+        // Check for a functional layout, which groups accomplishments by skill instead of by role
+        if is_functional_resume(resume_content) {
+            issues.push(FormatIssue {
+                issue_type: FormatIssueType::FunctionalLayout,
+                description: "Resume uses a functional layout that groups accomplishments by skill; many ATS systems expect a chronological work history and may misparse or downrank it".to_string(),
+                severity: IssueSeverity::High,
+                location: "Skill-grouped accomplishments".to_string(),
+                fix_suggestion: "List accomplishments under each role in reverse-chronological order, or keep a short skills summary above a full work history".to_string(),
+                ats_impact: 15.0,
+            });
+        }
+
         // Check for missing section breaks
         let section_breaks = resume_content.matches("\n\n").count();
         if section_breaks < 3 {
@@ -3819,24 +7322,7 @@ impl FormatAnalyzer {
         let mut issues = Vec::new();
 
         // Check for common encoding problems
-        let problematic_sequences = [
-            "\u{2019}", // Right single quotation mark (corrupted as â€™)
-            "\u{201C}", // Left double quotation mark (corrupted as â€œ)
-            "\u{201D}", // Right double quotation mark (corrupted as â€)
-            "\u{2026}", // Horizontal ellipsis (corrupted as â€¦)
-            "\u{2013}", // En dash (corrupted as â€")
-            "\u{2014}", // Em dash (corrupted as â€")
-            "\u{00A0}", // Non-breaking space (corrupted as Â )
-            "\u{00C3}", // Latin capital letter A with tilde (corrupted as Ã)
-            "\u{00A9}", // Copyright sign (corrupted as Â©)
-            "\u{00AE}", // Registered sign (corrupted as Â®)
-            "\u{2122}", // Trade mark sign (corrupted as Â™)
-            "\u{20AC}", // Euro sign (corrupted as â‚¬)
-            "\u{200B}", // Zero width space (corrupted as â€‹)
-            "\u{FFFD}", // Replacement character (corrupted as ï¿½)
-        ];
-
-        for sequence in &problematic_sequences {
+        for (sequence, _) in ENCODING_REPLACEMENTS {
             if resume_content.contains(sequence) {
                 issues.push(format!("Encoding issue detected: {}", sequence));
             }
@@ -4057,23 +7543,54 @@ impl FormatAnalyzer {
 
 // Default implementations for matchers
 impl ExactMatcher {
-    pub fn find_matches(
+    /// Whole-word occurrences of each keyword, case-insensitively; keywords that never
+    /// appear are left out
+    pub fn count_occurrences(
+        &self,
+        resume_content: &str,
+        keywords: &[String],
+    ) -> HashMap<String, usize> {
+        let tokens = tokenize(resume_content);
+        let mut counts = HashMap::new();
+
+        for keyword in keywords {
+            let count = find_whole_words(resume_content, &tokens, keyword).len();
+            if count > 0 {
+                counts.insert(keyword.to_lowercase(), count);
+            }
+        }
+
+        counts
+    }
+
+    /// Matches with the built-in context window
+    #[cfg(test)]
+    fn find_matches(&self, resume_content: &str, keywords: &[String]) -> Result<Vec<MatchResult>> {
+        self.find_matches_with_context(resume_content, keywords, DEFAULT_CONTEXT_WINDOW_WORDS)
+    }
+
+    fn find_matches_with_context(
         &self,
         resume_content: &str,
         keywords: &[String],
+        context_window: usize,
     ) -> Result<Vec<MatchResult>> {
         let mut matches = Vec::new();
+        let content = FoldedText::new(resume_content);
+        let tokens = tokenize(resume_content);
 
         for keyword in keywords {
-            if let Some(pos) = resume_content.to_lowercase().find(&keyword.to_lowercase()) {
+            if let Some(&(start, end)) = content.find_all(keyword, false).first() {
                 matches.push(MatchResult {
                     keyword: keyword.clone(),
-                    matched_text: keyword.clone(),
+                    matched_text: char_slice(resume_content, start, end).to_string(),
                     section: "general".to_string(),
-                    position: pos,
-                    context: "".to_string(),
+                    start,
+                    end,
+                    context: context_around(&tokens, start, end, context_window),
                     confidence: 1.0,
                     weight: 1.0,
+                    position_factor: 1.0,
                 });
             }
         }
@@ -4082,30 +7599,74 @@ impl ExactMatcher {
     }
 }
 
+/// Distinct resumes whose stemmed words are kept; the realtime optimizer rescores the same
+/// text many times in a row
+const STEMMED_TOKEN_CACHE_CAPACITY: usize = 16;
+
+/// Resume words and their stems, computed once per distinct resume text
+#[derive(Debug)]
+struct StemmedTokens {
+    language: Language,
+    /// (original word, stem, character span in the original text)
+    words: Vec<(String, String, (usize, usize))>,
+}
+
+static STEMMED_TOKEN_CACHE: Lazy<std::sync::Mutex<GenerationCache<Arc<StemmedTokens>>>> =
+    Lazy::new(|| std::sync::Mutex::new(GenerationCache::new(STEMMED_TOKEN_CACHE_CAPACITY)));
+
+fn stemmed_tokens(resume_content: &str) -> Arc<StemmedTokens> {
+    let mut hasher = DefaultHasher::new();
+    resume_content.hash(&mut hasher);
+    let key = hasher.finish();
+
+    let cached = STEMMED_TOKEN_CACHE
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(key);
+    if let Some(tokens) = cached {
+        return tokens;
+    }
+    let generation = cache_generation();
+
+    // Stem with the resume's language so inflected non-English words still match
+    let language = detect_language(resume_content);
+    let stemmer = language.stemmer();
+    let words = tokenize(resume_content)
+        .into_iter()
+        .map(|token| {
+            let normalized_word = token.text.nfc().collect::<String>().to_lowercase();
+            let stemmed = stemmer.stem(&normalized_word).to_string();
+            (token.text.to_string(), stemmed, (token.start, token.end))
+        })
+        .collect();
+    let tokens = Arc::new(StemmedTokens { language, words });
+
+    STEMMED_TOKEN_CACHE
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(key, Arc::clone(&tokens), generation);
+    tokens
+}
+
 impl StemmedMatcher {
-    pub fn find_matches(
+    /// Matches with the built-in context window
+    #[cfg(test)]
+    fn find_matches(&self, resume_content: &str, keywords: &[String]) -> Result<Vec<MatchResult>> {
+        self.find_matches_with_context(resume_content, keywords, DEFAULT_CONTEXT_WINDOW_WORDS)
+    }
+
+    fn find_matches_with_context(
         &self,
         resume_content: &str,
         keywords: &[String],
+        context_window: usize,
     ) -> Result<Vec<MatchResult>> {
         let mut matches = Vec::new();
 
-        // Initialize Porter stemmer
-        let stemmer = Stemmer::create(Algorithm::English);
-
-        // Normalize resume content
-        let normalized_content = resume_content.nfc().collect::<String>();
-
-        // Split resume into words and stem them
-        let resume_words: Vec<(String, String, usize)> = normalized_content
-            .unicode_words()
-            .enumerate()
-            .map(|(index, word)| {
-                let lower_word = word.to_lowercase();
-                let stemmed = stemmer.stem(&lower_word).to_string();
-                (word.to_string(), stemmed, index)
-            })
-            .collect();
+        let tokens = stemmed_tokens(resume_content);
+        let stemmer = tokens.language.stemmer();
+        let resume_words = &tokens.words;
+        let words = tokenize(resume_content);
 
         // Process each keyword
         for keyword in keywords {
@@ -4113,14 +7674,18 @@ impl StemmedMatcher {
             let keyword_stemmed = stemmer.stem(&keyword_lower).to_string();
 
             // Find matches by stemmed form
-            for (original_word, stemmed_word, position) in &resume_words {
+            for (original_word, stemmed_word, (start, end)) in resume_words {
                 if *stemmed_word == keyword_stemmed {
                     // Extract context around the match
-                    let context =
-                        self.extract_context(&normalized_content, *position, original_word);
+                    let context = context_around(&words, *start, *end, context_window);
 
-                    // Determine section
-                    let section = self.determine_section(&context);
+                    // Determine section from a fixed span around the match
+                    let section = self.determine_section(&context_around(
+                        &words,
+                        *start,
+                        *end,
+                        SECTION_CONTEXT_WORDS,
+                    ));
 
                     // Calculate confidence based on stem similarity
                     let confidence = self.calculate_stem_confidence(
@@ -4137,10 +7702,12 @@ impl StemmedMatcher {
                         keyword: keyword.clone(),
                         matched_text: original_word.clone(),
                         section: section.clone(),
-                        position: *position,
-                        context: context.clone(),
+                        start: *start,
+                        end: *end,
+                        context,
                         confidence,
                         weight,
+                        position_factor: 1.0,
                     });
                 }
             }
@@ -4151,23 +7718,12 @@ impl StemmedMatcher {
             b.confidence
                 .partial_cmp(&a.confidence)
                 .unwrap_or(std::cmp::Ordering::Equal)
-                .then_with(|| a.position.cmp(&b.position))
+                .then_with(|| a.start.cmp(&b.start))
         });
 
         Ok(matches)
     }
 
-    /// Extract context around a matched word
-    fn extract_context(&self, content: &str, position: usize, _word: &str) -> String {
-        let words: Vec<&str> = content.unicode_words().collect();
-        let context_size = 5; // 5 words before and after
-
-        let start = position.saturating_sub(context_size);
-        let end = std::cmp::min(position + context_size + 1, words.len());
-
-        words[start..end].join(" ")
-    }
-
     /// Determine section based on context
     fn determine_section(&self, context: &str) -> String {
         let context_lower = context.to_lowercase();
@@ -4272,37 +7828,132 @@ impl StemmedMatcher {
 }
 
 impl ContextualMatcher {
-    pub fn find_matches(
+    /// Context quality of every matched keyword, keyed in lowercase: the best score among the
+    /// sentences its matches fall in
+    pub fn keyword_context_quality(
+        &self,
+        resume_content: &str,
+        matches: &[&[MatchResult]],
+    ) -> HashMap<String, f64> {
+        // Lines and sentences, with the character span each covers
+        let mut sentences = Vec::new();
+        let mut position = 0;
+        for (index, piece) in resume_content.split(['\n', '.', '!', '?', ';']).enumerate() {
+            if index > 0 {
+                position += 1;
+            }
+            let end = position + piece.chars().count();
+            sentences.push((position, end, piece));
+            position = end;
+        }
+
+        let mut quality: HashMap<String, f64> = HashMap::new();
+        for result in matches.iter().copied().flatten() {
+            let Some(&(_, _, sentence)) = sentences
+                .iter()
+                .find(|(start, end, _)| (*start..=*end).contains(&result.start))
+            else {
+                continue;
+            };
+            let score = self.context_quality(sentence);
+            let best = quality.entry(result.keyword.to_lowercase()).or_insert(0.0);
+            *best = best.max(score);
+        }
+        quality
+    }
+
+    /// How strongly a sentence puts its keywords to work: action verbs and numbers raise it,
+    /// while a comma-separated list of short items scores as a bare mention
+    fn context_quality(&self, sentence: &str) -> f64 {
+        let sentence = sentence.trim().to_lowercase();
+        // "Skills: Kubernetes, Terraform, Go" lists names rather than using them
+        let body = sentence
+            .split_once(':')
+            .map_or(sentence.as_str(), |(_, rest)| rest);
+        let items: Vec<&str> = body
+            .split([',', '|', '•', '/'])
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .collect();
+        let is_list = items.len() >= 2
+            && items
+                .iter()
+                .all(|item| item.split_whitespace().count() <= LIST_ITEM_MAX_WORDS);
+        let word_count = sentence.split_whitespace().count();
+        if is_list || word_count < LIST_ITEM_MAX_WORDS {
+            return BARE_MENTION_CONTEXT_QUALITY;
+        }
+
+        let mut score = BARE_MENTION_CONTEXT_QUALITY;
+        if CONTEXT_ACTION_VERBS
+            .iter()
+            .chain(CONTEXT_ACHIEVEMENT_WORDS)
+            .any(|verb| contains_word(&sentence, verb))
+        {
+            score += 0.4;
+        }
+        if sentence.contains(|c: char| c.is_ascii_digit()) {
+            score += 0.3;
+        }
+        if word_count >= NARRATIVE_MIN_WORDS {
+            score += 0.2;
+        }
+        score.min(1.0)
+    }
+
+    /// Matches with the built-in context window and confidence threshold
+    #[cfg(test)]
+    fn find_matches(&self, resume_content: &str, keywords: &[String]) -> Result<Vec<MatchResult>> {
+        self.find_matches_with_context(
+            resume_content,
+            keywords,
+            DEFAULT_CONTEXT_WINDOW_WORDS,
+            DEFAULT_MIN_CONTEXTUAL_CONFIDENCE,
+        )
+    }
+
+    fn find_matches_with_context(
         &self,
         resume_content: &str,
         keywords: &[String],
+        context_window: usize,
+        min_confidence: f64,
     ) -> Result<Vec<MatchResult>> {
         let mut matches = Vec::new();
 
-        // Normalize resume content
-        let normalized_content = resume_content.nfc().collect::<String>();
-
-        // Split into sentences for context analysis
-        let sentences: Vec<&str> = normalized_content
-            .split(['.', '!', '?'])
-            .filter(|s| !s.trim().is_empty())
-            .collect();
+        // Split into sentences for context analysis, keeping where each starts in characters
+        let mut sentence_start = 0;
+        let mut sentences = Vec::new();
+        for sentence in resume_content.split(['.', '!', '?']) {
+            if !sentence.trim().is_empty() {
+                sentences.push((sentence_start, sentence));
+            }
+            sentence_start += sentence.chars().count() + 1;
+        }
+        let tokens = tokenize(resume_content);
 
         // Process each keyword
         for keyword in keywords {
             let keyword_lower = keyword.to_lowercase();
 
             // Find contextual matches
-            for (sentence_idx, sentence) in sentences.iter().enumerate() {
-                let sentence_lower = sentence.to_lowercase();
+            for &(sentence_start, sentence) in &sentences {
+                let sentence_lower = sentence.nfc().collect::<String>().to_lowercase();
 
                 // Check for keyword variations and contextual clues
-                if let Some(contextual_match) = self.find_contextual_match(
+                if let Some(mut contextual_match) = self.find_contextual_match(
                     &sentence_lower,
                     &keyword_lower,
                     sentence,
-                    sentence_idx,
+                    sentence_start,
+                    min_confidence,
                 ) {
+                    contextual_match.context = context_around(
+                        &tokens,
+                        contextual_match.start,
+                        contextual_match.end,
+                        context_window,
+                    );
                     matches.push(contextual_match);
                 }
             }
@@ -4323,26 +7974,18 @@ impl ContextualMatcher {
         Ok(matches)
     }
 
-    /// Find contextual matches considering surrounding words and phrases
+    /// Find contextual matches considering surrounding words and phrases; candidates whose
+    /// context scores no higher than `min_confidence` are dropped
     fn find_contextual_match(
         &self,
         sentence_lower: &str,
         keyword_lower: &str,
         original_sentence: &str,
-        sentence_idx: usize,
+        sentence_start: usize,
+        min_confidence: f64,
     ) -> Option<MatchResult> {
         // Context patterns for different keyword types
-        let tech_indicators = [
-            "developed",
-            "implemented",
-            "built",
-            "created",
-            "designed",
-            "managed",
-            "led",
-            "architected",
-            "optimized",
-        ];
+        let tech_indicators = CONTEXT_ACTION_VERBS;
         let skill_indicators = [
             "experienced",
             "proficient",
@@ -4352,15 +7995,7 @@ impl ContextualMatcher {
             "familiar",
             "versed",
         ];
-        let achievement_indicators = [
-            "achieved",
-            "improved",
-            "increased",
-            "reduced",
-            "delivered",
-            "completed",
-            "successful",
-        ];
+        let achievement_indicators = CONTEXT_ACHIEVEMENT_WORDS;
 
         // Look for keyword in various forms
         let keyword_variations = self.generate_keyword_variations(keyword_lower);
@@ -4371,21 +8006,26 @@ impl ContextualMatcher {
                 let context_score = self.analyze_context_relevance(
                     sentence_lower,
                     variation,
-                    &tech_indicators,
+                    tech_indicators,
                     &skill_indicators,
-                    &achievement_indicators,
+                    achievement_indicators,
                 );
 
-                if context_score > 0.3 {
-                    // Extract the specific matched text
-                    let matched_text = self.extract_matched_text(original_sentence, variation);
+                if context_score > min_confidence {
+                    // Locate the specific matched text in the original sentence
+                    let (start, end) = FoldedText::new(original_sentence)
+                        .find_all(variation, false)
+                        .first()
+                        .copied()
+                        .unwrap_or((0, original_sentence.chars().count()));
                     let section = self.determine_section_from_context(sentence_lower);
 
                     return Some(MatchResult {
                         keyword: keyword_lower.to_string(),
-                        matched_text,
+                        matched_text: char_slice(original_sentence, start, end).to_string(),
                         section: section.clone(),
-                        position: sentence_idx,
+                        start: sentence_start + start,
+                        end: sentence_start + end,
                         context: original_sentence.to_string(),
                         confidence: context_score,
                         weight: self.calculate_contextual_weight(
@@ -4393,6 +8033,7 @@ impl ContextualMatcher {
                             variation,
                             &section,
                         ),
+                        position_factor: 1.0,
                     });
                 }
             }
@@ -4529,16 +8170,6 @@ impl ContextualMatcher {
     }
 
     /// Extract the actual matched text from the original sentence
-    fn extract_matched_text(&self, sentence: &str, keyword: &str) -> String {
-        let sentence_lower = sentence.to_lowercase();
-        if let Some(start) = sentence_lower.find(keyword) {
-            let end = start + keyword.len();
-            sentence[start..end].to_string()
-        } else {
-            keyword.to_string()
-        }
-    }
-
     /// Determine section from context clues
     fn determine_section_from_context(&self, sentence: &str) -> String {
         if sentence.contains("work")
@@ -4619,19 +8250,32 @@ impl ContextualMatcher {
 }
 
 impl SynonymMatcher {
-    pub fn find_matches(
+    /// Matches with the built-in context window and skill taxonomy
+    #[cfg(test)]
+    fn find_matches(&self, resume_content: &str, keywords: &[String]) -> Result<Vec<MatchResult>> {
+        self.find_matches_with_context(
+            resume_content,
+            keywords,
+            DEFAULT_CONTEXT_WINDOW_WORDS,
+            &default_skill_taxonomy(),
+        )
+    }
+
+    fn find_matches_with_context(
         &self,
         resume_content: &str,
         keywords: &[String],
+        context_window: usize,
+        skill_taxonomy: &HashMap<String, Vec<String>>,
     ) -> Result<Vec<MatchResult>> {
         let mut matches = Vec::new();
 
-        // Normalize resume content
-        let normalized_content = resume_content.nfc().collect::<String>();
-        let content_lower = normalized_content.to_lowercase();
+        let folded_content = FoldedText::new(resume_content);
+        let tokens = tokenize(resume_content);
 
-        // Initialize synonym database
-        let synonym_db = self.build_synonym_database();
+        // Initialize synonym database, adding terms in the resume's language
+        let synonym_db =
+            self.build_synonym_database(detect_language(resume_content), skill_taxonomy);
 
         // Process each keyword
         for keyword in keywords {
@@ -4642,9 +8286,14 @@ impl SynonymMatcher {
 
             // Search for the keyword and its synonyms
             for synonym in &synonyms {
-                if let Some(synonym_matches) =
-                    self.find_synonym_matches(&content_lower, &normalized_content, keyword, synonym)
-                {
+                if let Some(synonym_matches) = self.find_synonym_matches(
+                    &folded_content,
+                    resume_content,
+                    &tokens,
+                    keyword,
+                    synonym,
+                    context_window,
+                ) {
                     matches.extend(synonym_matches);
                 }
             }
@@ -4656,8 +8305,13 @@ impl SynonymMatcher {
         Ok(matches)
     }
 
-    /// Build comprehensive synonym database
-    fn build_synonym_database(&self) -> HashMap<String, Vec<String>> {
+    /// Build comprehensive synonym database: language-agnostic technical terms merged with the
+    /// local-language terms for `language`
+    fn build_synonym_database(
+        &self,
+        language: Language,
+        skill_taxonomy: &HashMap<String, Vec<String>>,
+    ) -> HashMap<String, Vec<String>> {
         let mut db = HashMap::new();
 
         // Technical skills synonyms
@@ -4890,6 +8544,23 @@ impl SynonymMatcher {
             ],
         );
 
+        for (keyword, local_terms) in language.synonyms() {
+            db.entry(keyword.to_string())
+                .or_insert_with(Vec::new)
+                .extend(local_terms.iter().map(|term| term.to_string()));
+        }
+
+        // Variants from the skill taxonomy always count as the skill they name
+        for (skill, aliases) in skill_taxonomy {
+            let terms = db.entry(skill.to_lowercase()).or_insert_with(Vec::new);
+            for alias in aliases {
+                let alias = alias.to_lowercase();
+                if !terms.contains(&alias) {
+                    terms.push(alias);
+                }
+            }
+        }
+
         db
     }
 
@@ -4954,47 +8625,40 @@ impl SynonymMatcher {
     /// Find synonym matches in the content
     fn find_synonym_matches(
         &self,
-        content_lower: &str,
+        folded_content: &FoldedText,
         original_content: &str,
+        tokens: &[Token],
         original_keyword: &str,
         synonym: &str,
+        context_window: usize,
     ) -> Option<Vec<MatchResult>> {
         let mut matches = Vec::new();
 
-        // Find all occurrences of the synonym
-        let mut start = 0;
-        while let Some(pos) = content_lower[start..].find(synonym) {
-            let actual_pos = start + pos;
-
-            // Check if it's a whole word match
-            if self.is_whole_word_match(content_lower, actual_pos, synonym) {
-                let context = self.extract_context_around_position(
-                    original_content,
-                    actual_pos,
-                    synonym.len(),
-                );
-                let section = self.determine_section_from_context(&context);
-
-                // Calculate confidence based on synonym relationship
-                let confidence = self.calculate_synonym_confidence(original_keyword, synonym);
-                let weight = self.calculate_synonym_weight(original_keyword, synonym, &section);
-
-                matches.push(MatchResult {
-                    keyword: original_keyword.to_string(),
-                    matched_text: self.extract_original_text(
-                        original_content,
-                        actual_pos,
-                        synonym.len(),
-                    ),
-                    section,
-                    position: actual_pos,
-                    context,
-                    confidence,
-                    weight,
-                });
-            }
+        // Find all whole-word occurrences of the synonym
+        for (start, end) in folded_content.find_all(synonym, true) {
+            let context = context_around(tokens, start, end, context_window);
+            let section = self.determine_section_from_context(&context_around(
+                tokens,
+                start,
+                end,
+                SECTION_CONTEXT_WORDS,
+            ));
 
-            start = actual_pos + 1;
+            // Calculate confidence based on synonym relationship
+            let confidence = self.calculate_synonym_confidence(original_keyword, synonym);
+            let weight = self.calculate_synonym_weight(original_keyword, synonym, &section);
+
+            matches.push(MatchResult {
+                keyword: original_keyword.to_string(),
+                matched_text: char_slice(original_content, start, end).to_string(),
+                section,
+                start,
+                end,
+                context,
+                confidence,
+                weight,
+                position_factor: 1.0,
+            });
         }
 
         if matches.is_empty() {
@@ -5004,48 +8668,6 @@ impl SynonymMatcher {
         }
     }
 
-    /// Check if the match is a whole word
-    fn is_whole_word_match(&self, content: &str, position: usize, word: &str) -> bool {
-        let word_end = position + word.len();
-
-        // Check character before
-        let before_ok = position == 0 || {
-            let before_char = content.chars().nth(position - 1).unwrap_or(' ');
-            !before_char.is_alphanumeric() && before_char != '_'
-        };
-
-        // Check character after
-        let after_ok = word_end >= content.len() || {
-            let after_char = content.chars().nth(word_end).unwrap_or(' ');
-            !after_char.is_alphanumeric() && after_char != '_'
-        };
-
-        before_ok && after_ok
-    }
-
-    /// Extract context around a position
-    fn extract_context_around_position(
-        &self,
-        content: &str,
-        position: usize,
-        _word_len: usize,
-    ) -> String {
-        let words: Vec<&str> = content.unicode_words().collect();
-        let target_word_idx = content[..position].unicode_words().count();
-
-        let context_size = 5;
-        let start = target_word_idx.saturating_sub(context_size);
-        let end = std::cmp::min(target_word_idx + context_size + 1, words.len());
-
-        words[start..end].join(" ")
-    }
-
-    /// Extract original text from content
-    fn extract_original_text(&self, content: &str, position: usize, length: usize) -> String {
-        let end = std::cmp::min(position + length, content.len());
-        content[position..end].to_string()
-    }
-
     /// Calculate confidence for synonym matches
     fn calculate_synonym_confidence(&self, original_keyword: &str, synonym: &str) -> f64 {
         if original_keyword == synonym {
@@ -5138,15 +8760,15 @@ impl SynonymMatcher {
     /// Remove duplicates and sort matches
     fn deduplicate_and_sort_matches(&self, matches: &mut Vec<MatchResult>) {
         // Sort by position first to identify duplicates
-        matches.sort_by(|a, b| a.position.cmp(&b.position));
+        matches.sort_by_key(|m| m.start);
 
         // Remove duplicates based on position and keyword
         let mut unique_matches = Vec::new();
         for match_result in matches.iter() {
             if !unique_matches.iter().any(|m: &MatchResult| {
-                m.position == match_result.position
+                m.start == match_result.start
                     && m.keyword == match_result.keyword
-                    && (m.position as i32 - match_result.position as i32).abs() < 10
+                    && (m.start as i32 - match_result.start as i32).abs() < 10
             }) {
                 unique_matches.push(match_result.clone());
             }
@@ -5200,10 +8822,437 @@ impl Default for IndustryWeights {
     }
 }
 
+/// Built-in section header synonyms, keyed by the canonical section name the parsers report
+pub const DEFAULT_SECTION_HEADER_SYNONYMS: &[(&str, &[&str])] = &[
+    (
+        "Summary",
+        &[
+            "summary",
+            "professional summary",
+            "profile",
+            "objective",
+            "career objective",
+        ],
+    ),
+    (
+        "Experience",
+        &[
+            "experience",
+            "professional experience",
+            "work experience",
+            "employment",
+            "employment history",
+            "career history",
+        ],
+    ),
+    (
+        "Education",
+        &[
+            "education",
+            "academic background",
+            "educational background",
+            "qualifications",
+        ],
+    ),
+    (
+        "Skills",
+        &[
+            "skills",
+            "technical skills",
+            "core competencies",
+            "key skills",
+            "proficiencies",
+            "expertise",
+        ],
+    ),
+    (
+        "Projects",
+        &[
+            "projects",
+            "key projects",
+            "notable projects",
+            "project experience",
+        ],
+    ),
+    (
+        "Certifications",
+        &[
+            "certifications",
+            "certificates",
+            "professional certifications",
+            "licenses",
+        ],
+    ),
+    (
+        "Achievements",
+        &["achievements", "accomplishments", "awards"],
+    ),
+];
+
+/// Share of the overall quality blend taken from the ATS score rather than readability
+pub const DEFAULT_ATS_SCORE_WEIGHT: f64 = 0.7;
+
+/// Keyword rule from config with its pattern compiled
+#[derive(Debug, Clone)]
+pub struct CompiledKeywordRule {
+    pub name: String,
+    pub category: String,
+    pub weight: f64,
+    pub regex: Regex,
+}
+
+/// Compile keyword rules, failing on the first rule whose pattern is empty or not a valid regex
+pub fn compile_keyword_rules(rules: &[KeywordRule]) -> Result<Vec<CompiledKeywordRule>> {
+    rules
+        .iter()
+        .map(|rule| {
+            if rule.pattern.is_empty() {
+                return Err(anyhow!("Keyword rule '{}' has an empty pattern", rule.name));
+            }
+            let regex = Regex::new(&rule.pattern).map_err(|e| {
+                anyhow!("Keyword rule '{}' has an invalid pattern: {}", rule.name, e)
+            })?;
+            Ok(CompiledKeywordRule {
+                name: rule.name.clone(),
+                category: rule.category.clone(),
+                weight: rule.weight,
+                regex,
+            })
+        })
+        .collect()
+}
+
+/// First non-empty match of each rule in the resume, grouped by rule category
+fn find_rule_matches(
+    resume_content: &str,
+    rules: &[CompiledKeywordRule],
+    context_window: usize,
+) -> BTreeMap<String, Vec<MatchResult>> {
+    let mut matches: BTreeMap<String, Vec<MatchResult>> = BTreeMap::new();
+    if rules.is_empty() {
+        return matches;
+    }
+    let tokens = tokenize(resume_content);
+    for rule in rules {
+        let Some(found) = rule.regex.find_iter(resume_content).find(|m| !m.is_empty()) else {
+            continue;
+        };
+        let start = resume_content[..found.start()].chars().count();
+        let end = start + found.as_str().chars().count();
+        matches
+            .entry(rule.category.clone())
+            .or_default()
+            .push(MatchResult {
+                keyword: rule.name.clone(),
+                matched_text: found.as_str().to_string(),
+                section: "general".to_string(),
+                start,
+                end,
+                context: context_around(&tokens, start, end, context_window),
+                confidence: 1.0,
+                weight: rule.weight,
+                position_factor: 1.0,
+            });
+    }
+    matches
+}
+
+/// Spelling variants of the same skill, seeded from the alias entries of the synonym table.
+/// Unlike synonyms ("python" / "django"), every variant here names exactly the same skill.
+const SKILL_ALIASES: &[(&str, &[&str])] = &[
+    (
+        "JavaScript",
+        &["js", "ecmascript", "es6", "node", "node.js", "nodejs"],
+    ),
+    ("TypeScript", &["ts"]),
+    ("C++", &["cpp", "c plus plus"]),
+    ("C#", &["csharp", "c sharp"]),
+    ("PostgreSQL", &["postgres", "psql"]),
+    ("MongoDB", &["mongo"]),
+    ("AWS", &["amazon web services"]),
+    ("Azure", &["microsoft azure"]),
+    ("GCP", &["google cloud platform", "google cloud"]),
+    ("Kubernetes", &["k8s"]),
+    ("React", &["reactjs", "react.js"]),
+    ("Angular", &["angularjs"]),
+    ("Vue", &["vue.js", "vuejs"]),
+    ("Machine Learning", &["ml"]),
+];
+
+/// Built-in skill taxonomy: canonical skill name to the variants that mean the same skill
+pub fn default_skill_taxonomy() -> HashMap<String, Vec<String>> {
+    SKILL_ALIASES
+        .iter()
+        .map(|(skill, aliases)| {
+            (
+                skill.to_string(),
+                aliases.iter().map(|alias| alias.to_string()).collect(),
+            )
+        })
+        .collect()
+}
+
+/// Fold skill variants into their canonical skill so each skill is listed and counted once.
+/// A proficiency stated for any variant carries over, keeping the strongest.
+fn canonicalize_skills(parsed: &mut ParsedResume, settings: &ScoringSettings) {
+    let mut seen = HashSet::new();
+    let mut skills = Vec::with_capacity(parsed.skills.len());
+    for skill in parsed.skills.drain(..) {
+        let canonical = settings.canonical_skill(&skill).unwrap_or(skill);
+        if seen.insert(canonical.to_lowercase()) {
+            skills.push(canonical);
+        }
+    }
+    parsed.skills = skills;
+
+    let mut proficiencies: HashMap<String, SkillProficiency> = HashMap::new();
+    for (skill, level) in parsed.skill_proficiencies.drain() {
+        let key = settings
+            .canonical_skill(&skill)
+            .map_or(skill, |canonical| canonical.to_lowercase());
+        proficiencies
+            .entry(key)
+            .and_modify(|existing| {
+                if level.weight() > existing.weight() {
+                    *existing = level;
+                }
+            })
+            .or_insert(level);
+    }
+    parsed.skill_proficiencies = proficiencies;
+}
+
+/// Share of section pairs out of conventional order before reordering is suggested; one
+/// adjacent swap among four sections stays under it
+const SECTION_ORDER_MAX_INVERSION_RATIO: f64 = 0.25;
+
+/// Section order recruiters expect once work history is the main qualification
+const EXPERIENCED_SECTION_ORDER: &[&str] = &["Summary", "Experience", "Skills", "Education"];
+/// Section order for new graduates, whose education is their strongest qualification
+const EARLY_CAREER_SECTION_ORDER: &[&str] = &["Summary", "Education", "Experience", "Skills"];
+
+/// Conventional section order for an "entry", "mid" or "senior" candidate
+fn conventional_section_order(experience_level: &str) -> &'static [&'static str] {
+    match experience_level.to_lowercase().as_str() {
+        "entry" | "junior" | "intern" | "graduate" => EARLY_CAREER_SECTION_ORDER,
+        _ => EXPERIENCED_SECTION_ORDER,
+    }
+}
+
+/// Sections in the order their headers first appear in the resume, with the line each starts on
+pub fn detect_section_order(
+    resume_content: &str,
+    synonyms: &SectionHeaderSynonyms,
+) -> Vec<(String, usize)> {
+    let mut order: Vec<(String, usize)> = Vec::new();
+    for (line_number, line) in resume_content.lines().enumerate() {
+        if let Some(section) = synonyms.section_for_header(line) {
+            if !order.iter().any(|(seen, _)| seen == section) {
+                order.push((section.to_string(), line_number));
+            }
+        }
+    }
+    order
+}
+
+/// Section header synonyms shared by the ATS parsers
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct SectionHeaderSynonyms {
+    sections: Vec<(String, Vec<String>)>,
+}
+
+impl Default for SectionHeaderSynonyms {
+    fn default() -> Self {
+        Self::from_table(DEFAULT_SECTION_HEADER_SYNONYMS)
+    }
+}
+
+impl SectionHeaderSynonyms {
+    /// Synonyms for exactly the sections and headers in `table`
+    fn from_table(table: &[(&str, &[&str])]) -> Self {
+        Self {
+            sections: table
+                .iter()
+                .map(|(section, headers)| {
+                    (
+                        section.to_string(),
+                        headers.iter().map(|h| h.to_string()).collect(),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Built-in synonyms extended with custom headers from the config
+    pub fn with_custom(custom: &HashMap<String, Vec<String>>) -> Self {
+        let mut synonyms = Self::default();
+        // Sort so unknown sections are appended in a stable order
+        let mut sections: Vec<_> = custom.iter().collect();
+        sections.sort_by(|a, b| a.0.cmp(b.0));
+        for (section, headers) in sections {
+            for header in headers {
+                synonyms.add_synonym(section, header);
+            }
+        }
+        synonyms
+    }
+
+    /// Register a header for a section. Section names match case-insensitively; an unknown
+    /// section is added as a new one.
+    pub fn add_synonym(&mut self, section: &str, header: &str) {
+        let header = header.split_whitespace().collect::<Vec<_>>().join(" ");
+        if section.trim().is_empty() || header.is_empty() {
+            return;
+        }
+
+        let header = header.to_lowercase();
+        match self
+            .sections
+            .iter_mut()
+            .find(|(name, _)| name.eq_ignore_ascii_case(section.trim()))
+        {
+            Some((_, headers)) => {
+                if !headers.contains(&header) {
+                    headers.push(header);
+                }
+            }
+            None => self
+                .sections
+                .push((section.trim().to_string(), vec![header])),
+        }
+    }
+
+    pub fn section_names(&self) -> impl Iterator<Item = &str> {
+        self.sections.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// The section a header line stands for, e.g. "Work Experience:" -> "Experience"
+    pub fn section_for_header(&self, line: &str) -> Option<&str> {
+        let header = line
+            .trim()
+            .trim_end_matches(':')
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase();
+        if header.is_empty() {
+            return None;
+        }
+
+        self.sections
+            .iter()
+            .find(|(_, headers)| headers.contains(&header))
+            .map(|(name, _)| name.as_str())
+    }
+}
+
+/// Header regexes compiled from a synonym map for the sections one parser recognizes
+#[derive(Debug, Clone)]
+struct SectionHeaderPatterns {
+    sections: Vec<(String, Regex)>,
+    any_header: Option<Regex>,
+}
+
+impl SectionHeaderPatterns {
+    /// Compile patterns for `recognized` sections, or for every section when `None`
+    fn build(synonyms: &SectionHeaderSynonyms, recognized: Option<&[&str]>) -> Self {
+        let mut sections = Vec::new();
+        let mut all_headers = Vec::new();
+
+        for (section, headers) in &synonyms.sections {
+            if let Some(recognized) = recognized {
+                if !recognized.iter().any(|r| r.eq_ignore_ascii_case(section)) {
+                    continue;
+                }
+            }
+            if let Some(regex) = Self::header_regex(headers) {
+                sections.push((section.clone(), regex));
+                all_headers.extend(headers.iter().cloned());
+            }
+        }
+
+        Self {
+            sections,
+            any_header: Self::header_regex(&all_headers),
+        }
+    }
+
+    fn header_regex(headers: &[String]) -> Option<Regex> {
+        if headers.is_empty() {
+            return None;
+        }
+
+        let alternatives = headers
+            .iter()
+            .map(|header| {
+                header
+                    .split_whitespace()
+                    .map(regex::escape)
+                    .collect::<Vec<_>>()
+                    .join(r"\s+")
+            })
+            .collect::<Vec<_>>()
+            .join("|");
+
+        Regex::new(&format!(r"(?i)(?:^|\n)\s*(?:{})[\s:\-]*\n", alternatives)).ok()
+    }
+
+    /// Offset where the next section header starts, if any
+    fn next_header(&self, content: &str) -> Option<usize> {
+        self.any_header
+            .as_ref()
+            .and_then(|regex| regex.find(content))
+            .map(|mat| mat.start())
+    }
+
+    /// Find each recognized section and extract the text up to the next header
+    fn parse_sections(&self, content: &str) -> HashMap<String, String> {
+        let mut sections = HashMap::new();
+
+        for (section_name, regex) in &self.sections {
+            if let Some(mat) = regex.find(content) {
+                let remaining = &content[mat.end()..];
+                let section_content = match self.next_header(remaining) {
+                    Some(end) => remaining[..end].trim(),
+                    None => remaining.trim(),
+                };
+                if !section_content.is_empty() {
+                    sections.insert(section_name.clone(), section_content.to_string());
+                }
+            }
+        }
+
+        sections
+    }
+}
+
 // Sample ATS parser implementations
-pub struct WorkdayParser;
-pub struct TaleoParser;
-pub struct GenericParser;
+pub struct WorkdayParser {
+    headers: SectionHeaderPatterns,
+}
+
+pub struct TaleoParser {
+    headers: SectionHeaderPatterns,
+}
+
+pub struct GenericParser {
+    headers: SectionHeaderPatterns,
+}
+
+/// Taleo only recognizes the basic sections, under their plainest headers; custom and
+/// extended synonyms are deliberately left out so the simulation stays strict
+const TALEO_SECTIONS: &[(&str, &[&str])] = &[
+    ("Summary", &["summary", "objective"]),
+    ("Experience", &["experience", "work experience"]),
+    ("Education", &["education"]),
+    ("Skills", &["skills"]),
+];
+
+/// Sections generic ATS systems recognize besides any custom ones
+const GENERIC_EXCLUDED_SECTIONS: &[&str] = &["Achievements"];
+
+/// Longest title or company, in words, an undated job header may have
+const MAX_JOB_HEADER_PART_WORDS: usize = 5;
 
 impl Default for WorkdayParser {
     fn default() -> Self {
@@ -5213,7 +9262,13 @@ impl Default for WorkdayParser {
 
 impl WorkdayParser {
     pub fn new() -> Self {
-        Self
+        Self::with_synonyms(&SectionHeaderSynonyms::default())
+    }
+
+    pub fn with_synonyms(synonyms: &SectionHeaderSynonyms) -> Self {
+        Self {
+            headers: SectionHeaderPatterns::build(synonyms, None),
+        }
     }
 }
 
@@ -5225,7 +9280,12 @@ impl Default for TaleoParser {
 
 impl TaleoParser {
     pub fn new() -> Self {
-        Self
+        Self {
+            headers: SectionHeaderPatterns::build(
+                &SectionHeaderSynonyms::from_table(TALEO_SECTIONS),
+                None,
+            ),
+        }
     }
 }
 
@@ -5237,7 +9297,17 @@ impl Default for GenericParser {
 
 impl GenericParser {
     pub fn new() -> Self {
-        Self
+        Self::with_synonyms(&SectionHeaderSynonyms::default())
+    }
+
+    pub fn with_synonyms(synonyms: &SectionHeaderSynonyms) -> Self {
+        let recognized: Vec<&str> = synonyms
+            .section_names()
+            .filter(|name| !GENERIC_EXCLUDED_SECTIONS.contains(name))
+            .collect();
+        Self {
+            headers: SectionHeaderPatterns::build(synonyms, Some(recognized.as_slice())),
+        }
     }
 }
 
@@ -5248,10 +9318,19 @@ impl ATSParser for WorkdayParser {
 
         // Parse different sections
         let sections = self.parse_sections(&normalized_content)?;
+        let certifications = parse_certifications(
+            sections.get("Certifications").map_or("", String::as_str),
+            Utc::now().date_naive(),
+        );
         let contact_info = self.parse_contact_info(&normalized_content)?;
         let experience = self.parse_experience(&normalized_content)?;
         let education = self.parse_education(&normalized_content)?;
-        let skills = self.parse_skills(&normalized_content)?;
+        let skill_levels = self.parse_skills(&normalized_content)?;
+        let skill_proficiencies = skill_levels
+            .iter()
+            .filter_map(|(skill, level)| Some((skill.to_lowercase(), (*level)?)))
+            .collect();
+        let skills: Vec<String> = skill_levels.into_iter().map(|(skill, _)| skill).collect();
 
         // Calculate parsing confidence based on how well we could extract information
         let parsing_confidence = self.calculate_parsing_confidence(
@@ -5268,6 +9347,9 @@ impl ATSParser for WorkdayParser {
             experience,
             education,
             skills,
+            skill_proficiencies,
+            certifications,
+            skill_groups: Vec::new(),
             parsing_confidence,
         })
     }
@@ -5301,81 +9383,29 @@ impl ATSParser for WorkdayParser {
 
         score.clamp(0.0, 100.0)
     }
+
+    fn explain_issues(&self, _content: &str, resume: &ParsedResume) -> Vec<String> {
+        let mut issues = common_parsing_issues("Workday", resume);
+        if resume.parsing_confidence < 0.7 {
+            issues.push(format!(
+                "Workday parsing confidence is {:.0}%, below the 70% it needs to avoid a penalty",
+                resume.parsing_confidence * 100.0
+            ));
+        }
+        if resume.sections.len() < 4 {
+            issues.push(format!(
+                "Workday found only {} sections; it rewards resumes with at least 4",
+                resume.sections.len()
+            ));
+        }
+        issues
+    }
 }
 
 impl WorkdayParser {
     /// Parse resume sections (Workday expects clear section headers)
     fn parse_sections(&self, content: &str) -> Result<HashMap<String, String>> {
-        let mut sections = HashMap::new();
-
-        // Common section headers that Workday recognizes
-        let section_patterns = [
-            (
-                r"(?i)(?:^|\n)\s*(?:summary|professional\s+summary|profile|objective)[\s:\-]*\n",
-                "Summary",
-            ),
-            (
-                r"(?i)(?:^|\n)\s*(?:experience|professional\s+experience|work\s+experience|employment)[\s:\-]*\n",
-                "Experience",
-            ),
-            (
-                r"(?i)(?:^|\n)\s*(?:education|academic\s+background|educational\s+background)[\s:\-]*\n",
-                "Education",
-            ),
-            (
-                r"(?i)(?:^|\n)\s*(?:skills|technical\s+skills|core\s+competencies|proficiencies)[\s:\-]*\n",
-                "Skills",
-            ),
-            (
-                r"(?i)(?:^|\n)\s*(?:projects|key\s+projects|notable\s+projects)[\s:\-]*\n",
-                "Projects",
-            ),
-            (
-                r"(?i)(?:^|\n)\s*(?:certifications|certificates|professional\s+certifications)[\s:\-]*\n",
-                "Certifications",
-            ),
-            (
-                r"(?i)(?:^|\n)\s*(?:achievements|accomplishments|awards)[\s:\-]*\n",
-                "Achievements",
-            ),
-        ];
-
-        for (pattern, section_name) in &section_patterns {
-            if let Ok(regex) = Regex::new(pattern) {
-                if let Some(mat) = regex.find(content) {
-                    let section_content =
-                        self.extract_section_content(content, mat.end(), section_name);
-                    if !section_content.trim().is_empty() {
-                        sections.insert(section_name.to_string(), section_content);
-                    }
-                }
-            }
-        }
-
-        Ok(sections)
-    }
-
-    /// Extract content for a specific section
-    fn extract_section_content(
-        &self,
-        content: &str,
-        start: usize,
-        _current_section: &str,
-    ) -> String {
-        let remaining = &content[start..];
-
-        // Look for the next section header or end of content
-        let section_end_pattern = r"(?i)(?:^|\n)\s*(?:summary|experience|education|skills|projects|certifications|achievements|professional\s+summary|work\s+experience|technical\s+skills|core\s+competencies|key\s+projects|notable\s+projects|professional\s+certifications|academic\s+background|educational\s+background)[\s:\-]*\n";
-
-        if let Ok(regex) = Regex::new(section_end_pattern) {
-            if let Some(mat) = regex.find(remaining) {
-                remaining[..mat.start()].trim().to_string()
-            } else {
-                remaining.trim().to_string()
-            }
-        } else {
-            remaining.trim().to_string()
-        }
+        Ok(self.headers.parse_sections(content))
     }
 
     /// Parse contact information (Workday is good at extracting this)
@@ -5385,6 +9415,8 @@ impl WorkdayParser {
             email: None,
             phone: None,
             location: None,
+            confidence: ContactConfidence::default(),
+            unconfirmed: Vec::new(),
         };
 
         // Extract name (usually at the top)
@@ -5445,166 +9477,97 @@ impl WorkdayParser {
 
     /// Parse work experience (Workday expects chronological order)
     fn parse_experience(&self, content: &str) -> Result<Vec<ExperienceEntry>> {
-        let mut experience = Vec::new();
-
-        // Look for experience section
-        let experience_pattern = r"(?i)(?:experience|professional\s+experience|work\s+experience|employment)[\s:\-]*\n(.*?)(?=\n\s*(?:education|skills|projects|certifications|achievements|$))";
-
-        if let Ok(regex) = Regex::new(experience_pattern) {
-            if let Some(cap) = regex.captures(content) {
-                let experience_section = &cap[1];
-
-                // Parse individual experience entries
-                let job_pattern = r"(?i)([^(\n]+?)(?:\s*\|\s*|\s*,\s*|\s*-\s*|\s+)([^(\n]+?)(?:\s*\|\s*|\s*,\s*|\s*-\s*|\s+)([^(\n]+?)(?:\n|\s*$)";
-
-                if let Ok(job_regex) = Regex::new(job_pattern) {
-                    for cap in job_regex.captures_iter(experience_section) {
-                        let title = cap[1].trim().to_string();
-                        let company = cap[2].trim().to_string();
-                        let duration = cap[3].trim().to_string();
-
-                        // Extract description and achievements
-                        let (description, achievements) =
-                            self.parse_job_description(experience_section, &title, &company);
-
-                        experience.push(ExperienceEntry {
-                            title,
-                            company,
-                            duration,
-                            description,
-                            achievements,
-                        });
-                    }
-                }
-            }
-        }
-
-        Ok(experience)
-    }
-
-    /// Parse job description and extract achievements
-    fn parse_job_description(
-        &self,
-        section: &str,
-        title: &str,
-        company: &str,
-    ) -> (String, Vec<String>) {
-        let mut description = String::new();
-        let mut achievements = Vec::new();
-
-        // Look for bullet points or achievements after the job title/company
-        let lines: Vec<&str> = section.lines().collect();
-        let mut in_current_job = false;
-        let mut collecting_description = false;
-
-        for line in lines {
-            let line_trimmed = line.trim();
-
-            if line_trimmed.contains(title) && line_trimmed.contains(company) {
-                in_current_job = true;
-                collecting_description = true;
-                continue;
-            }
+        let sections = self.headers.parse_sections(content);
+        let Some(experience_section) = sections.get("Experience") else {
+            return Ok(Vec::new());
+        };
 
-            if in_current_job && collecting_description {
-                // Stop if we hit another job title
-                if !line_trimmed.is_empty()
-                    && !line_trimmed.starts_with('•')
-                    && !line_trimmed.starts_with('-')
-                    && !line_trimmed.starts_with('*')
-                {
-                    // Check if this might be another job
-                    if line_trimmed.contains("20") || line_trimmed.len() > 50 {
-                        break;
-                    }
+        // A new role starts at each non-bullet line carrying a date range, or at an undated job
+        // header after a role's bullets, so undated roles are not folded into the one before;
+        // any other line after a bullet is that bullet wrapping
+        let mut experience: Vec<ExperienceEntry> = Vec::new();
+        for line in experience_section
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+        {
+            let date_range = find_date_range(line);
+            if let Some(achievement) = line.strip_prefix(['•', '-', '*']) {
+                if let Some(job) = experience.last_mut() {
+                    job.achievements.push(achievement.trim().to_string());
                 }
-
-                if line_trimmed.starts_with('•')
-                    || line_trimmed.starts_with('-')
-                    || line_trimmed.starts_with('*')
-                {
-                    let achievement = line_trimmed
-                        .trim_start_matches('•')
-                        .trim_start_matches('-')
-                        .trim_start_matches('*')
-                        .trim();
-                    if !achievement.is_empty() {
-                        achievements.push(achievement.to_string());
-                    }
-                } else if !line_trimmed.is_empty() {
-                    if !description.is_empty() {
-                        description.push(' ');
-                    }
-                    description.push_str(line_trimmed);
+            } else if let Some(bullet) = experience
+                .last_mut()
+                .filter(|_| {
+                    date_range.is_none() && GenericParser::recognized_job_header(line).is_none()
+                })
+                .and_then(|job| job.achievements.last_mut())
+            {
+                bullet.push(' ');
+                bullet.push_str(line);
+            } else if let Some(job) = experience
+                .last_mut()
+                .filter(|job| date_range.is_none() && job.achievements.is_empty())
+            {
+                if !job.description.is_empty() {
+                    job.description.push(' ');
                 }
+                job.description.push_str(line);
+            } else {
+                let (title, company, duration) = GenericParser::parse_job_header(line);
+                experience.push(ExperienceEntry {
+                    title,
+                    company,
+                    duration: date_range.map_or(duration, str::to_string),
+                    description: String::new(),
+                    achievements: Vec::new(),
+                });
             }
         }
 
-        (description, achievements)
+        Ok(experience)
     }
 
     /// Parse education information
     fn parse_education(&self, content: &str) -> Result<Vec<EducationEntry>> {
-        let mut education = Vec::new();
-
-        let education_pattern = r"(?i)(?:education|academic\s+background|educational\s+background)[\s:\-]*\n(.*?)(?=\n\s*(?:experience|skills|projects|certifications|achievements|$))";
-
-        if let Ok(regex) = Regex::new(education_pattern) {
-            if let Some(cap) = regex.captures(content) {
-                let education_section = &cap[1];
-
-                // Parse degree entries
-                let degree_pattern = r"(?i)([^(\n]+?)(?:\s*\|\s*|\s*,\s*|\s*-\s*|\s+)([^(\n]+?)(?:\s*\|\s*|\s*,\s*|\s*-\s*|\s+)?([0-9]{4})?";
-
-                if let Ok(degree_regex) = Regex::new(degree_pattern) {
-                    for cap in degree_regex.captures_iter(education_section) {
-                        let degree = cap[1].trim().to_string();
-                        let institution = cap[2].trim().to_string();
-                        let year = cap.get(3).map(|m| m.as_str().to_string());
+        let sections = self.headers.parse_sections(content);
+        let Some(education_section) = sections.get("Education") else {
+            return Ok(Vec::new());
+        };
 
-                        education.push(EducationEntry {
-                            degree,
-                            institution,
-                            year,
-                            gpa: None, // Could be enhanced to parse GPA
-                        });
-                    }
+        let education = education_section
+            .lines()
+            .map(|line| line.trim_start_matches(['•', '-', '*']).trim())
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let (degree, institution, year) = GenericParser::parse_education_line(line);
+                EducationEntry {
+                    degree,
+                    institution,
+                    year,
+                    gpa: None, // Could be enhanced to parse GPA
                 }
-            }
-        }
+            })
+            .collect();
 
         Ok(education)
     }
 
     /// Parse skills section
-    fn parse_skills(&self, content: &str) -> Result<Vec<String>> {
-        let mut skills = Vec::new();
-
-        let skills_pattern = r"(?i)(?:skills|technical\s+skills|core\s+competencies|proficiencies)[\s:\-]*\n(.*?)(?=\n\s*(?:experience|education|projects|certifications|achievements|$))";
-
-        if let Ok(regex) = Regex::new(skills_pattern) {
-            if let Some(cap) = regex.captures(content) {
-                let skills_section = &cap[1];
-
-                // Parse skills - they can be comma-separated, bullet points, or line-separated
-                let skill_patterns = [
-                    r"(?i)([^,\n•\-\*]+)(?:,|\n|•|\-|\*|$)", // Comma or line separated
-                ];
-
-                for pattern in &skill_patterns {
-                    if let Ok(skill_regex) = Regex::new(pattern) {
-                        for cap in skill_regex.captures_iter(skills_section) {
-                            let skill = cap[1].trim().to_string();
-                            if !skill.is_empty() && skill.len() > 1 {
-                                skills.push(skill);
-                            }
-                        }
-                    }
-                }
-            }
-        }
+    /// Parse skills with the proficiency written beside each, e.g. "Python (expert)" or
+    /// "Java – intermediate"; skills may be comma-separated, bulleted or one per line
+    fn parse_skills(&self, content: &str) -> Result<Vec<(String, Option<SkillProficiency>)>> {
+        let sections = self.headers.parse_sections(content);
+        let Some(skills_section) = sections.get("Skills") else {
+            return Ok(Vec::new());
+        };
 
-        Ok(skills)
+        Ok(skills_section
+            .split([',', ';', '\n', '•', '|'])
+            .map(|entry| entry.trim().trim_start_matches(['-', '*']))
+            .map(split_proficiency)
+            .filter(|(skill, _)| skill.chars().count() > 1)
+            .collect())
     }
 
     /// Calculate parsing confidence based on extracted information
@@ -5670,6 +9633,10 @@ impl ATSParser for TaleoParser {
 
         // Parse with Taleo's more basic parsing approach
         let sections = self.parse_sections_basic(&simplified_content)?;
+        let certifications = parse_certifications(
+            sections.get("Certifications").map_or("", String::as_str),
+            Utc::now().date_naive(),
+        );
         let contact_info = self.parse_contact_info_basic(&simplified_content)?;
         let experience = self.parse_experience_basic(&simplified_content)?;
         let education = self.parse_education_basic(&simplified_content)?;
@@ -5690,6 +9657,9 @@ impl ATSParser for TaleoParser {
             experience,
             education,
             skills,
+            skill_proficiencies: HashMap::new(),
+            certifications,
+            skill_groups: Vec::new(),
             parsing_confidence,
         })
     }
@@ -5736,6 +9706,43 @@ impl ATSParser for TaleoParser {
 
         score.clamp(0.0, 100.0)
     }
+
+    fn explain_issues(&self, content: &str, resume: &ParsedResume) -> Vec<String> {
+        let mut issues = common_parsing_issues("Taleo", resume);
+        let column_lines = multi_column_lines(content);
+        if column_lines >= 3 {
+            issues.push(format!(
+                "{} lines look like side-by-side columns; Taleo reads each line left to right \
+                 and merges the columns into one jumbled line",
+                column_lines
+            ));
+        }
+        if resume.parsing_confidence < 0.7 {
+            issues.push(format!(
+                "Taleo parsing confidence is low ({:.0}%), which it penalizes heavily; \
+                 use a single-column layout with standard headers",
+                resume.parsing_confidence * 100.0
+            ));
+        }
+        if resume.contact_info.phone.is_none() {
+            issues.push(
+                "Taleo could not find a phone number; it expects name, email and phone together"
+                    .to_string(),
+            );
+        }
+        if resume.sections.len() > 6 {
+            issues.push(format!(
+                "Taleo found {} sections; more than 6 confuses its section detection",
+                resume.sections.len()
+            ));
+        } else if resume.sections.len() < 3 {
+            issues.push(format!(
+                "Taleo found only {} sections",
+                resume.sections.len()
+            ));
+        }
+        issues
+    }
 }
 
 impl TaleoParser {
@@ -5758,49 +9765,7 @@ impl TaleoParser {
 
     /// Basic section parsing (Taleo doesn't handle complex section detection well)
     fn parse_sections_basic(&self, content: &str) -> Result<HashMap<String, String>> {
-        let mut sections = HashMap::new();
-
-        // Very basic section headers - Taleo only recognizes simple patterns
-        let section_patterns = [
-            (r"(?i)(?:^|\n)\s*(?:summary|objective)[\s:\-]*\n", "Summary"),
-            (
-                r"(?i)(?:^|\n)\s*(?:experience|work experience)[\s:\-]*\n",
-                "Experience",
-            ),
-            (r"(?i)(?:^|\n)\s*(?:education)[\s:\-]*\n", "Education"),
-            (r"(?i)(?:^|\n)\s*(?:skills)[\s:\-]*\n", "Skills"),
-        ];
-
-        for (pattern, section_name) in &section_patterns {
-            if let Ok(regex) = Regex::new(pattern) {
-                if let Some(mat) = regex.find(content) {
-                    let section_content = self.extract_section_content_basic(content, mat.end());
-                    if !section_content.trim().is_empty() {
-                        sections.insert(section_name.to_string(), section_content);
-                    }
-                }
-            }
-        }
-
-        Ok(sections)
-    }
-
-    /// Basic section content extraction
-    fn extract_section_content_basic(&self, content: &str, start: usize) -> String {
-        let remaining = &content[start..];
-
-        // Look for next section (very basic patterns only)
-        let section_end_pattern = r"(?i)(?:^|\n)\s*(?:summary|objective|experience|work experience|education|skills)[\s:\-]*\n";
-
-        if let Ok(regex) = Regex::new(section_end_pattern) {
-            if let Some(mat) = regex.find(remaining) {
-                remaining[..mat.start()].trim().to_string()
-            } else {
-                remaining.trim().to_string()
-            }
-        } else {
-            remaining.trim().to_string()
-        }
+        Ok(self.headers.parse_sections(content))
     }
 
     /// Basic contact info parsing (Taleo struggles with complex formats)
@@ -5810,6 +9775,8 @@ impl TaleoParser {
             email: None,
             phone: None,
             location: None,
+            confidence: ContactConfidence::default(),
+            unconfirmed: Vec::new(),
         };
 
         // Very basic name extraction - first line approach
@@ -6008,6 +9975,10 @@ impl ATSParser for GenericParser {
 
         // Generic ATS systems typically have very basic parsing capabilities
         let sections = self.parse_sections_generic(&normalized_content)?;
+        let certifications = parse_certifications(
+            sections.get("Certifications").map_or("", String::as_str),
+            Utc::now().date_naive(),
+        );
         let contact_info = self.parse_contact_info_generic(&normalized_content)?;
         let experience = self.parse_experience_generic(&normalized_content)?;
         let education = self.parse_education_generic(&normalized_content)?;
@@ -6028,6 +9999,9 @@ impl ATSParser for GenericParser {
             experience,
             education,
             skills,
+            skill_proficiencies: HashMap::new(),
+            certifications,
+            skill_groups: Vec::new(),
             parsing_confidence,
         })
     }
@@ -6070,71 +10044,24 @@ impl ATSParser for GenericParser {
 
         score.clamp(0.0, 100.0)
     }
+
+    fn explain_issues(&self, _content: &str, resume: &ParsedResume) -> Vec<String> {
+        let mut issues = common_parsing_issues("Generic ATS", resume);
+        if resume.parsing_confidence < 0.6 {
+            issues.push(format!(
+                "Generic ATS parsing confidence is {:.0}%, below the 60% it needs to avoid a \
+                 penalty",
+                resume.parsing_confidence * 100.0
+            ));
+        }
+        issues
+    }
 }
 
 impl GenericParser {
     /// Generic section parsing (moderate capabilities)
     fn parse_sections_generic(&self, content: &str) -> Result<HashMap<String, String>> {
-        let mut sections = HashMap::new();
-
-        // Generic ATS systems recognize common section patterns
-        let section_patterns = [
-            (
-                r"(?i)(?:^|\n)\s*(?:summary|professional summary|profile|objective|career objective)[\s:\-]*\n",
-                "Summary",
-            ),
-            (
-                r"(?i)(?:^|\n)\s*(?:experience|professional experience|work experience|employment history|career history)[\s:\-]*\n",
-                "Experience",
-            ),
-            (
-                r"(?i)(?:^|\n)\s*(?:education|educational background|academic background|qualifications)[\s:\-]*\n",
-                "Education",
-            ),
-            (
-                r"(?i)(?:^|\n)\s*(?:skills|technical skills|core competencies|key skills|expertise)[\s:\-]*\n",
-                "Skills",
-            ),
-            (
-                r"(?i)(?:^|\n)\s*(?:projects|key projects|notable projects|project experience)[\s:\-]*\n",
-                "Projects",
-            ),
-            (
-                r"(?i)(?:^|\n)\s*(?:certifications|certificates|professional certifications|licenses)[\s:\-]*\n",
-                "Certifications",
-            ),
-        ];
-
-        for (pattern, section_name) in &section_patterns {
-            if let Ok(regex) = Regex::new(pattern) {
-                if let Some(mat) = regex.find(content) {
-                    let section_content = self.extract_section_content_generic(content, mat.end());
-                    if !section_content.trim().is_empty() {
-                        sections.insert(section_name.to_string(), section_content);
-                    }
-                }
-            }
-        }
-
-        Ok(sections)
-    }
-
-    /// Generic section content extraction
-    fn extract_section_content_generic(&self, content: &str, start: usize) -> String {
-        let remaining = &content[start..];
-
-        // Look for next section header
-        let section_end_pattern = r"(?i)(?:^|\n)\s*(?:summary|professional summary|profile|objective|career objective|experience|professional experience|work experience|employment history|career history|education|educational background|academic background|qualifications|skills|technical skills|core competencies|key skills|expertise|projects|key projects|notable projects|project experience|certifications|certificates|professional certifications|licenses)[\s:\-]*\n";
-
-        if let Ok(regex) = Regex::new(section_end_pattern) {
-            if let Some(mat) = regex.find(remaining) {
-                remaining[..mat.start()].trim().to_string()
-            } else {
-                remaining.trim().to_string()
-            }
-        } else {
-            remaining.trim().to_string()
-        }
+        Ok(self.headers.parse_sections(content))
     }
 
     /// Generic contact info parsing
@@ -6144,6 +10071,8 @@ impl GenericParser {
             email: None,
             phone: None,
             location: None,
+            confidence: ContactConfidence::default(),
+            unconfirmed: Vec::new(),
         };
 
         // Name extraction - try multiple approaches
@@ -6252,7 +10181,7 @@ impl GenericParser {
 
             // First line usually contains job title, company, and dates
             let first_line = lines[0].trim();
-            let (title, company, duration) = self.parse_job_header(first_line);
+            let (title, company, duration) = Self::parse_job_header(first_line);
 
             // Remaining lines are description and achievements
             let mut description = String::new();
@@ -6297,7 +10226,19 @@ impl GenericParser {
     }
 
     /// Parse job header line
-    fn parse_job_header(&self, header: &str) -> (String, String, String) {
+    fn parse_job_header(header: &str) -> (String, String, String) {
+        // Fallback: assume the whole line is the title
+        Self::recognized_job_header(header).unwrap_or_else(|| {
+            (
+                header.to_string(),
+                "Unknown Company".to_string(),
+                "Unknown Duration".to_string(),
+            )
+        })
+    }
+
+    /// Title, company and duration when the line has the shape of a job header
+    fn recognized_job_header(header: &str) -> Option<(String, String, String)> {
         // Try different patterns for job header
         let patterns = [
             r"([^|]+)\s*\|\s*([^|]+)\s*\|\s*([^|]+)", // Title | Company | Duration
@@ -6309,21 +10250,35 @@ impl GenericParser {
         for pattern in &patterns {
             if let Ok(regex) = Regex::new(pattern) {
                 if let Some(cap) = regex.captures(header) {
-                    return (
+                    return Some((
                         cap[1].trim().to_string(),
                         cap[2].trim().to_string(),
                         cap[3].trim().to_string(),
-                    );
+                    ));
                 }
             }
         }
 
-        // Fallback: assume the whole line is the title
-        (
-            header.to_string(),
-            "Unknown Company".to_string(),
-            "Unknown Duration".to_string(),
-        )
+        // Undated "Title, Company", "Title | Company" or "Title at Company", where each part is
+        // short and capitalized like a name rather than running prose
+        let (title, company) = header
+            .split_once(" | ")
+            .or_else(|| header.split_once(", "))
+            .or_else(|| header.split_once(" at "))?;
+        let is_name = |part: &str| {
+            let words: Vec<&str> = part.split_whitespace().collect();
+            (1..=MAX_JOB_HEADER_PART_WORDS).contains(&words.len())
+                && words
+                    .iter()
+                    .all(|word| !word.chars().next().is_some_and(char::is_lowercase))
+        };
+        (is_name(title) && is_name(company)).then(|| {
+            (
+                title.trim().to_string(),
+                company.trim().to_string(),
+                "Unknown Duration".to_string(),
+            )
+        })
     }
 
     /// Generic education parsing
@@ -6344,7 +10299,7 @@ impl GenericParser {
                     }
 
                     // Parse degree line - try multiple patterns
-                    let (degree, institution, year) = self.parse_education_line(line_trimmed);
+                    let (degree, institution, year) = Self::parse_education_line(line_trimmed);
 
                     education.push(EducationEntry {
                         degree,
@@ -6360,7 +10315,7 @@ impl GenericParser {
     }
 
     /// Parse individual education line
-    fn parse_education_line(&self, line: &str) -> (String, String, Option<String>) {
+    fn parse_education_line(line: &str) -> (String, String, Option<String>) {
         // Try different patterns for education
         let patterns = [
             r"([^|]+)\s*\|\s*([^|]+)\s*\|\s*([0-9]{4})", // Degree | Institution | Year
@@ -6480,3 +10435,2239 @@ impl GenericParser {
         confidence.clamp(0.0, 1.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keywords(words: &[&str]) -> Vec<String> {
+        words.iter().map(|w| w.to_string()).collect()
+    }
+
+    #[test]
+    fn test_section_density_flags_stuffed_skills_section() {
+        let analyzer = KeywordAnalyzer::new();
+        let resume = "Summary\n\
+            Backend engineer who enjoys building reliable services for customers and teams.\n\
+            Experience\n\
+            Built data pipelines in Python at Acme Corp and mentored two junior engineers on the team.\n\
+            Skills\n\
+            Python Java SQL Python AWS Docker Kubernetes Python Java SQL AWS Docker Kubernetes Python\n";
+        let target = keywords(&["python", "java", "sql", "aws", "docker", "kubernetes"]);
+
+        let density = analyzer.calculate_section_density(resume, &target).unwrap();
+        let stuffed = analyzer
+            .detect_stuffed_sections(
+                resume,
+                &target,
+                &density,
+                KEYWORD_STUFFING_DENSITY_THRESHOLD,
+            )
+            .unwrap();
+
+        assert!(density["Skills"] > KEYWORD_STUFFING_DENSITY_THRESHOLD);
+        assert!(density["Experience"] < KEYWORD_STUFFING_DENSITY_THRESHOLD);
+        assert_eq!(stuffed, vec!["Skills".to_string()]);
+
+        let keyword_match = KeywordMatch {
+            exact_matches: Vec::new(),
+            stemmed_matches: Vec::new(),
+            contextual_matches: Vec::new(),
+            synonym_matches: Vec::new(),
+            overall_score: 0.0,
+            match_density: 0.0,
+            section_distribution: HashMap::new(),
+            section_density: density,
+            stuffed_sections: stuffed,
+            language: Language::English,
+            keyword_score_match_cap: KEYWORD_SCORE_MATCH_CAP,
+            match_type_weights: MatchTypeWeights::default(),
+            keyword_occurrences: HashMap::new(),
+            rule_matches: BTreeMap::new(),
+            context_quality: HashMap::new(),
+        };
+        let issues = FormatAnalyzer::new().keyword_stuffing_issues(resume, &keyword_match);
+
+        assert_eq!(issues.len(), 1);
+        assert!(matches!(
+            issues[0].issue_type,
+            FormatIssueType::KeywordStuffing
+        ));
+        assert_eq!(issues[0].location, "Skills");
+    }
+
+    #[tokio::test]
+    async fn test_clean_skills_list_is_dense_but_not_stuffed() {
+        let resume = "Summary\n\
+            Backend engineer who enjoys building reliable services for customers and teams.\n\
+            Skills\n\
+            Python, Java, SQL, AWS, Docker, Kubernetes, Terraform, Go, Rust, Kafka\n";
+        let target = keywords(&[
+            "python",
+            "java",
+            "sql",
+            "aws",
+            "docker",
+            "kubernetes",
+            "terraform",
+            "go",
+            "rust",
+            "kafka",
+        ]);
+
+        let analyzer = KeywordAnalyzer::new();
+        let density = analyzer.calculate_section_density(resume, &target).unwrap();
+        assert!(density["Skills"] > KEYWORD_STUFFING_DENSITY_THRESHOLD);
+        assert!(analyzer
+            .detect_stuffed_sections(
+                resume,
+                &target,
+                &density,
+                KEYWORD_STUFFING_DENSITY_THRESHOLD
+            )
+            .unwrap()
+            .is_empty());
+
+        let job_description = "We need Python, Java, SQL, AWS, Docker, Kubernetes, Terraform, \
+            Go, Rust and Kafka experience.";
+        let keyword_analysis = analyzer
+            .analyze_comprehensive(resume, job_description, "technology")
+            .await
+            .unwrap();
+        assert!(keyword_analysis.stuffed_sections.is_empty());
+        assert!(FormatAnalyzer::new()
+            .keyword_stuffing_issues(resume, &keyword_analysis)
+            .is_empty());
+    }
+
+    async fn seed_industry_analyses(db: &Database, industry: &str, scores: &[f64]) {
+        use crate::models::{Analysis, JobDescription, Resume};
+        use chrono::Utc;
+
+        let now = Utc::now();
+        let job_id = format!("job-{}", industry);
+        db.save_job_description(&JobDescription {
+            id: job_id.clone(),
+            title: "Engineer".to_string(),
+            company: "Acme".to_string(),
+            content: "Build services".to_string(),
+            requirements: "[]".to_string(),
+            preferred_qualifications: None,
+            salary_range_min: None,
+            salary_range_max: None,
+            salary_currency: None,
+            location: String::new(),
+            remote_options: Default::default(),
+            remote_region: None,
+            visa_sponsorship: Default::default(),
+            employment_type: Default::default(),
+            experience_level: Default::default(),
+            posted_date: None,
+            application_deadline: None,
+            job_url: None,
+            keywords: "[]".to_string(),
+            industry: Some(industry.to_string()),
+            department: None,
+            status: Default::default(),
+            priority: Default::default(),
+            notes: None,
+            application_status: Default::default(),
+            application_date: None,
+            interview_date: None,
+            response_deadline: None,
+            contact_person: None,
+            contact_email: None,
+            tags: "[]".to_string(),
+            source: Default::default(),
+            is_archived: false,
+            created_at: now,
+            updated_at: now,
+        })
+        .await
+        .unwrap();
+
+        let resume_id = format!("resume-{}", industry);
+        db.save_resume(&Resume {
+            id: resume_id.clone(),
+            filename: "resume.txt".to_string(),
+            content: "Resume".to_string(),
+            file_type: "txt".to_string(),
+            created_at: now,
+            updated_at: now,
+        })
+        .await
+        .unwrap();
+
+        for (i, score) in scores.iter().enumerate() {
+            db.save_analysis(&Analysis {
+                id: format!("{}-{}", industry, i),
+                resume_id: resume_id.clone(),
+                job_description_id: job_id.clone(),
+                model_used: "test".to_string(),
+                overall_score: *score,
+                skills_score: *score,
+                experience_score: *score,
+                education_score: *score,
+                keywords_score: *score,
+                format_score: *score,
+                detailed_feedback: String::new(),
+                missing_keywords: String::new(),
+                recommendations: String::new(),
+                processing_time_ms: 0,
+                created_at: now,
+            })
+            .await
+            .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stored_analyses_drive_industry_percentile() {
+        let db = Database::new_with_url("sqlite::memory:").await.unwrap();
+        let low_scores: Vec<f64> = (0..25).map(|i| 40.0 + i as f64).collect();
+        seed_industry_analyses(&db, "technology", &low_scores).await;
+        seed_industry_analyses(&db, "finance", &[55.0, 65.0]).await;
+
+        // Too few finance samples to replace the built-in benchmarks
+        assert!(db
+            .compute_industry_distribution("finance")
+            .await
+            .unwrap()
+            .is_none());
+
+        let distribution = db
+            .compute_industry_distribution("Technology")
+            .await
+            .unwrap()
+            .expect("enough technology samples for a distribution");
+
+        let engine = AdvancedScoringEngine::new(Arc::new(Mutex::new(db)));
+        let default_distribution = engine.build_industry_benchmarks()["technology"]
+            .score_distribution
+            .clone();
+
+        let stored_percentile = engine.calculate_percentile(60.0, &distribution);
+        let default_percentile = engine.calculate_percentile(60.0, &default_distribution);
+
+        assert_eq!(stored_percentile, 21.0 / 25.0 * 100.0);
+        assert_ne!(stored_percentile, default_percentile);
+    }
+
+    #[test]
+    fn test_classified_sections_stay_out_of_ats_simulations() {
+        let resume = "Jane Doe\njane@example.com\n\nMy Journey\n\
+                      Senior Engineer, Acme Corp, 2019 - Present\n\
+                      • Led a team of five engineers building payment services\n\n\
+                      Education\nBS Computer Science\n";
+
+        let simulator = ATSSimulator::new();
+        for (system, report) in simulator.system_reports(resume).unwrap() {
+            assert!(
+                !report.sections_found.contains(&"Experience".to_string()),
+                "{:?} should not see the creative heading",
+                system
+            );
+        }
+        let parsed = simulator.parse_with_multiple_systems(resume).unwrap();
+        assert!(parsed.sections["Experience"].starts_with("Senior Engineer, Acme Corp"));
+    }
+
+    #[test]
+    fn test_custom_section_header_synonym_detected() {
+        let resume = "Jane Doe\njane@example.com\n\nCareer Highlights\nLed migration of billing platform to Rust\n\nEducation\nBS Computer Science\n";
+
+        let default_parser = WorkdayParser::with_synonyms(&SectionHeaderSynonyms::default());
+        let sections = default_parser.parse_resume(resume).unwrap().sections;
+        assert!(!sections.contains_key("Experience"));
+
+        let custom = HashMap::from([(
+            "experience".to_string(),
+            vec!["Career Highlights".to_string()],
+        )]);
+        let synonyms = SectionHeaderSynonyms::with_custom(&custom);
+        assert_eq!(
+            synonyms.section_for_header("Career Highlights:"),
+            Some("Experience")
+        );
+
+        let parsers: Vec<Box<dyn ATSParser + Send + Sync>> = vec![
+            Box::new(WorkdayParser::with_synonyms(&synonyms)),
+            Box::new(GenericParser::with_synonyms(&synonyms)),
+        ];
+        for parser in parsers {
+            let sections = parser.parse_resume(resume).unwrap().sections;
+            assert_eq!(
+                sections.get("Experience").map(String::as_str),
+                Some("Led migration of billing platform to Rust"),
+                "{:?} missed the custom header",
+                parser.get_system_type()
+            );
+            assert_eq!(
+                sections.get("Education").map(String::as_str),
+                Some("BS Computer Science")
+            );
+        }
+
+        // Taleo keeps to its own basic headers, so neither the custom nor the extended
+        // built-in synonyms reach it
+        let taleo = TaleoParser::new();
+        assert!(!taleo
+            .parse_resume(resume)
+            .unwrap()
+            .sections
+            .contains_key("Experience"));
+        let extended = "Jane Doe\n\nProfessional Summary\nBackend engineer\n\nEducation\nBS\n";
+        let taleo_sections = taleo.parse_resume(extended).unwrap().sections;
+        assert!(!taleo_sections.contains_key("Summary"));
+        assert_eq!(
+            taleo_sections.get("Education").map(String::as_str),
+            Some("BS")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_matchers_match_sequential_results() {
+        let job_description = "Senior Backend Engineer. Requirements: Python, Rust, PostgreSQL, Docker, Kubernetes, AWS, microservices, REST API design, leadership and communication skills. Bachelor's degree in Computer Science.";
+        let block = "Senior Software Engineer - Acme Corp (2019 - Present)\n\
+            • Developed Python and Rust microservices on Kubernetes serving 2M requests per day\n\
+            • Led migration of PostgreSQL clusters to AWS, reducing costs by 30%\n\
+            • Designed RESTful APIs and mentored engineers on containerization with Docker\n\
+            • Communicated roadmap to stakeholders and managed cross-functional delivery\n\n";
+        // Large fixture: ~40 roles worth of bullets
+        let resume = format!(
+            "Jane Doe\njane@example.com\n\nEXPERIENCE\n{}\nEDUCATION\nBS Computer Science\n",
+            block.repeat(40)
+        );
+
+        let analyzer = KeywordAnalyzer::new();
+        let keywords = analyzer
+            .extract_keywords_from_job_description(job_description)
+            .unwrap();
+
+        let sequential = analyzer
+            .run_matchers_sequentially(&resume, &keywords)
+            .unwrap();
+        let concurrent = analyzer
+            .run_matchers_concurrently(&resume, &keywords)
+            .await
+            .unwrap();
+
+        assert!(!sequential.exact_matches.is_empty());
+        assert_eq!(sequential, concurrent);
+    }
+
+    #[tokio::test]
+    async fn test_explain_reports_stemmed_match_forms() {
+        let resume = "EXPERIENCE\nBuilt a microservice platform for payments\n";
+        let job_description = "We need experience with microservices and docker.";
+
+        let keyword_match = KeywordAnalyzer::new()
+            .analyze_comprehensive(resume, job_description, "technology")
+            .await
+            .unwrap();
+        let explanations = keyword_match.explain();
+
+        let explanation = explanations
+            .iter()
+            .find(|e| e.keyword == "microservices")
+            .expect("microservices should be explained");
+        let stemmed = explanation
+            .matches
+            .iter()
+            .find(|m| m.match_type == MatchType::Stemmed)
+            .expect("microservice should match by stem");
+
+        assert_eq!(stemmed.matched_text, "microservice");
+        assert_eq!(stemmed.stemmed_form.as_deref(), Some("microservic"));
+        assert!(stemmed.weight > 0.0);
+        assert!(stemmed.score_contribution > 0.0);
+        assert!(explanation.total_contribution >= stemmed.score_contribution);
+        assert!(!explanations.iter().any(|e| e.keyword == "docker"));
+    }
+
+    #[tokio::test]
+    async fn test_keyword_near_top_scores_higher_than_at_bottom() {
+        let job_description = "Looking for an engineer with terraform.";
+        let filler = [
+            "Maintained the billing platform for regional customers.",
+            "Coordinated quarterly planning with product partners.",
+            "Reviewed pull requests and wrote design documents.",
+            "Organized onboarding sessions for new hires.",
+            "Presented roadmap updates at company meetings.",
+        ];
+        let keyword_line = "Provisioned cloud infrastructure with terraform.";
+
+        let top = std::iter::once(keyword_line)
+            .chain(filler)
+            .collect::<Vec<_>>()
+            .join("\n");
+        let bottom = filler
+            .into_iter()
+            .chain(std::iter::once(keyword_line))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let analyzer = KeywordAnalyzer::new();
+        let top_match = analyzer
+            .analyze_comprehensive(&top, job_description, "technology")
+            .await
+            .unwrap();
+        let bottom_match = analyzer
+            .analyze_comprehensive(&bottom, job_description, "technology")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            top_match.exact_matches.len(),
+            bottom_match.exact_matches.len()
+        );
+        assert!(!top_match.exact_matches.is_empty());
+        assert!(
+            top_match.overall_score > bottom_match.overall_score,
+            "top {} should beat bottom {}",
+            top_match.overall_score,
+            bottom_match.overall_score
+        );
+    }
+
+    #[test]
+    fn test_position_weighting_boosts_top_and_priority_sections() {
+        let weighting = KeywordPositionWeighting::default();
+
+        assert!((weighting.factor(0.0, "Experience") - 1.5).abs() < 1e-9);
+        assert!((weighting.factor(1.0, "Experience") - 1.0).abs() < 1e-9);
+        assert!(weighting.factor(0.2, "Experience") > weighting.factor(0.8, "Experience"));
+        assert!(weighting.factor(1.0, "skills") > weighting.factor(1.0, "Education"));
+    }
+
+    #[test]
+    fn test_spanish_resume_uses_spanish_stemmer() {
+        let resume = "Ingeniero de software con experiencia en la programación de sistemas \
+                      distribuidos y en el desarrollo de servicios para los clientes.";
+        assert_eq!(detect_language(resume), Language::Spanish);
+
+        // The Spanish stemmer reduces both forms to "program"; the English one leaves them apart
+        let english = Language::English.stemmer();
+        assert_ne!(english.stem("programación"), english.stem("programador"));
+
+        let matches = StemmedMatcher
+            .find_matches(resume, &["programador".to_string()])
+            .unwrap();
+
+        let matched = matches
+            .iter()
+            .find(|m| m.keyword == "programador")
+            .expect("programación should match programador by Spanish stem");
+        assert_eq!(matched.matched_text, "programación");
+    }
+
+    #[tokio::test]
+    async fn test_relevant_masters_outscores_unrelated_bachelors() {
+        let db = Database::new_with_url("sqlite::memory:").await.unwrap();
+        let engine = AdvancedScoringEngine::new(Arc::new(Mutex::new(db)));
+        let parser = WorkdayParser::new();
+        let education_of = |degree_line: &str| {
+            let resume = format!(
+                "Jane Doe\njane@example.com\n\nExperience\nSoftware Engineer, Acme\n\n\
+                 Education\n{}\n\nSkills\nRust, Python\n",
+                degree_line
+            );
+            parser.parse_resume(&resume).unwrap().education
+        };
+
+        let masters = education_of("M.S. Computer Science, Stanford University, 2018");
+        assert_eq!(masters.len(), 1);
+        assert_eq!(masters[0].degree_level(), Some(DegreeLevel::Master));
+        let bachelors = education_of("B.A. Art History, State University, 2016");
+        assert_eq!(bachelors[0].degree_level(), Some(DegreeLevel::Bachelor));
+        let doctorate = education_of("Ph.D. Computer Science, MIT, 2020");
+        assert_eq!(doctorate[0].degree_level(), Some(DegreeLevel::Doctorate));
+
+        let masters_score = engine.calculate_education_score(&masters, "technology");
+        let bachelors_score = engine.calculate_education_score(&bachelors, "technology");
+        let doctorate_score = engine.calculate_education_score(&doctorate, "technology");
+        assert!(
+            masters_score > bachelors_score,
+            "relevant master's {} should beat unrelated bachelor's {}",
+            masters_score,
+            bachelors_score
+        );
+        assert!(doctorate_score > bachelors_score);
+        assert!(doctorate_score >= masters_score);
+
+        // Field relevance depends on the industry being scored
+        assert!(engine.calculate_education_score(&masters, "healthcare") < masters_score);
+        assert_eq!(engine.calculate_education_score(&[], "technology"), 50.0);
+    }
+
+    #[test]
+    fn test_upward_progression_scores_above_flat() {
+        let resume = |titles: [&str; 4]| {
+            format!(
+                "Jane Doe\njane@example.com\n\n\
+                 Experience\n\
+                 {}, Initech, Jan 2022 - Present\n\
+                 • Owned the platform roadmap\n\
+                 {}, Initech, Mar 2019 - Dec 2021\n\
+                 • Ran the payments team\n\
+                 {}, Globex, Jun 2016 - Feb 2019\n\
+                 • Built billing services\n\
+                 {}, Hooli, Jan 2014 - May 2016\n\
+                 • Shipped reporting features\n\n\
+                 Skills\nRust, Go\n",
+                titles[0], titles[1], titles[2], titles[3]
+            )
+        };
+        let parser = WorkdayParser::new();
+
+        let upward = parser
+            .parse_resume(&resume([
+                "Engineering Manager",
+                "Tech Lead",
+                "Senior Software Engineer",
+                "Software Engineer",
+            ]))
+            .unwrap();
+        assert_eq!(upward.experience.len(), 4);
+        assert_eq!(upward.experience[0].duration, "Jan 2022 - Present");
+        let upward = analyze_career_progression(&upward.experience);
+        assert_eq!(upward.levels, vec![2, 3, 4, 5]);
+        assert_eq!(upward.trend, ProgressionTrend::Upward);
+        assert!(upward.flag.is_none());
+
+        let flat = parser
+            .parse_resume(&resume([
+                "Software Engineer",
+                "Software Engineer",
+                "Software Engineer",
+                "Software Engineer",
+            ]))
+            .unwrap();
+        let flat = analyze_career_progression(&flat.experience);
+        assert_eq!(flat.trend, ProgressionTrend::Flat);
+        assert!(flat.flag.is_some());
+
+        assert!(
+            upward.score > flat.score,
+            "upward {} should outscore flat {}",
+            upward.score,
+            flat.score
+        );
+    }
+
+    #[tokio::test]
+    async fn test_strict_profile_scores_below_lenient() {
+        let db = Database::new_with_url("sqlite::memory:").await.unwrap();
+        let engine = AdvancedScoringEngine::new(Arc::new(Mutex::new(db)));
+        let resume = "Jane Doe\njane@example.com\n\n\
+                      EXPERIENCE\n\
+                      Software Engineer | Acme | 2019 - Present\n\
+                      \t\t\t\t\t\tBuilt Python services and REST APIs\n\
+                      • Migrated reporting jobs to PostgreSQL\n\n\
+                      SKILLS\nPython, SQL\n";
+        let job_description = "Backend engineer with Python, Go, Kubernetes, PostgreSQL, \
+                               REST APIs, Terraform, AWS and observability experience.";
+
+        let analyze = |name: &'static str| {
+            let engine = &engine;
+            async move {
+                engine
+                    .analyze_comprehensive_with_options(
+                        resume,
+                        job_description,
+                        "technology",
+                        "mid",
+                        &AnalysisOptions {
+                            scoring_profile: ScoringSettings::default()
+                                .scoring_profile(Some(name))
+                                .unwrap(),
+                            ..AnalysisOptions::default()
+                        },
+                    )
+                    .await
+                    .unwrap()
+            }
+        };
+        let strict = analyze("strict").await;
+        let lenient = analyze("lenient").await;
+
+        assert!(
+            strict.base_analysis.overall_score < lenient.base_analysis.overall_score,
+            "strict {} should score below lenient {}",
+            strict.base_analysis.overall_score,
+            lenient.base_analysis.overall_score
+        );
+        assert!(strict.keyword_analysis.overall_score < lenient.keyword_analysis.overall_score);
+        assert!(ScoringSettings::default()
+            .scoring_profile(Some("harsh"))
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_suggestion_limit_keeps_highest_impact() {
+        let db = Database::new_with_url("sqlite::memory:").await.unwrap();
+        let engine = AdvancedScoringEngine::new(Arc::new(Mutex::new(db)));
+        let resume = "Jane Doe\n\nEXPERIENCE\n\
+                      Engineer | Acme | 2019 - Present\n\
+                      \t\t\t\t\t\tWorked on services\n";
+        let job_description = "Backend engineer with Python, Go, Kubernetes, PostgreSQL, \
+                               REST APIs, Terraform, AWS and observability experience.";
+
+        let analyze = |limits: SuggestionLimits| {
+            let engine = &engine;
+            async move {
+                engine
+                    .analyze_comprehensive_with_options(
+                        resume,
+                        job_description,
+                        "technology",
+                        "mid",
+                        &AnalysisOptions {
+                            suggestion_limits: limits,
+                            ..AnalysisOptions::default()
+                        },
+                    )
+                    .await
+                    .unwrap()
+            }
+        };
+        let exhaustive = analyze(SuggestionLimits {
+            max_suggestions: usize::MAX,
+            ..SuggestionLimits::default()
+        })
+        .await;
+        let focused = analyze(SuggestionLimits {
+            max_suggestions: 3,
+            max_recommendations: 3,
+            ..SuggestionLimits::default()
+        })
+        .await;
+
+        assert!(exhaustive.improvement_suggestions.len() > 3);
+        let titles = |suggestions: &[OptimizationSuggestion]| {
+            suggestions
+                .iter()
+                .map(|s| s.title.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            titles(&focused.improvement_suggestions),
+            titles(&exhaustive.improvement_suggestions[..3])
+        );
+        assert_eq!(focused.base_analysis.recommendations.len(), 3);
+
+        let min_impact = exhaustive.improvement_suggestions[2].impact_score;
+        let filtered = analyze(SuggestionLimits {
+            min_impact_score: min_impact,
+            ..SuggestionLimits::default()
+        })
+        .await;
+        assert!(filtered
+            .improvement_suggestions
+            .iter()
+            .all(|s| s.impact_score >= min_impact));
+    }
+
+    #[test]
+    fn test_parses_certification_entries() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+
+        let certs = parse_certifications(
+            "• AWS Certified Solutions Architect, Amazon, 2022\n\
+             Certified Kubernetes Administrator | CNCF | 2019 | Expires Mar 2022\n\
+             PMP, PMI, 2021 (valid until 2027)\n",
+            today,
+        );
+
+        assert_eq!(
+            certs[0],
+            CertificationEntry {
+                name: "AWS Certified Solutions Architect".to_string(),
+                issuer: Some("Amazon".to_string()),
+                date: Some("2022".to_string()),
+                expiry: None,
+                expired: false,
+            }
+        );
+
+        let cka = &certs[1];
+        assert_eq!(cka.issuer.as_deref(), Some("CNCF"));
+        assert_eq!(cka.date.as_deref(), Some("2019"));
+        assert_eq!(cka.expiry.as_deref(), Some("Mar 2022"));
+        assert!(cka.expired);
+
+        assert_eq!(certs[2].expiry.as_deref(), Some("2027"));
+        assert!(!certs[2].expired);
+    }
+
+    #[test]
+    fn test_parsers_extract_certifications_section() {
+        let resume = "Jane Doe\njane@example.com\n\n\
+                      Experience\nEngineer, Acme, 2020 - Present\n\n\
+                      Certifications\n\
+                      AWS Certified Solutions Architect, Amazon, 2022\n";
+
+        let parsed = WorkdayParser::new().parse_resume(resume).unwrap();
+
+        assert_eq!(parsed.certifications.len(), 1);
+        assert_eq!(
+            parsed.certifications[0].name,
+            "AWS Certified Solutions Architect"
+        );
+    }
+
+    #[test]
+    fn test_aggregate_job_keywords_ranks_common_requirements_first() {
+        let job_descriptions = vec![
+            "Backend engineer with Python and PostgreSQL experience.".to_string(),
+            "Data engineer skilled in Python, SQL and Airflow.".to_string(),
+            "Platform engineer using Python and Rust for systems tooling.".to_string(),
+        ];
+
+        let aggregated = KeywordAnalyzer::new()
+            .aggregate_job_keywords(&job_descriptions)
+            .unwrap();
+
+        assert_eq!(aggregated[0].keyword, "python");
+        assert_eq!(aggregated[0].job_count, 3);
+        assert_eq!(aggregated[0].job_share, 1.0);
+
+        let rust = aggregated
+            .iter()
+            .find(|k| k.keyword == "rust")
+            .expect("rust extracted from the third job description");
+        assert_eq!(rust.job_count, 1);
+    }
+
+    #[test]
+    fn test_normalize_resume_text_fixes_each_character_class() {
+        let cases = [
+            (
+                "\u{201C}Led\u{201D} the \u{2018}core\u{2019} team",
+                "\"Led\" the 'core' team",
+            ),
+            (
+                "2019 \u{2013} 2021 \u{2014} remote \u{2212} 5",
+                "2019 - 2021 - remote - 5",
+            ),
+            ("Grew revenue\u{2026}", "Grew revenue..."),
+            (
+                "Jane\u{00A0}Doe\u{2003}|\u{202F}NYC\u{3000}",
+                "Jane Doe | NYC ",
+            ),
+            (
+                "Py\u{200B}thon\u{FEFF} Ku\u{00AD}bernetes\u{2060}",
+                "Python Kubernetes",
+            ),
+            ("\u{202E}Engineer\u{202C} \u{200F}II\u{2066}", "Engineer II"),
+            (
+                "Acme\u{2122} \u{00A9}2024 \u{20AC}5M\u{FFFD}",
+                "Acme(TM) (c)2024 EUR5M",
+            ),
+            (
+                "Experience\nBuilt APIs\tin Go",
+                "Experience\nBuilt APIs\tin Go",
+            ),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(normalize_resume_text(input), expected, "{:?}", input);
+            // Normalizing is idempotent, so a second pass changes nothing
+            assert_eq!(normalize_resume_text(expected), expected);
+        }
+        // Flagged but not guessable, so left for the user to fix
+        assert_eq!(
+            normalize_resume_text("S\u{00C3}o Paulo"),
+            "S\u{00C3}o Paulo"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_repeated_keyword_flagged_as_stuffing() {
+        let bullets = (0..30)
+            .map(|i| format!("• Python work item {}", i))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let resume = format!(
+            "Jane Doe\n\nEXPERIENCE\nEngineer | Acme | 2019 - Present\n{}\n",
+            bullets
+        );
+        let job_description = "Backend engineer with Python and SQL experience.";
+
+        let keyword_analysis = KeywordAnalyzer::new()
+            .analyze_comprehensive(&resume, job_description, "technology")
+            .await
+            .unwrap();
+        assert_eq!(keyword_analysis.keyword_occurrences["python"], 30);
+
+        let issues = FormatAnalyzer::new()
+            .keyword_repetition_issues(&resume, &keyword_analysis.keyword_occurrences);
+        assert_eq!(issues.len(), 1, "{:?}", issues);
+        assert!(matches!(
+            issues[0].issue_type,
+            FormatIssueType::KeywordStuffing
+        ));
+        assert_eq!(issues[0].location, "python");
+
+        // A normal skills line is neither repetition nor a wall
+        let skills = "Skills\nPython, Go, Rust, SQL, PostgreSQL, Kafka, Docker, Kubernetes\n";
+        let counts = ExactMatcher.count_occurrences(skills, &["python".to_string()]);
+        assert!(FormatAnalyzer::new()
+            .keyword_repetition_issues(skills, &counts)
+            .is_empty());
+
+        let wall = vec!["Python, SQL, AWS"; 5].join(", ");
+        let issues = FormatAnalyzer::new().keyword_repetition_issues(&wall, &HashMap::new());
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].location, "Line 1");
+    }
+
+    #[test]
+    fn test_zero_contextual_weight_ignores_contextual_matches() {
+        let analyzer = KeywordAnalyzer::new();
+        let match_result = |keyword: &str| MatchResult {
+            keyword: keyword.to_string(),
+            matched_text: keyword.to_string(),
+            section: "Experience".to_string(),
+            start: 0,
+            end: keyword.chars().count(),
+            context: String::new(),
+            confidence: 0.8,
+            weight: 1.0,
+            position_factor: 1.0,
+        };
+        let exact = vec![match_result("python")];
+        let contextual = vec![match_result("leadership"), match_result("mentoring")];
+        let score = |contextual: &[MatchResult], weights: &MatchTypeWeights| {
+            analyzer
+                .calculate_overall_keyword_score(
+                    &exact,
+                    &[],
+                    contextual,
+                    &[],
+                    KEYWORD_SCORE_MATCH_CAP,
+                    weights,
+                )
+                .unwrap()
+        };
+
+        let defaults = MatchTypeWeights::default();
+        assert!(score(&contextual, &defaults) > score(&[], &defaults));
+
+        let no_contextual = MatchTypeWeights {
+            contextual: 0.0,
+            ..MatchTypeWeights::default()
+        };
+        assert_eq!(
+            score(&contextual, &no_contextual),
+            score(&[], &no_contextual)
+        );
+        assert_eq!(MatchType::Contextual.score_factor(&no_contextual), 0.0);
+    }
+
+    #[test]
+    fn test_missing_tool_keyword_placed_in_skills() {
+        let analyzer = KeywordAnalyzer::new();
+
+        let placements = analyzer.plan_keyword_placement("terraform");
+        assert_eq!(placements[0].section, "Skills");
+        let best = placements[0].placement.as_ref().unwrap();
+        assert_eq!(best.rank, 1);
+        assert_eq!(best.keyword_type, KeywordType::Tool);
+        assert!(best.example.contains("terraform"));
+        assert_eq!(placements[1].section, "Experience");
+
+        assert_eq!(
+            analyzer.plan_keyword_placement("leadership")[0].section,
+            "Experience"
+        );
+        assert_eq!(
+            analyzer.plan_keyword_placement("pmp")[0].section,
+            "Certifications"
+        );
+    }
+
+    #[test]
+    fn test_multi_column_resume_explains_taleo_low_confidence() {
+        let resume = "Jane Doe          jane@example.com          Portland, OR\n\
+                      EXPERIENCE                              SKILLS\n\
+                      Software Engineer, Acme                 Python, SQL, Rust\n\
+                      2019 - Present                          Kubernetes, AWS\n\
+                      Built billing services                  EDUCATION\n\
+                      Reduced latency by 40%                  B.S. Computer Science\n";
+
+        let reports = ATSSimulator::new().system_reports(resume).unwrap();
+
+        let taleo = &reports[&ATSSystem::Taleo];
+        assert!(taleo.parsing_confidence < 0.7);
+        assert!(
+            taleo.issues.iter().any(|issue| issue.contains("columns")),
+            "{:?}",
+            taleo.issues
+        );
+        assert!(taleo
+            .issues
+            .iter()
+            .any(|issue| issue.starts_with("Taleo parsing confidence is low")));
+        assert!(reports.contains_key(&ATSSystem::Workday));
+        assert!(reports.contains_key(&ATSSystem::Generic));
+    }
+
+    #[test]
+    fn test_stemmed_tokens_cached_for_identical_resume() {
+        let resume = "Optimized billing pipelines and mentored engineers at Stemco";
+        let edited = "Optimized billing pipelines and mentored engineers at Stemco Labs";
+
+        let first = stemmed_tokens(resume);
+        let second = stemmed_tokens(resume);
+        assert!(
+            Arc::ptr_eq(&first, &second),
+            "identical resume text should reuse the stemmed tokens"
+        );
+        assert_eq!(first.words[0].1, "optim");
+
+        let changed = stemmed_tokens(edited);
+        assert!(!Arc::ptr_eq(&first, &changed));
+        assert_eq!(changed.words.len(), first.words.len() + 1);
+
+        // Matching through the cache gives the same result as a fresh stem
+        let matches = StemmedMatcher
+            .find_matches(resume, &["optimize".to_string()])
+            .unwrap();
+        assert_eq!(matches[0].matched_text, "Optimized");
+    }
+
+    #[tokio::test]
+    async fn test_missing_keywords_ranked_by_market_demand() {
+        let db = Database::new_with_url("sqlite::memory:").await.unwrap();
+        let engine = AdvancedScoringEngine::new(Arc::new(Mutex::new(db)));
+        let industry_keywords =
+            HashMap::from([("Kafka".to_string(), 3.0), ("Hadoop".to_string(), 3.0)]);
+        let target_keywords = vec!["Hadoop".to_string(), "Kafka".to_string()];
+        let resume = "Built Python services on AWS";
+
+        // Equal industry weight and no market data: alphabetical tie-break
+        let neutral = engine.find_missing_keywords(
+            resume,
+            &target_keywords,
+            &industry_keywords,
+            &HashMap::new(),
+        );
+        assert_eq!(neutral[0], ("Hadoop".to_string(), 3.0));
+        assert_eq!(neutral[1], ("Kafka".to_string(), 3.0));
+
+        let market_demand =
+            HashMap::from([("kafka".to_string(), 0.9), ("hadoop".to_string(), 0.2)]);
+        let ranked = engine.find_missing_keywords(
+            resume,
+            &target_keywords,
+            &industry_keywords,
+            &market_demand,
+        );
+        assert_eq!(ranked[0].0, "Kafka");
+        assert_eq!(ranked[1].0, "Hadoop");
+        assert!(ranked[0].1 > 3.0 && ranked[1].1 < 3.0, "{:?}", ranked);
+    }
+
+    #[tokio::test]
+    async fn test_messy_resume_gets_wider_score_range() {
+        let db = Database::new_with_url("sqlite::memory:").await.unwrap();
+        let engine = AdvancedScoringEngine::new(Arc::new(Mutex::new(db)));
+        let job_description = "Backend engineer with Python, Kubernetes, PostgreSQL and AWS.";
+        let clean = "Jane Doe\njane.doe@example.com | (555) 123-4567 | Austin, TX\n\n\
+                     SUMMARY\nBackend engineer building Python services on AWS.\n\n\
+                     EXPERIENCE\nSenior Engineer | Acme Corp | Jan 2020 - Present\n\
+                     • Built Python services on Kubernetes and AWS\n\
+                     • Tuned PostgreSQL queries, cutting latency by 40%\n\
+                     Engineer | Globex | Mar 2016 - Dec 2019\n\
+                     • Maintained PostgreSQL databases\n\n\
+                     EDUCATION\nB.S. Computer Science, State University, 2016\n\n\
+                     SKILLS\nPython, Kubernetes, PostgreSQL, AWS, Docker, Terraform, Git\n";
+        let messy = "jd    pyth0n    k8s stuff\t\t\tpostgre-sql\n\
+                     did things w/ cloud      2019ish\n\
+                     ~~~ ||| ~~~\nprogramming, develop, developer, developing";
+
+        let clean_result = engine
+            .analyze_comprehensive(clean, job_description, "technology", "senior")
+            .await
+            .unwrap();
+        let messy_result = engine
+            .analyze_comprehensive(messy, job_description, "technology", "senior")
+            .await
+            .unwrap();
+
+        for result in [&clean_result, &messy_result] {
+            let range = result.score_range;
+            assert!(range.lower <= result.base_analysis.overall_score);
+            assert!(range.upper >= result.base_analysis.overall_score);
+        }
+        assert!(
+            clean_result.score_range.parsing_confidence
+                > messy_result.score_range.parsing_confidence
+        );
+        let width = |range: &ScoreRange| range.upper - range.lower;
+        assert!(
+            width(&messy_result.score_range) > width(&clean_result.score_range),
+            "messy {:?} should be wider than clean {:?}",
+            messy_result.score_range,
+            clean_result.score_range
+        );
+    }
+
+    #[test]
+    fn test_score_range_narrows_with_parsing_confidence() {
+        let shaky = ScoreRange::estimate(60.0, &[0.3, 0.5, 0.4], &[1.0, 0.6]);
+        let solid = ScoreRange::estimate(60.0, &[0.95, 1.0, 0.9], &[1.0, 0.6]);
+        let (solid_width, shaky_width) = (solid.upper - solid.lower, shaky.upper - shaky.lower);
+        assert!(solid_width < shaky_width);
+        assert!(solid_width >= 2.0 * MIN_SCORE_MARGIN);
+    }
+
+    #[tokio::test]
+    async fn test_custom_industry_drives_industry_alignment() {
+        let db = Arc::new(Mutex::new(
+            Database::new_with_url("sqlite::memory:").await.unwrap(),
+        ));
+        let engine = AdvancedScoringEngine::with_settings(
+            Arc::clone(&db),
+            Arc::new(ScoringSettings::default()),
+        );
+        let legal_resume = engine
+            .ats_simulator
+            .parse_with_multiple_systems(
+                "Alex Counsel\nalex@example.com\n\nEXPERIENCE\n\
+                 Associate Attorney | Smith & Partners LLP | 2018 - Present\n\
+                 • Drafted contracts and led litigation discovery for commercial clients\n\
+                 • Managed legal research and due diligence for mergers\n\n\
+                 EDUCATION\nJuris Doctor, State Law School, 2018\n\n\
+                 SKILLS\nLitigation, Contract Drafting, Legal Research, Due Diligence\n",
+            )
+            .unwrap();
+        let before = engine
+            .calculate_industry_alignment(&legal_resume, "legal", "mid")
+            .await
+            .unwrap();
+
+        let legal = IndustryDefinition {
+            keywords: HashMap::from([
+                ("litigation".to_string(), 3.0),
+                ("contract drafting".to_string(), 2.8),
+                ("legal research".to_string(), 2.5),
+                ("due diligence".to_string(), 2.0),
+            ]),
+            skill_requirements: HashMap::from([(
+                "practice".to_string(),
+                vec![
+                    ("litigation".to_string(), 3.0),
+                    ("legal research".to_string(), 2.5),
+                ],
+            )]),
+            experience_keywords: vec!["attorney".to_string(), "llp".to_string()],
+            preferred_degrees: vec![("juris doctor".to_string(), 25.0)],
+            benchmark: None,
+            scoring_weights: Some(ScoringWeights {
+                keyword_match: 0.3,
+                format_compatibility: 0.2,
+                section_completeness: 0.1,
+                achievement_quality: 0.2,
+                industry_alignment: 0.2,
+            }),
+        };
+        let engine = AdvancedScoringEngine::with_settings(
+            db,
+            Arc::new(ScoringSettings {
+                custom_industries: HashMap::from([("legal".to_string(), legal)]),
+                ..ScoringSettings::default()
+            }),
+        );
+
+        let registered = engine
+            .build_industry_keyword_database()
+            .get("legal")
+            .is_some_and(|keywords| keywords.contains_key("litigation"));
+        let after = engine
+            .calculate_industry_alignment(&legal_resume, "legal", "mid")
+            .await
+            .unwrap();
+        let weights = engine.get_industry_weights("Legal").await.unwrap();
+        assert!(registered);
+        assert_eq!(weights.industry_alignment, 0.2);
+        assert!(
+            after > before,
+            "registered legal industry should reward legal keywords: {} vs {}",
+            after,
+            before
+        );
+    }
+
+    #[test]
+    fn test_match_spans_slice_to_surface_text() {
+        let resume = "Zoë Müller — İstanbul\n\
+                      SKILLS\nJavaScript, PostgreSQL, Kubernetes\n\
+                      EXPERIENCE\nDeveloped APIs in Python. Managed PostgreSQL clusters.";
+        let keywords = vec![
+            "javascript".to_string(),
+            "postgresql".to_string(),
+            "python".to_string(),
+            "js".to_string(),
+        ];
+
+        let results = [
+            ExactMatcher.find_matches(resume, &keywords).unwrap(),
+            StemmedMatcher.find_matches(resume, &keywords).unwrap(),
+            ContextualMatcher.find_matches(resume, &keywords).unwrap(),
+            SynonymMatcher.find_matches(resume, &keywords).unwrap(),
+        ];
+        for matches in &results {
+            assert!(!matches.is_empty());
+            for m in matches {
+                assert!(m.start < m.end, "{:?}", m);
+                assert_eq!(
+                    char_slice(resume, m.start, m.end),
+                    m.matched_text,
+                    "{:?}",
+                    m
+                );
+            }
+        }
+        let python = results[0].iter().find(|m| m.keyword == "python").unwrap();
+        assert_eq!(char_slice(resume, python.start, python.end), "Python");
+    }
+
+    #[test]
+    fn test_keyword_matched_several_ways_counts_once() {
+        let analyzer = KeywordAnalyzer::new();
+        let match_result = |matched_text: &str, confidence: f64| MatchResult {
+            keyword: "python".to_string(),
+            matched_text: matched_text.to_string(),
+            section: "Skills".to_string(),
+            start: 0,
+            end: matched_text.chars().count(),
+            context: String::new(),
+            confidence,
+            weight: 1.0,
+            position_factor: 1.0,
+        };
+        let exact = vec![match_result("python", 1.0)];
+        let synonym = vec![match_result("py", 0.95)];
+        let weights = MatchTypeWeights::default();
+        let score = |synonym: &[MatchResult]| {
+            analyzer
+                .calculate_overall_keyword_score(
+                    &exact,
+                    &[],
+                    &[],
+                    synonym,
+                    KEYWORD_SCORE_MATCH_CAP,
+                    &weights,
+                )
+                .unwrap()
+        };
+
+        let single_weighted_count =
+            MatchType::Exact.score_factor(&weights) / KEYWORD_SCORE_MATCH_CAP * 100.0;
+        assert!((score(&synonym) - single_weighted_count).abs() < 1e-9);
+        assert_eq!(score(&synonym), score(&[]));
+
+        // Two exact mentions of the same keyword still count once, at the better placement
+        let mut repeated = match_result("Python", 1.0);
+        repeated.position_factor = 1.5;
+        let twice = analyzer
+            .calculate_overall_keyword_score(
+                &[match_result("python", 1.0), repeated],
+                &[],
+                &[],
+                &synonym,
+                KEYWORD_SCORE_MATCH_CAP,
+                &weights,
+            )
+            .unwrap();
+        assert!((twice - 1.5 * single_weighted_count).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_reanalyze_stored_analysis_at_another_level() {
+        let db = Database::new_with_url("sqlite::memory:").await.unwrap();
+        let resume = crate::models::Resume::new(
+            "resume.txt".to_string(),
+            format!(
+                "Sam Lee\nsam@example.com\n\nEXPERIENCE\n\
+                 Software Engineer | Acme | 2021 - Present\n{}\n\n\
+                 SKILLS\nPython\n",
+                "• Worked with the team on weekly planning and shared notes with others\n"
+                    .repeat(12)
+            ),
+            "txt".to_string(),
+        );
+        let job = crate::models::JobDescription::new(
+            "Software Engineer".to_string(),
+            "Globex".to_string(),
+            "Software engineer with Python, React, AWS, SQL, Kubernetes, Terraform, Go, \
+             Kafka, Redis and GraphQL."
+                .to_string(),
+        );
+        let stored = crate::models::Analysis {
+            id: "original".to_string(),
+            resume_id: resume.id.clone(),
+            job_description_id: job.id.clone(),
+            model_used: "advanced".to_string(),
+            overall_score: 61.0,
+            created_at: Utc::now(),
+            ..Default::default()
+        };
+        db.save_resume(&resume).await.unwrap();
+        db.save_job_description(&job).await.unwrap();
+        db.save_analysis(&stored).await.unwrap();
+        let engine = AdvancedScoringEngine::new(Arc::new(Mutex::new(db)));
+
+        let entry = engine
+            .reanalyze_stored("original", "technology", "entry")
+            .await
+            .unwrap();
+        let senior = engine
+            .reanalyze_stored("original", "technology", "senior")
+            .await
+            .unwrap();
+        assert_ne!(
+            entry.benchmark_comparison.experience_level_percentile,
+            senior.benchmark_comparison.experience_level_percentile
+        );
+
+        let db = engine.db.lock().await;
+        let original = db.get_analysis("original").await.unwrap().unwrap();
+        assert_eq!(original.overall_score, 61.0);
+        assert_eq!(db.get_analysis_history(None).await.unwrap().len(), 1);
+        drop(db);
+        assert!(engine
+            .reanalyze_stored("missing", "technology", "senior")
+            .await
+            .is_err());
+    }
+
+    #[test]
+    fn test_context_window_narrows_match_context() {
+        let resume = "Led a small team that migrated the billing platform to Kubernetes \
+                      on AWS during the second quarter of the year";
+        let keywords = vec!["kubernetes".to_string()];
+
+        let contexts = |window: usize| {
+            [
+                ExactMatcher.find_matches_with_context(resume, &keywords, window),
+                StemmedMatcher.find_matches_with_context(resume, &keywords, window),
+                SynonymMatcher.find_matches_with_context(
+                    resume,
+                    &keywords,
+                    window,
+                    &default_skill_taxonomy(),
+                ),
+            ]
+            .into_iter()
+            .map(|matches| matches.unwrap()[0].context.clone())
+            .collect::<Vec<_>>()
+        };
+
+        for context in contexts(2) {
+            assert_eq!(context, "platform to Kubernetes on AWS");
+        }
+        for context in contexts(DEFAULT_CONTEXT_WINDOW_WORDS) {
+            assert_eq!(
+                context,
+                "migrated the billing platform to Kubernetes on AWS during the second"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_generic_summary_gets_quality_warnings() {
+        let db = Database::new_with_url("sqlite::memory:").await.unwrap();
+        let engine = AdvancedScoringEngine::new(Arc::new(Mutex::new(db)));
+        let resume = engine
+            .ats_simulator
+            .parse_with_multiple_systems(
+                "Pat Doe\npat@example.com\n\nSUMMARY\nHardworking professional.\n\n\
+                 EXPERIENCE\nEngineer | Acme | 2019 - Present\n• Built Python services\n",
+            )
+            .unwrap();
+        assert!(resume.sections.contains_key("Summary"));
+        let keywords = vec!["python".to_string(), "kubernetes".to_string()];
+
+        let suggestions = engine
+            .generate_section_suggestions(&resume, "technology", &keywords)
+            .unwrap();
+        assert!(suggestions
+            .iter()
+            .all(|s| s.title != "Add professional summary"));
+        let summary = suggestions
+            .iter()
+            .find(|s| s.title == "Strengthen professional summary")
+            .expect("generic summary flagged");
+        let actions: Vec<&str> = summary
+            .specific_actions
+            .iter()
+            .map(|a| a.action.as_str())
+            .collect();
+        assert!(actions.iter().any(|a| a.starts_with("Expand the summary")));
+        assert!(actions.iter().any(|a| a.contains("years of experience")));
+        assert!(actions.iter().any(|a| a.contains("role you are targeting")));
+        assert!(actions.iter().any(|a| a.contains("kubernetes")));
+
+        let strong = "Backend engineer with 8+ years building Python and Kubernetes \
+                      platforms for payments companies, leading teams that ship reliable \
+                      services at scale.";
+        assert!(engine
+            .summary_quality_suggestion(strong, &keywords)
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_resolved_suggestion_stays_resolved_on_reanalysis() {
+        let db = Database::new_with_url("sqlite::memory:").await.unwrap();
+        let resume = crate::models::Resume::new(
+            "resume.txt".to_string(),
+            "Sam Lee\nsam@example.com\n\nEXPERIENCE\n\
+             Engineer | Acme | 2021 - Present\n• Maintained internal tools\n"
+                .to_string(),
+            "txt".to_string(),
+        );
+        let job = crate::models::JobDescription::new(
+            "Backend Engineer".to_string(),
+            "Globex".to_string(),
+            "Backend engineer with Python, Kubernetes and PostgreSQL.".to_string(),
+        );
+        let stored = crate::models::Analysis {
+            id: "analysis-1".to_string(),
+            resume_id: resume.id.clone(),
+            job_description_id: job.id.clone(),
+            created_at: Utc::now(),
+            ..Default::default()
+        };
+        db.save_resume(&resume).await.unwrap();
+        db.save_job_description(&job).await.unwrap();
+        db.save_analysis(&stored).await.unwrap();
+        let engine = AdvancedScoringEngine::new(Arc::new(Mutex::new(db)));
+
+        let first = engine
+            .reanalyze_stored("analysis-1", "technology", "mid")
+            .await
+            .unwrap();
+        let summary_id = OptimizationSuggestion::stable_id("Sections", "Add professional summary");
+        assert!(first
+            .improvement_suggestions
+            .iter()
+            .any(|s| s.id == summary_id && !s.resolved));
+
+        engine
+            .db
+            .lock()
+            .await
+            .set_suggestion_resolved("analysis-1", &summary_id, true)
+            .await
+            .unwrap();
+        let second = engine
+            .reanalyze_stored("analysis-1", "technology", "mid")
+            .await
+            .unwrap();
+        for suggestion in &second.improvement_suggestions {
+            assert_eq!(suggestion.resolved, suggestion.id == summary_id);
+        }
+        assert!(second
+            .improvement_suggestions
+            .iter()
+            .any(|s| s.id == summary_id));
+
+        engine
+            .db
+            .lock()
+            .await
+            .set_suggestion_resolved("analysis-1", &summary_id, false)
+            .await
+            .unwrap();
+        let third = engine
+            .reanalyze_stored("analysis-1", "technology", "mid")
+            .await
+            .unwrap();
+        assert!(third.improvement_suggestions.iter().all(|s| !s.resolved));
+    }
+
+    #[tokio::test]
+    async fn test_keyword_in_recent_role_outscores_old_role() {
+        let db = Database::new_with_url("sqlite::memory:").await.unwrap();
+        let engine = AdvancedScoringEngine::new(Arc::new(Mutex::new(db)));
+        let resume = |recent: &str, old: &str| {
+            format!(
+                "Sam Lee\nsam@example.com\n\nEXPERIENCE\n\
+                 Senior Engineer | Acme | Jan 2022 - Present\n• {}\n\
+                 Engineer | Globex | Jan 2008 - Dec 2010\n• {}\n",
+                recent, old
+            )
+        };
+        let kubernetes = "Led the Kubernetes migration for billing";
+        let other = "Maintained internal reporting tools";
+        let job_description = "Platform engineer who has led Kubernetes migrations.";
+
+        let mut scores = Vec::new();
+        for content in [resume(kubernetes, other), resume(other, kubernetes)] {
+            let parsed = engine
+                .ats_simulator
+                .parse_with_multiple_systems(&content)
+                .unwrap();
+            let keyword_analysis = engine
+                .keyword_analyzer
+                .analyze_with_profile(
+                    &content,
+                    job_description,
+                    "technology",
+                    &ScoringProfile::default(),
+                )
+                .await
+                .unwrap();
+            scores.push(engine.calculate_achievement_score(&keyword_analysis, &parsed));
+        }
+
+        assert!(
+            scores[0] > scores[1],
+            "recent placement {} should beat old placement {}",
+            scores[0],
+            scores[1]
+        );
+    }
+
+    #[test]
+    fn test_skills_parsed_with_proficiency_levels() {
+        let resume = "Sam Lee\nsam@example.com\n\nSKILLS\nPython (expert), Go (beginner)\n\n\
+                      EXPERIENCE\nEngineer | Acme | 2021 - Present\n";
+
+        let skills = WorkdayParser::new().parse_skills(resume).unwrap();
+
+        assert_eq!(
+            skills,
+            [
+                ("Python".to_string(), Some(SkillProficiency::Expert)),
+                ("Go".to_string(), Some(SkillProficiency::Beginner)),
+            ]
+        );
+        assert_eq!(
+            split_proficiency("Java – intermediate"),
+            ("Java".to_string(), Some(SkillProficiency::Intermediate))
+        );
+        assert_eq!(split_proficiency("Rust"), ("Rust".to_string(), None));
+        assert!(SkillProficiency::Expert.weight() > SkillProficiency::Familiar.weight());
+    }
+
+    #[tokio::test]
+    async fn test_industry_benchmark_returns_default_distribution_when_unseeded() {
+        let db = Database::new_with_url("sqlite::memory:").await.unwrap();
+        let scores: Vec<f64> = (0..25).map(|i| 50.0 + i as f64 * 2.0).collect();
+        seed_industry_analyses(&db, "technology", &scores).await;
+        let engine = AdvancedScoringEngine::new(Arc::new(Mutex::new(db)));
+
+        let report = engine.industry_benchmark("aerospace", "mid").await.unwrap();
+        let defaults = IndustryBenchmark::default();
+        assert!(!report.data_driven);
+        assert_eq!(report.score_distribution, defaults.score_distribution);
+        assert_eq!(report.average_score, defaults.average_score);
+        assert_eq!(report.top_10_percent_score, defaults.top_10_percent_score);
+        assert_eq!(
+            report.bottom_10_percent_score,
+            defaults.bottom_10_percent_score
+        );
+
+        let seeded = engine
+            .industry_benchmark("Technology", "mid")
+            .await
+            .unwrap();
+        assert!(seeded.data_driven);
+        assert_eq!(seeded.sample_size, 25);
+        assert_eq!(seeded.average_score, 74.0);
+        assert_eq!(seeded.bottom_10_percent_score, 54.0);
+        assert_eq!(seeded.top_10_percent_score, 94.0);
+
+        for distribution in [
+            &report.score_distribution,
+            &report.experience_level_distribution,
+            &seeded.score_distribution,
+        ] {
+            assert!(distribution
+                .windows(2)
+                .all(|pair| pair[0].0 < pair[1].0 && pair[0].1 <= pair[1].1));
+        }
+    }
+
+    #[test]
+    fn test_french_resume_matches_english_keyword_through_synonyms() {
+        let resume = "Ingénieur de données chez Acme depuis 2020. Responsable de la mise en \
+                      production des modèles d'apprentissage automatique pour les équipes \
+                      produit et de la gestion de projet avec les clients.";
+        assert_eq!(detect_language(resume), Language::French);
+
+        let keywords = vec![
+            "machine learning".to_string(),
+            "project management".to_string(),
+        ];
+        let matches = SynonymMatcher.find_matches(resume, &keywords).unwrap();
+
+        let machine_learning = matches
+            .iter()
+            .find(|m| m.keyword == "machine learning")
+            .expect("French term should match the English keyword");
+        assert_eq!(machine_learning.matched_text, "apprentissage automatique");
+        assert!(matches
+            .iter()
+            .any(|m| m.keyword == "project management" && m.matched_text == "gestion de projet"));
+
+        // The French table only applies to French text
+        let english = "Built apprentissage automatique pipelines for the team and the clients";
+        assert!(SynonymMatcher
+            .find_matches(english, &keywords[..1])
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_section_policy_flags_missing_required_section() {
+        let mut settings = ScoringSettings::default();
+        settings.section_policies.insert(
+            "academic".to_string(),
+            SectionPolicy {
+                required: vec!["Publications".to_string()],
+                recommended: Vec::new(),
+            },
+        );
+
+        let db = Database::new_with_url("sqlite::memory:").await.unwrap();
+        let engine =
+            AdvancedScoringEngine::with_settings(Arc::new(Mutex::new(db)), Arc::new(settings));
+        let resume = engine
+            .ats_simulator
+            .parse_with_multiple_systems(
+                "Dr. Pat Doe\npat@example.edu\n\nEXPERIENCE\n\
+                 Lecturer | State University | 2019 - Present\n• Taught statistics\n",
+            )
+            .unwrap();
+
+        let missing = engine
+            .generate_section_suggestions(&resume, "academic", &[])
+            .unwrap();
+        let mut with_publications = resume;
+        with_publications
+            .sections
+            .insert("Publications".to_string(), "Doe, P. (2021)".to_string());
+        let present = engine
+            .generate_section_suggestions(&with_publications, "academic", &[])
+            .unwrap();
+
+        let publications = missing
+            .iter()
+            .find(|s| s.title == "Add publications section")
+            .expect("missing required section flagged");
+        assert_eq!(publications.impact_score, REQUIRED_SECTION_IMPACT);
+        assert!(publications
+            .description
+            .contains("required for academic roles"));
+        assert!(present
+            .iter()
+            .all(|s| s.title != "Add publications section"));
+    }
+
+    #[tokio::test]
+    async fn test_unparsed_education_has_low_category_confidence() {
+        let db = Database::new_with_url("sqlite::memory:").await.unwrap();
+        let engine = AdvancedScoringEngine::new(Arc::new(Mutex::new(db)));
+        let bullets = "• Built Python services on Kubernetes handling payments for millions of users\n\
+                       • Designed PostgreSQL schemas and tuned queries to cut latency by 40 percent\n\
+                       • Led a team of five engineers through the migration to AWS and Terraform\n";
+        let resume = format!(
+            "Pat Doe\npat@example.com\n\nSUMMARY\nBackend engineer with eight years building \
+             Python and Kubernetes platforms for payments companies.\n\n\
+             EXPERIENCE\nSenior Engineer | Acme | 2019 - Present\n{bullets}\
+             Engineer | Globex | 2015 - 2019\n{bullets}{bullets}\n\
+             EDUCATION\nSome coursework, evenings\n\n\
+             SKILLS\nPython, Kubernetes, PostgreSQL, AWS, Terraform, Go\n"
+        );
+        let job_description =
+            "Senior backend engineer with Python, Kubernetes, PostgreSQL and AWS.";
+
+        let result = engine
+            .analyze_comprehensive(&resume, job_description, "technology", "senior")
+            .await
+            .unwrap();
+        let confidence = result
+            .base_analysis
+            .category_scores
+            .confidence
+            .expect("advanced engine assesses confidence");
+
+        assert!(confidence.education < 0.3, "{:?}", confidence);
+        assert!(confidence.keywords > 0.9, "{:?}", confidence);
+    }
+
+    #[test]
+    fn test_tokenizer_spans_agree_across_stemmer_and_context() {
+        let text = "Zoë Müller — İstanbul 東京 naïve café; JavaScript/TypeScript dev";
+        let tokens = tokenize(text);
+        let words: Vec<&str> = tokens.iter().map(|token| token.text).collect();
+        assert_eq!(
+            words,
+            [
+                "Zoë",
+                "Müller",
+                "İstanbul",
+                "東",
+                "京",
+                "naïve",
+                "café",
+                "JavaScript",
+                "TypeScript",
+                "dev"
+            ]
+        );
+        for token in &tokens {
+            assert_eq!(char_slice(text, token.start, token.end), token.text);
+        }
+
+        // The stemmer's cached words carry the same spans as the tokenizer
+        let stemmed = stemmed_tokens(text);
+        let stemmed_spans: Vec<(usize, usize)> =
+            stemmed.words.iter().map(|(_, _, span)| *span).collect();
+        let token_spans: Vec<(usize, usize)> = tokens
+            .iter()
+            .map(|token| (token.start, token.end))
+            .collect();
+        assert_eq!(stemmed_spans, token_spans);
+
+        // A match inside a word keeps that word in its context instead of skipping it
+        let script = &tokens[7];
+        let start = script.start + "Java".chars().count();
+        assert_eq!(
+            context_around(&tokens, start, script.end, 1),
+            "café JavaScript TypeScript"
+        );
+        assert_eq!(
+            context_around(&tokens, tokens[2].start, tokens[2].end, 1),
+            "Müller İstanbul 東"
+        );
+    }
+
+    #[test]
+    fn test_raised_contextual_threshold_drops_weak_match() {
+        let resume = "Ran budgeting for the regional office. \
+                      Developed forecasting models that reduced costs by 12%.";
+        let keywords = vec!["budgeting".to_string(), "forecasting".to_string()];
+        let window = DEFAULT_CONTEXT_WINDOW_WORDS;
+
+        let default_matches = ContextualMatcher
+            .find_matches_with_context(resume, &keywords, window, DEFAULT_MIN_CONTEXTUAL_CONFIDENCE)
+            .unwrap();
+        let weak = default_matches
+            .iter()
+            .find(|m| m.keyword == "budgeting")
+            .expect("weak match passes the default threshold");
+        assert!(weak.confidence < 0.8);
+
+        let strict_matches = ContextualMatcher
+            .find_matches_with_context(resume, &keywords, window, 0.8)
+            .unwrap();
+        let matched: Vec<&str> = strict_matches.iter().map(|m| m.keyword.as_str()).collect();
+        assert_eq!(matched, ["forecasting"]);
+    }
+
+    #[test]
+    fn test_format_penalty_cap_keeps_cluttered_resume_above_floor() {
+        // Every line trips a different minor check: symbols, markup, missing contact details
+        // and sections, mixed bullets and mixed date formats
+        let resume = "│ Jordan Lee │\n\
+                      ★ Shipped ★ features ★ weekly\n\
+                      ① Planning ② Delivery ③ Review\n\
+                      ➤ Mentoring ➢ Hiring\n\
+                      ✓ On call ✔ Incident review\n\
+                      • Kept <b>dashboards</b> <i>current</i> <u>daily</u> <em>always</em>\n\
+                      - Worked {alpha} {beta} {gamma} {delta}\n\
+                      1. Filed [one] [two] [three] [four]\n\
+                      01/02/2020 to 03-04-2021, then Jan 2022, 2019 - 2020\n";
+        let uncapped = FormatPenalties {
+            max_total_penalty: 100.0,
+            ..FormatPenalties::default()
+        };
+        assert_eq!(
+            FormatAnalyzer::with_penalties(uncapped)
+                .calculate_ats_compatibility(resume)
+                .unwrap(),
+            0.0
+        );
+
+        let capped = FormatPenalties {
+            max_total_penalty: 45.0,
+            ..FormatPenalties::default()
+        };
+        let score = FormatAnalyzer::with_penalties(capped)
+            .calculate_ats_compatibility(resume)
+            .unwrap();
+        assert_eq!(score, 55.0);
+    }
+
+    #[tokio::test]
+    async fn test_analyze_saved_links_result_to_resume_and_job() {
+        let db = Database::new_with_url("sqlite::memory:").await.unwrap();
+        let resume = crate::models::Resume::new(
+            "resume.txt".to_string(),
+            "Sam Lee\nsam@example.com\n\nEXPERIENCE\n\
+             Software Engineer | Acme | 2021 - Present\n\
+             • Built Python services on AWS that cut latency by 30%\n\n\
+             SKILLS\nPython, AWS, SQL\n"
+                .to_string(),
+            "txt".to_string(),
+        );
+        let mut job = crate::models::JobDescription::new(
+            "Backend Engineer".to_string(),
+            "Globex".to_string(),
+            "Backend engineer with Python, AWS, SQL and Kubernetes.".to_string(),
+        );
+        job.industry = Some("Technology".to_string());
+        db.save_resume(&resume).await.unwrap();
+        db.save_job_description(&job).await.unwrap();
+        let engine = AdvancedScoringEngine::new(Arc::new(Mutex::new(db)));
+
+        let saved = engine.analyze_saved(&resume.id, &job.id).await.unwrap();
+        assert_eq!(saved.analysis.resume_id, resume.id);
+        assert_eq!(saved.analysis.job_description_id, job.id);
+        assert_eq!(
+            saved.analysis.overall_score,
+            saved.result.base_analysis.overall_score
+        );
+        assert!(saved
+            .result
+            .keyword_analysis
+            .exact_matches
+            .iter()
+            .any(|m| m.keyword.eq_ignore_ascii_case("python")));
+
+        let db = engine.db.lock().await;
+        let stored = db.get_analyses_by_resume(&resume.id).await.unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].id, saved.analysis.id);
+        assert_eq!(stored[0].job_description_id, job.id);
+        drop(db);
+
+        assert!(engine
+            .analyze_saved(&resume.id, "missing-job")
+            .await
+            .is_err());
+    }
+
+    #[test]
+    fn test_skill_variants_count_as_one_canonical_skill() {
+        let resume = "Dana Park\ndana@example.com\n\n\
+                      EXPERIENCE\n\
+                      Frontend Developer | Acme | 2020 - Present\n\
+                      • Built dashboards in React\n\n\
+                      SKILLS\n\
+                      JS, Javascript, Python\n";
+        let parsed = ATSSimulator::new()
+            .parse_with_multiple_systems(resume)
+            .unwrap();
+        let javascript: Vec<&String> = parsed
+            .skills
+            .iter()
+            .filter(|skill| {
+                ScoringSettings::default().canonical_skill(skill).as_deref() == Some("JavaScript")
+            })
+            .collect();
+        assert_eq!(javascript, ["JavaScript"]);
+        assert!(parsed.skills.iter().any(|skill| skill == "Python"));
+        assert_eq!(parsed.skills.len(), 2);
+
+        // A job asking for both spellings has one requirement, not two
+        let keywords = KeywordAnalyzer::new()
+            .extract_keywords_from_job_description("Strong JS and JavaScript skills required.")
+            .unwrap();
+        assert_eq!(
+            keywords
+                .iter()
+                .filter(|k| k.as_str() == "javascript")
+                .count(),
+            1
+        );
+        assert!(!keywords.iter().any(|k| k.as_str() == "js"));
+    }
+
+    #[tokio::test]
+    async fn test_weight_change_recomputes_cached_keyword_analysis() {
+        let resume = "Platform engineer: Rust services, PostgreSQL tuning and Kubernetes rollouts";
+        let job = "We need Rust, PostgreSQL and Kubernetes experience for our platform team";
+        let profile = ScoringProfile::default();
+        let settings = ScoringSettings::default();
+        // Only the fuzzy weight changes; no matcher reports fuzzy matches, so both score the same
+        let reweighted = ScoringSettings {
+            match_type_weights: MatchTypeWeights {
+                fuzzy: settings.match_type_weights.fuzzy + 0.1,
+                ..settings.match_type_weights.clone()
+            },
+            ..settings.clone()
+        };
+        let cached = |settings: &ScoringSettings| {
+            let key = keyword_match_cache_key(resume, job, "technology", &profile, settings);
+            KEYWORD_MATCH_CACHE
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .get(key)
+                .is_some()
+        };
+
+        let first = KeywordAnalyzer::with_settings(Arc::new(settings.clone()))
+            .analyze_with_profile(resume, job, "technology", &profile)
+            .await
+            .unwrap();
+        assert!(cached(&settings));
+        assert!(!cached(&reweighted));
+
+        let recomputed = KeywordAnalyzer::with_settings(Arc::new(reweighted.clone()))
+            .analyze_with_profile(resume, job, "technology", &profile)
+            .await
+            .unwrap();
+        assert!(cached(&reweighted));
+        assert_eq!(recomputed.overall_score, first.overall_score);
+        assert_eq!(recomputed.exact_matches.len(), first.exact_matches.len());
+    }
+
+    #[tokio::test]
+    async fn test_failed_suggestion_stage_keeps_keyword_and_format_results() {
+        let db = Database::new_with_url("sqlite::memory:").await.unwrap();
+        let mut engine = AdvancedScoringEngine::new(Arc::new(Mutex::new(db)));
+        engine.failing_stage = Some(AnalysisStage::Suggestions);
+
+        let resume = "Jordan Park\njordan@example.com\n\nEXPERIENCE\n\
+                      Backend Engineer | Acme | 2019 - Present\n\
+                      • Built Python and PostgreSQL services handling 2M requests a day\n\n\
+                      SKILLS\nPython, PostgreSQL, Docker\n";
+        let job = "Backend engineer with Python, PostgreSQL, Docker and Kubernetes";
+        let result = engine
+            .analyze_comprehensive(resume, job, "technology", "mid")
+            .await
+            .expect("a failed suggestion stage should not fail the analysis");
+
+        assert_eq!(result.failed_stages.len(), 1);
+        assert_eq!(result.failed_stages[0].stage, AnalysisStage::Suggestions);
+        assert!(result.improvement_suggestions.is_empty());
+        assert!(!result.keyword_analysis.exact_matches.is_empty());
+        assert!(result.format_analysis.ats_compatibility_score > 0.0);
+        assert!(!result.ats_compatibility.is_empty());
+        assert!(result.base_analysis.overall_score > 0.0);
+
+        engine.failing_stage = None;
+        let complete = engine
+            .analyze_comprehensive(resume, job, "technology", "mid")
+            .await
+            .unwrap();
+        assert!(complete.failed_stages.is_empty());
+        assert!(!complete.improvement_suggestions.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_score_breakdown_contributions_sum_to_overall_score() {
+        let db = Database::new_with_url("sqlite::memory:").await.unwrap();
+        let engine = AdvancedScoringEngine::new(Arc::new(Mutex::new(db)));
+        let resume = "Priya Shah\npriya@example.com\n\nSUMMARY\n\
+                      Data engineer building Spark and Airflow pipelines\n\n\
+                      EXPERIENCE\nData Engineer | Initech | 2018 - Present\n\
+                      • Built Spark pipelines in Python processing 4TB daily\n\
+                      • Cut Airflow job failures 60% with retries and alerting\n\n\
+                      EDUCATION\nB.S. Computer Science\n\nSKILLS\nPython, Spark, Airflow, SQL\n";
+        let job = "Data engineer with Python, Spark, Airflow, SQL and Kafka experience";
+
+        let result = engine
+            .analyze_comprehensive(resume, job, "technology", "mid")
+            .await
+            .unwrap();
+        let breakdown = &result.score_breakdown;
+        assert_eq!(breakdown.components.len(), 5);
+
+        let contributions: f64 = breakdown.components.iter().map(|c| c.contribution).sum();
+        assert!((contributions - result.base_analysis.overall_score).abs() < 0.01);
+        assert_eq!(breakdown.total, result.base_analysis.overall_score);
+        for component in &breakdown.components {
+            assert!((component.contribution - component.raw_score * component.weight).abs() < 1e-9);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_keyword_rule_reports_security_clearance() {
+        let invalid = compile_keyword_rules(&[KeywordRule {
+            name: "broken".to_string(),
+            pattern: "(unclosed".to_string(),
+            category: "misc".to_string(),
+            weight: 1.0,
+        }]);
+        assert!(invalid
+            .unwrap_err()
+            .to_string()
+            .contains("Keyword rule 'broken' has an invalid pattern"));
+
+        let rules = compile_keyword_rules(&[KeywordRule {
+            name: "security clearance".to_string(),
+            pattern: r"(?i)\b(?:top secret|ts/sci|secret)(?:/sci)? clearance\b".to_string(),
+            category: "clearance".to_string(),
+            weight: 2.0,
+        }])
+        .unwrap();
+        let settings = ScoringSettings {
+            keyword_rules: Arc::from(rules),
+            ..ScoringSettings::default()
+        };
+        let resume = "Systems Engineer\nHolds an active Top Secret clearance.\nPython, Linux";
+        let keyword_match = KeywordAnalyzer::with_settings(Arc::new(settings))
+            .analyze_comprehensive(
+                resume,
+                "Systems engineer with Python and Linux",
+                "technology",
+            )
+            .await
+            .unwrap();
+
+        let clearance = &keyword_match.rule_matches["clearance"];
+        assert_eq!(clearance.len(), 1);
+        assert_eq!(clearance[0].keyword, "security clearance");
+        assert_eq!(clearance[0].matched_text, "Top Secret clearance");
+        assert_eq!(
+            char_slice(resume, clearance[0].start, clearance[0].end),
+            "Top Secret clearance"
+        );
+        assert_eq!(clearance[0].weight, 2.0);
+        assert!(keyword_match
+            .matched_keywords()
+            .contains(&"security clearance".to_string()));
+    }
+
+    #[test]
+    fn test_quality_blend_weights_ats_and_readability_scores() {
+        let readability = ReadabilityAnalyzer::new().analyze(
+            "Jordan Lee\nEXPERIENCE\n\
+             • Was responsible for the comprehensive administration and continuous optimization \
+             of organizational infrastructure initiatives across interdepartmental environments \
+             throughout the enterprise in order to deliver measurable value to stakeholders\n",
+        );
+        assert!(readability.score < 100.0);
+        let ats_score = 82.0;
+
+        let default_blend =
+            QualityBlend::new(ats_score, readability.score, DEFAULT_ATS_SCORE_WEIGHT);
+        assert!(
+            (default_blend.blended_score - (0.7 * ats_score + 0.3 * readability.score)).abs()
+                < 1e-9
+        );
+
+        let even_blend = QualityBlend::new(ats_score, readability.score, 0.5);
+        assert!(
+            (even_blend.blended_score - (0.5 * ats_score + 0.5 * readability.score)).abs() < 1e-9
+        );
+        assert!(even_blend.blended_score < default_blend.blended_score);
+        assert_eq!(even_blend.readability_score, readability.score);
+    }
+
+    #[tokio::test]
+    async fn test_education_before_experience_on_senior_resume_suggests_reorder() {
+        let db = Database::new_with_url("sqlite::memory:").await.unwrap();
+        let engine = AdvancedScoringEngine::new(Arc::new(Mutex::new(db)));
+        let resume =
+            "Pat Doe\npat@example.com\n\nSUMMARY\nStaff engineer with 12 years in backend \
+                      systems.\n\nEDUCATION\nBS Computer Science | State University | 2010\n\n\
+                      EXPERIENCE\nStaff Engineer | Acme | 2016 - Present\n• Led platform team\n\n\
+                      SKILLS\nRust, Go, Kubernetes\n";
+        let order: Vec<String> = detect_section_order(resume, &SectionHeaderSynonyms::default())
+            .into_iter()
+            .map(|(section, _)| section)
+            .collect();
+        assert_eq!(order, ["Summary", "Education", "Experience", "Skills"]);
+
+        let suggestion = engine
+            .section_order_suggestion(resume, "senior")
+            .expect("reorder suggested for senior resume");
+        assert_eq!(suggestion.title, "Reorder resume sections");
+        assert_eq!(
+            suggestion.after_example,
+            "Summary → Experience → Skills → Education"
+        );
+        assert!(suggestion
+            .specific_actions
+            .iter()
+            .any(|action| action.action == "Move Education to position 4 of 4"));
+
+        // The same order is what a new graduate is expected to use
+        assert!(engine.section_order_suggestion(resume, "entry").is_none());
+    }
+
+    #[test]
+    fn test_target_plan_closes_gap_with_highest_impact_suggestions() {
+        let suggestion = |category: &str, title: &str, impact_score: f64| OptimizationSuggestion {
+            category: category.to_string(),
+            title: title.to_string(),
+            impact_score,
+            ..Default::default()
+        };
+        let suggestions = vec![
+            suggestion("Keywords", "Add Kubernetes", 90.0),
+            suggestion("Keywords", "Add Terraform", 70.0),
+            suggestion("Content", "Quantify achievements", 70.0),
+            suggestion("Format", "Fix date formats", 30.0),
+            suggestion("Sections", "Add professional summary", 85.0),
+            OptimizationSuggestion {
+                resolved: true,
+                ..suggestion("Keywords", "Already done", 95.0)
+            },
+        ];
+        // At a keyword score of 70, keywords have lost 15 points and format 5; sections and
+        // achievements are perfect
+        let breakdown = |keyword_score: f64| {
+            let components = vec![
+                ScoreComponent::new("keyword_match", keyword_score, 0.5),
+                ScoreComponent::new("format_compatibility", 80.0, 0.25),
+                ScoreComponent::new("section_completeness", 100.0, 0.125),
+                ScoreComponent::new("achievement_quality", 100.0, 0.125),
+            ];
+            ScoreBreakdown {
+                total: components.iter().map(|c| c.contribution).sum(),
+                components,
+            }
+        };
+
+        let plan = TargetScorePlan::new(&suggestions, &breakdown(70.0), 95.0);
+
+        assert_eq!(plan.current_score, 80.0);
+        assert_eq!(plan.gap, 15.0);
+        assert!(plan.reachable);
+        assert!(plan.estimated_gain >= plan.gap);
+        // Kubernetes wins back 13.5 of keywords' 15 points, after which fixing the dates
+        // (1.5 of format's 5) adds more than Terraform would from the 1.5 keywords have left
+        let titles: Vec<&str> = plan.steps.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(titles, ["Add Kubernetes", "Fix date formats"]);
+
+        assert!(TargetScorePlan::new(&suggestions, &breakdown(100.0), 90.0)
+            .steps
+            .is_empty());
+        assert!(!TargetScorePlan::new(&suggestions, &breakdown(0.0), 99.0).reachable);
+    }
+
+    #[tokio::test]
+    async fn test_old_role_with_most_bullets_is_flagged_as_imbalanced() {
+        let db = Database::new_with_url("sqlite::memory:").await.unwrap();
+        let engine = AdvancedScoringEngine::new(Arc::new(Mutex::new(db)));
+        let old_bullets: String = (1..=12)
+            .map(|i| format!("• Maintained legacy report number {}\n", i))
+            .collect();
+        let resume = engine
+            .ats_simulator
+            .parse_with_multiple_systems(&format!(
+                "Pat Doe\npat@example.com\n\nEXPERIENCE\n\
+                 Staff Engineer | Acme | 2021 - Present\n• Led the platform team\n\
+                 Analyst | Initech | 2010 - 2014\n{}",
+                old_bullets
+            ))
+            .unwrap();
+
+        let today = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+        let balance = BulletBalance::analyze(&resume.experience, today);
+        let counts: Vec<usize> = balance.roles.iter().map(|r| r.bullet_count).collect();
+        assert_eq!(counts, [1, 12]);
+        assert_eq!(balance.most_recent, Some(0));
+        assert_eq!(balance.heaviest, Some(1));
+        assert!(balance.imbalanced);
+
+        let suggestion = engine
+            .bullet_balance_suggestion(&balance)
+            .expect("imbalance suggestion");
+        assert_eq!(suggestion.title, "Rebalance bullets toward recent roles");
+        assert!(suggestion.specific_actions[0]
+            .action
+            .starts_with("Trim Analyst"));
+        assert!(suggestion.specific_actions[0]
+            .action
+            .ends_with("to its 4 strongest bullets"));
+    }
+
+    #[tokio::test]
+    async fn test_contextualized_keyword_has_higher_context_quality_than_listed() {
+        let resume = "EXPERIENCE\n\
+            Led the migration of 40 services to Docker, cutting deploy times by 60%\n\
+            SKILLS\n\
+            Terraform, Python, Go\n";
+        let job_description = "We need experience with docker and terraform.";
+
+        let keyword_match = KeywordAnalyzer::new()
+            .analyze_comprehensive(resume, job_description, "technology")
+            .await
+            .unwrap();
+        let explanations = keyword_match.explain();
+        let quality = |keyword: &str| {
+            explanations
+                .iter()
+                .find(|e| e.keyword == keyword)
+                .unwrap_or_else(|| panic!("{keyword} should be explained"))
+                .context_quality
+        };
+
+        assert_eq!(quality("terraform"), BARE_MENTION_CONTEXT_QUALITY);
+        assert!(quality("docker") > 0.8);
+        assert!(quality("docker") > quality("terraform"));
+    }
+
+    #[tokio::test]
+    async fn test_rank_job_fit_puts_best_matching_job_first() {
+        let db = Database::new_with_url("sqlite::memory:").await.unwrap();
+        let jobs = [
+            (
+                "Data Analyst",
+                "Seeking an analyst skilled in excel, tableau and statistics reporting.",
+            ),
+            (
+                "Backend Engineer",
+                "Seeking an engineer with rust, postgresql, kubernetes and terraform experience.",
+            ),
+            (
+                "Nurse",
+                "Registered nurse with patient care, triage and medication administration.",
+            ),
+        ];
+        for (title, content) in jobs {
+            db.save_job_description(&JobDescription::new(
+                title.to_string(),
+                "Acme".to_string(),
+                content.to_string(),
+            ))
+            .await
+            .unwrap();
+        }
+        let resume = "EXPERIENCE\n\
+            Backend Engineer, Initech, 2019 - Present\n\
+            • Built rust services on postgresql deployed to kubernetes with terraform\n\
+            SKILLS\n\
+            Rust, PostgreSQL, Kubernetes, Terraform\n";
+
+        let stored = db.get_all_job_descriptions(false).await.unwrap();
+        let fits = KeywordAnalyzer::new()
+            .rank_job_fit(resume, &stored, 2)
+            .await
+            .unwrap();
+
+        assert_eq!(fits.len(), 2);
+        assert_eq!(fits[0].title, "Backend Engineer");
+        assert!(fits[0].fit_score > fits[1].fit_score);
+        assert!(fits[0]
+            .matched_keywords
+            .iter()
+            .any(|keyword| keyword == "kubernetes"));
+    }
+
+    #[tokio::test]
+    async fn test_certification_expiring_next_month_gets_renewal_suggestion() {
+        let db = Database::new_with_url("sqlite::memory:").await.unwrap();
+        let engine = AdvancedScoringEngine::new(Arc::new(Mutex::new(db)));
+        let today = NaiveDate::from_ymd_opt(2025, 6, 10).unwrap();
+        let certifications = parse_certifications(
+            "Certified Kubernetes Administrator | CNCF | Expires Jul 2025\n\
+             AWS Certified Solutions Architect, Amazon, Expires 2028\n",
+            today,
+        );
+
+        let suggestion = engine
+            .certification_expiry_suggestion(
+                &certifications,
+                today,
+                DEFAULT_CERT_EXPIRY_WARNING_DAYS,
+            )
+            .expect("renewal suggestion");
+        assert_eq!(suggestion.title, "Renew or remove lapsing certifications");
+        assert!(suggestion.impact_score >= 85.0);
+        assert_eq!(suggestion.specific_actions.len(), 1);
+        assert_eq!(
+            suggestion.specific_actions[0].action,
+            "Certified Kubernetes Administrator expires Jul 2025; renew it and update the expiry date"
+        );
+
+        // Outside the window nothing is flagged
+        assert!(engine
+            .certification_expiry_suggestion(&certifications, today, 10)
+            .is_none());
+    }
+
+    #[test]
+    fn test_stored_match_without_end_spans_its_matched_text() {
+        let stored = r#"{"keyword":"python","matched_text":"Python","section":"Skills",
+            "position":12,"context":"Skills: Python","confidence":1.0,"weight":1.0}"#;
+        let result: MatchResult = serde_json::from_str(stored).unwrap();
+        assert_eq!((result.start, result.end), (12, 18));
+        assert_eq!(result.position_factor, 1.0);
+
+        let round_trip: MatchResult =
+            serde_json::from_str(&serde_json::to_string(&result).unwrap()).unwrap();
+        assert_eq!(round_trip, result);
+    }
+
+    #[test]
+    fn test_context_window_leaves_section_and_weight_unchanged() {
+        let resume = "Summary of my background\nSkills\nPython programming and Rust tooling";
+        let keywords = vec!["python".to_string()];
+
+        let placement = |window: usize| {
+            [
+                StemmedMatcher.find_matches_with_context(resume, &keywords, window),
+                SynonymMatcher.find_matches_with_context(
+                    resume,
+                    &keywords,
+                    window,
+                    &default_skill_taxonomy(),
+                ),
+            ]
+            .into_iter()
+            .map(|matches| {
+                let found = &matches.unwrap()[0];
+                (found.section.clone(), found.weight, found.context.clone())
+            })
+            .collect::<Vec<_>>()
+        };
+
+        let narrow = placement(0);
+        let wide = placement(5);
+        for ((narrow_section, narrow_weight, narrow_context), (section, weight, _)) in
+            narrow.iter().zip(&wide)
+        {
+            assert_eq!(narrow_context, "Python");
+            assert_eq!(narrow_section, "Skills");
+            assert_eq!(narrow_section, section);
+            assert_eq!(narrow_weight, weight);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_experience_alignment_buckets_tenure_with_configured_thresholds() {
+        let db = Arc::new(Mutex::new(
+            Database::new_with_url("sqlite::memory:").await.unwrap(),
+        ));
+        let resume = ATSSimulator::new()
+            .parse_with_multiple_systems(
+                "Sam Lee\nsam@example.com\n\nEXPERIENCE\n\
+                 Data Analyst | Initech | Jan 2022 - Present\n\
+                 • Built weekly revenue dashboards in SQL\n",
+            )
+            .unwrap();
+        let alignment = |experience_level_thresholds| {
+            let settings = ScoringSettings {
+                experience_level_thresholds,
+                ..ScoringSettings::default()
+            };
+            AdvancedScoringEngine::with_settings(Arc::clone(&db), Arc::new(settings))
+                .calculate_experience_alignment(&resume, "technology", "senior")
+                .unwrap()
+        };
+
+        let fast = alignment(ExperienceLevelThresholds {
+            mid_years: 1,
+            senior_years: 2,
+            executive_years: 50,
+        });
+        let strict = alignment(ExperienceLevelThresholds {
+            mid_years: 30,
+            senior_years: 40,
+            executive_years: 50,
+        });
+        assert_eq!(fast - strict, LEVEL_MET_BONUS);
+    }
+}
@@ -0,0 +1,94 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::advanced_scoring::KeywordAnalyzer;
+use crate::enhanced_prompts::estimate_prompt_tokens;
+use crate::ollama::OllamaClient;
+
+/// Recent analyses averaged for the time estimate
+pub const RECENT_METRICS_WINDOW: i64 = 20;
+/// Prompt size a typical recorded analysis is assumed to have had
+const REFERENCE_PROMPT_TOKENS: f64 = 1500.0;
+/// Generation cost per prompt token when the model has no recorded analyses
+const DEFAULT_MS_PER_PROMPT_TOKEN: f64 = 15.0;
+/// Exact, stemmed, synonym, contextual and fuzzy passes over the resume
+const MATCHER_STAGES: u64 = 5;
+/// Keyword comparisons the matchers get through per millisecond
+const MATCHER_OPERATIONS_PER_MS: f64 = 50_000.0;
+
+/// What a full analysis would cost, worked out without calling the model
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnalysisEstimate {
+    pub model: String,
+    pub prompt_tokens: usize,
+    /// Keyword-against-word comparisons across all matcher stages
+    pub matcher_operations: u64,
+    pub estimated_time_ms: u64,
+    /// Recorded analyses behind the estimate; 0 means a built-in default rate was used
+    pub based_on_samples: usize,
+}
+
+/// Estimate prompt size, matcher work and duration from the inputs and the model's recent
+/// processing times
+pub fn estimate_analysis(
+    client: &OllamaClient,
+    model: &str,
+    resume_content: &str,
+    job_description: &str,
+    recent_processing_times_ms: &[i64],
+) -> Result<AnalysisEstimate> {
+    let prompt = client.create_analysis_prompt(model, resume_content, job_description);
+    let prompt_tokens = estimate_prompt_tokens(&model.to_lowercase(), &prompt);
+
+    let keywords = KeywordAnalyzer::new().extract_keywords_from_job_description(job_description)?;
+    let resume_words = resume_content.split_whitespace().count() as u64;
+    let matcher_operations = keywords.len() as u64 * resume_words * MATCHER_STAGES;
+
+    let ms_per_token = if recent_processing_times_ms.is_empty() {
+        DEFAULT_MS_PER_PROMPT_TOKEN
+    } else {
+        let total: i64 = recent_processing_times_ms.iter().sum();
+        total as f64 / recent_processing_times_ms.len() as f64 / REFERENCE_PROMPT_TOKENS
+    };
+    let model_ms = prompt_tokens as f64 * ms_per_token;
+    let matcher_ms = matcher_operations as f64 / MATCHER_OPERATIONS_PER_MS;
+
+    Ok(AnalysisEstimate {
+        model: model.to_string(),
+        prompt_tokens,
+        matcher_operations,
+        estimated_time_ms: (model_ms + matcher_ms).ceil() as u64,
+        based_on_samples: recent_processing_times_ms.len(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_scales_with_input_size() {
+        let client = OllamaClient::new(None).unwrap();
+        let job_description = "Backend engineer with Python, Kubernetes, PostgreSQL and AWS \
+                               experience building REST APIs.";
+        let bullet = "Built Python services on Kubernetes and tuned PostgreSQL queries.\n";
+        let short_resume = bullet.repeat(5);
+        let long_resume = bullet.repeat(50);
+        let recent = [3000, 4000, 5000];
+
+        let short =
+            estimate_analysis(&client, "llama2", &short_resume, job_description, &recent).unwrap();
+        let long =
+            estimate_analysis(&client, "llama2", &long_resume, job_description, &recent).unwrap();
+
+        assert!(long.prompt_tokens > short.prompt_tokens);
+        assert_eq!(long.matcher_operations, short.matcher_operations * 10);
+        assert!(long.estimated_time_ms > short.estimated_time_ms);
+        assert_eq!(long.based_on_samples, 3);
+
+        // Slower recorded runs stretch the estimate for the same input
+        let slower =
+            estimate_analysis(&client, "llama2", &long_resume, job_description, &[9000]).unwrap();
+        assert!(slower.estimated_time_ms > long.estimated_time_ms);
+    }
+}
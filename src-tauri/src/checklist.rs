@@ -0,0 +1,262 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use crate::achievement_analyzer::AchievementAnalyzer;
+use crate::advanced_scoring::{ScoringSettings, SectionHeaderSynonyms};
+use crate::document::DocumentParser;
+use crate::format_checker::FormatCompatibilityChecker;
+use crate::models::LengthVerdict;
+
+/// Format issues that mean part of the resume is a graphic an ATS cannot read
+const GRAPHIC_ISSUES: &[&str] = &["text_in_images"];
+/// Layout elements that often, but not always, break parsing
+const LAYOUT_ISSUES: &[&str] = &["tables", "text_boxes"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChecklistStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChecklistItem {
+    pub id: String,
+    pub label: String,
+    pub status: ChecklistStatus,
+    pub explanation: String,
+}
+
+/// Go/no-go view of the basics a resume needs before it is worth scoring in detail
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResumeChecklist {
+    pub items: Vec<ChecklistItem>,
+    /// True when no item failed
+    pub ready: bool,
+}
+
+/// Checklist for `content`, finding sections and judging length by `settings`
+pub fn build_resume_checklist(
+    content: &str,
+    settings: &ScoringSettings,
+) -> Result<ResumeChecklist> {
+    let sections = detect_sections(content, &settings.section_header_synonyms);
+    let items = vec![
+        contact_item(content),
+        section_item(
+            &sections,
+            "summary",
+            "Summary",
+            "Professional summary",
+            "Add a 2-3 sentence summary at the top so recruiters see your focus first",
+        ),
+        section_item(
+            &sections,
+            "skills",
+            "Skills",
+            "Skills section",
+            "Add a skills section; most ATS extract keywords from it directly",
+        ),
+        quantified_achievement_item(content)?,
+        graphics_item(content)?,
+        length_item(content, settings),
+    ];
+
+    let ready = items
+        .iter()
+        .all(|item| item.status != ChecklistStatus::Fail);
+    Ok(ResumeChecklist { items, ready })
+}
+
+fn item(id: &str, label: &str, status: ChecklistStatus, explanation: String) -> ChecklistItem {
+    ChecklistItem {
+        id: id.to_string(),
+        label: label.to_string(),
+        status,
+        explanation,
+    }
+}
+
+fn detect_sections(content: &str, synonyms: &SectionHeaderSynonyms) -> HashSet<String> {
+    content
+        .lines()
+        .filter_map(|line| synonyms.section_for_header(line))
+        .map(str::to_string)
+        .collect()
+}
+
+fn contact_item(content: &str) -> ChecklistItem {
+    let contact = DocumentParser::extract_contact_info(content);
+    let (status, explanation) = match (contact.email.is_some(), contact.phone.is_some()) {
+        (true, true) => (ChecklistStatus::Pass, "Email and phone found".to_string()),
+        (true, false) => (
+            ChecklistStatus::Warn,
+            "Email found but no phone number".to_string(),
+        ),
+        (false, true) => (
+            ChecklistStatus::Warn,
+            "Phone found but no email address".to_string(),
+        ),
+        (false, false) => (
+            ChecklistStatus::Fail,
+            "No email or phone number found; recruiters need a way to reach you".to_string(),
+        ),
+    };
+    item("contact", "Contact information", status, explanation)
+}
+
+fn section_item(
+    sections: &HashSet<String>,
+    id: &str,
+    section: &str,
+    label: &str,
+    missing_explanation: &str,
+) -> ChecklistItem {
+    if sections.contains(section) {
+        item(
+            id,
+            label,
+            ChecklistStatus::Pass,
+            format!("{} section found", section),
+        )
+    } else {
+        item(
+            id,
+            label,
+            ChecklistStatus::Fail,
+            missing_explanation.to_string(),
+        )
+    }
+}
+
+fn quantified_achievement_item(content: &str) -> Result<ChecklistItem> {
+    let analysis = AchievementAnalyzer::new().analyze_achievements(content)?;
+    let quantified = analysis
+        .strong_achievements
+        .iter()
+        .filter(|bullet| bullet.has_quantification)
+        .count();
+
+    let (status, explanation) = if quantified > 0 {
+        (
+            ChecklistStatus::Pass,
+            format!("{} achievement(s) backed by numbers", quantified),
+        )
+    } else if !analysis.strong_achievements.is_empty()
+        || !analysis.improvement_opportunities.is_empty()
+    {
+        (
+            ChecklistStatus::Warn,
+            "Bullets found but none quantify their impact; add numbers, percentages or amounts"
+                .to_string(),
+        )
+    } else {
+        (
+            ChecklistStatus::Fail,
+            "No achievement bullets found; list accomplishments with measurable results"
+                .to_string(),
+        )
+    };
+    Ok(item(
+        "quantified_achievement",
+        "Quantified achievement",
+        status,
+        explanation,
+    ))
+}
+
+fn graphics_item(content: &str) -> Result<ChecklistItem> {
+    let report = FormatCompatibilityChecker::new().check_comprehensive_compatibility(content)?;
+    let has_issue = |types: &[&str]| {
+        report
+            .format_issues
+            .iter()
+            .any(|issue| types.contains(&issue.issue_type.as_str()))
+    };
+
+    let (status, explanation) = if has_issue(GRAPHIC_ISSUES) {
+        (
+            ChecklistStatus::Fail,
+            "Images detected; ATS cannot read text inside graphics".to_string(),
+        )
+    } else if has_issue(LAYOUT_ISSUES) {
+        (
+            ChecklistStatus::Warn,
+            "Tables or text boxes detected; some ATS skip their content".to_string(),
+        )
+    } else {
+        (
+            ChecklistStatus::Pass,
+            "No graphics, tables or text boxes".to_string(),
+        )
+    };
+    Ok(item("no_graphics", "No graphics", status, explanation))
+}
+
+fn length_item(content: &str, settings: &ScoringSettings) -> ChecklistItem {
+    let length =
+        DocumentParser::analyze_length(content, None, &settings.experience_level_thresholds);
+    let status = match length.verdict {
+        LengthVerdict::WithinRange => ChecklistStatus::Pass,
+        LengthVerdict::TooShort | LengthVerdict::TooLong => ChecklistStatus::Warn,
+    };
+    item("length", "Reasonable length", status, length.message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resume_without_summary() -> String {
+        let bullets = [
+            "• Led migration of 40 services to Kubernetes, reducing deploy time by 60%",
+            "• Built a payments API handling 2 million requests per day with 99.9% uptime",
+            "• Reduced cloud costs by $120,000 per year by rightsizing database clusters",
+            "• Mentored 5 engineers and improved code review turnaround by 35%",
+            "• Designed an event pipeline that increased reporting accuracy by 25%",
+            "• Automated release checks, cutting production incidents by 40%",
+            "• Rebuilt the search indexer in Rust, lowering p99 latency from 800ms to 120ms",
+        ];
+        format!(
+            "Jane Doe\n\
+             jane.doe@example.com | (555) 123-4567\n\n\
+             Experience\n\
+             Software Engineer II, Acme Corp, 2022 - Present\n\
+             {bullets}\n\
+             Software Engineer, Globex, 2020 - 2022\n\
+             {bullets}\n\n\
+             Education\n\
+             B.S. Computer Science, State University, 2020\n\n\
+             Skills\n\
+             Rust, Go, Python, Kubernetes, AWS, PostgreSQL, Terraform, gRPC, Kafka, Redis, \
+             Docker, Linux, CI/CD, observability, distributed systems, API design",
+            bullets = bullets.join("\n")
+        )
+    }
+
+    #[test]
+    fn test_missing_summary_fails_only_that_item() {
+        let checklist =
+            build_resume_checklist(&resume_without_summary(), &ScoringSettings::default()).unwrap();
+
+        let summary = checklist
+            .items
+            .iter()
+            .find(|item| item.id == "summary")
+            .unwrap();
+        assert_eq!(summary.status, ChecklistStatus::Fail);
+        assert!(!checklist.ready);
+
+        for item in checklist.items.iter().filter(|item| item.id != "summary") {
+            assert_eq!(
+                item.status,
+                ChecklistStatus::Pass,
+                "{}: {}",
+                item.id,
+                item.explanation
+            );
+        }
+    }
+}
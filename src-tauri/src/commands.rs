@@ -2,49 +2,65 @@ use log::{error, info, warn};
 use serde::Serialize;
 
 use crate::errors::{ATSError, ATSResult};
-use std::path::Path;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
 use tauri::{Manager, State};
 
 use crate::models::{
-    ATSCompatibilityRule, Analysis, AnalysisRequest, AnalysisResult, DocumentInfo, IndustryKeyword,
-    JobAnalytics, JobComparisonRequest, JobComparisonResult, JobDescription, JobSearchRequest,
-    JobSearchResult, JobUrlExtractionRequest, JobUrlExtractionResult, ModelPerformance,
-    ModelPerformanceMetrics, OptimizationRequest, OptimizationResult, Resume, ScoringBenchmark,
+    ATSCompatibilityRule, Analysis, AnalysisRequest, AnalysisResult, DocumentInfo,
+    IncrementalExport, IndustryKeyword, JobAnalytics, JobComparisonRequest, JobComparisonResult,
+    JobDescription, JobSearchRequest, JobSearchResult, JobUrlExtractionRequest,
+    JobUrlExtractionResult, KeywordCoverageTrend, ModelPerformance, ModelPerformanceMetrics,
+    OptimizationRequest, OptimizationResult, Resume, ResumeTimelineEntry, ScoringBenchmark,
     UserFeedback, UserPreferences, UserPreferencesUpdate,
 };
 // Phase 2 imports
 use crate::ats_simulator::{ATSSimulationResult, ATSSimulator};
 use crate::enhanced_prompts::{
-    EnhancedPromptEngine, EnhancedPromptRequest, EnhancedPromptResponse,
+    EnhancedPromptEngine, EnhancedPromptRequest, EnhancedPromptResponse, PromptComparison,
 };
 use crate::enhanced_scoring::{EnhancedAnalysisResult, EnhancedScoringEngine};
-use crate::industry_analyzer::{IndustryAnalysisResult, IndustryAnalyzer};
+use crate::industry_analyzer::{
+    IndustryAnalysisResult, IndustryAnalyzer, TransferableSkillsReport,
+};
 use crate::semantic_analyzer::{SemanticAnalysisResult, SemanticAnalyzer};
 // Phase 3 imports
 use crate::format_checker::{FormatCompatibilityChecker, FormatCompatibilityReport};
 use crate::format_issue_detector::{FormatIssueDetector, FormatIssueReport};
 use crate::testing_framework::{ATSTestingFramework, ValidationReport};
 // Phase 4 imports
-use crate::achievement_analyzer::{AchievementAnalysis, AchievementAnalyzer};
-use crate::realtime_optimizer::{LiveSuggestions, RealtimeOptimizer};
+use crate::achievement_analyzer::{AchievementAnalysis, AchievementAnalyzer, ImpactTier};
+use crate::cover_letter::{CoverLetterAnalysis, CoverLetterAnalyzer};
+use crate::realtime_optimizer::{IncrementalScore, LiveSuggestions, RealtimeOptimizer, TextDelta};
 use crate::smart_optimizer::{
     ComprehensiveOptimization, OptimizationLevel, SmartOptimizationEngine,
 };
 // Phase 5 imports
 use crate::competitive_analyzer::{CompetitiveAnalysis, CompetitiveAnalyzer};
 // Phase 6 imports
+use crate::analysis_estimate::{self, AnalysisEstimate, RECENT_METRICS_WINDOW};
+use crate::checklist::{build_resume_checklist, ResumeChecklist};
 use crate::document::DocumentParser;
+use crate::employment_gaps::{analyze_employment_gaps, EmploymentGapAnalysis};
 use crate::migrations::{MigrationManager, MigrationResult, SchemaVersion};
 use crate::ml_insights::{MLInsights, MLInsightsEngine};
 use crate::modern_keyword_extractor::ExtractionResult;
 use crate::ollama::OllamaClient;
 use crate::plugin_system::{PluginExecutionResult, PluginInfo, PluginManager};
+use crate::requirement_coverage::{build_coverage_matrix, CoverageMatrix};
+use crate::resume_export;
 use crate::scoring::AnalysisEngine;
-use crate::utils::{export_data, security};
+use crate::system_health::{check_config, check_system_health, SystemHealth};
+use crate::utils::{export_batch_data, export_data, redaction::Redactor, security, BatchResultRow};
+use crate::webhook::{notify_analysis_completed, AnalysisCompletedPayload};
 use crate::AppState;
 // Advanced Scoring Engine
 use crate::advanced_scoring::{
-    AdvancedScoringEngine, EnhancedAnalysisResult as AdvancedAnalysisResult,
+    normalize_resume_text, ATSSystem, ATSSystemReport, AdvancedScoringEngine, AggregatedKeyword,
+    AnalysisOptions, EnhancedAnalysisResult as AdvancedAnalysisResult, IndustryBenchmarkReport,
+    JobFit, KeywordAnalyzer, SavedAnalysis, ScoringSettings, SuggestionLimits, TargetScorePlan,
+    DEFAULT_SIMILAR_ROLES,
 };
 
 // Frontend-compatible achievement analysis structures
@@ -69,6 +85,8 @@ pub struct FrontendBulletAnalysis {
     pub impact: String,
     pub strength_score: f64,
     pub suggestions: Vec<String>,
+    pub scope: String,
+    pub impact_tier: ImpactTier,
 }
 
 #[derive(Debug, Serialize)]
@@ -130,25 +148,54 @@ impl<T> CommandResult<T> {
     }
 
     /// Legacy error method for backward compatibility during migration
-    #[deprecated(note = "Use CommandResult::from_string_error(ATSError) instead")]
+    #[deprecated(note = "Use CommandResult::from_error(context, err) instead")]
     #[allow(dead_code)]
     pub fn legacy_error(message: String) -> Self {
         Self::error(ATSError::validation(message))
     }
 
-    /// Create error from string for compatibility during migration
-    pub fn from_string_error(message: String) -> Self {
-        Self {
-            success: false,
-            data: None,
-            error: Some(CommandError {
-                code: "LEGACY_ERROR".to_string(),
-                message,
-                severity: "Medium".to_string(),
-                details: None,
-            }),
-        }
+    /// Error classified from its source (database, Ollama, I/O, ...) with a context message
+    pub fn from_error(context: &str, err: impl Into<anyhow::Error>) -> Self {
+        Self::error(ATSError::with_context(context, err))
+    }
+}
+
+/// Check a file the user picked before any parser reads it: the path must pass the traversal
+/// check first, then the file must exist and be an upload we accept
+fn validate_upload_path(file_path: &str, max_size_mb: u64) -> ATSResult<PathBuf> {
+    // SECURITY: Validate file path to prevent path traversal attacks
+    let path = security::validate_file_path(file_path, None).map_err(|e| {
+        error!(
+            "Security violation: Invalid file path '{}': {}",
+            file_path, e
+        );
+        ATSError::security("Invalid file path")
+    })?;
+
+    if !path.exists() {
+        return Err(ATSError::file_operation("File does not exist"));
     }
+
+    DocumentParser::validate_upload(&path, max_size_mb).map_err(|e| {
+        warn!("Rejected upload '{}': {}", file_path, e);
+        ATSError::validation(e.to_string())
+    })?;
+    Ok(path)
+}
+
+/// Scoring inputs built from the current analysis config
+async fn scoring_settings(state: &AppState) -> Arc<ScoringSettings> {
+    state.config.lock().await.scoring_settings()
+}
+
+/// Largest upload the current performance config accepts, in megabytes
+async fn max_upload_size_mb(state: &AppState) -> u64 {
+    state
+        .config
+        .lock()
+        .await
+        .get_performance_config()
+        .max_upload_size_mb
 }
 
 #[allow(dead_code)]
@@ -212,52 +259,71 @@ pub async fn ollama_health_check() -> CommandResult<bool> {
 }
 
 #[tauri::command]
-pub async fn parse_document(file_path: String) -> CommandResult<DocumentInfo> {
-    info!("Parsing document: {}", file_path);
+pub async fn system_health(
+    state: State<'_, AppState>,
+) -> Result<CommandResult<SystemHealth>, String> {
+    let ollama_client = match OllamaClient::new(None) {
+        Ok(client) => client,
+        Err(e) => {
+            return Ok(CommandResult::from_error(
+                "Failed to create Ollama client",
+                e,
+            ))
+        }
+    };
+    // Copy what the checks need and release both locks before probing Ollama, so a slow model
+    // endpoint does not stall every other command
+    let db = state.db.lock().await.clone();
+    let (model, config_health) = {
+        let config = state.config.lock().await;
+        (
+            config.get_ollama_config().default_model.clone(),
+            check_config(&config),
+        )
+    };
 
-    // SECURITY: Validate file path to prevent path traversal attacks
-    if let Err(e) = security::validate_file_path(&file_path, None) {
-        error!(
-            "Security violation: Invalid file path '{}': {}",
-            file_path, e
-        );
-        return CommandResult::from_string_error("Invalid file path".to_string());
-    }
+    Ok(CommandResult::success(
+        check_system_health(&ollama_client, &model, &db, config_health).await,
+    ))
+}
+
+#[tauri::command]
+pub async fn parse_document(
+    state: State<'_, AppState>,
+    file_path: String,
+) -> Result<CommandResult<DocumentInfo>, String> {
+    info!("Parsing document: {}", file_path);
 
-    if !Path::new(&file_path).exists() {
-        return CommandResult::from_string_error("File does not exist".to_string());
+    if let Err(e) = validate_upload_path(&file_path, max_upload_size_mb(&state).await) {
+        return Ok(CommandResult::error(e));
     }
 
-    match DocumentParser::parse_file(&file_path).await {
+    let settings = scoring_settings(&state).await;
+    match DocumentParser::parse_file(&file_path, &settings.experience_level_thresholds).await {
         Ok(document_info) => {
             info!("Successfully parsed document: {}", document_info.filename);
-            CommandResult::success(document_info)
+            Ok(CommandResult::success(document_info))
         }
         Err(e) => {
             error!("Failed to parse document: {}", e);
-            CommandResult::from_string_error(format!("Failed to parse document: {}", e))
+            Ok(CommandResult::from_error("Failed to parse document", e))
         }
     }
 }
 
 #[tauri::command]
-pub async fn parse_document_with_metadata(file_path: String) -> CommandResult<DocumentInfo> {
+pub async fn parse_document_with_metadata(
+    state: State<'_, AppState>,
+    file_path: String,
+) -> Result<CommandResult<DocumentInfo>, String> {
     info!("Parsing document with full metadata: {}", file_path);
 
-    // SECURITY: Validate file path to prevent path traversal attacks
-    if let Err(e) = security::validate_file_path(&file_path, None) {
-        error!(
-            "Security violation: Invalid file path '{}': {}",
-            file_path, e
-        );
-        return CommandResult::from_string_error("Invalid file path".to_string());
-    }
-
-    if !Path::new(&file_path).exists() {
-        return CommandResult::from_string_error("File does not exist".to_string());
+    if let Err(e) = validate_upload_path(&file_path, max_upload_size_mb(&state).await) {
+        return Ok(CommandResult::error(e));
     }
 
-    match DocumentParser::parse_file(&file_path).await {
+    let settings = scoring_settings(&state).await;
+    match DocumentParser::parse_file(&file_path, &settings.experience_level_thresholds).await {
         Ok(document_info) => {
             info!(
                 "Successfully parsed document with metadata: {} (Quality Score: {:.1})",
@@ -268,38 +334,88 @@ pub async fn parse_document_with_metadata(file_path: String) -> CommandResult<Do
                     .map(|q| q.overall_quality_score)
                     .unwrap_or(0.0)
             );
-            CommandResult::success(document_info)
+            Ok(CommandResult::success(document_info))
         }
         Err(e) => {
             error!("Failed to parse document with metadata: {}", e);
-            CommandResult::from_string_error(format!(
-                "Failed to parse document with metadata: {}",
-                e
+            Ok(CommandResult::from_error(
+                "Failed to parse document with metadata",
+                e,
             ))
         }
     }
 }
 
+/// Write a copy of a PDF or DOCX resume with identifying metadata removed to `output_path`
 #[tauri::command]
-pub async fn extract_document_structure(
+pub async fn scrub_document_metadata(
+    state: State<'_, AppState>,
     file_path: String,
-) -> CommandResult<crate::models::DocumentStructure> {
-    info!("Extracting document structure: {}", file_path);
+    output_path: String,
+) -> Result<CommandResult<String>, String> {
+    info!("Scrubbing document metadata: {}", file_path);
 
-    // SECURITY: Validate file path to prevent path traversal attacks
-    if let Err(e) = security::validate_file_path(&file_path, None) {
-        error!(
-            "Security violation: Invalid file path '{}': {}",
-            file_path, e
-        );
-        return CommandResult::from_string_error("Invalid file path".to_string());
+    let source = match validate_upload_path(&file_path, max_upload_size_mb(&state).await) {
+        Ok(source) => source,
+        Err(e) => return Ok(CommandResult::error(e)),
+    };
+    // SECURITY: Validate the output path too to prevent path traversal attacks
+    let destination = match security::validate_file_path(&output_path, None) {
+        Ok(destination) => destination,
+        Err(e) => {
+            error!(
+                "Security violation: Invalid file path '{}': {}",
+                output_path, e
+            );
+            return Ok(CommandResult::error(ATSError::security(
+                "Invalid file path",
+            )));
+        }
+    };
+
+    let file_type = source
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or_default()
+        .to_lowercase();
+    let scrubbed = match tokio::fs::read(&source).await {
+        Ok(content) => DocumentParser::scrub_metadata(&content, &file_type),
+        Err(e) => return Ok(CommandResult::from_error("Failed to read document", e)),
+    };
+    let written = match scrubbed {
+        Ok(scrubbed) => tokio::fs::write(&destination, scrubbed).await,
+        Err(e) => {
+            return Ok(CommandResult::from_error(
+                "Failed to scrub document metadata",
+                e,
+            ))
+        }
+    };
+
+    match written {
+        Ok(()) => Ok(CommandResult::success(
+            destination.to_string_lossy().to_string(),
+        )),
+        Err(e) => Ok(CommandResult::from_error(
+            "Failed to write scrubbed document",
+            e,
+        )),
     }
+}
+
+#[tauri::command]
+pub async fn extract_document_structure(
+    state: State<'_, AppState>,
+    file_path: String,
+) -> Result<CommandResult<crate::models::DocumentStructure>, String> {
+    info!("Extracting document structure: {}", file_path);
 
-    if !Path::new(&file_path).exists() {
-        return CommandResult::from_string_error("File does not exist".to_string());
+    if let Err(e) = validate_upload_path(&file_path, max_upload_size_mb(&state).await) {
+        return Ok(CommandResult::error(e));
     }
 
-    match DocumentParser::parse_file(&file_path).await {
+    let settings = scoring_settings(&state).await;
+    match DocumentParser::parse_file(&file_path, &settings.experience_level_thresholds).await {
         Ok(document_info) => {
             if let Some(structure) = document_info.structure {
                 info!(
@@ -307,90 +423,85 @@ pub async fn extract_document_structure(
                     structure.total_sections,
                     structure.headings.len()
                 );
-                CommandResult::success(structure)
+                Ok(CommandResult::success(structure))
             } else {
-                CommandResult::from_string_error(
-                    "No document structure could be extracted".to_string(),
-                )
+                Ok(CommandResult::error(ATSError::document_parsing(
+                    "No document structure could be extracted",
+                )))
             }
         }
         Err(e) => {
             error!("Failed to extract document structure: {}", e);
-            CommandResult::from_string_error(format!("Failed to extract document structure: {}", e))
+            Ok(CommandResult::from_error(
+                "Failed to extract document structure",
+                e,
+            ))
         }
     }
 }
 
 #[tauri::command]
 pub async fn analyze_document_quality(
+    state: State<'_, AppState>,
     file_path: String,
-) -> CommandResult<crate::models::DocumentQualityMetrics> {
+) -> Result<CommandResult<crate::models::DocumentQualityMetrics>, String> {
     info!("Analyzing document quality: {}", file_path);
 
-    // SECURITY: Validate file path to prevent path traversal attacks
-    if let Err(e) = security::validate_file_path(&file_path, None) {
-        error!(
-            "Security violation: Invalid file path '{}': {}",
-            file_path, e
-        );
-        return CommandResult::from_string_error("Invalid file path".to_string());
-    }
-
-    if !Path::new(&file_path).exists() {
-        return CommandResult::from_string_error("File does not exist".to_string());
+    if let Err(e) = validate_upload_path(&file_path, max_upload_size_mb(&state).await) {
+        return Ok(CommandResult::error(e));
     }
 
-    match DocumentParser::parse_file(&file_path).await {
+    let settings = scoring_settings(&state).await;
+    match DocumentParser::parse_file(&file_path, &settings.experience_level_thresholds).await {
         Ok(document_info) => {
             if let Some(quality_metrics) = document_info.quality_metrics {
                 info!(
                     "Document quality analysis completed - Overall Score: {:.1}, ATS Score: {:.1}",
                     quality_metrics.overall_quality_score, quality_metrics.ats_compatibility_score
                 );
-                CommandResult::success(quality_metrics)
+                Ok(CommandResult::success(quality_metrics))
             } else {
-                CommandResult::from_string_error(
-                    "No quality metrics could be calculated".to_string(),
-                )
+                Ok(CommandResult::error(ATSError::document_parsing(
+                    "No quality metrics could be calculated",
+                )))
             }
         }
         Err(e) => {
             error!("Failed to analyze document quality: {}", e);
-            CommandResult::from_string_error(format!("Failed to analyze document quality: {}", e))
+            Ok(CommandResult::from_error(
+                "Failed to analyze document quality",
+                e,
+            ))
         }
     }
 }
 
 #[tauri::command]
 pub async fn get_document_metadata(
+    state: State<'_, AppState>,
     file_path: String,
-) -> CommandResult<crate::models::DocumentMetadata> {
+) -> Result<CommandResult<crate::models::DocumentMetadata>, String> {
     info!("Extracting document metadata: {}", file_path);
 
-    // SECURITY: Validate file path to prevent path traversal attacks
-    if let Err(e) = security::validate_file_path(&file_path, None) {
-        error!(
-            "Security violation: Invalid file path '{}': {}",
-            file_path, e
-        );
-        return CommandResult::from_string_error("Invalid file path".to_string());
-    }
-
-    if !Path::new(&file_path).exists() {
-        return CommandResult::from_string_error("File does not exist".to_string());
+    if let Err(e) = validate_upload_path(&file_path, max_upload_size_mb(&state).await) {
+        return Ok(CommandResult::error(e));
     }
 
-    match DocumentParser::parse_file(&file_path).await {
+    let settings = scoring_settings(&state).await;
+    match DocumentParser::parse_file(&file_path, &settings.experience_level_thresholds).await {
         Ok(document_info) => {
             info!(
                 "Successfully extracted metadata for: {} (Created: {:?})",
                 document_info.filename, document_info.metadata.creation_date
             );
-            CommandResult::success(document_info.metadata)
+            Ok(CommandResult::success(document_info.metadata))
         }
         Err(e) => {
             error!("Failed to extract document metadata: {}", e);
-            CommandResult::from_string_error(format!("Failed to extract document metadata: {}", e))
+            Ok(CommandResult::from_error(
+                "Failed to extract document metadata",
+                e,
+            ))
         }
     }
 }
@@ -401,6 +512,7 @@ pub async fn save_resume(
     filename: String,
     content: String,
     file_type: String,
+    previous_version_id: Option<String>,
 ) -> Result<CommandResult<String>, String> {
     info!("Saving resume: {}", filename);
 
@@ -410,15 +522,21 @@ pub async fn save_resume(
 
     match db.save_resume(&resume).await {
         Ok(()) => {
+            if let Some(previous_id) = previous_version_id {
+                if let Err(e) = db.link_resume_version(&resume_id, &previous_id).await {
+                    error!("Failed to link resume version: {}", e);
+                    return Ok(CommandResult::from_error(
+                        "Failed to link resume version",
+                        e,
+                    ));
+                }
+            }
             info!("Resume saved with ID: {}", resume_id);
             Ok(CommandResult::success(resume_id))
         }
         Err(e) => {
             error!("Failed to save resume: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to save resume: {}",
-                e
-            )))
+            Ok(CommandResult::from_error("Failed to save resume", e))
         }
     }
 }
@@ -439,10 +557,7 @@ pub async fn get_all_resumes(
         }
         Err(e) => {
             error!("Failed to get resumes: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to get resumes: {}",
-                e
-            )))
+            Ok(CommandResult::from_error("Failed to get resumes", e))
         }
     }
 }
@@ -464,10 +579,7 @@ pub async fn get_resume(
         }
         Err(e) => {
             error!("Failed to get resume: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to get resume: {}",
-                e
-            )))
+            Ok(CommandResult::from_error("Failed to get resume", e))
         }
     }
 }
@@ -488,10 +600,7 @@ pub async fn delete_resume(
         }
         Err(e) => {
             error!("Failed to delete resume: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to delete resume: {}",
-                e
-            )))
+            Ok(CommandResult::from_error("Failed to delete resume", e))
         }
     }
 }
@@ -503,14 +612,37 @@ pub async fn analyze_resume(
 ) -> Result<CommandResult<AnalysisResult>, String> {
     info!("Analyzing resume with model: {}", request.model_name);
 
+    // A retry of a request that already succeeded gets the saved result back
+    if let Some(key) = &request.idempotency_key {
+        match state.db.lock().await.get_idempotent_analysis(key).await {
+            Ok(Some(result)) => {
+                info!("Returning saved analysis for idempotency key {}", key);
+                return Ok(CommandResult::success(result));
+            }
+            Ok(None) => {}
+            Err(e) => warn!("Failed to look up idempotency key {}: {}", key, e),
+        }
+    }
+
+    let database = state.db.lock().await.clone();
+    let seed = state.config.lock().await.deterministic_seed();
     let ollama_client = match OllamaClient::new(None) {
-        Ok(client) => client,
+        Ok(client) => client.with_performance_tracking(database).with_seed(seed),
         Err(e) => {
             error!("Failed to create Ollama client: {}", e);
-            return Ok(CommandResult::from_string_error(format!(
-                "Failed to create Ollama client: {}",
-                e
-            )));
+            return Ok(CommandResult::from_error(
+                "Failed to create Ollama client",
+                e,
+            ));
+        }
+    };
+
+    // Make sure the requested model is installed, falling back to one that is
+    let model_name = match ollama_client.resolve_model(&request.model_name).await {
+        Ok(selection) => selection.selected,
+        Err(e) => {
+            error!("Model resolution failed: {}", e);
+            return Ok(CommandResult::from_error("Analysis failed", e));
         }
     };
     let analysis_engine = AnalysisEngine::new(ollama_client);
@@ -519,7 +651,7 @@ pub async fn analyze_resume(
         .analyze_resume(
             &request.resume_content,
             &request.job_description,
-            &request.model_name,
+            &model_name,
         )
         .await
     {
@@ -542,29 +674,65 @@ pub async fn analyze_resume(
                 error!("Failed to save resume: {}", e);
             }
 
-            // Create and save analysis
-            let analysis = Analysis::new(
-                resume.id,
-                "temp_job_id".to_string(),
-                request.model_name,
-                &result,
+            // Save the pasted job too, so the analysis links to a real job description
+            let mut job = JobDescription::new(
+                "Pasted job description".to_string(),
+                String::new(),
+                request.job_description,
             );
-            if let Err(e) = db.save_analysis(&analysis).await {
-                error!("Failed to save analysis: {}", e);
-            }
+            job.industry = request
+                .industry
+                .map(|industry| industry.trim().to_string())
+                .filter(|industry| !industry.is_empty());
+            let job_description_id = match db.save_job_description(&job).await {
+                Ok(()) => job.id,
+                Err(e) => {
+                    error!("Failed to save job description: {}", e);
+                    String::new()
+                }
+            };
 
-            Ok(CommandResult::success(result))
+            // Create and save analysis
+            let analysis = Analysis::new(resume.id, job_description_id, model_name, &result);
+            let saved = match &request.idempotency_key {
+                Some(key) => db.save_analysis_idempotent(key, &analysis, &result).await,
+                None => db.save_analysis(&analysis).await.map(|()| result.clone()),
+            };
+            drop(db);
+
+            match saved {
+                Ok(saved) => {
+                    on_analysis_saved(&state, &analysis, &saved).await;
+                    Ok(CommandResult::success(saved))
+                }
+                Err(e) => {
+                    error!("Failed to save analysis: {}", e);
+                    Ok(CommandResult::success(result))
+                }
+            }
         }
         Err(e) => {
             error!("Resume analysis failed: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Analysis failed: {}",
-                e
-            )))
+            Ok(CommandResult::from_error("Analysis failed", e))
         }
     }
 }
 
+/// Post-save hook shared by every command that stores an analysis: fires the completion
+/// webhook when one is configured
+async fn on_analysis_saved(state: &AppState, analysis: &Analysis, result: &AnalysisResult) {
+    let webhook_url = state
+        .config
+        .lock()
+        .await
+        .get_analysis_config()
+        .completion_webhook_url
+        .clone();
+    if let Some(url) = webhook_url {
+        notify_analysis_completed(url, AnalysisCompletedPayload::new(analysis, result));
+    }
+}
+
 #[allow(dead_code)]
 #[tauri::command]
 pub async fn get_analysis_history(
@@ -582,10 +750,7 @@ pub async fn get_analysis_history(
         }
         Err(e) => {
             error!("Failed to get analysis history: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to get history: {}",
-                e
-            )))
+            Ok(CommandResult::from_error("Failed to get history", e))
         }
     }
 }
@@ -606,32 +771,56 @@ pub async fn delete_analysis(
         }
         Err(e) => {
             error!("Failed to delete analysis: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to delete analysis: {}",
-                e
-            )))
+            Ok(CommandResult::from_error("Failed to delete analysis", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn prune_old_analyses(state: State<'_, AppState>) -> Result<CommandResult<u64>, String> {
+    let policy = state
+        .config
+        .lock()
+        .await
+        .get_performance_config()
+        .retention_policy
+        .clone();
+    let db = state.db.lock().await;
+
+    match db.prune_old_analyses(&policy).await {
+        Ok(pruned) => Ok(CommandResult::success(pruned)),
+        Err(e) => {
+            error!("Failed to prune analyses: {}", e);
+            Ok(CommandResult::from_error("Failed to prune analyses", e))
         }
     }
 }
 
 #[tauri::command]
-pub async fn optimize_resume(request: OptimizationRequest) -> CommandResult<OptimizationResult> {
+pub async fn optimize_resume(
+    request: OptimizationRequest,
+    state: State<'_, AppState>,
+) -> Result<CommandResult<OptimizationResult>, String> {
     info!(
         "Optimizing resume with level: {:?}",
         request.optimization_level
     );
 
+    let database = state.db.lock().await.clone();
+    let seed = state.config.lock().await.deterministic_seed();
     let ollama_client = match OllamaClient::new(None) {
-        Ok(client) => client,
+        Ok(client) => client.with_performance_tracking(database).with_seed(seed),
         Err(e) => {
             error!("Failed to create Ollama client: {}", e);
-            return CommandResult::from_string_error(format!(
-                "Failed to create Ollama client: {}",
-                e
+            return Ok(CommandResult::from_error(
+                "Failed to create Ollama client",
+                e,
             ));
         }
     };
     let analysis_engine = AnalysisEngine::new(ollama_client);
+    let offline_engine =
+        AdvancedScoringEngine::with_settings(state.db.clone(), scoring_settings(&state).await);
 
     let optimization_level = match request.optimization_level {
         crate::models::OptimizationLevel::Conservative => "conservative",
@@ -640,24 +829,217 @@ pub async fn optimize_resume(request: OptimizationRequest) -> CommandResult<Opti
     };
 
     match analysis_engine
-        .optimize_resume(
+        .optimize_resume_or_offline(
             &request.resume_content,
             &request.job_description,
             &request.model_name,
             optimization_level,
+            &offline_engine,
         )
         .await
     {
         Ok(result) => {
-            info!(
-                "Resume optimization completed: {:.1}% improvement",
-                result.improvement_percentage
-            );
-            CommandResult::success(result)
+            if result.offline_mode {
+                warn!(
+                    "Ollama unavailable, returned {} offline suggestions",
+                    result.changes_made.len()
+                );
+            } else {
+                info!(
+                    "Resume optimization completed: {:.1}% improvement",
+                    result.improvement_percentage
+                );
+            }
+            Ok(CommandResult::success(result))
         }
         Err(e) => {
             error!("Resume optimization failed: {}", e);
-            CommandResult::from_string_error(format!("Optimization failed: {}", e))
+            Ok(CommandResult::from_error("Optimization failed", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn resume_checklist(
+    state: State<'_, AppState>,
+    resume_content: String,
+) -> Result<CommandResult<ResumeChecklist>, String> {
+    info!("Building resume completeness checklist");
+
+    match build_resume_checklist(&resume_content, scoring_settings(&state).await.as_ref()) {
+        Ok(checklist) => Ok(CommandResult::success(checklist)),
+        Err(e) => {
+            error!("Failed to build resume checklist: {}", e);
+            Ok(CommandResult::from_error(
+                "Failed to build resume checklist",
+                e,
+            ))
+        }
+    }
+}
+
+/// Each job description requirement with the resume line that satisfies it, if any
+#[tauri::command]
+pub async fn requirement_coverage(
+    state: State<'_, AppState>,
+    resume_content: String,
+    job_description: String,
+) -> Result<CommandResult<CoverageMatrix>, String> {
+    info!("Building job requirement coverage matrix");
+
+    let settings = scoring_settings(&state).await;
+    Ok(CommandResult::success(build_coverage_matrix(
+        &resume_content,
+        &job_description,
+        &settings.section_header_synonyms,
+    )))
+}
+
+#[tauri::command]
+pub async fn normalize_document(content: String) -> CommandResult<String> {
+    info!("Normalizing document text");
+
+    CommandResult::success(normalize_resume_text(&content))
+}
+
+/// Dry run of an analysis: prompt size, matcher work and expected duration, without calling
+/// the model
+#[tauri::command]
+pub async fn estimate_analysis(
+    resume_content: String,
+    job_description: String,
+    model: String,
+    state: State<'_, AppState>,
+) -> Result<CommandResult<AnalysisEstimate>, String> {
+    info!("Estimating analysis cost for model: {}", model);
+
+    let recent_times = {
+        let db = state.db.lock().await;
+        db.get_recent_processing_times(&model, RECENT_METRICS_WINDOW)
+            .await
+            .unwrap_or_else(|e| {
+                warn!("Failed to load recent performance for {}: {}", model, e);
+                Vec::new()
+            })
+    };
+
+    let client = match OllamaClient::new(None) {
+        Ok(client) => client,
+        Err(e) => {
+            return Ok(CommandResult::from_error(
+                "Failed to build analysis prompt",
+                e,
+            ))
+        }
+    };
+
+    match analysis_estimate::estimate_analysis(
+        &client,
+        &model,
+        &resume_content,
+        &job_description,
+        &recent_times,
+    ) {
+        Ok(estimate) => Ok(CommandResult::success(estimate)),
+        Err(e) => {
+            error!("Failed to estimate analysis: {}", e);
+            Ok(CommandResult::from_error("Failed to estimate analysis", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn employment_gaps(
+    state: State<'_, AppState>,
+    resume_content: String,
+) -> Result<CommandResult<EmploymentGapAnalysis>, String> {
+    info!("Analyzing employment gaps");
+
+    let settings = scoring_settings(&state).await;
+    Ok(CommandResult::success(analyze_employment_gaps(
+        &resume_content,
+        &settings.section_header_synonyms,
+        settings.employment_gap_threshold_months,
+    )))
+}
+
+#[tauri::command]
+pub async fn export_optimized_resume(
+    state: State<'_, AppState>,
+    optimization: OptimizationResult,
+    format: String,
+) -> Result<CommandResult<String>, String> {
+    info!("Exporting optimized resume as {}", format);
+
+    let settings = scoring_settings(&state).await;
+    match resume_export::export_optimized_resume(
+        &optimization,
+        &format,
+        &settings.section_header_synonyms,
+    )
+    .await
+    {
+        Ok(file_path) => {
+            info!("Exported optimized resume to: {}", file_path);
+            Ok(CommandResult::success(file_path))
+        }
+        Err(e) => {
+            error!("Failed to export optimized resume: {}", e);
+            Ok(CommandResult::from_error("Resume export failed", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn export_changes_since(
+    since: String,
+    state: State<'_, AppState>,
+) -> Result<CommandResult<IncrementalExport>, String> {
+    info!("Exporting resumes and analyses changed since {}", since);
+
+    let since = match chrono::DateTime::parse_from_rfc3339(&since) {
+        Ok(since) => since.with_timezone(&chrono::Utc),
+        Err(e) => {
+            return Ok(CommandResult::error(ATSError::validation(format!(
+                "Invalid timestamp '{}': {}",
+                since, e
+            ))))
+        }
+    };
+
+    let db = state.db.lock().await;
+    match db.export_since(since).await {
+        Ok(export) => Ok(CommandResult::success(export)),
+        Err(e) => {
+            error!("Failed to export changes: {}", e);
+            Ok(CommandResult::from_error("Incremental export failed", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn get_resume_timeline(
+    resume_id: String,
+    job_description_id: String,
+    state: State<'_, AppState>,
+) -> Result<CommandResult<Vec<ResumeTimelineEntry>>, String> {
+    info!(
+        "Building version timeline for resume {} against job {}",
+        resume_id, job_description_id
+    );
+
+    let db = state.db.lock().await;
+    match db
+        .get_resume_timeline(&resume_id, &job_description_id)
+        .await
+    {
+        Ok(timeline) => Ok(CommandResult::success(timeline)),
+        Err(e) => {
+            error!("Failed to build resume timeline: {}", e);
+            Ok(CommandResult::from_error(
+                "Failed to build resume timeline",
+                e,
+            ))
         }
     }
 }
@@ -666,12 +1048,15 @@ pub async fn optimize_resume(request: OptimizationRequest) -> CommandResult<Opti
 pub async fn export_results(
     analysis_ids: Vec<String>,
     format: String,
+    redact_pii: Option<bool>,
     state: State<'_, AppState>,
 ) -> Result<CommandResult<String>, String> {
+    let redact_pii = redact_pii.unwrap_or(false);
     info!(
-        "Exporting {} analyses in {} format",
+        "Exporting {} analyses in {} format (redaction: {})",
         analysis_ids.len(),
-        format
+        format,
+        redact_pii
     );
 
     let db = state.db.lock().await;
@@ -687,6 +1072,19 @@ pub async fn export_results(
         }
     }
 
+    if redact_pii {
+        // Redacted copies only exist for the export; the detected values are never persisted
+        let mut redacted = Vec::with_capacity(analyses.len());
+        for analysis in &analyses {
+            let redactor = match db.get_resume(&analysis.resume_id).await {
+                Ok(Some(resume)) => Redactor::from_resume(&resume.content),
+                _ => Redactor::default(),
+            };
+            redacted.push(redactor.redact_analysis(analysis));
+        }
+        analyses = redacted;
+    }
+
     match export_data(&analyses, &format).await {
         Ok(file_path) => {
             info!("Successfully exported results to: {}", file_path);
@@ -694,10 +1092,54 @@ pub async fn export_results(
         }
         Err(e) => {
             error!("Failed to export results: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Export failed: {}",
-                e
-            )))
+            Ok(CommandResult::from_error("Export failed", e))
+        }
+    }
+}
+
+/// Analyze several saved resumes against one saved job and export a row per resume as CSV or
+/// JSON. Each analysis is also stored, as with `analyze_saved`.
+#[tauri::command]
+pub async fn export_batch_results(
+    resume_ids: Vec<String>,
+    job_description_id: String,
+    format: String,
+    state: State<'_, AppState>,
+) -> Result<CommandResult<String>, String> {
+    info!(
+        "Batch analyzing {} resumes against job {} for {} export",
+        resume_ids.len(),
+        job_description_id,
+        format
+    );
+
+    let engine =
+        AdvancedScoringEngine::with_settings(state.db.clone(), scoring_settings(&state).await);
+    let mut rows = Vec::with_capacity(resume_ids.len());
+    for resume_id in &resume_ids {
+        let saved = match engine.analyze_saved(resume_id, &job_description_id).await {
+            Ok(saved) => saved,
+            Err(e) => {
+                error!("Batch analysis failed for resume {}: {}", resume_id, e);
+                return Ok(CommandResult::from_error("Batch analysis failed", e));
+            }
+        };
+        on_analysis_saved(&state, &saved.analysis, &saved.result.base_analysis).await;
+        let resume_name = match state.db.lock().await.get_resume(resume_id).await {
+            Ok(Some(resume)) => resume.filename,
+            _ => resume_id.clone(),
+        };
+        rows.push(BatchResultRow::from_result(&resume_name, &saved.result));
+    }
+
+    match export_batch_data(&rows, &format).await {
+        Ok(file_path) => {
+            info!("Exported batch results to: {}", file_path);
+            Ok(CommandResult::success(file_path))
+        }
+        Err(e) => {
+            error!("Failed to export batch results: {}", e);
+            Ok(CommandResult::from_error("Batch export failed", e))
         }
     }
 }
@@ -751,10 +1193,10 @@ pub async fn get_model_performance(
         }
         Err(e) => {
             error!("Failed to get model performance: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to get performance data: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to get performance data",
+                e,
+            ))
         }
     }
 }
@@ -776,10 +1218,7 @@ pub async fn get_analysis_stats(
         }
         Err(e) => {
             error!("Failed to get analysis stats: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to get analysis stats: {}",
-                e
-            )))
+            Ok(CommandResult::from_error("Failed to get analysis stats", e))
         }
     }
 }
@@ -798,10 +1237,10 @@ pub async fn get_score_distribution(
         }
         Err(e) => {
             error!("Failed to get score distribution: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to get score distribution: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to get score distribution",
+                e,
+            ))
         }
     }
 }
@@ -820,10 +1259,35 @@ pub async fn get_improvement_trends(
         }
         Err(e) => {
             error!("Failed to get improvement trends: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to get improvement trends: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to get improvement trends",
+                e,
+            ))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn get_keyword_coverage_trend(
+    industry: String,
+    state: State<'_, AppState>,
+) -> Result<CommandResult<KeywordCoverageTrend>, String> {
+    info!("Getting keyword coverage trend for {} industry", industry);
+
+    match state
+        .db
+        .lock()
+        .await
+        .get_keyword_coverage_trend(&industry)
+        .await
+    {
+        Ok(trend) => Ok(CommandResult::success(trend)),
+        Err(e) => {
+            error!("Failed to get keyword coverage trend: {}", e);
+            Ok(CommandResult::from_error(
+                "Failed to get keyword coverage trend",
+                e,
+            ))
         }
     }
 }
@@ -852,10 +1316,10 @@ pub async fn get_user_preferences(
         }
         Err(e) => {
             error!("Failed to get user preferences: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to get user preferences: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to get user preferences",
+                e,
+            ))
         }
     }
 }
@@ -883,24 +1347,24 @@ pub async fn update_user_preferences(
             }
             Ok(None) => {
                 error!("Failed to retrieve updated preferences");
-                Ok(CommandResult::from_string_error(
-                    "Failed to retrieve updated preferences".to_string(),
-                ))
+                Ok(CommandResult::error(ATSError::database(
+                    "Failed to retrieve updated preferences",
+                )))
             }
             Err(e) => {
                 error!("Failed to retrieve updated preferences: {}", e);
-                Ok(CommandResult::from_string_error(format!(
-                    "Failed to retrieve updated preferences: {}",
-                    e
-                )))
+                Ok(CommandResult::from_error(
+                    "Failed to retrieve updated preferences",
+                    e,
+                ))
             }
         },
         Err(e) => {
             error!("Failed to update user preferences: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to update user preferences: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to update user preferences",
+                e,
+            ))
         }
     }
 }
@@ -925,10 +1389,10 @@ pub async fn reset_user_preferences(
         }
         Err(e) => {
             error!("Failed to reset user preferences: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to reset user preferences: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to reset user preferences",
+                e,
+            ))
         }
     }
 }
@@ -949,24 +1413,24 @@ pub async fn export_user_preferences(
             }
             Err(e) => {
                 error!("Failed to serialize preferences: {}", e);
-                Ok(CommandResult::from_string_error(format!(
-                    "Failed to serialize preferences: {}",
-                    e
-                )))
+                Ok(CommandResult::from_error(
+                    "Failed to serialize preferences",
+                    e,
+                ))
             }
         },
         Ok(None) => {
             error!("User preferences not found");
-            Ok(CommandResult::from_string_error(
-                "User preferences not found".to_string(),
-            ))
+            Ok(CommandResult::error(ATSError::validation(
+                "User preferences not found",
+            )))
         }
         Err(e) => {
             error!("Failed to export user preferences: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to export user preferences: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to export user preferences",
+                e,
+            ))
         }
     }
 }
@@ -998,19 +1462,19 @@ pub async fn import_user_preferences(
                 }
                 Err(e) => {
                     error!("Failed to save imported preferences: {}", e);
-                    Ok(CommandResult::from_string_error(format!(
-                        "Failed to save imported preferences: {}",
-                        e
-                    )))
+                    Ok(CommandResult::from_error(
+                        "Failed to save imported preferences",
+                        e,
+                    ))
                 }
             }
         }
         Err(e) => {
             error!("Failed to parse preferences JSON: {}", e);
-            Ok(CommandResult::from_string_error(format!(
+            Ok(CommandResult::error(ATSError::validation(format!(
                 "Failed to parse preferences JSON: {}",
                 e
-            )))
+            ))))
         }
     }
 }
@@ -1027,9 +1491,9 @@ pub async fn list_plugins(
         Ok(dir) => dir.join("plugins"),
         Err(e) => {
             error!("Failed to get current directory: {}", e);
-            return Ok(CommandResult::from_string_error(
-                "Failed to access plugins directory".to_string(),
-            ));
+            return Ok(CommandResult::error(ATSError::file_operation(
+                "Failed to access plugins directory",
+            )));
         }
     };
     let plugin_manager = PluginManager::new(plugins_dir).await;
@@ -1052,9 +1516,9 @@ pub async fn get_plugin_info(
         Ok(dir) => dir.join("plugins"),
         Err(e) => {
             error!("Failed to get current directory: {}", e);
-            return Ok(CommandResult::from_string_error(
-                "Failed to access plugins directory".to_string(),
-            ));
+            return Ok(CommandResult::error(ATSError::file_operation(
+                "Failed to access plugins directory",
+            )));
         }
     };
     let plugin_manager = PluginManager::new(plugins_dir).await;
@@ -1080,9 +1544,9 @@ pub async fn execute_plugin(
         Ok(dir) => dir.join("plugins"),
         Err(e) => {
             error!("Failed to get current directory: {}", e);
-            return Ok(CommandResult::from_string_error(
-                "Failed to access plugins directory".to_string(),
-            ));
+            return Ok(CommandResult::error(ATSError::file_operation(
+                "Failed to access plugins directory",
+            )));
         }
     };
     let plugin_manager = PluginManager::new(plugins_dir).await;
@@ -1097,10 +1561,7 @@ pub async fn execute_plugin(
         }
         Err(e) => {
             error!("Plugin execution failed: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Plugin execution failed: {}",
-                e
-            )))
+            Ok(CommandResult::from_error("Plugin execution failed", e))
         }
     }
 }
@@ -1117,9 +1578,9 @@ pub async fn update_plugin_config(
         Ok(dir) => dir.join("plugins"),
         Err(e) => {
             error!("Failed to get current directory: {}", e);
-            return Ok(CommandResult::from_string_error(
-                "Failed to access plugins directory".to_string(),
-            ));
+            return Ok(CommandResult::error(ATSError::file_operation(
+                "Failed to access plugins directory",
+            )));
         }
     };
     let plugin_manager = PluginManager::new(plugins_dir).await;
@@ -1136,10 +1597,10 @@ pub async fn update_plugin_config(
         }
         Err(e) => {
             error!("Failed to update plugin config: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to update plugin config: {}",
+            Ok(CommandResult::error(ATSError::plugin(format!(
+                "Failed to update plugin config: {:#}",
                 e
-            )))
+            ))))
         }
     }
 }
@@ -1159,10 +1620,10 @@ pub async fn get_industry_keywords(
         Ok(keywords) => Ok(CommandResult::success(keywords)),
         Err(e) => {
             error!("Failed to get industry keywords: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to get industry keywords: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to get industry keywords",
+                e,
+            ))
         }
     }
 }
@@ -1179,10 +1640,7 @@ pub async fn get_all_industries(
         Ok(industries) => Ok(CommandResult::success(industries)),
         Err(e) => {
             error!("Failed to get industries: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to get industries: {}",
-                e
-            )))
+            Ok(CommandResult::from_error("Failed to get industries", e))
         }
     }
 }
@@ -1201,10 +1659,7 @@ pub async fn save_industry_keyword(
         )),
         Err(e) => {
             error!("Failed to save industry keyword: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to save keyword: {}",
-                e
-            )))
+            Ok(CommandResult::from_error("Failed to save keyword", e))
         }
     }
 }
@@ -1222,10 +1677,7 @@ pub async fn get_ats_rules(
         Ok(rules) => Ok(CommandResult::success(rules)),
         Err(e) => {
             error!("Failed to get ATS rules: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to get ATS rules: {}",
-                e
-            )))
+            Ok(CommandResult::from_error("Failed to get ATS rules", e))
         }
     }
 }
@@ -1244,10 +1696,7 @@ pub async fn save_ats_rule(
         )),
         Err(e) => {
             error!("Failed to save ATS rule: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to save ATS rule: {}",
-                e
-            )))
+            Ok(CommandResult::from_error("Failed to save ATS rule", e))
         }
     }
 }
@@ -1269,10 +1718,7 @@ pub async fn get_scoring_benchmarks(
         Ok(benchmarks) => Ok(CommandResult::success(benchmarks)),
         Err(e) => {
             error!("Failed to get scoring benchmarks: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to get benchmarks: {}",
-                e
-            )))
+            Ok(CommandResult::from_error("Failed to get benchmarks", e))
         }
     }
 }
@@ -1291,10 +1737,7 @@ pub async fn save_scoring_benchmark(
         )),
         Err(e) => {
             error!("Failed to save benchmark: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to save benchmark: {}",
-                e
-            )))
+            Ok(CommandResult::from_error("Failed to save benchmark", e))
         }
     }
 }
@@ -1316,10 +1759,7 @@ pub async fn save_user_feedback(
         )),
         Err(e) => {
             error!("Failed to save feedback: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to save feedback: {}",
-                e
-            )))
+            Ok(CommandResult::from_error("Failed to save feedback", e))
         }
     }
 }
@@ -1337,10 +1777,7 @@ pub async fn get_feedback_by_analysis(
         Ok(feedback) => Ok(CommandResult::success(feedback)),
         Err(e) => {
             error!("Failed to get feedback: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to get feedback: {}",
-                e
-            )))
+            Ok(CommandResult::from_error("Failed to get feedback", e))
         }
     }
 }
@@ -1358,10 +1795,7 @@ pub async fn get_feedback_stats(
         Ok(stats) => Ok(CommandResult::success(stats)),
         Err(e) => {
             error!("Failed to get feedback stats: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to get feedback stats: {}",
-                e
-            )))
+            Ok(CommandResult::from_error("Failed to get feedback stats", e))
         }
     }
 }
@@ -1383,10 +1817,7 @@ pub async fn save_model_performance(
         )),
         Err(e) => {
             error!("Failed to save performance metrics: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to save metrics: {}",
-                e
-            )))
+            Ok(CommandResult::from_error("Failed to save metrics", e))
         }
     }
 }
@@ -1404,10 +1835,10 @@ pub async fn get_model_performance_stats(
         Ok(stats) => Ok(CommandResult::success(stats)),
         Err(e) => {
             error!("Failed to get model performance stats: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to get performance stats: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to get performance stats",
+                e,
+            ))
         }
     }
 }
@@ -1424,10 +1855,10 @@ pub async fn get_all_model_performance(
         Ok(stats) => Ok(CommandResult::success(stats)),
         Err(e) => {
             error!("Failed to get all model performance: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to get all performance stats: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to get all performance stats",
+                e,
+            ))
         }
     }
 }
@@ -1445,18 +1876,15 @@ pub async fn get_app_config(
             Ok(config_value) => Ok(CommandResult::success(config_value)),
             Err(e) => {
                 error!("Failed to parse config JSON: {}", e);
-                Ok(CommandResult::from_string_error(format!(
+                Ok(CommandResult::error(ATSError::configuration(format!(
                     "Failed to parse config: {}",
                     e
-                )))
+                ))))
             }
         },
         Err(e) => {
             error!("Failed to export config: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to get config: {}",
-                e
-            )))
+            Ok(CommandResult::from_error("Failed to get config", e))
         }
     }
 }
@@ -1472,10 +1900,10 @@ pub async fn validate_app_config(
         Ok(warnings) => Ok(CommandResult::success(warnings)),
         Err(e) => {
             error!("Failed to validate config: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to validate config: {}",
+            Ok(CommandResult::error(ATSError::configuration(format!(
+                "Failed to validate config: {:#}",
                 e
-            )))
+            ))))
         }
     }
 }
@@ -1505,16 +1933,13 @@ pub async fn semantic_analysis(
             }
             Ok(false) => {
                 error!("Database health check failed in semantic analysis");
-                return Ok(CommandResult::from_string_error(
-                    "Database health check failed".to_string(),
-                ));
+                return Ok(CommandResult::error(ATSError::database(
+                    "Database health check failed",
+                )));
             }
             Err(e) => {
                 error!("Database health check error in semantic analysis: {}", e);
-                return Ok(CommandResult::from_string_error(format!(
-                    "Database health check error: {}",
-                    e
-                )));
+                return Ok(CommandResult::from_error("Database health check error", e));
             }
         }
 
@@ -1532,10 +1957,10 @@ pub async fn semantic_analysis(
                     "Failed to load industry keywords directly in command: {}",
                     e
                 );
-                return Ok(CommandResult::from_string_error(format!(
-                    "Failed to load industry keywords: {}",
-                    e
-                )));
+                return Ok(CommandResult::from_error(
+                    "Failed to load industry keywords",
+                    e,
+                ));
             }
         }
 
@@ -1557,10 +1982,10 @@ pub async fn semantic_analysis(
         }
         Err(e) => {
             error!("Failed to perform semantic analysis: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to perform semantic analysis: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to perform semantic analysis",
+                e,
+            ))
         }
     }
 }
@@ -1590,19 +2015,16 @@ pub async fn comprehensive_analysis(
             }
             Ok(false) => {
                 error!("Database health check failed in comprehensive analysis");
-                return Ok(CommandResult::from_string_error(
-                    "Database health check failed".to_string(),
-                ));
+                return Ok(CommandResult::error(ATSError::database(
+                    "Database health check failed",
+                )));
             }
             Err(e) => {
                 error!(
                     "Database health check error in comprehensive analysis: {}",
                     e
                 );
-                return Ok(CommandResult::from_string_error(format!(
-                    "Database health check error: {}",
-                    e
-                )));
+                return Ok(CommandResult::from_error("Database health check error", e));
             }
         }
 
@@ -1620,10 +2042,10 @@ pub async fn comprehensive_analysis(
                     "Failed to load industry keywords directly in comprehensive analysis: {}",
                     e
                 );
-                return Ok(CommandResult::from_string_error(format!(
-                    "Failed to load industry keywords in comprehensive analysis: {}",
-                    e
-                )));
+                return Ok(CommandResult::from_error(
+                    "Failed to load industry keywords in comprehensive analysis",
+                    e,
+                ));
             }
         }
 
@@ -1632,7 +2054,8 @@ pub async fn comprehensive_analysis(
     }; // Lock is released here
 
     info!("Database lock released, creating EnhancedScoringEngine");
-    let scoring_engine = EnhancedScoringEngine::new(db_clone);
+    let scoring_engine = EnhancedScoringEngine::new(db_clone)
+        .with_custom_industries(scoring_settings(&state).await.custom_industries.clone());
     info!("EnhancedScoringEngine created successfully");
 
     match scoring_engine
@@ -1650,10 +2073,10 @@ pub async fn comprehensive_analysis(
         }
         Err(e) => {
             error!("Failed to perform comprehensive analysis: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to perform comprehensive analysis: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to perform comprehensive analysis",
+                e,
+            ))
         }
     }
 }
@@ -1667,8 +2090,9 @@ pub async fn industry_analysis(
 ) -> Result<CommandResult<IndustryAnalysisResult>, ()> {
     info!("Performing industry analysis for: {}", target_industry);
 
+    let custom_industries = scoring_settings(&state).await.custom_industries.clone();
     let db = state.db.lock().await;
-    let analyzer = IndustryAnalyzer::new(db.clone());
+    let analyzer = IndustryAnalyzer::with_custom_industries(db.clone(), custom_industries);
 
     match analyzer
         .analyze_for_industry(&resume_content, &job_description, &target_industry)
@@ -1677,16 +2101,44 @@ pub async fn industry_analysis(
         Ok(result) => Ok(CommandResult::success(result)),
         Err(e) => {
             error!("Failed to perform industry analysis: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to perform industry analysis: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to perform industry analysis",
+                e,
+            ))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn transferable_skills(
+    state: State<'_, AppState>,
+    resume_content: String,
+    job_description: String,
+) -> Result<CommandResult<TransferableSkillsReport>, ()> {
+    info!("Identifying transferable skills");
+
+    let custom_industries = scoring_settings(&state).await.custom_industries.clone();
+    let db = state.db.lock().await;
+    let analyzer = IndustryAnalyzer::with_custom_industries(db.clone(), custom_industries);
+
+    match analyzer
+        .identify_transferable_skills(&resume_content, &job_description)
+        .await
+    {
+        Ok(result) => Ok(CommandResult::success(result)),
+        Err(e) => {
+            error!("Failed to identify transferable skills: {}", e);
+            Ok(CommandResult::from_error(
+                "Failed to identify transferable skills",
+                e,
+            ))
         }
     }
 }
 
 #[tauri::command]
 pub async fn create_enhanced_prompt(
+    state: State<'_, AppState>,
     prompt_request: EnhancedPromptRequest,
 ) -> Result<CommandResult<EnhancedPromptResponse>, ()> {
     info!(
@@ -1694,16 +2146,60 @@ pub async fn create_enhanced_prompt(
         prompt_request.model_name, prompt_request.prompt_type
     );
 
-    let prompt_engine = EnhancedPromptEngine::new();
+    let templates = state.config.lock().await.prompt_templates();
+    let prompt_engine = EnhancedPromptEngine::new().with_custom_templates(templates);
+
+    match prompt_engine.create_enhanced_prompt(prompt_request) {
+        Ok(result) => Ok(CommandResult::success(result)),
+        Err(e) => {
+            error!("Failed to create enhanced prompt: {}", e);
+            Ok(CommandResult::from_error(
+                "Failed to create enhanced prompt",
+                e,
+            ))
+        }
+    }
+}
+
+/// Run one input through two prompt templates and report which output looks better
+#[tauri::command]
+pub async fn compare_prompts(
+    state: State<'_, AppState>,
+    prompt_request: EnhancedPromptRequest,
+    first_template: String,
+    second_template: String,
+) -> Result<CommandResult<PromptComparison>, ()> {
+    info!(
+        "Comparing prompt templates '{}' and '{}' on model: {}",
+        first_template, second_template, prompt_request.model_name
+    );
+
+    let database = state.db.lock().await.clone();
+    let seed = state.config.lock().await.deterministic_seed();
+    let client = match OllamaClient::new(None) {
+        Ok(client) => client.with_performance_tracking(database).with_seed(seed),
+        Err(e) => {
+            error!("Failed to create Ollama client: {}", e);
+            return Ok(CommandResult::from_error(
+                "Failed to create Ollama client",
+                e,
+            ));
+        }
+    };
+    let templates = state.config.lock().await.prompt_templates();
+    let prompt_engine = EnhancedPromptEngine::new().with_custom_templates(templates);
 
-    match prompt_engine.create_enhanced_prompt(prompt_request) {
-        Ok(result) => Ok(CommandResult::success(result)),
+    match prompt_engine
+        .compare_prompts(&client, prompt_request, &first_template, &second_template)
+        .await
+    {
+        Ok(comparison) => {
+            info!("Better prompt template: {}", comparison.better_template);
+            Ok(CommandResult::success(comparison))
+        }
         Err(e) => {
-            error!("Failed to create enhanced prompt: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to create enhanced prompt: {}",
-                e
-            )))
+            error!("Failed to compare prompts: {}", e);
+            Ok(CommandResult::from_error("Failed to compare prompts", e))
         }
     }
 }
@@ -1729,10 +2225,10 @@ pub async fn simulate_ats_processing(
         Ok(result) => Ok(CommandResult::success(result)),
         Err(e) => {
             error!("Failed to simulate ATS processing: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to simulate ATS processing: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to simulate ATS processing",
+                e,
+            ))
         }
     }
 }
@@ -1751,22 +2247,24 @@ pub async fn check_format_compatibility(
         Ok(report) => Ok(CommandResult::success(report)),
         Err(e) => {
             error!("Failed to check format compatibility: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to check format compatibility: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to check format compatibility",
+                e,
+            ))
         }
     }
 }
 
 #[tauri::command]
 pub async fn analyze_format_issues(
+    state: State<'_, AppState>,
     resume_content: String,
 ) -> Result<CommandResult<FormatIssueReport>, ()> {
     info!("Analyzing format issues for resume");
 
     let format_checker = FormatCompatibilityChecker::new();
-    let issue_detector = FormatIssueDetector::new();
+    let issue_detector =
+        FormatIssueDetector::new().with_region(scoring_settings(&state).await.resume_region);
 
     match format_checker.check_comprehensive_compatibility(&resume_content) {
         Ok(compatibility_report) => {
@@ -1774,39 +2272,41 @@ pub async fn analyze_format_issues(
                 Ok(issue_report) => Ok(CommandResult::success(issue_report)),
                 Err(e) => {
                     error!("Failed to analyze format issues: {}", e);
-                    Ok(CommandResult::from_string_error(format!(
-                        "Failed to analyze format issues: {}",
-                        e
-                    )))
+                    Ok(CommandResult::from_error(
+                        "Failed to analyze format issues",
+                        e,
+                    ))
                 }
             }
         }
         Err(e) => {
             error!("Failed to check format compatibility: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to check format compatibility: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to check format compatibility",
+                e,
+            ))
         }
     }
 }
 
 #[tauri::command]
 pub async fn detect_advanced_format_issues(
+    state: State<'_, AppState>,
     resume_content: String,
 ) -> Result<CommandResult<Vec<crate::format_checker::FormatIssue>>, ()> {
     info!("Detecting advanced format issues for resume");
 
-    let issue_detector = FormatIssueDetector::new();
+    let issue_detector =
+        FormatIssueDetector::new().with_region(scoring_settings(&state).await.resume_region);
 
     match issue_detector.detect_advanced_issues(&resume_content) {
         Ok(issues) => Ok(CommandResult::success(issues)),
         Err(e) => {
             error!("Failed to detect advanced format issues: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to detect advanced format issues: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to detect advanced format issues",
+                e,
+            ))
         }
     }
 }
@@ -1824,10 +2324,10 @@ pub async fn run_ats_validation_suite(
         Ok(report) => Ok(CommandResult::success(report)),
         Err(e) => {
             error!("Failed to run ATS validation suite: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to run validation suite: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to run validation suite",
+                e,
+            ))
         }
     }
 }
@@ -1850,10 +2350,10 @@ pub async fn simulate_multiple_ats_systems(
         Ok(result) => Ok(CommandResult::success(result)),
         Err(e) => {
             error!("Failed to simulate multiple ATS systems: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to simulate multiple ATS systems: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to simulate multiple ATS systems",
+                e,
+            ))
         }
     }
 }
@@ -1888,6 +2388,8 @@ pub async fn analyze_achievements(
                         impact: bullet.outcome_description.unwrap_or_default(),
                         strength_score: bullet.strength_score,
                         suggestions: bullet.improvement_suggestions,
+                        scope: bullet.scope.unwrap_or_default(),
+                        impact_tier: bullet.impact_tier,
                     })
                     .collect(),
                 xyz_formula_usage: analysis.xyz_formula_compliance,
@@ -1914,10 +2416,10 @@ pub async fn analyze_achievements(
         }
         Err(e) => {
             error!("Failed to analyze achievements: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to analyze achievements: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to analyze achievements",
+                e,
+            ))
         }
     }
 }
@@ -1957,10 +2459,10 @@ pub async fn generate_comprehensive_optimization(
         }
         Err(e) => {
             error!("Failed to generate comprehensive optimization: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to generate optimization: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to generate optimization",
+                e,
+            ))
         }
     }
 }
@@ -1978,8 +2480,9 @@ pub async fn get_realtime_suggestions(
         cursor_position
     );
 
+    let settings = scoring_settings(&state).await;
     let db = state.db.lock().await;
-    let mut optimizer = RealtimeOptimizer::new(db.clone());
+    let mut optimizer = RealtimeOptimizer::new(db.clone()).with_settings(settings);
 
     match optimizer
         .get_live_suggestions(&resume_content, &job_description, cursor_position)
@@ -1995,10 +2498,43 @@ pub async fn get_realtime_suggestions(
         }
         Err(e) => {
             error!("Failed to get real-time suggestions: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to get real-time suggestions: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to get real-time suggestions",
+                e,
+            ))
+        }
+    }
+}
+
+/// Rescore the live editor after an edit. Pass `previous: None` for the first call or after the
+/// job description changes; the delta's text is then scored as the whole document.
+#[tauri::command]
+pub async fn rescore_incremental(
+    state: State<'_, AppState>,
+    job_description: String,
+    previous: Option<IncrementalScore>,
+    delta: TextDelta,
+) -> Result<CommandResult<IncrementalScore>, ()> {
+    let settings = scoring_settings(&state).await;
+    let db = state.db.lock().await;
+    let optimizer = RealtimeOptimizer::new(db.clone()).with_settings(settings);
+
+    let result = match previous {
+        Some(previous) => optimizer.rescore_incremental(&previous, &delta),
+        None => optimizer.score_full(&delta.new_text, &job_description),
+    };
+
+    match result {
+        Ok(score) => {
+            info!(
+                "Incremental rescore: {:.1} ({} lines, {} sections recomputed)",
+                score.overall_score, score.rescored_lines, score.rescored_sections
+            );
+            Ok(CommandResult::success(score))
+        }
+        Err(e) => {
+            error!("Incremental rescore failed: {}", e);
+            Ok(CommandResult::from_error("Incremental rescore failed", e))
         }
     }
 }
@@ -2048,10 +2584,10 @@ pub async fn validate_xyz_formula(
         }
         Err(e) => {
             error!("Failed to validate X-Y-Z formula: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to validate X-Y-Z formula: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to validate X-Y-Z formula",
+                e,
+            ))
         }
     }
 }
@@ -2098,10 +2634,46 @@ pub async fn get_achievement_suggestions(
         }
         Err(e) => {
             error!("Failed to get achievement suggestions: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to get achievement suggestions: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to get achievement suggestions",
+                e,
+            ))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn analyze_cover_letter(
+    cover_letter: String,
+    job_description: String,
+    resume_content: String,
+    company_name: String,
+    industry: String,
+) -> Result<CommandResult<CoverLetterAnalysis>, ()> {
+    info!("Analyzing cover letter for {} industry", industry);
+
+    let analyzer = CoverLetterAnalyzer::new();
+
+    match analyzer
+        .analyze(
+            &cover_letter,
+            &job_description,
+            &resume_content,
+            &company_name,
+            &industry,
+        )
+        .await
+    {
+        Ok(analysis) => {
+            info!(
+                "Cover letter analysis completed with score: {:.1}",
+                analysis.overall_score
+            );
+            Ok(CommandResult::success(analysis))
+        }
+        Err(e) => {
+            error!("Cover letter analysis failed: {}", e);
+            Ok(CommandResult::from_error("Cover letter analysis failed", e))
         }
     }
 }
@@ -2135,10 +2707,10 @@ pub async fn generate_competitive_analysis(
         }
         Err(e) => {
             error!("Failed to generate competitive analysis: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to generate competitive analysis: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to generate competitive analysis",
+                e,
+            ))
         }
     }
 }
@@ -2170,10 +2742,10 @@ pub async fn get_market_position_analysis(
         }
         Err(e) => {
             error!("Failed to get market position analysis: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to get market position analysis: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to get market position analysis",
+                e,
+            ))
         }
     }
 }
@@ -2205,10 +2777,10 @@ pub async fn get_salary_insights(
         }
         Err(e) => {
             error!("Failed to get salary insights: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to get salary insights: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to get salary insights",
+                e,
+            ))
         }
     }
 }
@@ -2240,10 +2812,10 @@ pub async fn get_hiring_probability(
         }
         Err(e) => {
             error!("Failed to calculate hiring probability: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to calculate hiring probability: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to calculate hiring probability",
+                e,
+            ))
         }
     }
 }
@@ -2274,10 +2846,10 @@ pub async fn generate_ml_insights(
         }
         Err(e) => {
             error!("Failed to generate ML insights: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to generate ML insights: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to generate ML insights",
+                e,
+            ))
         }
     }
 }
@@ -2310,10 +2882,10 @@ pub async fn predict_application_success(
         }
         Err(e) => {
             error!("Failed to predict application success: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to predict application success: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to predict application success",
+                e,
+            ))
         }
     }
 }
@@ -2346,10 +2918,10 @@ pub async fn get_career_path_suggestions(
         }
         Err(e) => {
             error!("Failed to generate career path suggestions: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to generate career path suggestions: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to generate career path suggestions",
+                e,
+            ))
         }
     }
 }
@@ -2371,116 +2943,339 @@ pub async fn get_salary_prediction_ml(
         .generate_ml_insights(&resume_content, &job_description, &user_history)
         .await
     {
-        Ok(insights) => {
-            let result = serde_json::json!({
-                "salary_prediction": insights.salary_prediction,
-                "confidence_metrics": insights.confidence_metrics,
-                "generated_at": insights.generated_at
-            });
-
-            info!("Successfully generated ML salary prediction");
-            Ok(CommandResult::success(result))
-        }
+        Ok(insights) => {
+            let result = serde_json::json!({
+                "salary_prediction": insights.salary_prediction,
+                "confidence_metrics": insights.confidence_metrics,
+                "generated_at": insights.generated_at
+            });
+
+            info!("Successfully generated ML salary prediction");
+            Ok(CommandResult::success(result))
+        }
+        Err(e) => {
+            error!("Failed to generate ML salary prediction: {}", e);
+            Ok(CommandResult::from_error(
+                "Failed to generate ML salary prediction",
+                e,
+            ))
+        }
+    }
+}
+
+#[allow(dead_code)]
+#[tauri::command]
+pub async fn get_ml_recommendations(
+    state: State<'_, AppState>,
+    resume_content: String,
+    job_description: String,
+) -> Result<CommandResult<serde_json::Value>, ()> {
+    info!("Generating ML-based recommendations");
+
+    let db = state.db.lock().await;
+    let ml_engine = MLInsightsEngine::new(db.clone());
+    let user_history = vec![];
+
+    match ml_engine
+        .generate_ml_insights(&resume_content, &job_description, &user_history)
+        .await
+    {
+        Ok(insights) => {
+            let result = serde_json::json!({
+                "recommendations": insights.recommendation_engine,
+                "optimization_prioritization": insights.optimization_prioritization,
+                "confidence_metrics": insights.confidence_metrics,
+                "generated_at": insights.generated_at
+            });
+
+            info!("Successfully generated ML recommendations");
+            Ok(CommandResult::success(result))
+        }
+        Err(e) => {
+            error!("Failed to generate ML recommendations: {}", e);
+            Ok(CommandResult::from_error(
+                "Failed to generate ML recommendations",
+                e,
+            ))
+        }
+    }
+}
+
+// ==================== Advanced Scoring Engine Commands ====================
+
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn analyze_resume_advanced(
+    state: State<'_, AppState>,
+    resume_content: String,
+    job_description: String,
+    industry: String,
+    experience_level: String,
+    explain: Option<bool>,
+    scoring_profile: Option<String>,
+    suggestion_limits: Option<SuggestionLimits>,
+    analysis_id: Option<String>,
+    target_score: Option<f64>,
+) -> Result<CommandResult<AdvancedAnalysisResult>, ()> {
+    info!(
+        "Starting advanced analysis for {} industry, {} level",
+        industry, experience_level
+    );
+
+    let settings = scoring_settings(&state).await;
+    let profile = match settings.scoring_profile(scoring_profile.as_deref()) {
+        Ok(profile) => profile,
+        Err(e) => return Ok(CommandResult::from_error("Invalid scoring profile", e)),
+    };
+    let options = AnalysisOptions {
+        scoring_profile: profile,
+        suggestion_limits: suggestion_limits.unwrap_or_default(),
+    };
+
+    let db = state.db.clone();
+    let advanced_engine = AdvancedScoringEngine::with_settings(db, settings);
+
+    match advanced_engine
+        .analyze_comprehensive_with_options(
+            &resume_content,
+            &job_description,
+            &industry,
+            &experience_level,
+            &options,
+        )
+        .await
+    {
+        Ok(mut result) => {
+            info!(
+                "Advanced analysis completed with enhanced score: {:.1}",
+                result.base_analysis.overall_score
+            );
+            if explain.unwrap_or(false) {
+                result.keyword_explanations = Some(result.keyword_analysis.explain());
+            }
+            if let Some(analysis_id) = analysis_id {
+                if let Err(e) = advanced_engine
+                    .apply_suggestion_resolutions(&analysis_id, &mut result)
+                    .await
+                {
+                    warn!("Could not load resolved suggestions: {}", e);
+                }
+            }
+            let target_score = match target_score {
+                Some(target) => Some(target),
+                None => state.config.lock().await.get_analysis_config().target_score,
+            };
+            if let Some(target) = target_score {
+                result.target_plan = Some(TargetScorePlan::new(
+                    &result.improvement_suggestions,
+                    &result.score_breakdown,
+                    target,
+                ));
+            }
+            Ok(CommandResult::success(result))
+        }
+        Err(e) => {
+            error!("Advanced analysis failed: {}", e);
+            Ok(CommandResult::from_error("Advanced analysis failed", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn analyze_saved(
+    state: State<'_, AppState>,
+    resume_id: String,
+    job_description_id: String,
+) -> Result<CommandResult<SavedAnalysis>, ()> {
+    info!(
+        "Analyzing saved resume {} against job description {}",
+        resume_id, job_description_id
+    );
+
+    let advanced_engine =
+        AdvancedScoringEngine::with_settings(state.db.clone(), scoring_settings(&state).await);
+    match advanced_engine
+        .analyze_saved(&resume_id, &job_description_id)
+        .await
+    {
+        Ok(saved) => {
+            info!(
+                "Saved analysis {} with score: {:.1}",
+                saved.analysis.id, saved.analysis.overall_score
+            );
+            on_analysis_saved(&state, &saved.analysis, &saved.result.base_analysis).await;
+            Ok(CommandResult::success(saved))
+        }
+        Err(e) => {
+            error!("Analysis of saved resume failed: {}", e);
+            Ok(CommandResult::from_error("Analysis failed", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn mark_suggestion_resolved(
+    state: State<'_, AppState>,
+    analysis_id: String,
+    suggestion_id: String,
+) -> Result<CommandResult<bool>, String> {
+    set_suggestion_resolved(&state, &analysis_id, &suggestion_id, true).await
+}
+
+#[tauri::command]
+pub async fn unmark_suggestion_resolved(
+    state: State<'_, AppState>,
+    analysis_id: String,
+    suggestion_id: String,
+) -> Result<CommandResult<bool>, String> {
+    set_suggestion_resolved(&state, &analysis_id, &suggestion_id, false).await
+}
+
+async fn set_suggestion_resolved(
+    state: &AppState,
+    analysis_id: &str,
+    suggestion_id: &str,
+    resolved: bool,
+) -> Result<CommandResult<bool>, String> {
+    info!(
+        "Setting suggestion {} on analysis {} resolved: {}",
+        suggestion_id, analysis_id, resolved
+    );
+
+    let db = state.db.lock().await;
+    match db
+        .set_suggestion_resolved(analysis_id, suggestion_id, resolved)
+        .await
+    {
+        Ok(()) => Ok(CommandResult::success(resolved)),
+        Err(e) => {
+            error!("Failed to update suggestion resolution: {}", e);
+            Ok(CommandResult::from_error(
+                "Failed to update suggestion resolution",
+                e,
+            ))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn reanalyze_with_params(
+    state: State<'_, AppState>,
+    analysis_id: String,
+    industry: String,
+    experience_level: String,
+) -> Result<CommandResult<AdvancedAnalysisResult>, ()> {
+    info!(
+        "Re-running analysis {} for {} industry, {} level",
+        analysis_id, industry, experience_level
+    );
+
+    let advanced_engine =
+        AdvancedScoringEngine::with_settings(state.db.clone(), scoring_settings(&state).await);
+    match advanced_engine
+        .reanalyze_stored(&analysis_id, &industry, &experience_level)
+        .await
+    {
+        Ok(result) => Ok(CommandResult::success(result)),
         Err(e) => {
-            error!("Failed to generate ML salary prediction: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to generate ML salary prediction: {}",
-                e
-            )))
+            error!("Re-analysis failed: {}", e);
+            Ok(CommandResult::from_error("Re-analysis failed", e))
         }
     }
 }
 
-#[allow(dead_code)]
 #[tauri::command]
-pub async fn get_ml_recommendations(
+pub async fn aggregate_job_keywords(
     state: State<'_, AppState>,
-    resume_content: String,
-    job_description: String,
-) -> Result<CommandResult<serde_json::Value>, ()> {
-    info!("Generating ML-based recommendations");
-
-    let db = state.db.lock().await;
-    let ml_engine = MLInsightsEngine::new(db.clone());
-    let user_history = vec![];
+    job_descriptions: Vec<String>,
+) -> Result<CommandResult<Vec<AggregatedKeyword>>, String> {
+    info!(
+        "Aggregating keywords across {} job descriptions",
+        job_descriptions.len()
+    );
 
-    match ml_engine
-        .generate_ml_insights(&resume_content, &job_description, &user_history)
-        .await
+    match KeywordAnalyzer::with_settings(scoring_settings(&state).await)
+        .aggregate_job_keywords(&job_descriptions)
     {
-        Ok(insights) => {
-            let result = serde_json::json!({
-                "recommendations": insights.recommendation_engine,
-                "optimization_prioritization": insights.optimization_prioritization,
-                "confidence_metrics": insights.confidence_metrics,
-                "generated_at": insights.generated_at
-            });
-
-            info!("Successfully generated ML recommendations");
-            Ok(CommandResult::success(result))
-        }
+        Ok(keywords) => Ok(CommandResult::success(keywords)),
         Err(e) => {
-            error!("Failed to generate ML recommendations: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to generate ML recommendations: {}",
-                e
-            )))
+            error!("Failed to aggregate job keywords: {}", e);
+            Ok(CommandResult::from_error(
+                "Failed to aggregate job keywords",
+                e,
+            ))
         }
     }
 }
 
-// ==================== Advanced Scoring Engine Commands ====================
-
-#[allow(dead_code)]
 #[tauri::command]
-pub async fn analyze_resume_advanced(
+pub async fn similar_roles(
     state: State<'_, AppState>,
     resume_content: String,
-    job_description: String,
-    industry: String,
-    experience_level: String,
-) -> Result<CommandResult<AdvancedAnalysisResult>, ()> {
-    info!(
-        "Starting advanced analysis for {} industry, {} level",
-        industry, experience_level
-    );
+    exclude_job_id: Option<String>,
+    limit: Option<usize>,
+) -> Result<CommandResult<Vec<JobFit>>, String> {
+    info!("Ranking stored job descriptions by fit");
 
-    let db = state.db.clone();
-    let advanced_engine = AdvancedScoringEngine::new(db);
+    let jobs = match state.db.lock().await.get_all_job_descriptions(false).await {
+        Ok(jobs) => jobs,
+        Err(e) => {
+            error!("Failed to load job descriptions: {}", e);
+            return Ok(CommandResult::from_error(
+                "Failed to load job descriptions",
+                e,
+            ));
+        }
+    };
+    let candidates: Vec<JobDescription> = jobs
+        .into_iter()
+        .filter(|job| exclude_job_id.as_deref() != Some(job.id.as_str()))
+        .collect();
 
-    match advanced_engine
-        .analyze_comprehensive(
+    match KeywordAnalyzer::with_settings(scoring_settings(&state).await)
+        .rank_job_fit(
             &resume_content,
-            &job_description,
-            &industry,
-            &experience_level,
+            &candidates,
+            limit.unwrap_or(DEFAULT_SIMILAR_ROLES),
         )
         .await
     {
-        Ok(result) => {
-            info!(
-                "Advanced analysis completed with enhanced score: {:.1}",
-                result.base_analysis.overall_score
-            );
-            Ok(CommandResult::success(result))
+        Ok(fits) => Ok(CommandResult::success(fits)),
+        Err(e) => {
+            error!("Failed to rank job descriptions: {}", e);
+            Ok(CommandResult::from_error(
+                "Failed to rank job descriptions",
+                e,
+            ))
         }
+    }
+}
+
+#[tauri::command]
+pub async fn ats_system_reports(
+    state: State<'_, AppState>,
+    resume_content: String,
+) -> Result<CommandResult<HashMap<ATSSystem, ATSSystemReport>>, String> {
+    info!("Building per-ATS parsing reports");
+
+    let simulator =
+        crate::advanced_scoring::ATSSimulator::with_settings(scoring_settings(&state).await);
+    match simulator.system_reports(&resume_content) {
+        Ok(reports) => Ok(CommandResult::success(reports)),
         Err(e) => {
-            error!("Advanced analysis failed: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Advanced analysis failed: {}",
-                e
-            )))
+            error!("Failed to build ATS reports: {}", e);
+            Ok(CommandResult::from_error("Failed to build ATS reports", e))
         }
     }
 }
 
-#[allow(dead_code)]
 #[tauri::command]
 pub async fn get_keyword_analysis_detailed(
     state: State<'_, AppState>,
     resume_content: String,
     job_description: String,
     industry: String,
+    explain: Option<bool>,
 ) -> Result<CommandResult<serde_json::Value>, ()> {
     info!(
         "Getting detailed keyword analysis for {} industry",
@@ -2488,14 +3283,14 @@ pub async fn get_keyword_analysis_detailed(
     );
 
     let db = state.db.clone();
-    let advanced_engine = AdvancedScoringEngine::new(db);
+    let advanced_engine = AdvancedScoringEngine::with_settings(db, scoring_settings(&state).await);
 
     match advanced_engine
         .analyze_comprehensive(&resume_content, &job_description, &industry, "mid-level")
         .await
     {
         Ok(result) => {
-            let keyword_analysis = serde_json::json!({
+            let mut keyword_analysis = serde_json::json!({
                 "exact_matches": result.keyword_analysis.exact_matches,
                 "stemmed_matches": result.keyword_analysis.stemmed_matches,
                 "contextual_matches": result.keyword_analysis.contextual_matches,
@@ -2504,16 +3299,20 @@ pub async fn get_keyword_analysis_detailed(
                 "match_density": result.keyword_analysis.match_density,
                 "section_distribution": result.keyword_analysis.section_distribution
             });
+            if explain.unwrap_or(false) {
+                keyword_analysis["explanations"] =
+                    serde_json::json!(result.keyword_analysis.explain());
+            }
 
             info!("Detailed keyword analysis completed");
             Ok(CommandResult::success(keyword_analysis))
         }
         Err(e) => {
             error!("Detailed keyword analysis failed: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Detailed keyword analysis failed: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Detailed keyword analysis failed",
+                e,
+            ))
         }
     }
 }
@@ -2529,7 +3328,7 @@ pub async fn get_ats_compatibility_scores(
     info!("Getting ATS compatibility scores for {} industry", industry);
 
     let db = state.db.clone();
-    let advanced_engine = AdvancedScoringEngine::new(db);
+    let advanced_engine = AdvancedScoringEngine::with_settings(db, scoring_settings(&state).await);
 
     match advanced_engine
         .analyze_comprehensive(&resume_content, &job_description, &industry, "mid-level")
@@ -2548,10 +3347,10 @@ pub async fn get_ats_compatibility_scores(
         }
         Err(e) => {
             error!("ATS compatibility analysis failed: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "ATS compatibility analysis failed: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "ATS compatibility analysis failed",
+                e,
+            ))
         }
     }
 }
@@ -2571,7 +3370,7 @@ pub async fn get_benchmark_comparison(
     );
 
     let db = state.db.clone();
-    let advanced_engine = AdvancedScoringEngine::new(db);
+    let advanced_engine = AdvancedScoringEngine::with_settings(db, scoring_settings(&state).await);
 
     match advanced_engine
         .analyze_comprehensive(
@@ -2596,10 +3395,35 @@ pub async fn get_benchmark_comparison(
         }
         Err(e) => {
             error!("Benchmark comparison failed: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Benchmark comparison failed: {}",
-                e
-            )))
+            Ok(CommandResult::from_error("Benchmark comparison failed", e))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn get_industry_benchmark(
+    state: State<'_, AppState>,
+    industry: String,
+    experience_level: String,
+) -> Result<CommandResult<IndustryBenchmarkReport>, String> {
+    info!(
+        "Getting industry benchmark for {} industry, {} level",
+        industry, experience_level
+    );
+
+    let engine =
+        AdvancedScoringEngine::with_settings(state.db.clone(), scoring_settings(&state).await);
+    match engine
+        .industry_benchmark(&industry, &experience_level)
+        .await
+    {
+        Ok(report) => Ok(CommandResult::success(report)),
+        Err(e) => {
+            error!("Failed to load industry benchmark: {}", e);
+            Ok(CommandResult::from_error(
+                "Failed to load industry benchmark",
+                e,
+            ))
         }
     }
 }
@@ -2619,7 +3443,7 @@ pub async fn get_optimization_suggestions_prioritized(
     );
 
     let db = state.db.clone();
-    let advanced_engine = AdvancedScoringEngine::new(db);
+    let advanced_engine = AdvancedScoringEngine::with_settings(db, scoring_settings(&state).await);
 
     match advanced_engine
         .analyze_comprehensive(
@@ -2651,10 +3475,10 @@ pub async fn get_optimization_suggestions_prioritized(
         }
         Err(e) => {
             error!("Prioritized optimization suggestions failed: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Prioritized optimization suggestions failed: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Prioritized optimization suggestions failed",
+                e,
+            ))
         }
     }
 }
@@ -2664,9 +3488,10 @@ pub async fn get_optimization_suggestions_prioritized(
 #[tauri::command]
 pub async fn save_job_description(
     state: State<'_, AppState>,
-    job: JobDescription,
+    mut job: JobDescription,
 ) -> Result<CommandResult<String>, ()> {
     info!("Saving job description: {}", job.title);
+    job.apply_location_signals();
 
     let db = state.db.lock().await;
     match db.save_job_description(&job).await {
@@ -2676,10 +3501,10 @@ pub async fn save_job_description(
         }
         Err(e) => {
             error!("Failed to save job description: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to save job description: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to save job description",
+                e,
+            ))
         }
     }
 }
@@ -2699,10 +3524,10 @@ pub async fn get_job_description(
         }
         Err(e) => {
             error!("Failed to get job description: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to get job description: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to get job description",
+                e,
+            ))
         }
     }
 }
@@ -2722,10 +3547,10 @@ pub async fn update_job_description(
         }
         Err(e) => {
             error!("Failed to update job description: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to update job description: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to update job description",
+                e,
+            ))
         }
     }
 }
@@ -2747,10 +3572,10 @@ pub async fn delete_job_description(
         }
         Err(e) => {
             error!("Failed to delete job description: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to delete job description: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to delete job description",
+                e,
+            ))
         }
     }
 }
@@ -2772,10 +3597,10 @@ pub async fn get_job_descriptions(
         }
         Err(e) => {
             error!("Failed to get job descriptions: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to get job descriptions: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to get job descriptions",
+                e,
+            ))
         }
     }
 }
@@ -2795,10 +3620,10 @@ pub async fn search_job_descriptions(
         }
         Err(e) => {
             error!("Failed to search job descriptions: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to search job descriptions: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to search job descriptions",
+                e,
+            ))
         }
     }
 }
@@ -2817,10 +3642,7 @@ pub async fn get_job_analytics(
         }
         Err(e) => {
             error!("Failed to get job analytics: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to get job analytics: {}",
-                e
-            )))
+            Ok(CommandResult::from_error("Failed to get job analytics", e))
         }
     }
 }
@@ -2868,16 +3690,16 @@ pub async fn compare_job_descriptions(
         match db.get_job_description(job_id).await {
             Ok(Some(job)) => jobs.push(job),
             Ok(None) => {
-                return Ok(CommandResult::from_string_error(format!(
+                return Ok(CommandResult::error(ATSError::validation(format!(
                     "Job description not found: {}",
                     job_id
-                )));
+                ))));
             }
             Err(e) => {
-                return Ok(CommandResult::from_string_error(format!(
-                    "Failed to get job description {}: {}",
-                    job_id, e
-                )));
+                return Ok(CommandResult::from_error(
+                    &format!("Failed to get job description {}", job_id),
+                    e,
+                ));
             }
         }
     }
@@ -2975,14 +3797,12 @@ pub async fn analyze_resume_modern_nlp(
     let database = (*db_guard).clone();
     drop(db_guard);
 
+    let seed = state.config.lock().await.deterministic_seed();
     let ollama_client = match OllamaClient::new(None) {
-        Ok(client) => client,
+        Ok(client) => client.with_seed(seed),
         Err(e) => {
             error!("Failed to create Ollama client: {}", e);
-            return Ok(CommandResult::from_string_error(format!(
-                "Ollama connection failed: {}",
-                e
-            )));
+            return Ok(CommandResult::from_error("Ollama connection failed", e));
         }
     };
 
@@ -2991,10 +3811,10 @@ pub async fn analyze_resume_modern_nlp(
             Ok(engine) => engine,
             Err(e) => {
                 error!("Failed to create modern analysis engine: {}", e);
-                return Ok(CommandResult::from_string_error(format!(
-                    "Modern analysis engine initialization failed: {}",
-                    e
-                )));
+                return Ok(CommandResult::from_error(
+                    "Modern analysis engine initialization failed",
+                    e,
+                ));
             }
         };
 
@@ -3016,10 +3836,7 @@ pub async fn analyze_resume_modern_nlp(
         }
         Err(e) => {
             error!("Modern resume analysis failed: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Analysis failed: {}",
-                e
-            )))
+            Ok(CommandResult::from_error("Analysis failed", e))
         }
     }
 }
@@ -3043,14 +3860,12 @@ pub async fn analyze_context_aware_match(
     drop(db_guard);
 
     // First, perform modern keyword extraction to get extraction results
+    let seed = state.config.lock().await.deterministic_seed();
     let ollama_client = match OllamaClient::new(None) {
-        Ok(client) => client,
+        Ok(client) => client.with_seed(seed),
         Err(e) => {
             error!("Failed to create Ollama client: {}", e);
-            return Ok(CommandResult::from_string_error(format!(
-                "Ollama connection failed: {}",
-                e
-            )));
+            return Ok(CommandResult::from_error("Ollama connection failed", e));
         }
     };
 
@@ -3059,10 +3874,10 @@ pub async fn analyze_context_aware_match(
             Ok(engine) => engine,
             Err(e) => {
                 error!("Failed to create modern analysis engine: {}", e);
-                return Ok(CommandResult::from_string_error(format!(
-                    "Modern analysis engine initialization failed: {}",
-                    e
-                )));
+                return Ok(CommandResult::from_error(
+                    "Modern analysis engine initialization failed",
+                    e,
+                ));
             }
         };
 
@@ -3079,10 +3894,10 @@ pub async fn analyze_context_aware_match(
         Ok(result) => result,
         Err(e) => {
             error!("Modern resume analysis failed: {}", e);
-            return Ok(CommandResult::from_string_error(format!(
-                "Modern resume analysis failed: {}",
-                e
-            )));
+            return Ok(CommandResult::from_error(
+                "Modern resume analysis failed",
+                e,
+            ));
         }
     };
 
@@ -3107,19 +3922,19 @@ pub async fn analyze_context_aware_match(
                 }
                 Err(e) => {
                     error!("Context-aware match analysis failed: {}", e);
-                    Ok(CommandResult::from_string_error(format!(
-                        "Context-aware analysis failed: {}",
-                        e
-                    )))
+                    Ok(CommandResult::from_error(
+                        "Context-aware analysis failed",
+                        e,
+                    ))
                 }
             }
         }
         Err(e) => {
             error!("Failed to create context-aware matcher: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Context-aware matcher initialization failed: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Context-aware matcher initialization failed",
+                e,
+            ))
         }
     }
 }
@@ -3165,19 +3980,19 @@ pub async fn analyze_skill_relationships(
                 }
                 Err(e) => {
                     error!("Skill relationship analysis failed: {}", e);
-                    Ok(CommandResult::from_string_error(format!(
-                        "Skill relationship analysis failed: {}",
-                        e
-                    )))
+                    Ok(CommandResult::from_error(
+                        "Skill relationship analysis failed",
+                        e,
+                    ))
                 }
             }
         }
         Err(e) => {
             error!("Failed to create skill relationship mapper: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Skill relationship mapper initialization failed: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Skill relationship mapper initialization failed",
+                e,
+            ))
         }
     }
 }
@@ -3224,19 +4039,16 @@ pub async fn optimize_ml_parameters(
                 }
                 Err(e) => {
                     error!("ML optimization failed for user {}: {}", user_id, e);
-                    Ok(CommandResult::from_string_error(format!(
-                        "ML optimization failed: {}",
-                        e
-                    )))
+                    Ok(CommandResult::from_error("ML optimization failed", e))
                 }
             }
         }
         Err(e) => {
             error!("Failed to create ML optimization engine: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "ML optimization engine initialization failed: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "ML optimization engine initialization failed",
+                e,
+            ))
         }
     }
 }
@@ -3253,8 +4065,17 @@ pub async fn get_trending_keywords(
     let db_guard = state.db.lock().await;
     let database = (*db_guard).clone();
     drop(db_guard);
+    let half_life_days = state
+        .config
+        .lock()
+        .await
+        .get_analysis_config()
+        .trend_half_life_days;
 
-    match crate::dynamic_keyword_db::DynamicKeywordDatabase::new(database).await {
+    match crate::dynamic_keyword_db::DynamicKeywordDatabase::new(database)
+        .await
+        .map(|dynamic_db| dynamic_db.with_trend_half_life(half_life_days))
+    {
         Ok(dynamic_db) => {
             let trending = dynamic_db.get_trending_keywords(limit);
             let trending_data: Vec<_> = trending.into_iter().cloned().collect();
@@ -3264,10 +4085,10 @@ pub async fn get_trending_keywords(
         }
         Err(e) => {
             error!("Failed to access dynamic keyword database: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to get trending keywords: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to get trending keywords",
+                e,
+            ))
         }
     }
 }
@@ -3283,8 +4104,17 @@ pub async fn get_market_demand_data(
     let db_guard = state.db.lock().await;
     let database = (*db_guard).clone();
     drop(db_guard);
+    let half_life_days = state
+        .config
+        .lock()
+        .await
+        .get_analysis_config()
+        .trend_half_life_days;
 
-    match crate::dynamic_keyword_db::DynamicKeywordDatabase::new(database).await {
+    match crate::dynamic_keyword_db::DynamicKeywordDatabase::new(database)
+        .await
+        .map(|dynamic_db| dynamic_db.with_trend_half_life(half_life_days))
+    {
         Ok(dynamic_db) => match dynamic_db.get_market_demand(&skill).await {
             Ok(market_data) => {
                 info!(
@@ -3299,18 +4129,18 @@ pub async fn get_market_demand_data(
                     "Failed to get market demand data for skill '{}': {}",
                     skill, e
                 );
-                Ok(CommandResult::from_string_error(format!(
-                    "Failed to get market demand data: {}",
-                    e
-                )))
+                Ok(CommandResult::from_error(
+                    "Failed to get market demand data",
+                    e,
+                ))
             }
         },
         Err(e) => {
             error!("Failed to access dynamic keyword database: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to access dynamic keyword database: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to access dynamic keyword database",
+                e,
+            ))
         }
     }
 }
@@ -3326,8 +4156,17 @@ pub async fn get_industry_keywords_dynamic(
     let db_guard = state.db.lock().await;
     let database = (*db_guard).clone();
     drop(db_guard);
+    let half_life_days = state
+        .config
+        .lock()
+        .await
+        .get_analysis_config()
+        .trend_half_life_days;
 
-    match crate::dynamic_keyword_db::DynamicKeywordDatabase::new(database).await {
+    match crate::dynamic_keyword_db::DynamicKeywordDatabase::new(database)
+        .await
+        .map(|dynamic_db| dynamic_db.with_trend_half_life(half_life_days))
+    {
         Ok(mut dynamic_db) => match dynamic_db.get_industry_keywords(&industry).await {
             Ok(keywords) => {
                 info!(
@@ -3342,18 +4181,18 @@ pub async fn get_industry_keywords_dynamic(
                     "Failed to get dynamic keywords for industry '{}': {}",
                     industry, e
                 );
-                Ok(CommandResult::from_string_error(format!(
-                    "Failed to get industry keywords: {}",
-                    e
-                )))
+                Ok(CommandResult::from_error(
+                    "Failed to get industry keywords",
+                    e,
+                ))
             }
         },
         Err(e) => {
             error!("Failed to access dynamic keyword database: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to access dynamic keyword database: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to access dynamic keyword database",
+                e,
+            ))
         }
     }
 }
@@ -3400,19 +4239,73 @@ pub async fn submit_keyword_feedback(
                 }
                 Err(e) => {
                     error!("Failed to submit feedback for keyword '{}': {}", keyword, e);
-                    Ok(CommandResult::from_string_error(format!(
-                        "Failed to submit keyword feedback: {}",
-                        e
-                    )))
+                    Ok(CommandResult::from_error(
+                        "Failed to submit keyword feedback",
+                        e,
+                    ))
                 }
             }
         }
         Err(e) => {
             error!("Failed to access dynamic keyword database: {}", e);
-            Ok(CommandResult::from_string_error(format!(
-                "Failed to access dynamic keyword database: {}",
-                e
-            )))
+            Ok(CommandResult::from_error(
+                "Failed to access dynamic keyword database",
+                e,
+            ))
+        }
+    }
+}
+
+/// Submit many keyword feedback entries at once, e.g. to seed the dynamic keyword database
+/// from a market dataset. Invalid entries are reported individually without failing the batch.
+#[tauri::command]
+pub async fn submit_keyword_feedback_batch(
+    app: tauri::AppHandle,
+    entries: Vec<crate::dynamic_keyword_db::UserFeedback>,
+) -> Result<CommandResult<crate::dynamic_keyword_db::FeedbackBatchResult>, String> {
+    info!(
+        "Submitting batch of {} keyword feedback entries",
+        entries.len()
+    );
+
+    let state = app.state::<AppState>();
+    let db_guard = state.db.lock().await;
+    let database = (*db_guard).clone();
+    drop(db_guard);
+    let half_life_days = state
+        .config
+        .lock()
+        .await
+        .get_analysis_config()
+        .trend_half_life_days;
+
+    match crate::dynamic_keyword_db::DynamicKeywordDatabase::new(database)
+        .await
+        .map(|dynamic_db| dynamic_db.with_trend_half_life(half_life_days))
+    {
+        Ok(mut dynamic_db) => match dynamic_db.add_user_feedback_batch(entries).await {
+            Ok(result) => {
+                info!(
+                    "Accepted {} keyword feedback entries, rejected {}",
+                    result.accepted,
+                    result.errors.len()
+                );
+                Ok(CommandResult::success(result))
+            }
+            Err(e) => {
+                error!("Failed to submit keyword feedback batch: {}", e);
+                Ok(CommandResult::from_error(
+                    "Failed to submit keyword feedback batch",
+                    e,
+                ))
+            }
+        },
+        Err(e) => {
+            error!("Failed to access dynamic keyword database: {}", e);
+            Ok(CommandResult::from_error(
+                "Failed to access dynamic keyword database",
+                e,
+            ))
         }
     }
 }
@@ -3444,7 +4337,7 @@ pub async fn get_schema_version(app: tauri::AppHandle) -> CommandResult<SchemaVe
         }
         Err(e) => {
             error!("Failed to get schema version: {}", e);
-            CommandResult::from_string_error(format!("Failed to get schema version: {}", e))
+            CommandResult::from_error("Failed to get schema version", e)
         }
     }
 }
@@ -3479,16 +4372,13 @@ pub async fn run_database_migrations(app: tauri::AppHandle) -> CommandResult<Vec
                 }
                 Err(e) => {
                     error!("Migration failed: {}", e);
-                    CommandResult::from_string_error(format!("Migration failed: {}", e))
+                    CommandResult::from_error("Migration failed", e)
                 }
             }
         }
         Err(e) => {
             error!("Failed to initialize migration manager: {}", e);
-            CommandResult::from_string_error(format!(
-                "Failed to initialize migration manager: {}",
-                e
-            ))
+            CommandResult::from_error("Failed to initialize migration manager", e)
         }
     }
 }
@@ -3524,10 +4414,44 @@ pub async fn rollback_migration(
         }
         Err(e) => {
             error!("Failed to rollback migration {}: {}", version, e);
-            CommandResult::from_string_error(format!(
-                "Failed to rollback migration {}: {}",
-                version, e
-            ))
+            CommandResult::from_error(&format!("Failed to rollback migration {}", version), e)
+        }
+    }
+}
+
+/// Rollback whichever migration was applied most recently, returning `None` if none are applied
+#[tauri::command]
+pub async fn rollback_last_migration(
+    app: tauri::AppHandle,
+) -> CommandResult<Option<MigrationResult>> {
+    info!("Rolling back the latest migration");
+
+    let state = app.state::<AppState>();
+    let db_guard = state.db.lock().await;
+    let database = (*db_guard).clone();
+    drop(db_guard);
+
+    let mut migration_manager = MigrationManager::new(database.get_pool().clone());
+    migration_manager.register_migrations();
+
+    match migration_manager.rollback_last().await {
+        Ok(Some(result)) => {
+            if !result.success {
+                warn!(
+                    "Migration {} rollback failed: {}",
+                    result.version,
+                    result.error_message.as_deref().unwrap_or("Unknown error")
+                );
+            }
+            CommandResult::success(Some(result))
+        }
+        Ok(None) => {
+            info!("No applied migrations to roll back");
+            CommandResult::success(None)
+        }
+        Err(e) => {
+            error!("Failed to rollback the latest migration: {}", e);
+            CommandResult::from_error("Failed to rollback the latest migration", e)
         }
     }
 }
@@ -3556,7 +4480,7 @@ pub async fn verify_migration_integrity(app: tauri::AppHandle) -> CommandResult<
         }
         Err(e) => {
             error!("Failed to verify migration integrity: {}", e);
-            CommandResult::from_string_error(format!("Failed to verify migration integrity: {}", e))
+            CommandResult::from_error("Failed to verify migration integrity", e)
         }
     }
 }
@@ -3580,7 +4504,7 @@ pub async fn cleanup_expired_cache(app: tauri::AppHandle) -> CommandResult<u64>
         }
         Err(e) => {
             error!("Failed to clean up expired cache: {}", e);
-            CommandResult::from_string_error(format!("Failed to clean up expired cache: {}", e))
+            CommandResult::from_error("Failed to clean up expired cache", e)
         }
     }
 }
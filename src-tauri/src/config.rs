@@ -1,40 +1,79 @@
 use anyhow::{Context, Result};
 use log::{info, warn};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
+use crate::advanced_scoring::{
+    ScoringSettings, DEFAULT_ATS_SCORE_WEIGHT, DEFAULT_CERT_EXPIRY_WARNING_DAYS,
+    DEFAULT_CONTEXT_WINDOW_WORDS, DEFAULT_MIN_CONTEXTUAL_CONFIDENCE,
+};
+use crate::contact_confidence::DEFAULT_MIN_CONTACT_CONFIDENCE;
+use crate::document::DEFAULT_MAX_UPLOAD_SIZE_MB;
+use crate::dynamic_keyword_db::DEFAULT_TREND_HALF_LIFE_DAYS;
+use crate::employment_gaps::DEFAULT_GAP_THRESHOLD_MONTHS;
+use crate::enhanced_prompts::PromptTemplate;
 use crate::models::{
-    AnalysisConfig, AppConfig, LoggingConfig, OllamaConfig, OptimizationLevel, PerformanceConfig,
+    AnalysisConfig, AppConfig, ExperienceLevelThresholds, FormatPenalties, IndustryDefinition,
+    KeywordPositionWeighting, KeywordRule, LoggingConfig, MatchTypeWeights, OllamaConfig,
+    OptimizationLevel, PerformanceConfig, RecencyDecay, ResumeRegion, RetentionPolicy,
+    ScoringProfile, SectionPolicy, TonePhrases,
 };
 
 #[derive(Debug, Clone)]
 pub struct ConfigManager {
     config_path: PathBuf,
     config: AppConfig,
+    /// Scoring inputs built from `config.analysis_config`, rebuilt whenever it changes
+    scoring_settings: Arc<ScoringSettings>,
 }
 
 impl ConfigManager {
     pub fn new() -> Result<Self> {
         let config_path = Self::get_config_path()?;
         let config = Self::load_or_create_default_config(&config_path)?;
+        let scoring_settings = Arc::new(ScoringSettings::from_config(&config.analysis_config)?);
 
-        Ok(ConfigManager {
+        let mut manager = ConfigManager {
             config_path,
             config,
-        })
+            scoring_settings,
+        };
+        if manager.config.portable {
+            // Portable mode may have been switched on in the per-user config; keep the config
+            // beside the executable from now on so the next start finds it there
+            if let Err(e) = portable_data_dir().and_then(|dir| manager.move_config_to(&dir)) {
+                warn!(
+                    "Keeping configuration at {:?}: {:#}",
+                    manager.config_path, e
+                );
+            }
+        }
+        Ok(manager)
     }
 
     pub fn new_with_path(config_path: PathBuf) -> Result<Self> {
         let config = Self::load_or_create_default_config(&config_path)?;
+        let scoring_settings = Arc::new(ScoringSettings::from_config(&config.analysis_config)?);
 
         Ok(ConfigManager {
             config_path,
             config,
+            scoring_settings,
         })
     }
 
     fn get_config_path() -> Result<PathBuf> {
+        // A portable install carries its config beside the executable
+        if let Ok(portable_dir) = portable_data_dir() {
+            let portable_config = portable_dir.join("config.json");
+            if portable_config.exists() {
+                return Ok(portable_config);
+            }
+        }
+
         let config_dir = dirs::config_dir()
             .or_else(|| dirs::home_dir().map(|h| h.join(".config")))
             .context("Could not determine config directory")?;
@@ -50,6 +89,20 @@ impl ConfigManager {
         Ok(app_config_dir.join("config.json"))
     }
 
+    // Save the config as `config.json` in `dir` and keep saving it there
+    fn move_config_to(&mut self, dir: &Path) -> Result<()> {
+        let config_path = dir.join("config.json");
+        if config_path == self.config_path {
+            return Ok(());
+        }
+
+        fs::create_dir_all(dir).context("Failed to create portable data directory")?;
+        Self::save_config_to_path(&self.config, &config_path)?;
+        info!("Moved configuration to: {:?}", config_path);
+        self.config_path = config_path;
+        Ok(())
+    }
+
     fn load_or_create_default_config(config_path: &PathBuf) -> Result<AppConfig> {
         if config_path.exists() {
             info!("Loading configuration from: {:?}", config_path);
@@ -67,6 +120,8 @@ impl ConfigManager {
 
         let config: AppConfig =
             serde_json::from_str(&config_str).context("Failed to parse config file")?;
+        crate::advanced_scoring::compile_keyword_rules(&config.analysis_config.keyword_rules)
+            .context("Invalid keyword rules in config file")?;
 
         info!("Configuration loaded successfully");
         Ok(config)
@@ -92,6 +147,8 @@ impl ConfigManager {
 
         AppConfig {
             database_url,
+            data_dir: None,
+            portable: false,
             ollama_config: OllamaConfig {
                 host: "localhost".to_string(),
                 port: 11434,
@@ -104,6 +161,7 @@ impl ConfigManager {
                     "mistral".to_string(),
                     "neural-chat".to_string(),
                 ],
+                max_concurrent_requests: crate::ollama::DEFAULT_MAX_CONCURRENT_REQUESTS,
             },
             analysis_config: AnalysisConfig {
                 enable_industry_analysis: true,
@@ -112,6 +170,33 @@ impl ConfigManager {
                 default_optimization_level: OptimizationLevel::Balanced,
                 max_suggestions: 10,
                 confidence_threshold: 0.7,
+                deterministic_mode: false,
+                deterministic_seed: 42,
+                section_header_synonyms: HashMap::new(),
+                keyword_position_weighting: KeywordPositionWeighting::default(),
+                employment_gap_threshold_months: DEFAULT_GAP_THRESHOLD_MONTHS,
+                scoring_profiles: ScoringProfile::presets(),
+                default_scoring_profile: ScoringProfile::DEFAULT_NAME.to_string(),
+                match_type_weights: MatchTypeWeights::default(),
+                completion_webhook_url: None,
+                trend_half_life_days: DEFAULT_TREND_HALF_LIFE_DAYS,
+                prompt_template_dir: None,
+                custom_industries: HashMap::new(),
+                context_window_words: DEFAULT_CONTEXT_WINDOW_WORDS,
+                min_contextual_confidence: DEFAULT_MIN_CONTEXTUAL_CONFIDENCE,
+                recency_decay: RecencyDecay::default(),
+                section_policies: SectionPolicy::presets(),
+                format_penalties: FormatPenalties::default(),
+                skill_taxonomy: crate::advanced_scoring::default_skill_taxonomy(),
+                experience_level_thresholds: ExperienceLevelThresholds::default(),
+                resume_region: ResumeRegion::default(),
+                keyword_rules: Vec::new(),
+                ats_score_weight: DEFAULT_ATS_SCORE_WEIGHT,
+                target_score: None,
+                tone_phrases: TonePhrases::default(),
+                min_contact_confidence: DEFAULT_MIN_CONTACT_CONFIDENCE,
+                cert_expiry_warning_days: DEFAULT_CERT_EXPIRY_WARNING_DAYS,
+                section_embedding_model: None,
             },
             performance_config: PerformanceConfig {
                 max_concurrent_analyses: 3,
@@ -119,6 +204,8 @@ impl ConfigManager {
                 enable_gpu_acceleration: false,
                 memory_limit_mb: 1024,
                 timeout_seconds: 300,
+                max_upload_size_mb: DEFAULT_MAX_UPLOAD_SIZE_MB,
+                retention_policy: RetentionPolicy::default(),
             },
             logging_config: LoggingConfig {
                 level: "info".to_string(),
@@ -154,14 +241,46 @@ impl ConfigManager {
         &self.config.logging_config
     }
 
+    // Scoring inputs for the current analysis config; analyzers keep the `Arc` they were
+    // built with, so a config change only affects analyses started after it
+    pub fn scoring_settings(&self) -> Arc<ScoringSettings> {
+        Arc::clone(&self.scoring_settings)
+    }
+
+    // Seed for generation requests when deterministic mode is on
+    pub fn deterministic_seed(&self) -> Option<u64> {
+        let analysis_config = &self.config.analysis_config;
+        analysis_config
+            .deterministic_mode
+            .then_some(analysis_config.deterministic_seed)
+    }
+
+    // Prompt templates from the configured directory, to layer over the built-in ones
+    pub fn prompt_templates(&self) -> HashMap<String, PromptTemplate> {
+        match &self.config.analysis_config.prompt_template_dir {
+            Some(dir) => crate::enhanced_prompts::load_prompt_templates(Path::new(dir))
+                .unwrap_or_else(|e| {
+                    warn!("Using built-in prompt templates: {:#}", e);
+                    HashMap::new()
+                }),
+            None => HashMap::new(),
+        }
+    }
+
     // Update methods
     pub fn update_ollama_config(&mut self, ollama_config: OllamaConfig) -> Result<()> {
         self.config.ollama_config = ollama_config;
+        self.apply_ollama_request_limit();
         self.save_config()
     }
 
     pub fn update_analysis_config(&mut self, analysis_config: AnalysisConfig) -> Result<()> {
+        crate::level_fit::validate_experience_level_thresholds(
+            &analysis_config.experience_level_thresholds,
+        )?;
+        let scoring_settings = ScoringSettings::from_config(&analysis_config)?;
         self.config.analysis_config = analysis_config;
+        self.set_scoring_settings(scoring_settings);
         self.save_config()
     }
 
@@ -197,6 +316,10 @@ impl ConfigManager {
             warnings.extend(db_warnings);
         }
 
+        if self.config.portable && self.config.data_dir.is_some() {
+            warnings.push("Data directory is ignored in portable mode".to_string());
+        }
+
         // Validate Ollama config
         if self.config.ollama_config.port == 0 {
             warnings.push("Ollama port cannot be 0".to_string());
@@ -206,11 +329,27 @@ impl ConfigManager {
             warnings.push("Default Ollama model must be specified".to_string());
         }
 
+        if self.config.ollama_config.max_concurrent_requests == 0 {
+            warnings.push("Max concurrent Ollama requests must be at least 1".to_string());
+        }
+
         // Validate performance config
         if self.config.performance_config.max_concurrent_analyses == 0 {
             warnings.push("Max concurrent analyses must be at least 1".to_string());
         }
 
+        let max_upload_size_mb = self.config.performance_config.max_upload_size_mb;
+        if max_upload_size_mb == 0 {
+            warnings.push("Max upload size must be at least 1 MB".to_string());
+        } else if max_upload_size_mb as usize * 1024 * 1024
+            > crate::memory_manager::limits::MAX_DOCUMENT_SIZE
+        {
+            warnings.push(format!(
+                "Max upload size above {} MB is capped by the document memory limit",
+                crate::memory_manager::limits::MAX_DOCUMENT_SIZE / 1024 / 1024
+            ));
+        }
+
         if self.config.performance_config.cache_size_mb < 64 {
             warnings.push("Cache size should be at least 64MB for optimal performance".to_string());
         }
@@ -230,6 +369,121 @@ impl ConfigManager {
             warnings.push("Confidence threshold must be between 0.0 and 1.0".to_string());
         }
 
+        let weighting = &self.config.analysis_config.keyword_position_weighting;
+        if weighting.top_boost < 1.0 {
+            warnings.push("Keyword position top boost should be at least 1.0".to_string());
+        }
+        if weighting.decay_exponent <= 0.0 {
+            warnings.push("Keyword position decay exponent must be positive".to_string());
+        }
+        if weighting.section_boosts.values().any(|boost| *boost <= 0.0) {
+            warnings.push("Keyword section boosts must be positive".to_string());
+        }
+
+        if !(0.0..1.0).contains(&self.config.analysis_config.min_contextual_confidence) {
+            warnings.push(
+                "Minimum contextual match confidence should be at least 0 and below 1".to_string(),
+            );
+        }
+
+        if !(0.0..=1.0).contains(&self.config.analysis_config.min_contact_confidence) {
+            warnings.push("Minimum contact field confidence should be between 0 and 1".to_string());
+        }
+
+        if self.config.analysis_config.employment_gap_threshold_months == 0 {
+            warnings.push("Employment gap threshold must be at least 1 month".to_string());
+        }
+
+        let analysis = &self.config.analysis_config;
+        let default_profile = analysis.default_scoring_profile.to_lowercase();
+        if !analysis.scoring_profiles.contains_key(&default_profile)
+            && !ScoringProfile::presets().contains_key(&default_profile)
+        {
+            warnings.push(format!(
+                "Unknown default scoring profile '{}', using '{}'",
+                analysis.default_scoring_profile,
+                ScoringProfile::DEFAULT_NAME
+            ));
+        }
+        if analysis
+            .scoring_profiles
+            .values()
+            .any(|profile| profile.penalty_multiplier < 0.0 || profile.threshold_multiplier <= 0.0)
+        {
+            warnings.push(
+                "Scoring profile multipliers must be positive (penalty may be 0)".to_string(),
+            );
+        }
+        let weights = &analysis.match_type_weights;
+        if [
+            weights.exact,
+            weights.stemmed,
+            weights.contextual,
+            weights.synonym,
+            weights.fuzzy,
+        ]
+        .iter()
+        .any(|weight| !(0.0..=1.0).contains(weight))
+        {
+            warnings.push("Match type weights should be between 0.0 and 1.0".to_string());
+        }
+        if let Some(dir) = &analysis.prompt_template_dir {
+            if let Err(e) = crate::enhanced_prompts::load_prompt_templates(Path::new(dir)) {
+                warnings.push(format!(
+                    "Custom prompt templates ignored, using built-in ones: {:#}",
+                    e
+                ));
+            }
+        }
+        for (name, industry) in &analysis.custom_industries {
+            if industry.keywords.is_empty() {
+                warnings.push(format!("Custom industry '{}' has no keywords", name));
+            }
+        }
+        if !(0.0..=1.0).contains(&analysis.recency_decay.floor) {
+            warnings.push("Recency decay floor should be between 0.0 and 1.0".to_string());
+        }
+        if let Err(e) = crate::level_fit::validate_experience_level_thresholds(
+            &analysis.experience_level_thresholds,
+        ) {
+            warnings.push(e.to_string());
+        }
+        if let Some(target) = analysis.target_score {
+            if !(0.0..=100.0).contains(&target) {
+                warnings.push("Target score should be between 0 and 100".to_string());
+            }
+        }
+        if !(0.0..=1.0).contains(&analysis.ats_score_weight) {
+            warnings.push("ATS score weight should be between 0.0 and 1.0".to_string());
+        }
+        if !(0.0..=100.0).contains(&analysis.format_penalties.max_total_penalty) {
+            warnings.push("Maximum total format penalty should be between 0 and 100".to_string());
+        }
+        let mut taxonomy_owners: HashMap<String, &str> = HashMap::new();
+        for (skill, aliases) in &analysis.skill_taxonomy {
+            for alias in aliases {
+                if let Some(owner) = taxonomy_owners.insert(alias.to_lowercase(), skill) {
+                    if owner != skill {
+                        warnings.push(format!(
+                            "Skill variant '{}' is listed under both '{}' and '{}'",
+                            alias, owner, skill
+                        ));
+                    }
+                }
+            }
+        }
+        if analysis.trend_half_life_days <= 0.0 {
+            warnings.push("Trend half-life must be a positive number of days".to_string());
+        }
+        if let Some(url) = &analysis.completion_webhook_url {
+            if !url.starts_with("http://") && !url.starts_with("https://") {
+                warnings.push(format!(
+                    "Completion webhook URL '{}' must start with http:// or https://",
+                    url
+                ));
+            }
+        }
+
         // Log warnings
         for warning in &warnings {
             warn!("Configuration warning: {}", warning);
@@ -345,12 +599,34 @@ impl ConfigManager {
         Ok(())
     }
 
+    // Push the configured Ollama concurrency cap to the shared request limiter
+    pub fn apply_ollama_request_limit(&self) {
+        crate::ollama::configure_request_limit(self.config.ollama_config.max_concurrent_requests);
+    }
+
+    // Directory the database lives in when set explicitly or by portable mode; `None` leaves
+    // the choice to `database_url`
+    pub fn data_directory(&self) -> Result<Option<PathBuf>> {
+        if self.config.portable {
+            return portable_data_dir().map(Some);
+        }
+        Ok(self.config.data_dir.as_ref().map(PathBuf::from))
+    }
+
     // Reset to default configuration
     pub fn reset_to_default(&mut self) -> Result<()> {
         self.config = Self::default_config();
+        self.apply_ollama_request_limit();
+        self.set_scoring_settings(ScoringSettings::from_config(&self.config.analysis_config)?);
         self.save_config()
     }
 
+    // Swap in settings built from the new analysis config and drop results scored with the old
+    fn set_scoring_settings(&mut self, scoring_settings: ScoringSettings) {
+        self.scoring_settings = Arc::new(scoring_settings);
+        crate::advanced_scoring::invalidate_caches();
+    }
+
     // Environment variable overrides
     pub fn apply_env_overrides(&mut self) -> Result<()> {
         if let Ok(db_url) = std::env::var("ATS_DATABASE_URL") {
@@ -358,6 +634,11 @@ impl ConfigManager {
             info!("Database URL overridden from environment");
         }
 
+        if let Ok(data_dir) = std::env::var("ATS_DATA_DIR") {
+            self.config.data_dir = Some(data_dir);
+            info!("Data directory overridden from environment");
+        }
+
         if let Ok(ollama_host) = std::env::var("ATS_OLLAMA_HOST") {
             self.config.ollama_config.host = ollama_host;
             info!("Ollama host overridden from environment");
@@ -375,6 +656,13 @@ impl ConfigManager {
             info!("Default model overridden from environment");
         }
 
+        if let Ok(max_requests) = std::env::var("ATS_OLLAMA_MAX_CONCURRENT") {
+            if let Ok(requests) = max_requests.parse::<usize>() {
+                self.config.ollama_config.max_concurrent_requests = requests;
+                info!("Max concurrent Ollama requests overridden from environment");
+            }
+        }
+
         if let Ok(log_level) = std::env::var("ATS_LOG_LEVEL") {
             self.config.logging_config.level = log_level;
             info!("Log level overridden from environment");
@@ -415,6 +703,7 @@ pub struct OllamaConfigUpdate {
     pub max_retries: Option<u32>,
     pub default_model: Option<String>,
     pub models: Option<Vec<String>>,
+    pub max_concurrent_requests: Option<usize>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -425,6 +714,48 @@ pub struct AnalysisConfigUpdate {
     pub default_optimization_level: Option<OptimizationLevel>,
     pub max_suggestions: Option<usize>,
     pub confidence_threshold: Option<f64>,
+    pub deterministic_mode: Option<bool>,
+    pub deterministic_seed: Option<u64>,
+    pub section_header_synonyms: Option<HashMap<String, Vec<String>>>,
+    pub keyword_position_weighting: Option<KeywordPositionWeighting>,
+    pub employment_gap_threshold_months: Option<u32>,
+    pub scoring_profiles: Option<HashMap<String, ScoringProfile>>,
+    pub default_scoring_profile: Option<String>,
+    pub match_type_weights: Option<MatchTypeWeights>,
+    /// An empty string clears the webhook
+    pub completion_webhook_url: Option<String>,
+    pub trend_half_life_days: Option<f64>,
+    /// An empty string goes back to the built-in templates only
+    pub prompt_template_dir: Option<String>,
+    pub custom_industries: Option<HashMap<String, IndustryDefinition>>,
+    pub context_window_words: Option<usize>,
+    pub min_contextual_confidence: Option<f64>,
+    pub recency_decay: Option<RecencyDecay>,
+    pub section_policies: Option<HashMap<String, SectionPolicy>>,
+    pub format_penalties: Option<FormatPenalties>,
+    pub skill_taxonomy: Option<HashMap<String, Vec<String>>>,
+    pub experience_level_thresholds: Option<ExperienceLevelThresholds>,
+    pub resume_region: Option<ResumeRegion>,
+    pub keyword_rules: Option<Vec<KeywordRule>>,
+    pub ats_score_weight: Option<f64>,
+    /// `null` clears the target; leaving the field out keeps it
+    #[serde(default, deserialize_with = "deserialize_present")]
+    pub target_score: Option<Option<f64>>,
+    pub tone_phrases: Option<TonePhrases>,
+    pub min_contact_confidence: Option<f64>,
+    pub cert_expiry_warning_days: Option<u32>,
+    /// An empty string turns section embeddings off
+    pub section_embedding_model: Option<String>,
+}
+
+/// Deserialize a field that is present, even as `null`, to `Some`, so an update can tell a
+/// cleared value from one it leaves alone
+fn deserialize_present<'de, D, T>(deserializer: D) -> std::result::Result<Option<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    T::deserialize(deserializer).map(Some)
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -434,6 +765,8 @@ pub struct PerformanceConfigUpdate {
     pub enable_gpu_acceleration: Option<bool>,
     pub memory_limit_mb: Option<usize>,
     pub timeout_seconds: Option<u64>,
+    pub max_upload_size_mb: Option<u64>,
+    pub retention_policy: Option<RetentionPolicy>,
 }
 
 impl ConfigManager {
@@ -457,11 +790,24 @@ impl ConfigManager {
         if let Some(models) = update.models {
             self.config.ollama_config.models = models;
         }
+        if let Some(max_requests) = update.max_concurrent_requests {
+            self.config.ollama_config.max_concurrent_requests = max_requests;
+            self.apply_ollama_request_limit();
+        }
 
         self.save_config()
     }
 
     pub fn partial_update_analysis(&mut self, update: AnalysisConfigUpdate) -> Result<()> {
+        // Rules and thresholds go first so invalid ones reject the update before anything
+        // changes
+        if let Some(thresholds) = &update.experience_level_thresholds {
+            crate::level_fit::validate_experience_level_thresholds(thresholds)?;
+        }
+        if let Some(rules) = update.keyword_rules {
+            crate::advanced_scoring::compile_keyword_rules(&rules)?;
+            self.config.analysis_config.keyword_rules = rules;
+        }
         if let Some(industry_analysis) = update.enable_industry_analysis {
             self.config.analysis_config.enable_industry_analysis = industry_analysis;
         }
@@ -480,7 +826,92 @@ impl ConfigManager {
         if let Some(confidence) = update.confidence_threshold {
             self.config.analysis_config.confidence_threshold = confidence;
         }
+        if let Some(deterministic) = update.deterministic_mode {
+            self.config.analysis_config.deterministic_mode = deterministic;
+        }
+        if let Some(seed) = update.deterministic_seed {
+            self.config.analysis_config.deterministic_seed = seed;
+        }
+        if let Some(synonyms) = update.section_header_synonyms {
+            self.config.analysis_config.section_header_synonyms = synonyms;
+        }
+        if let Some(weighting) = update.keyword_position_weighting {
+            self.config.analysis_config.keyword_position_weighting = weighting;
+        }
+        if let Some(months) = update.employment_gap_threshold_months {
+            self.config.analysis_config.employment_gap_threshold_months = months;
+        }
+        if let Some(profiles) = update.scoring_profiles {
+            self.config.analysis_config.scoring_profiles = profiles;
+        }
+        if let Some(name) = update.default_scoring_profile {
+            self.config.analysis_config.default_scoring_profile = name;
+        }
+        if let Some(weights) = update.match_type_weights {
+            self.config.analysis_config.match_type_weights = weights;
+        }
+        if let Some(url) = update.completion_webhook_url {
+            let url = url.trim();
+            self.config.analysis_config.completion_webhook_url =
+                (!url.is_empty()).then(|| url.to_string());
+        }
+        if let Some(days) = update.trend_half_life_days {
+            self.config.analysis_config.trend_half_life_days = days;
+        }
+        if let Some(dir) = update.prompt_template_dir {
+            let dir = dir.trim();
+            self.config.analysis_config.prompt_template_dir =
+                (!dir.is_empty()).then(|| dir.to_string());
+        }
+        if let Some(industries) = update.custom_industries {
+            self.config.analysis_config.custom_industries = industries;
+        }
+        if let Some(words) = update.context_window_words {
+            self.config.analysis_config.context_window_words = words;
+        }
+        if let Some(threshold) = update.min_contextual_confidence {
+            self.config.analysis_config.min_contextual_confidence = threshold;
+        }
+        if let Some(decay) = update.recency_decay {
+            self.config.analysis_config.recency_decay = decay;
+        }
+        if let Some(policies) = update.section_policies {
+            self.config.analysis_config.section_policies = policies;
+        }
+        if let Some(penalties) = update.format_penalties {
+            self.config.analysis_config.format_penalties = penalties;
+        }
+        if let Some(taxonomy) = update.skill_taxonomy {
+            self.config.analysis_config.skill_taxonomy = taxonomy;
+        }
+        if let Some(thresholds) = update.experience_level_thresholds {
+            self.config.analysis_config.experience_level_thresholds = thresholds;
+        }
+        if let Some(region) = update.resume_region {
+            self.config.analysis_config.resume_region = region;
+        }
+        if let Some(weight) = update.ats_score_weight {
+            self.config.analysis_config.ats_score_weight = weight;
+        }
+        if let Some(target) = update.target_score {
+            self.config.analysis_config.target_score = target;
+        }
+        if let Some(phrases) = update.tone_phrases {
+            self.config.analysis_config.tone_phrases = phrases;
+        }
+        if let Some(threshold) = update.min_contact_confidence {
+            self.config.analysis_config.min_contact_confidence = threshold;
+        }
+        if let Some(days) = update.cert_expiry_warning_days {
+            self.config.analysis_config.cert_expiry_warning_days = days;
+        }
+        if let Some(model) = update.section_embedding_model {
+            let model = model.trim();
+            self.config.analysis_config.section_embedding_model =
+                (!model.is_empty()).then(|| model.to_string());
+        }
 
+        self.set_scoring_settings(ScoringSettings::from_config(&self.config.analysis_config)?);
         self.save_config()
     }
 
@@ -500,11 +931,24 @@ impl ConfigManager {
         if let Some(timeout) = update.timeout_seconds {
             self.config.performance_config.timeout_seconds = timeout;
         }
+        if let Some(max_upload_size) = update.max_upload_size_mb {
+            self.config.performance_config.max_upload_size_mb = max_upload_size;
+        }
+        if let Some(policy) = update.retention_policy {
+            self.config.performance_config.retention_policy = policy;
+        }
 
         self.save_config()
     }
 }
 
+/// `data` directory beside the running executable, used by portable mode
+fn portable_data_dir() -> Result<PathBuf> {
+    let exe = std::env::current_exe().context("Could not locate the executable")?;
+    let exe_dir = exe.parent().context("Executable has no parent directory")?;
+    Ok(exe_dir.join("data"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -521,6 +965,23 @@ mod tests {
         assert_eq!(config_manager.get_ollama_config().port, 11434);
     }
 
+    #[test]
+    fn test_move_config_to_portable_directory() {
+        let temp_dir = tempdir().unwrap();
+        let user_config = temp_dir.path().join("user").join("config.json");
+        std::fs::create_dir_all(user_config.parent().unwrap()).unwrap();
+        let portable_dir = temp_dir.path().join("portable").join("data");
+
+        let mut config_manager = ConfigManager::new_with_path(user_config).unwrap();
+        config_manager.config.portable = true;
+        config_manager.move_config_to(&portable_dir).unwrap();
+
+        let portable_config = portable_dir.join("config.json");
+        assert_eq!(config_manager.get_config_file_path(), &portable_config);
+        let reloaded = ConfigManager::new_with_path(portable_config).unwrap();
+        assert!(reloaded.get_config().portable);
+    }
+
     #[test]
     fn test_config_validation() {
         let temp_dir = tempdir().unwrap();
@@ -538,9 +999,17 @@ mod tests {
             .config
             .performance_config
             .max_concurrent_analyses = 0;
+        config_manager
+            .config
+            .analysis_config
+            .experience_level_thresholds
+            .executive_years = 1;
 
         let warnings = config_manager.validate_config().unwrap();
-        assert!(warnings.len() >= 2);
+        assert!(warnings.len() >= 3);
+        assert!(warnings
+            .iter()
+            .any(|warning| warning.contains("Experience level thresholds")));
     }
 
     #[test]
@@ -558,6 +1027,7 @@ mod tests {
             max_retries: None,
             default_model: None,
             models: None,
+            max_concurrent_requests: None,
         };
 
         config_manager.partial_update_ollama(ollama_update).unwrap();
@@ -567,4 +1037,57 @@ mod tests {
         // Unchanged values should remain the same
         assert_eq!(config_manager.get_ollama_config().timeout_seconds, 30);
     }
+
+    #[test]
+    fn test_target_score_update_clears_only_on_null() {
+        let temp_dir = tempdir().unwrap();
+        let mut config_manager =
+            ConfigManager::new_with_path(temp_dir.path().join("test_config.json")).unwrap();
+        let update = |json: &str| serde_json::from_str::<AnalysisConfigUpdate>(json).unwrap();
+
+        config_manager
+            .partial_update_analysis(update(r#"{"target_score": 85}"#))
+            .unwrap();
+        assert_eq!(
+            config_manager.get_analysis_config().target_score,
+            Some(85.0)
+        );
+
+        config_manager
+            .partial_update_analysis(update("{}"))
+            .unwrap();
+        assert_eq!(
+            config_manager.get_analysis_config().target_score,
+            Some(85.0)
+        );
+
+        config_manager
+            .partial_update_analysis(update(r#"{"target_score": null}"#))
+            .unwrap();
+        assert_eq!(config_manager.get_analysis_config().target_score, None);
+    }
+
+    #[test]
+    fn test_reset_to_default_rebuilds_scoring_settings() {
+        let temp_dir = tempdir().unwrap();
+        let mut config_manager =
+            ConfigManager::new_with_path(temp_dir.path().join("test_config.json")).unwrap();
+
+        config_manager
+            .partial_update_analysis(
+                serde_json::from_str(
+                    r#"{"match_type_weights": {"exact": 1.0, "stemmed": 0.85, "contextual": 0.6, "synonym": 0.7, "fuzzy": 0.9}}"#,
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        let customized = config_manager.scoring_settings();
+
+        config_manager.reset_to_default().unwrap();
+        assert_eq!(customized.match_type_weights.fuzzy, 0.9);
+        assert_eq!(
+            config_manager.scoring_settings().match_type_weights.fuzzy,
+            MatchTypeWeights::default().fuzzy
+        );
+    }
 }
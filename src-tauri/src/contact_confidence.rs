@@ -0,0 +1,218 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::advanced_scoring::ContactInfo;
+
+/// Confidence an extracted contact field must reach to be reported when none is configured
+pub const DEFAULT_MIN_CONTACT_CONFIDENCE: f64 = 0.5;
+
+/// Lines at the top of a resume where contact details are expected
+const CONTACT_HEADER_LINES: usize = 4;
+
+/// Words that mark a line as an organization rather than a person
+static ORGANIZATION_WORD: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)\b(?:inc|llc|ltd|corp|corporation|company|co|group|solutions|technologies|systems|consulting|partners|holdings|university|college|bank)\b\.?",
+    )
+    .expect("valid organization pattern")
+});
+
+/// Words that mark a line as a heading or job title rather than a name
+static HEADING_WORD: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)\b(?:resume|curriculum|vitae|cv|summary|profile|experience|objective|engineer|developer|manager|analyst|designer|consultant|director|specialist)\b",
+    )
+    .expect("valid heading pattern")
+});
+
+static PHONE_LABEL: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\b(?:phone|tel|telephone|mobile|cell)\b").expect("valid phone label pattern")
+});
+
+/// Confidence in each extracted contact field, 0.0-1.0; 0.0 when nothing was extracted
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ContactConfidence {
+    pub name: f64,
+    pub email: f64,
+    pub phone: f64,
+    pub location: f64,
+}
+
+/// A contact field withheld for low confidence, for the user to confirm or correct
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnconfirmedContactField {
+    /// "name", "email", "phone" or "location"
+    pub field: String,
+    /// What the parser extracted
+    pub value: String,
+    pub confidence: f64,
+    pub note: String,
+}
+
+/// Score each extracted contact field and withhold those below `threshold`
+pub fn apply_contact_confidence(contact: &mut ContactInfo, content: &str, threshold: f64) {
+    let header: Vec<&str> = content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .take(CONTACT_HEADER_LINES)
+        .collect();
+    let in_header = |value: &str| header.iter().any(|line| line.contains(value));
+
+    contact.confidence = ContactConfidence {
+        name: contact.name.as_deref().map_or(0.0, |name| {
+            name_confidence(name, contact.email.as_deref(), in_header(name))
+        }),
+        email: contact.email.as_deref().map_or(
+            0.0,
+            |email| {
+                if in_header(email) {
+                    0.95
+                } else {
+                    0.8
+                }
+            },
+        ),
+        phone: contact
+            .phone
+            .as_deref()
+            .map_or(0.0, |phone| phone_confidence(phone, content, &header)),
+        location: contact.location.as_deref().map_or(0.0, |location| {
+            if in_header(location) {
+                0.8
+            } else {
+                0.3
+            }
+        }),
+    };
+
+    let confidence = contact.confidence.clone();
+    let fields = [
+        ("name", &mut contact.name, confidence.name),
+        ("email", &mut contact.email, confidence.email),
+        ("phone", &mut contact.phone, confidence.phone),
+        ("location", &mut contact.location, confidence.location),
+    ];
+    for (field, value, confidence) in fields {
+        if confidence >= threshold {
+            continue;
+        }
+        if let Some(value) = value.take() {
+            contact.unconfirmed.push(UnconfirmedContactField {
+                field: field.to_string(),
+                note: format!(
+                    "Low confidence that \"{}\" is the candidate's {}; please confirm",
+                    value, field
+                ),
+                value,
+                confidence,
+            });
+        }
+    }
+}
+
+/// A person's name is two to four capitalized words near the top, and often echoes the email
+fn name_confidence(name: &str, email: Option<&str>, in_header: bool) -> f64 {
+    let words: Vec<&str> = name.split_whitespace().collect();
+    let looks_like_name = words.iter().all(|word| {
+        word.chars().next().is_some_and(char::is_uppercase)
+            && word
+                .chars()
+                .all(|c| c.is_alphabetic() || matches!(c, '-' | '\'' | '.'))
+    });
+
+    let mut confidence: f64 = if ORGANIZATION_WORD.is_match(name) {
+        0.2
+    } else if HEADING_WORD.is_match(name) || name.contains('@') {
+        0.3
+    } else if !looks_like_name || !(2..=4).contains(&words.len()) {
+        0.4
+    } else {
+        0.85
+    };
+    if !in_header {
+        confidence *= 0.7;
+    }
+    let local_part = email
+        .and_then(|email| email.split('@').next())
+        .map(str::to_lowercase)
+        .unwrap_or_default();
+    if words
+        .iter()
+        .any(|word| word.len() > 1 && local_part.contains(&word.to_lowercase()))
+    {
+        confidence += 0.1;
+    }
+    confidence.min(1.0)
+}
+
+/// A phone number beside a label or in the header is reliable; one deep in the body may be
+/// any other run of digits
+fn phone_confidence(phone: &str, content: &str, header: &[&str]) -> f64 {
+    let digits: String = phone.chars().filter(char::is_ascii_digit).collect();
+    let line = content
+        .lines()
+        .find(|line| {
+            line.chars()
+                .filter(char::is_ascii_digit)
+                .collect::<String>()
+                .contains(&digits)
+        })
+        .unwrap_or_default();
+
+    if PHONE_LABEL.is_match(line) {
+        1.0
+    } else if header.contains(&line.trim()) {
+        0.9
+    } else {
+        0.4
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::advanced_scoring::ATSSimulator;
+
+    #[test]
+    fn test_company_on_top_line_is_withheld_as_low_confidence_name() {
+        let resume = "Acme Solutions Inc\n\
+                      jane.doe@example.com | (555) 123-4567\n\n\
+                      EXPERIENCE\n\
+                      Engineer, Acme Solutions, 2020 - Present\n\
+                      • Built billing services\n";
+
+        let mut contact = ATSSimulator::new()
+            .parse_with_multiple_systems(resume)
+            .unwrap()
+            .contact_info;
+        assert_eq!(contact.name, None);
+        assert_eq!(contact.email.as_deref(), Some("jane.doe@example.com"));
+        assert!(contact.phone.is_some());
+
+        let name = contact
+            .unconfirmed
+            .iter()
+            .find(|field| field.field == "name")
+            .expect("the company line should be flagged as a name to confirm");
+        assert!(name.value.starts_with("Acme Solutions Inc"), "{:?}", name);
+        assert!(name.confidence < DEFAULT_MIN_CONTACT_CONFIDENCE);
+        assert!(name.note.contains("confirm"));
+        assert!(!contact
+            .unconfirmed
+            .iter()
+            .any(|field| field.field == "email"));
+
+        // A clear name on the top line passes the same threshold
+        contact.name = Some("Jane Doe".to_string());
+        contact.unconfirmed.clear();
+        apply_contact_confidence(
+            &mut contact,
+            "Jane Doe\njane.doe@example.com\n",
+            DEFAULT_MIN_CONTACT_CONFIDENCE,
+        );
+        assert_eq!(contact.name.as_deref(), Some("Jane Doe"));
+        assert!(contact.confidence.name > 0.9);
+    }
+}
@@ -0,0 +1,227 @@
+use anyhow::Result;
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use crate::advanced_scoring::KeywordAnalyzer;
+
+/// Recommended cover letter length in words
+pub const MIN_COVER_LETTER_WORDS: usize = 200;
+pub const MAX_COVER_LETTER_WORDS: usize = 450;
+
+/// Share of the letter's JD keywords already covered by the resume above which the letter
+/// mostly restates the resume
+pub const RESUME_REDUNDANCY_THRESHOLD: f64 = 0.9;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoverLetterAnalysis {
+    pub overall_score: f64,
+    /// Percentage of job description keywords the letter mentions
+    pub keyword_coverage: f64,
+    pub matched_keywords: Vec<String>,
+    pub missing_keywords: Vec<String>,
+    pub word_count: usize,
+    pub length_appropriate: bool,
+    pub mentions_company: bool,
+    /// Set when the letter never names the company it is addressed to
+    pub missing_personalization: bool,
+    /// Share of the letter's matched keywords that the resume already covers
+    pub resume_keyword_overlap: f64,
+    /// Keywords the letter adds on top of the resume
+    pub keywords_not_in_resume: Vec<String>,
+    pub redundant_with_resume: bool,
+    pub suggestions: Vec<String>,
+}
+
+/// Scores a cover letter against a job description using the same keyword matching as
+/// resume analysis, plus checks that only make sense for letters
+pub struct CoverLetterAnalyzer {
+    keyword_analyzer: KeywordAnalyzer,
+}
+
+impl Default for CoverLetterAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CoverLetterAnalyzer {
+    pub fn new() -> Self {
+        Self {
+            keyword_analyzer: KeywordAnalyzer::new(),
+        }
+    }
+
+    pub async fn analyze(
+        &self,
+        cover_letter: &str,
+        job_description: &str,
+        resume_content: &str,
+        company_name: &str,
+        industry: &str,
+    ) -> Result<CoverLetterAnalysis> {
+        info!("Analyzing cover letter for {}", company_name);
+
+        let target_keywords = self
+            .keyword_analyzer
+            .extract_keywords_from_job_description(job_description)?;
+        let letter_matches = self
+            .keyword_analyzer
+            .analyze_comprehensive(cover_letter, job_description, industry)
+            .await?
+            .matched_keywords();
+        let resume_matches: HashSet<String> = self
+            .keyword_analyzer
+            .analyze_comprehensive(resume_content, job_description, industry)
+            .await?
+            .matched_keywords()
+            .into_iter()
+            .collect();
+
+        let matched: HashSet<&str> = letter_matches.iter().map(|k| k.as_str()).collect();
+        let missing_keywords: Vec<String> = target_keywords
+            .iter()
+            .filter(|keyword| !matched.contains(keyword.to_lowercase().as_str()))
+            .cloned()
+            .collect();
+        let keyword_coverage = if target_keywords.is_empty() {
+            100.0
+        } else {
+            letter_matches.len().min(target_keywords.len()) as f64 / target_keywords.len() as f64
+                * 100.0
+        };
+
+        let keywords_not_in_resume: Vec<String> = letter_matches
+            .iter()
+            .filter(|keyword| !resume_matches.contains(*keyword))
+            .cloned()
+            .collect();
+        let resume_keyword_overlap = if letter_matches.is_empty() {
+            0.0
+        } else {
+            1.0 - keywords_not_in_resume.len() as f64 / letter_matches.len() as f64
+        };
+        let redundant_with_resume = letter_matches.len() >= 3
+            && resume_keyword_overlap >= RESUME_REDUNDANCY_THRESHOLD
+            && keywords_not_in_resume.is_empty();
+
+        let word_count = cover_letter.split_whitespace().count();
+        let length_appropriate =
+            (MIN_COVER_LETTER_WORDS..=MAX_COVER_LETTER_WORDS).contains(&word_count);
+        let mentions_company = Self::mentions_company(cover_letter, company_name);
+
+        let mut suggestions = Vec::new();
+        if !mentions_company {
+            suggestions.push(format!(
+                "Mention {} by name, ideally with a specific reason you want to work there",
+                company_name.trim()
+            ));
+        }
+        if word_count < MIN_COVER_LETTER_WORDS {
+            suggestions.push(format!(
+                "Expand the letter to at least {} words ({} now) with a concrete example of relevant work",
+                MIN_COVER_LETTER_WORDS, word_count
+            ));
+        } else if word_count > MAX_COVER_LETTER_WORDS {
+            suggestions.push(format!(
+                "Trim the letter to under {} words ({} now); recruiters skim cover letters",
+                MAX_COVER_LETTER_WORDS, word_count
+            ));
+        }
+        if !missing_keywords.is_empty() {
+            let top_missing: Vec<&str> = missing_keywords
+                .iter()
+                .take(5)
+                .map(|k| k.as_str())
+                .collect();
+            suggestions.push(format!(
+                "Work these job description keywords into the letter: {}",
+                top_missing.join(", ")
+            ));
+        }
+        if redundant_with_resume {
+            suggestions.push(
+                "The letter repeats what the resume already covers; use it to explain motivation or a skill the resume doesn't show"
+                    .to_string(),
+            );
+        }
+
+        let overall_score = keyword_coverage * 0.5
+            + if mentions_company { 20.0 } else { 0.0 }
+            + if length_appropriate { 15.0 } else { 5.0 }
+            + if redundant_with_resume { 5.0 } else { 15.0 };
+
+        Ok(CoverLetterAnalysis {
+            overall_score: overall_score.clamp(0.0, 100.0),
+            keyword_coverage,
+            matched_keywords: letter_matches,
+            missing_keywords,
+            word_count,
+            length_appropriate,
+            mentions_company,
+            missing_personalization: !mentions_company,
+            resume_keyword_overlap,
+            keywords_not_in_resume,
+            redundant_with_resume,
+            suggestions,
+        })
+    }
+
+    fn mentions_company(cover_letter: &str, company_name: &str) -> bool {
+        let company = company_name.trim().to_lowercase();
+        !company.is_empty() && cover_letter.to_lowercase().contains(&company)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JOB_DESCRIPTION: &str = "Backend Engineer at Acme Robotics. \
+        Requirements: experience with Python, AWS and Docker, strong communication and leadership.";
+    const RESUME: &str = "Experience\nBuilt Python services on AWS.\nSkills\nPython, AWS";
+
+    #[tokio::test]
+    async fn test_missing_company_name_flags_personalization() {
+        let analyzer = CoverLetterAnalyzer::new();
+        let letter =
+            "Dear Hiring Manager,\n\nI am excited to apply for the backend engineer role. \
+            I have built Python services on AWS and packaged them with Docker, and I enjoy \
+            mentoring teammates through clear communication.\n\nSincerely,\nJordan";
+
+        let analysis = analyzer
+            .analyze(
+                letter,
+                JOB_DESCRIPTION,
+                RESUME,
+                "Acme Robotics",
+                "technology",
+            )
+            .await
+            .unwrap();
+
+        assert!(!analysis.mentions_company);
+        assert!(analysis.missing_personalization);
+        assert!(analysis
+            .suggestions
+            .iter()
+            .any(|s| s.contains("Acme Robotics")));
+
+        let personalized = letter.replace(
+            "the backend engineer role",
+            "the backend engineer role at Acme Robotics",
+        );
+        let analysis = analyzer
+            .analyze(
+                &personalized,
+                JOB_DESCRIPTION,
+                RESUME,
+                "Acme Robotics",
+                "technology",
+            )
+            .await
+            .unwrap();
+        assert!(analysis.mentions_company);
+        assert!(!analysis.missing_personalization);
+    }
+}
@@ -1,18 +1,36 @@
 use anyhow::{Context, Result};
 use chrono::{DateTime, NaiveDateTime, Utc};
-use log::{error, info, warn};
+use log::{debug, error, info, warn};
+use sqlx::sqlite::{SqliteConnection, SqlitePoolOptions};
 use sqlx::{Row, SqlitePool};
-use std::path::PathBuf;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
 
-use crate::migrations::MigrationManager;
+use crate::migrations::{MigrationManager, SchemaVersion};
 use crate::models::{
-    ATSCompatibilityRule, Analysis, ApplicationStatus, ApplicationStatusCount, CompanyCount,
-    IndustryKeyword, JobAnalytics, JobDescription, JobPriority, JobPriorityCount, JobSearchRequest,
-    JobSearchResult, JobSortOption, JobStatus, JobStatusCount, LocationCount,
-    ModelPerformanceMetrics, Resume, ScoringBenchmark, SortOrder, UserFeedback, UserPreferences,
-    UserPreferencesUpdate,
+    ATSCompatibilityRule, Analysis, AnalysisResult, ApplicationStatus, ApplicationStatusCount,
+    CompanyCount, IncrementalExport, IndustryKeyword, JobAnalytics, JobDescription, JobPriority,
+    JobPriorityCount, JobSearchRequest, JobSearchResult, JobSortOption, JobStatus, JobStatusCount,
+    KeywordCoveragePoint, KeywordCoverageTrend, LocationCount, ModelPerformanceMetrics,
+    PersistentKeywordGap, Resume, ResumeTimelineEntry, RetentionPolicy, ScoringBenchmark,
+    SortOrder, UserFeedback, UserPreferences, UserPreferencesUpdate,
 };
 
+/// File name of the database inside the data directory
+const DATABASE_FILE_NAME: &str = "ats_scanner.db";
+
+/// Create `dir` if needed and prove it accepts writes, so a read-only location fails with a
+/// clear message instead of an opaque SQLite error
+pub fn ensure_writable_directory(dir: &Path) -> Result<()> {
+    std::fs::create_dir_all(dir)
+        .with_context(|| format!("Cannot create data directory '{}'", dir.display()))?;
+    let probe = dir.join(".ats-scanner-write-test");
+    std::fs::write(&probe, b"")
+        .with_context(|| format!("Data directory '{}' is not writable", dir.display()))?;
+    std::fs::remove_file(&probe).ok();
+    Ok(())
+}
+
 /// Helper function to parse timestamps in multiple formats
 fn parse_timestamp(timestamp_str: &str) -> Result<DateTime<Utc>> {
     // Try RFC3339 format first
@@ -45,6 +63,43 @@ fn parse_timestamp(timestamp_str: &str) -> Result<DateTime<Utc>> {
     ))
 }
 
+/// Stored analyses needed before an industry's score distribution replaces the built-in one
+pub const MIN_DISTRIBUTION_SAMPLES: usize = 20;
+
+/// Share of an industry's analyses a keyword must be missing from to count as a persistent gap
+const PERSISTENT_GAP_MIN_MISS_RATE: f64 = 0.75;
+/// Analyses needed before a keyword missing from all of them reads as a pattern
+const PERSISTENT_GAP_MIN_ANALYSES: usize = 2;
+
+/// Hours an analysis idempotency key is honoured before a retry with it analyzes afresh
+pub const IDEMPOTENCY_KEY_TTL_HOURS: i64 = 24;
+
+/// Score thresholds sampled when building a distribution from stored analyses
+const DISTRIBUTION_THRESHOLDS: [f64; 7] = [50.0, 60.0, 70.0, 80.0, 90.0, 95.0, 100.0];
+
+/// Lowercased keywords from a stored `missing_keywords` column, which holds a JSON array but
+/// may be a comma-separated list in older rows
+fn parse_missing_keywords(raw: &str) -> HashSet<String> {
+    serde_json::from_str::<Vec<String>>(raw)
+        .unwrap_or_else(|_| raw.split(',').map(str::to_string).collect())
+        .iter()
+        .map(|keyword| keyword.trim().to_lowercase())
+        .filter(|keyword| !keyword.is_empty())
+        .collect()
+}
+
+/// `(score_threshold, percent_of_scores_at_or_below)` points for `scores`
+pub fn score_distribution(scores: &[f64]) -> Vec<(f64, f64)> {
+    let total = scores.len().max(1) as f64;
+    DISTRIBUTION_THRESHOLDS
+        .iter()
+        .map(|&threshold| {
+            let at_or_below = scores.iter().filter(|&&score| score <= threshold).count();
+            (threshold, at_or_below as f64 / total * 100.0)
+        })
+        .collect()
+}
+
 #[derive(Debug, Clone)]
 pub struct Database {
     pool: SqlitePool,
@@ -82,6 +137,24 @@ impl Database {
         }
     }
 
+    /// Open (or create) the database inside `data_dir`
+    pub async fn new_in_directory(data_dir: &Path) -> Result<Self> {
+        ensure_writable_directory(data_dir)?;
+        let db_path = data_dir.join(DATABASE_FILE_NAME);
+        let database_url = format!("sqlite:{}?mode=rwc", db_path.to_string_lossy());
+        info!("Using configured data directory: {:?}", data_dir);
+
+        let pool =
+            Self::connect_with_error_handling(&database_url, &data_dir.to_path_buf(), &db_path)
+                .await?;
+
+        let db = Database { pool };
+        db.run_migrations().await?;
+        db.seed_initial_data().await?;
+
+        Ok(db)
+    }
+
     async fn try_current_directory_database() -> Result<Self> {
         // Use persistent database file with absolute path
         let current_dir = std::env::current_dir().context("Failed to get current directory")?;
@@ -246,8 +319,16 @@ impl Database {
             }
         }
 
-        // For in-memory databases or other cases
-        let pool = SqlitePool::connect(database_url).await?;
+        // For in-memory databases or other cases. Every SQLite connection to ":memory:" opens its
+        // own empty database, so the pool must stick to a single connection
+        let pool = if database_url.contains(":memory:") {
+            SqlitePoolOptions::new()
+                .max_connections(1)
+                .connect(database_url)
+                .await?
+        } else {
+            SqlitePool::connect(database_url).await?
+        };
 
         let db = Database { pool };
         db.run_migrations().await?;
@@ -581,6 +662,23 @@ impl Database {
         .await
         .context("Failed to create model_performance_metrics table")?;
 
+        // Create suggestion_resolutions table so suggestions marked done stay done
+        info!("Creating suggestion_resolutions table");
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS suggestion_resolutions (
+                analysis_id TEXT NOT NULL,
+                suggestion_id TEXT NOT NULL,
+                resolved INTEGER NOT NULL DEFAULT 0,
+                updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                PRIMARY KEY (analysis_id, suggestion_id)
+            )
+            "#,
+        )
+        .execute(&mut *tx)
+        .await
+        .context("Failed to create suggestion_resolutions table")?;
+
         // Create indexes for enhanced performance
         info!("Creating enhanced performance indexes");
         sqlx::query("CREATE INDEX IF NOT EXISTS idx_industry_keywords_industry ON industry_keywords(industry)")
@@ -1459,14 +1557,92 @@ impl Database {
 
     // Analysis operations
     pub async fn save_analysis(&self, analysis: &Analysis) -> Result<()> {
+        let mut conn = self.pool.acquire().await?;
+        Self::insert_analysis(&mut conn, analysis).await?;
+
+        info!("Analysis saved with ID: {}", analysis.id);
+        Ok(())
+    }
+
+    /// Result previously saved under `idempotency_key`, unless the key has expired
+    pub async fn get_idempotent_analysis(
+        &self,
+        idempotency_key: &str,
+    ) -> Result<Option<AnalysisResult>> {
+        let cutoff = (Utc::now() - chrono::Duration::hours(IDEMPOTENCY_KEY_TTL_HOURS)).to_rfc3339();
+        let result: Option<String> = sqlx::query_scalar(
+            "SELECT result FROM analysis_idempotency_keys WHERE idempotency_key = ? AND created_at >= ?",
+        )
+        .bind(idempotency_key)
+        .bind(&cutoff)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        result
+            .map(|json| serde_json::from_str(&json).context("Stored analysis result is invalid"))
+            .transpose()
+    }
+
+    /// Save `analysis` under `idempotency_key` and return `result`, or, if the key was already
+    /// used within the expiry window, save nothing and return the result stored with it
+    pub async fn save_analysis_idempotent(
+        &self,
+        idempotency_key: &str,
+        analysis: &Analysis,
+        result: &AnalysisResult,
+    ) -> Result<AnalysisResult> {
+        let now = Utc::now();
+        let cutoff = (now - chrono::Duration::hours(IDEMPOTENCY_KEY_TTL_HOURS)).to_rfc3339();
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("DELETE FROM analysis_idempotency_keys WHERE created_at < ?")
+            .bind(&cutoff)
+            .execute(&mut *tx)
+            .await?;
+        let existing: Option<String> = sqlx::query_scalar(
+            "SELECT result FROM analysis_idempotency_keys WHERE idempotency_key = ?",
+        )
+        .bind(idempotency_key)
+        .fetch_optional(&mut *tx)
+        .await?;
+        if let Some(json) = existing {
+            tx.commit().await?;
+            info!(
+                "Analysis for idempotency key {} already saved, skipping duplicate",
+                idempotency_key
+            );
+            return serde_json::from_str(&json).context("Stored analysis result is invalid");
+        }
+
+        Self::insert_analysis(&mut tx, analysis).await?;
+        sqlx::query(
+            "INSERT INTO analysis_idempotency_keys (idempotency_key, analysis_id, result, created_at) VALUES (?, ?, ?, ?)",
+        )
+        .bind(idempotency_key)
+        .bind(&analysis.id)
+        .bind(serde_json::to_string(result)?)
+        .bind(now.to_rfc3339())
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
+        info!(
+            "Analysis saved with ID: {} under idempotency key {}",
+            analysis.id, idempotency_key
+        );
+        Ok(result.clone())
+    }
+
+    async fn insert_analysis(conn: &mut SqliteConnection, analysis: &Analysis) -> Result<()> {
         sqlx::query(
             r#"
             INSERT INTO analyses (
                 id, resume_id, job_description_id, model_used, overall_score,
                 skills_score, experience_score, education_score, keywords_score, format_score,
-                detailed_feedback, missing_keywords, recommendations, processing_time_ms, created_at
+                detailed_feedback, missing_keywords, recommendations, processing_time_ms, created_at,
+                updated_at
             )
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
         )
         .bind(&analysis.id)
@@ -1484,13 +1660,172 @@ impl Database {
         .bind(&analysis.recommendations)
         .bind(analysis.processing_time_ms)
         .bind(analysis.created_at.to_rfc3339())
-        .execute(&self.pool)
+        .bind(analysis.created_at.to_rfc3339())
+        .execute(conn)
         .await?;
 
-        info!("Analysis saved with ID: {}", analysis.id);
         Ok(())
     }
 
+    /// Resumes and analyses added or changed after `since`, oldest change first
+    pub async fn export_since(&self, since: DateTime<Utc>) -> Result<IncrementalExport> {
+        let exported_at = Utc::now();
+        let cutoff = since.to_rfc3339();
+
+        let resume_rows = sqlx::query(
+            "SELECT id, filename, content, file_type, created_at, updated_at FROM resumes WHERE updated_at > ? ORDER BY updated_at",
+        )
+        .bind(&cutoff)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to load changed resumes")?;
+        let mut resumes = Vec::with_capacity(resume_rows.len());
+        for row in resume_rows {
+            resumes.push(Resume {
+                id: row.get("id"),
+                filename: row.get("filename"),
+                content: row.get("content"),
+                file_type: row.get("file_type"),
+                created_at: parse_timestamp(&row.get::<String, _>("created_at"))?,
+                updated_at: parse_timestamp(&row.get::<String, _>("updated_at"))?,
+            });
+        }
+
+        let analysis_rows =
+            sqlx::query("SELECT * FROM analyses WHERE updated_at > ? ORDER BY updated_at")
+                .bind(&cutoff)
+                .fetch_all(&self.pool)
+                .await
+                .context("Failed to load changed analyses")?;
+        let mut analyses = Vec::with_capacity(analysis_rows.len());
+        for row in analysis_rows {
+            analyses.push(Analysis {
+                id: row.get("id"),
+                resume_id: row.get("resume_id"),
+                job_description_id: row.get("job_description_id"),
+                model_used: row.get("model_used"),
+                overall_score: row.get("overall_score"),
+                skills_score: row.get("skills_score"),
+                experience_score: row.get("experience_score"),
+                education_score: row.get("education_score"),
+                keywords_score: row.get("keywords_score"),
+                format_score: row.get("format_score"),
+                detailed_feedback: row.get("detailed_feedback"),
+                missing_keywords: row.get("missing_keywords"),
+                recommendations: row.get("recommendations"),
+                processing_time_ms: row.get("processing_time_ms"),
+                created_at: parse_timestamp(&row.get::<String, _>("created_at"))?,
+            });
+        }
+
+        info!(
+            "Incremental export since {}: {} resumes, {} analyses",
+            cutoff,
+            resumes.len(),
+            analyses.len()
+        );
+        Ok(IncrementalExport {
+            since,
+            exported_at,
+            resumes,
+            analyses,
+        })
+    }
+
+    /// Record `resume_id` as the next version of the resume `previous_resume_id` belongs to
+    pub async fn link_resume_version(
+        &self,
+        resume_id: &str,
+        previous_resume_id: &str,
+    ) -> Result<i64> {
+        let mut tx = self.pool.begin().await?;
+
+        let root_resume_id: String =
+            sqlx::query_scalar("SELECT root_resume_id FROM resume_versions WHERE resume_id = ?")
+                .bind(previous_resume_id)
+                .fetch_optional(&mut *tx)
+                .await?
+                .unwrap_or_else(|| previous_resume_id.to_string());
+        let now = Utc::now().to_rfc3339();
+
+        // The first save only gets a row once something follows it
+        sqlx::query(
+            "INSERT OR IGNORE INTO resume_versions (resume_id, root_resume_id, version, created_at) VALUES (?, ?, 1, ?)",
+        )
+        .bind(&root_resume_id)
+        .bind(&root_resume_id)
+        .bind(&now)
+        .execute(&mut *tx)
+        .await?;
+
+        let version: i64 = sqlx::query_scalar(
+            "SELECT MAX(version) + 1 FROM resume_versions WHERE root_resume_id = ?",
+        )
+        .bind(&root_resume_id)
+        .fetch_one(&mut *tx)
+        .await?;
+        sqlx::query(
+            "INSERT INTO resume_versions (resume_id, root_resume_id, version, created_at) VALUES (?, ?, ?, ?)",
+        )
+        .bind(resume_id)
+        .bind(&root_resume_id)
+        .bind(version)
+        .bind(&now)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        info!(
+            "Resume {} saved as version {} of {}",
+            resume_id, version, root_resume_id
+        );
+        Ok(version)
+    }
+
+    /// Every version of the resume `resume_id` belongs to, oldest first, each with its latest
+    /// overall score against `job_description_id`
+    pub async fn get_resume_timeline(
+        &self,
+        resume_id: &str,
+        job_description_id: &str,
+    ) -> Result<Vec<ResumeTimelineEntry>> {
+        let root_resume_id: String =
+            sqlx::query_scalar("SELECT root_resume_id FROM resume_versions WHERE resume_id = ?")
+                .bind(resume_id)
+                .fetch_optional(&self.pool)
+                .await?
+                .unwrap_or_else(|| resume_id.to_string());
+
+        let rows = sqlx::query(
+            r#"
+            SELECT r.id, r.created_at, COALESCE(v.version, 1) AS version,
+                (SELECT a.overall_score FROM analyses a
+                 WHERE a.resume_id = r.id AND a.job_description_id = ?
+                 ORDER BY a.created_at DESC LIMIT 1) AS overall_score
+            FROM resumes r
+            LEFT JOIN resume_versions v ON v.resume_id = r.id
+            WHERE r.id = ? OR v.root_resume_id = ?
+            ORDER BY version, r.created_at
+            "#,
+        )
+        .bind(job_description_id)
+        .bind(&root_resume_id)
+        .bind(&root_resume_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter()
+            .map(|row| {
+                Ok(ResumeTimelineEntry {
+                    resume_id: row.get("id"),
+                    version: row.get("version"),
+                    saved_at: parse_timestamp(&row.get::<String, _>("created_at"))?,
+                    overall_score: row.get("overall_score"),
+                })
+            })
+            .collect()
+    }
+
     pub async fn get_analysis_history(&self, limit: Option<i64>) -> Result<Vec<Analysis>> {
         let query = if let Some(limit) = limit {
             format!(
@@ -1560,6 +1895,71 @@ impl Database {
         Ok(analyses)
     }
 
+    pub async fn get_analysis(&self, id: &str) -> Result<Option<Analysis>> {
+        let row = sqlx::query("SELECT * FROM analyses WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        row.map(|row| {
+            Ok(Analysis {
+                id: row.get("id"),
+                resume_id: row.get("resume_id"),
+                job_description_id: row.get("job_description_id"),
+                model_used: row.get("model_used"),
+                overall_score: row.get("overall_score"),
+                skills_score: row.get("skills_score"),
+                experience_score: row.get("experience_score"),
+                education_score: row.get("education_score"),
+                keywords_score: row.get("keywords_score"),
+                format_score: row.get("format_score"),
+                detailed_feedback: row.get("detailed_feedback"),
+                missing_keywords: row.get("missing_keywords"),
+                recommendations: row.get("recommendations"),
+                processing_time_ms: row.get("processing_time_ms"),
+                created_at: parse_timestamp(&row.get::<String, _>("created_at"))?,
+            })
+        })
+        .transpose()
+    }
+
+    /// Mark or unmark a suggestion as done for an analysis
+    pub async fn set_suggestion_resolved(
+        &self,
+        analysis_id: &str,
+        suggestion_id: &str,
+        resolved: bool,
+    ) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO suggestion_resolutions (analysis_id, suggestion_id, resolved, updated_at)
+            VALUES (?, ?, ?, ?)
+            ON CONFLICT(analysis_id, suggestion_id)
+            DO UPDATE SET resolved = excluded.resolved, updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(analysis_id)
+        .bind(suggestion_id)
+        .bind(resolved)
+        .bind(Utc::now().to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// IDs of the suggestions currently marked done for an analysis
+    pub async fn get_resolved_suggestions(&self, analysis_id: &str) -> Result<HashSet<String>> {
+        let ids = sqlx::query_scalar::<_, String>(
+            "SELECT suggestion_id FROM suggestion_resolutions WHERE analysis_id = ? AND resolved = 1",
+        )
+        .bind(analysis_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(ids.into_iter().collect())
+    }
+
     pub async fn delete_analysis(&self, id: &str) -> Result<()> {
         sqlx::query("DELETE FROM analyses WHERE id = ?")
             .bind(id)
@@ -1587,12 +1987,77 @@ impl Database {
         Ok(())
     }
 
+    /// Delete the analyses the retention policy no longer keeps, with their idempotency keys
+    /// and suggestion resolutions; returns how many analyses were removed
+    pub async fn prune_old_analyses(&self, policy: &RetentionPolicy) -> Result<u64> {
+        if !policy.is_enabled() {
+            return Ok(0);
+        }
+
+        let mut expired: Vec<String> = Vec::new();
+        if let Some(days) = policy.max_age_days {
+            let cutoff = (Utc::now() - chrono::Duration::days(i64::from(days))).to_rfc3339();
+            expired.extend(
+                sqlx::query_scalar::<_, String>("SELECT id FROM analyses WHERE created_at < ?")
+                    .bind(cutoff)
+                    .fetch_all(&self.pool)
+                    .await?,
+            );
+        }
+        if let Some(keep) = policy.keep_last_per_resume {
+            expired.extend(
+                sqlx::query_scalar::<_, String>(
+                    r#"
+                    SELECT id FROM (
+                        SELECT id, ROW_NUMBER() OVER (
+                            PARTITION BY resume_id ORDER BY created_at DESC, id DESC
+                        ) AS position
+                        FROM analyses
+                    )
+                    WHERE position > ?
+                    "#,
+                )
+                .bind(i64::from(keep))
+                .fetch_all(&self.pool)
+                .await?,
+            );
+        }
+        expired.sort();
+        expired.dedup();
+
+        let mut tx = self.pool.begin().await?;
+        for id in &expired {
+            for statement in [
+                "DELETE FROM analysis_idempotency_keys WHERE analysis_id = ?",
+                "DELETE FROM suggestion_resolutions WHERE analysis_id = ?",
+                "DELETE FROM analyses WHERE id = ?",
+            ] {
+                sqlx::query(statement).bind(id).execute(&mut *tx).await?;
+            }
+        }
+        tx.commit().await?;
+
+        info!(
+            "Pruned {} analyses past the retention policy",
+            expired.len()
+        );
+        Ok(expired.len() as u64)
+    }
+
     // Health check for testing
     pub async fn health_check(&self) -> Result<bool> {
         let result = sqlx::query("SELECT 1").fetch_one(&self.pool).await?;
         Ok(result.get::<i32, _>(0) == 1)
     }
 
+    /// Applied and pending migrations, for diagnostics
+    pub async fn schema_version(&self) -> Result<SchemaVersion> {
+        let mut migration_manager = MigrationManager::new(self.pool.clone());
+        migration_manager.initialize().await?;
+        migration_manager.register_migrations();
+        migration_manager.get_schema_version().await
+    }
+
     // Comprehensive health check with detailed information
     pub async fn comprehensive_health_check(&self) -> Result<serde_json::Value> {
         let mut health_info = std::collections::HashMap::new();
@@ -1962,6 +2427,128 @@ impl Database {
         Ok(result)
     }
 
+    /// Build an industry's score distribution from stored analyses, as
+    /// `(score_threshold, percent_of_analyses_at_or_below)` points.
+    ///
+    /// Analyses are matched to an industry through their saved job description. Returns `None`
+    /// when fewer than [`MIN_DISTRIBUTION_SAMPLES`] analyses exist, so callers can fall back to
+    /// their built-in benchmarks.
+    pub async fn compute_industry_distribution(
+        &self,
+        industry: &str,
+    ) -> Result<Option<Vec<(f64, f64)>>> {
+        let scores = self.get_industry_scores(industry).await?;
+
+        if scores.len() < MIN_DISTRIBUTION_SAMPLES {
+            debug!(
+                "Only {} stored analyses for industry {}, need {} for a distribution",
+                scores.len(),
+                industry,
+                MIN_DISTRIBUTION_SAMPLES
+            );
+            return Ok(None);
+        }
+
+        Ok(Some(score_distribution(&scores)))
+    }
+
+    /// Overall scores of the stored analyses whose job description is in `industry`
+    pub async fn get_industry_scores(&self, industry: &str) -> Result<Vec<f64>> {
+        sqlx::query_scalar(
+            r#"
+            SELECT a.overall_score
+            FROM analyses a
+            JOIN job_descriptions j ON a.job_description_id = j.id
+            WHERE LOWER(j.industry) = LOWER(?)
+            "#,
+        )
+        .bind(industry)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to load analysis scores for industry distribution")
+    }
+
+    /// Monthly keyword coverage across every analysis whose job description is in `industry`,
+    /// plus the keywords those analyses keep reporting missing. A gap counts as persistent when
+    /// it is missing from most of the analyses, including the latest one.
+    pub async fn get_keyword_coverage_trend(&self, industry: &str) -> Result<KeywordCoverageTrend> {
+        let rows = sqlx::query(
+            r#"
+            SELECT a.keywords_score, a.missing_keywords, a.created_at
+            FROM analyses a
+            JOIN job_descriptions j ON a.job_description_id = j.id
+            WHERE LOWER(j.industry) = LOWER(?)
+            ORDER BY a.created_at
+            "#,
+        )
+        .bind(industry)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to load analyses for keyword coverage trend")?;
+
+        let mut monthly: BTreeMap<String, (f64, usize)> = BTreeMap::new();
+        let mut missed: HashMap<String, usize> = HashMap::new();
+        let mut latest_missing = HashSet::new();
+        for row in &rows {
+            let created_at = parse_timestamp(&row.get::<String, _>("created_at"))?;
+            let (total, count) = monthly
+                .entry(created_at.format("%Y-%m").to_string())
+                .or_default();
+            *total += row.get::<f64, _>("keywords_score");
+            *count += 1;
+
+            latest_missing = parse_missing_keywords(&row.get::<String, _>("missing_keywords"));
+            for keyword in &latest_missing {
+                *missed.entry(keyword.clone()).or_default() += 1;
+            }
+        }
+
+        let points: Vec<KeywordCoveragePoint> = monthly
+            .into_iter()
+            .map(|(period, (total, count))| KeywordCoveragePoint {
+                period,
+                average_keywords_score: total / count as f64,
+                analysis_count: count,
+            })
+            .collect();
+        let change = match (points.first(), points.last()) {
+            (Some(first), Some(last)) => last.average_keywords_score - first.average_keywords_score,
+            _ => 0.0,
+        };
+
+        let analysis_count = rows.len();
+        let mut persistent_gaps: Vec<PersistentKeywordGap> =
+            if analysis_count >= PERSISTENT_GAP_MIN_ANALYSES {
+                missed
+                    .into_iter()
+                    .map(|(keyword, missed_count)| PersistentKeywordGap {
+                        miss_rate: missed_count as f64 / analysis_count as f64,
+                        keyword,
+                        missed_count,
+                    })
+                    .filter(|gap| {
+                        gap.miss_rate >= PERSISTENT_GAP_MIN_MISS_RATE
+                            && latest_missing.contains(&gap.keyword)
+                    })
+                    .collect()
+            } else {
+                Vec::new()
+            };
+        persistent_gaps.sort_by(|a, b| {
+            b.missed_count
+                .cmp(&a.missed_count)
+                .then_with(|| a.keyword.cmp(&b.keyword))
+        });
+
+        Ok(KeywordCoverageTrend {
+            industry: industry.to_string(),
+            analysis_count,
+            points,
+            change,
+            persistent_gaps,
+        })
+    }
+
     pub async fn get_improvement_trends(&self) -> Result<serde_json::Value> {
         let trends = sqlx::query(
             r#"
@@ -2709,8 +3296,9 @@ impl Database {
             r#"
             INSERT INTO model_performance_metrics (
                 id, model_name, analysis_id, processing_time_ms, memory_usage_mb,
-                accuracy_score, user_satisfaction, error_count, created_at
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+                accuracy_score, user_satisfaction, error_count, created_at,
+                prompt_tokens, response_tokens
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
         )
         .bind(&metrics.id)
@@ -2722,6 +3310,8 @@ impl Database {
         .bind(metrics.user_satisfaction)
         .bind(metrics.error_count)
         .bind(metrics.created_at.to_rfc3339())
+        .bind(metrics.prompt_tokens)
+        .bind(metrics.response_tokens)
         .execute(&self.pool)
         .await?;
 
@@ -2732,6 +3322,28 @@ impl Database {
         Ok(())
     }
 
+    /// Processing times of the model's most recent analyses, newest first
+    pub async fn get_recent_processing_times(
+        &self,
+        model_name: &str,
+        limit: i64,
+    ) -> Result<Vec<i64>> {
+        let times = sqlx::query_scalar(
+            r#"
+            SELECT processing_time_ms FROM model_performance_metrics
+            WHERE model_name = ?
+            ORDER BY created_at DESC
+            LIMIT ?
+            "#,
+        )
+        .bind(model_name)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(times)
+    }
+
     pub async fn get_model_performance_stats(&self, model_name: &str) -> Result<serde_json::Value> {
         let stats = sqlx::query(
             r#"
@@ -2741,7 +3353,9 @@ impl Database {
                 AVG(memory_usage_mb) as avg_memory_usage,
                 AVG(accuracy_score) as avg_accuracy,
                 AVG(user_satisfaction) as avg_satisfaction,
-                SUM(error_count) as total_errors
+                SUM(error_count) as total_errors,
+                AVG(prompt_tokens) as avg_prompt_tokens,
+                AVG(response_tokens) as avg_response_tokens
             FROM model_performance_metrics WHERE model_name = ?
             "#,
         )
@@ -2756,7 +3370,9 @@ impl Database {
             "avg_memory_usage_mb": stats.get::<Option<f64>, _>("avg_memory_usage").unwrap_or(0.0),
             "avg_accuracy_score": stats.get::<Option<f64>, _>("avg_accuracy").unwrap_or(0.0),
             "avg_user_satisfaction": stats.get::<Option<f64>, _>("avg_satisfaction").unwrap_or(0.0),
-            "total_errors": stats.get::<Option<i32>, _>("total_errors").unwrap_or(0)
+            "total_errors": stats.get::<Option<i32>, _>("total_errors").unwrap_or(0),
+            "avg_prompt_tokens": stats.get::<Option<f64>, _>("avg_prompt_tokens").unwrap_or(0.0),
+            "avg_response_tokens": stats.get::<Option<f64>, _>("avg_response_tokens").unwrap_or(0.0)
         });
 
         Ok(result)
@@ -2772,7 +3388,9 @@ impl Database {
                 AVG(memory_usage_mb) as avg_memory_usage,
                 AVG(accuracy_score) as avg_accuracy,
                 AVG(user_satisfaction) as avg_satisfaction,
-                SUM(error_count) as total_errors
+                SUM(error_count) as total_errors,
+                AVG(prompt_tokens) as avg_prompt_tokens,
+                AVG(response_tokens) as avg_response_tokens
             FROM model_performance_metrics 
             GROUP BY model_name
             ORDER BY analysis_count DESC
@@ -2790,7 +3408,9 @@ impl Database {
                 "avg_memory_usage_mb": row.get::<Option<f64>, _>("avg_memory_usage").unwrap_or(0.0),
                 "avg_accuracy_score": row.get::<Option<f64>, _>("avg_accuracy").unwrap_or(0.0),
                 "avg_user_satisfaction": row.get::<Option<f64>, _>("avg_satisfaction").unwrap_or(0.0),
-                "total_errors": row.get::<Option<i32>, _>("total_errors").unwrap_or(0)
+                "total_errors": row.get::<Option<i32>, _>("total_errors").unwrap_or(0),
+                "avg_prompt_tokens": row.get::<Option<f64>, _>("avg_prompt_tokens").unwrap_or(0.0),
+                "avg_response_tokens": row.get::<Option<f64>, _>("avg_response_tokens").unwrap_or(0.0)
             });
             results.push(result);
         }
@@ -2805,13 +3425,13 @@ impl Database {
             INSERT INTO job_descriptions (
                 id, title, company, content, requirements, preferred_qualifications,
                 salary_range_min, salary_range_max, salary_currency, location,
-                remote_options, employment_type, experience_level, posted_date,
-                application_deadline, job_url, keywords, industry, department,
-                status, priority, notes, application_status, application_date,
-                interview_date, response_deadline, contact_person, contact_email,
-                tags, source, is_archived, created_at, updated_at
+                remote_options, remote_region, visa_sponsorship, employment_type,
+                experience_level, posted_date, application_deadline, job_url, keywords,
+                industry, department, status, priority, notes, application_status,
+                application_date, interview_date, response_deadline, contact_person,
+                contact_email, tags, source, is_archived, created_at, updated_at
             )
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
         )
         .bind(&job.id)
@@ -2825,6 +3445,8 @@ impl Database {
         .bind(&job.salary_currency)
         .bind(&job.location)
         .bind(serde_json::to_string(&job.remote_options).unwrap_or_default())
+        .bind(&job.remote_region)
+        .bind(serde_json::to_string(&job.visa_sponsorship).unwrap_or_default())
         .bind(serde_json::to_string(&job.employment_type).unwrap_or_default())
         .bind(serde_json::to_string(&job.experience_level).unwrap_or_default())
         .bind(job.posted_date.map(|d| d.to_rfc3339()))
@@ -2859,7 +3481,7 @@ impl Database {
             r#"
             SELECT id, title, company, content, requirements, preferred_qualifications,
                    salary_range_min, salary_range_max, salary_currency, location,
-                   remote_options, employment_type, experience_level, posted_date,
+                   remote_options, remote_region, visa_sponsorship, employment_type, experience_level, posted_date,
                    application_deadline, job_url, keywords, industry, department,
                    status, priority, notes, application_status, application_date,
                    interview_date, response_deadline, contact_person, contact_email,
@@ -2885,6 +3507,9 @@ impl Database {
                 location: row.get("location"),
                 remote_options: serde_json::from_str(&row.get::<String, _>("remote_options"))
                     .unwrap_or_default(),
+                remote_region: row.get("remote_region"),
+                visa_sponsorship: serde_json::from_str(&row.get::<String, _>("visa_sponsorship"))
+                    .unwrap_or_default(),
                 employment_type: serde_json::from_str(&row.get::<String, _>("employment_type"))
                     .unwrap_or_default(),
                 experience_level: serde_json::from_str(&row.get::<String, _>("experience_level"))
@@ -2936,7 +3561,8 @@ impl Database {
             UPDATE job_descriptions SET
                 title = ?, company = ?, content = ?, requirements = ?, preferred_qualifications = ?,
                 salary_range_min = ?, salary_range_max = ?, salary_currency = ?, location = ?,
-                remote_options = ?, employment_type = ?, experience_level = ?, posted_date = ?,
+                remote_options = ?, remote_region = ?, visa_sponsorship = ?,
+                employment_type = ?, experience_level = ?, posted_date = ?,
                 application_deadline = ?, job_url = ?, keywords = ?, industry = ?, department = ?,
                 status = ?, priority = ?, notes = ?, application_status = ?, application_date = ?,
                 interview_date = ?, response_deadline = ?, contact_person = ?, contact_email = ?,
@@ -2954,6 +3580,8 @@ impl Database {
         .bind(&job.salary_currency)
         .bind(&job.location)
         .bind(serde_json::to_string(&job.remote_options).unwrap_or_default())
+        .bind(&job.remote_region)
+        .bind(serde_json::to_string(&job.visa_sponsorship).unwrap_or_default())
         .bind(serde_json::to_string(&job.employment_type).unwrap_or_default())
         .bind(serde_json::to_string(&job.experience_level).unwrap_or_default())
         .bind(job.posted_date.map(|d| d.to_rfc3339()))
@@ -3020,6 +3648,9 @@ impl Database {
                 location: row.get("location"),
                 remote_options: serde_json::from_str(&row.get::<String, _>("remote_options"))
                     .unwrap_or_default(),
+                remote_region: row.get("remote_region"),
+                visa_sponsorship: serde_json::from_str(&row.get::<String, _>("visa_sponsorship"))
+                    .unwrap_or_default(),
                 employment_type: serde_json::from_str(&row.get::<String, _>("employment_type"))
                     .unwrap_or_default(),
                 experience_level: serde_json::from_str(&row.get::<String, _>("experience_level"))
@@ -3116,6 +3747,11 @@ impl Database {
             params.push(format!("%{}%", industry));
         }
 
+        if request.exclude_no_sponsorship.unwrap_or(false) {
+            query.push_str(" AND visa_sponsorship != '\"NotOffered\"'");
+            count_query.push_str(" AND visa_sponsorship != '\"NotOffered\"'");
+        }
+
         if let Some(include_archived) = request.include_archived {
             if !include_archived {
                 query.push_str(" AND is_archived = FALSE");
@@ -3200,6 +3836,9 @@ impl Database {
                 location: row.get("location"),
                 remote_options: serde_json::from_str(&row.get::<String, _>("remote_options"))
                     .unwrap_or_default(),
+                remote_region: row.get("remote_region"),
+                visa_sponsorship: serde_json::from_str(&row.get::<String, _>("visa_sponsorship"))
+                    .unwrap_or_default(),
                 employment_type: serde_json::from_str(&row.get::<String, _>("employment_type"))
                     .unwrap_or_default(),
                 experience_level: serde_json::from_str(&row.get::<String, _>("experience_level"))
@@ -3377,6 +4016,7 @@ impl Database {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::CategoryScores;
     use chrono::Utc;
     use uuid::Uuid;
 
@@ -3497,4 +4137,306 @@ mod tests {
         let history = db.get_analysis_history(None).await.unwrap();
         assert_eq!(history.len(), 0);
     }
+
+    #[tokio::test]
+    async fn test_reopening_database_keeps_schema_version() {
+        let db_file = tempfile::NamedTempFile::new().unwrap();
+        let database_url = format!("sqlite:{}", db_file.path().display());
+
+        let first = Database::new_with_url(&database_url).await.unwrap();
+        let mut migration_manager = MigrationManager::new(first.get_pool().clone());
+        migration_manager.register_migrations();
+        let version = migration_manager.get_current_version().await.unwrap();
+        let applied = migration_manager.get_applied_migrations().await.unwrap();
+        drop(first);
+
+        // Opening again runs the migration pass a second time
+        let second = Database::new_with_url(&database_url).await.unwrap();
+        let mut migration_manager = MigrationManager::new(second.get_pool().clone());
+        migration_manager.register_migrations();
+        let reapplied = migration_manager.get_applied_migrations().await.unwrap();
+
+        assert_eq!(
+            migration_manager.get_current_version().await.unwrap(),
+            version
+        );
+        assert_eq!(
+            reapplied.iter().map(|m| m.version).collect::<Vec<_>>(),
+            applied.iter().map(|m| m.version).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            reapplied.iter().map(|m| m.applied_at).collect::<Vec<_>>(),
+            applied.iter().map(|m| m.applied_at).collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_database_created_in_configured_data_directory() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let data_dir = temp_dir.path().join("portable").join("data");
+
+        let db = Database::new_in_directory(&data_dir).await.unwrap();
+
+        assert!(db.health_check().await.unwrap());
+        assert!(data_dir.join(DATABASE_FILE_NAME).is_file());
+
+        // A file where the directory should be cannot hold the database
+        let blocked = temp_dir.path().join("not-a-directory");
+        std::fs::write(&blocked, b"").unwrap();
+        let error = Database::new_in_directory(&blocked).await.unwrap_err();
+        assert!(format!("{:#}", error).contains("Cannot create data directory"));
+    }
+
+    #[tokio::test]
+    async fn test_export_since_returns_only_later_changes() {
+        let db = setup_test_db().await.unwrap();
+        let earlier = Utc::now() - chrono::Duration::days(10);
+        let later = Utc::now() - chrono::Duration::days(2);
+
+        let mut old_resume = create_test_resume();
+        old_resume.created_at = earlier;
+        old_resume.updated_at = earlier;
+        let mut new_resume = create_test_resume();
+        new_resume.created_at = later;
+        new_resume.updated_at = later;
+        db.save_resume(&old_resume).await.unwrap();
+        db.save_resume(&new_resume).await.unwrap();
+
+        let mut old_analysis = create_test_analysis(&old_resume.id);
+        old_analysis.created_at = earlier;
+        let mut new_analysis = create_test_analysis(&new_resume.id);
+        new_analysis.created_at = later;
+        db.save_analysis(&old_analysis).await.unwrap();
+        db.save_analysis(&new_analysis).await.unwrap();
+
+        let cutoff = Utc::now() - chrono::Duration::days(5);
+        let export = db.export_since(cutoff).await.unwrap();
+        let resume_ids: Vec<&str> = export.resumes.iter().map(|r| r.id.as_str()).collect();
+        let analysis_ids: Vec<&str> = export.analyses.iter().map(|a| a.id.as_str()).collect();
+        assert_eq!(resume_ids, [new_resume.id.as_str()]);
+        assert_eq!(analysis_ids, [new_analysis.id.as_str()]);
+
+        assert_eq!(
+            db.export_since(earlier - chrono::Duration::days(1))
+                .await
+                .unwrap()
+                .analyses
+                .len(),
+            2
+        );
+
+        // Editing an old row moves it past the cutoff
+        sqlx::query("UPDATE analyses SET recommendations = 'Revised' WHERE id = ?")
+            .bind(&old_analysis.id)
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        let export = db.export_since(cutoff).await.unwrap();
+        assert_eq!(export.analyses.len(), 2);
+        assert_eq!(export.analyses[1].id, old_analysis.id);
+    }
+
+    #[tokio::test]
+    async fn test_resume_timeline_orders_versions_with_scores() {
+        let db = setup_test_db().await.unwrap();
+        let mut previous: Option<String> = None;
+        for (days_ago, score) in [(30, 58.0), (20, 71.5), (10, 84.0)] {
+            let mut resume = create_test_resume();
+            resume.created_at = Utc::now() - chrono::Duration::days(days_ago);
+            resume.updated_at = resume.created_at;
+            db.save_resume(&resume).await.unwrap();
+            if let Some(previous_id) = &previous {
+                db.link_resume_version(&resume.id, previous_id)
+                    .await
+                    .unwrap();
+            }
+
+            let mut analysis = create_test_analysis(&resume.id);
+            analysis.overall_score = score;
+            db.save_analysis(&analysis).await.unwrap();
+            previous = Some(resume.id);
+        }
+        // An unrelated resume stays off the timeline
+        db.save_resume(&create_test_resume()).await.unwrap();
+
+        let latest = previous.unwrap();
+        let timeline = db
+            .get_resume_timeline(&latest, "test_job_id")
+            .await
+            .unwrap();
+        let versions: Vec<i64> = timeline.iter().map(|entry| entry.version).collect();
+        let scores: Vec<f64> = timeline
+            .iter()
+            .filter_map(|entry| entry.overall_score)
+            .collect();
+        assert_eq!(versions, [1, 2, 3]);
+        assert_eq!(scores, [58.0, 71.5, 84.0]);
+        assert!(timeline
+            .windows(2)
+            .all(|pair| pair[0].saved_at < pair[1].saved_at));
+        assert_eq!(timeline[2].resume_id, latest);
+
+        // Another job has no analyses, so the versions come back unscored
+        let other = db.get_resume_timeline(&latest, "other_job").await.unwrap();
+        assert_eq!(other.len(), 3);
+        assert!(other.iter().all(|entry| entry.overall_score.is_none()));
+    }
+
+    #[tokio::test]
+    async fn test_keyword_coverage_trend_reports_persistent_gap() {
+        let db = setup_test_db().await.unwrap();
+        let mut job = JobDescription::new(
+            "Platform Engineer".to_string(),
+            "Acme".to_string(),
+            "Kubernetes, Terraform and Go".to_string(),
+        );
+        job.industry = Some("Technology".to_string());
+        db.save_job_description(&job).await.unwrap();
+        let resume = create_test_resume();
+        db.save_resume(&resume).await.unwrap();
+
+        let runs = [
+            (90, 52.0, r#"["Kubernetes","Terraform","Go"]"#),
+            (60, 61.0, r#"["kubernetes","terraform"]"#),
+            (30, 70.0, r#"["Kubernetes"]"#),
+            (1, 74.0, r#"["Kubernetes"]"#),
+        ];
+        for (days_ago, keywords_score, missing) in runs {
+            let mut analysis = create_test_analysis(&resume.id);
+            analysis.job_description_id = job.id.clone();
+            analysis.keywords_score = keywords_score;
+            analysis.missing_keywords = missing.to_string();
+            analysis.created_at = Utc::now() - chrono::Duration::days(days_ago);
+            db.save_analysis(&analysis).await.unwrap();
+        }
+
+        let trend = db.get_keyword_coverage_trend("technology").await.unwrap();
+        assert_eq!(trend.analysis_count, 4);
+        assert!(trend.change > 0.0);
+        assert_eq!(trend.points.first().unwrap().average_keywords_score, 52.0);
+
+        let gaps: Vec<&str> = trend
+            .persistent_gaps
+            .iter()
+            .map(|gap| gap.keyword.as_str())
+            .collect();
+        assert_eq!(gaps, ["kubernetes"]);
+        assert_eq!(trend.persistent_gaps[0].missed_count, 4);
+        assert_eq!(trend.persistent_gaps[0].miss_rate, 1.0);
+
+        let other = db.get_keyword_coverage_trend("finance").await.unwrap();
+        assert_eq!(other.analysis_count, 0);
+        assert!(other.persistent_gaps.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_retried_keyed_analysis_saves_one_row() -> Result<()> {
+        let db = setup_test_db().await?;
+        let resume = create_test_resume();
+        db.save_resume(&resume).await?;
+        let result = |overall_score: f64| AnalysisResult {
+            overall_score,
+            category_scores: CategoryScores {
+                skills: 80.0,
+                experience: 75.0,
+                education: 90.0,
+                keywords: 70.0,
+                format: 85.0,
+                confidence: None,
+            },
+            detailed_feedback: "Solid match".to_string(),
+            missing_keywords: vec!["kubernetes".to_string()],
+            recommendations: vec!["Quantify impact".to_string()],
+            processing_time_ms: 1200,
+            model_used: Some("test_model".to_string()),
+        };
+
+        // The retry re-runs the model, which scores slightly differently
+        let first_result = result(78.0);
+        let first = Analysis::new(
+            resume.id.clone(),
+            "job".to_string(),
+            "m".to_string(),
+            &first_result,
+        );
+        let retry_result = result(79.5);
+        let retry = Analysis::new(
+            resume.id.clone(),
+            "job".to_string(),
+            "m".to_string(),
+            &retry_result,
+        );
+
+        let saved = db
+            .save_analysis_idempotent("request-42", &first, &first_result)
+            .await?;
+        assert!(db.get_idempotent_analysis("request-42").await?.is_some());
+        let replayed = db
+            .save_analysis_idempotent("request-42", &retry, &retry_result)
+            .await?;
+
+        let rows: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM analyses")
+            .fetch_one(&db.pool)
+            .await?;
+        assert_eq!(rows, 1);
+        assert_eq!(
+            serde_json::to_value(&saved)?,
+            serde_json::to_value(&replayed)?
+        );
+        assert_eq!(replayed.overall_score, 78.0);
+        assert!(db.get_idempotent_analysis("request-43").await?.is_none());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_prune_old_analyses_removes_only_those_beyond_policy() {
+        let db = setup_test_db().await.unwrap();
+        let busy_resume = create_test_resume();
+        let quiet_resume = create_test_resume();
+        db.save_resume(&busy_resume).await.unwrap();
+        db.save_resume(&quiet_resume).await.unwrap();
+
+        let seed = |resume_id: &str, days_ago: i64| {
+            let mut analysis = create_test_analysis(resume_id);
+            analysis.created_at = Utc::now() - chrono::Duration::days(days_ago);
+            analysis
+        };
+        let busy: Vec<Analysis> = [1, 2, 3, 4, 100]
+            .into_iter()
+            .map(|days| seed(&busy_resume.id, days))
+            .collect();
+        let quiet: Vec<Analysis> = [1, 50]
+            .into_iter()
+            .map(|days| seed(&quiet_resume.id, days))
+            .collect();
+        for analysis in busy.iter().chain(&quiet) {
+            db.save_analysis(analysis).await.unwrap();
+        }
+
+        assert_eq!(
+            db.prune_old_analyses(&RetentionPolicy::default())
+                .await
+                .unwrap(),
+            0
+        );
+
+        let policy = RetentionPolicy {
+            keep_last_per_resume: Some(3),
+            max_age_days: Some(30),
+        };
+        assert_eq!(db.prune_old_analyses(&policy).await.unwrap(), 3);
+
+        let mut remaining: Vec<String> = sqlx::query_scalar("SELECT id FROM analyses")
+            .fetch_all(&db.pool)
+            .await
+            .unwrap();
+        remaining.sort();
+        let mut expected: Vec<String> = busy[..3]
+            .iter()
+            .chain(&quiet[..1])
+            .map(|analysis| analysis.id.clone())
+            .collect();
+        expected.sort();
+        assert_eq!(remaining, expected);
+    }
 }
@@ -1,28 +1,104 @@
 use anyhow::{anyhow, Result};
-use chrono::DateTime;
+use chrono::{DateTime, NaiveDate, Utc};
 use log::{error, info, warn};
 use mime_guess::from_path;
+use once_cell::sync::Lazy;
 use quick_xml::events::Event;
 use quick_xml::name::QName;
 use quick_xml::Reader;
 use regex::Regex;
-use std::io::{Cursor, Read};
+use std::io::{Cursor, Read, Write};
 use std::path::Path;
 use tokio::io::{AsyncReadExt, BufReader as AsyncBufReader};
-use zip::ZipArchive;
+use zip::write::FileOptions;
+use zip::{ZipArchive, ZipWriter};
 
+use crate::level_fit::SeniorityLevel;
 use crate::memory_manager::{limits, utils as memory_utils, MemoryTracker, StreamingTextProcessor};
 use crate::models::{
     DocumentContactInfo, DocumentHeading, DocumentInfo, DocumentIssue, DocumentIssueType,
     DocumentMetadata, DocumentQualityMetrics, DocumentSection, DocumentStructure,
-    HeadingFormatting, IssueSeverity,
+    ExperienceLevelThresholds, HeadingFormatting, IssueSeverity, LengthAnalysis, LengthVerdict,
 };
 use crate::utils::security;
 
+/// Approximate words on a single-spaced resume page, used when the file has no page count
+pub const WORDS_PER_PAGE: usize = 500;
+
+/// Below this a resume reads as sparse regardless of level
+const MIN_RESUME_WORDS: usize = 200;
+
+/// US-style phone numbers, optionally with a +1 prefix
+pub const PHONE_PATTERN: &str = r"(\+?1[-.\s]?)?\(?([0-9]{3})\)?[-.\s]?([0-9]{3})[-.\s]?([0-9]{4})";
+
+/// Street addresses ("123 Main St, Apt 4, Springfield, IL 62704")
+pub const ADDRESS_PATTERN: &str = r"(?i)\b\d{1,6}\s+(?:[A-Za-z0-9.'-]+\s+){0,4}(?:street|st|avenue|ave|road|rd|boulevard|blvd|lane|ln|drive|dr|court|ct|way|place|pl|terrace|parkway|pkwy)\b\.?(?:,?\s*(?:apt|suite|unit|#)\s*#?[A-Za-z0-9-]+)?(?:,\s*[A-Za-z][A-Za-z .]+)?(?:,?\s*[A-Z]{2})?(?:\s+\d{5}(?:-\d{4})?)?";
+
+/// Upload limit used until the config sets one
+pub const DEFAULT_MAX_UPLOAD_SIZE_MB: u64 = 10;
+
+/// Extensions `parse_file` can read
+const SUPPORTED_EXTENSIONS: &[&str] = &["pdf", "docx", "doc", "txt", "text"];
+
+/// "5+ years of experience" stated outright
+static STATED_EXPERIENCE_YEARS: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(\d{1,2})\+?\s*(?:years?|yrs?)\s+(?:of\s+)?experience")
+        .expect("valid stated experience pattern")
+});
+
 pub struct DocumentParser;
 
 impl DocumentParser {
-    pub async fn parse_file(file_path: &str) -> Result<DocumentInfo> {
+    /// Reject a file over `max_size_mb`, with an extension we cannot parse, or whose leading
+    /// bytes contradict its extension, before any parser reads it
+    pub fn validate_upload(path: &Path, max_size_mb: u64) -> Result<()> {
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+        if !SUPPORTED_EXTENSIONS.contains(&extension.as_str()) {
+            return Err(anyhow!(
+                "Unsupported file type '{}'; upload a PDF, DOCX, DOC or TXT file",
+                path.file_name()
+                    .map_or_else(String::new, |name| name.to_string_lossy().to_string())
+            ));
+        }
+
+        let size = std::fs::metadata(path)?.len();
+        if size > max_size_mb.saturating_mul(1024 * 1024) {
+            return Err(anyhow!(
+                "File is {:.1} MB, over the {} MB upload limit",
+                size as f64 / 1024.0 / 1024.0,
+                max_size_mb
+            ));
+        }
+
+        let signature: &[u8] = match extension.as_str() {
+            "pdf" => b"%PDF",
+            "docx" => b"PK\x03\x04",
+            "doc" => &[0xD0, 0xCF, 0x11, 0xE0],
+            _ => return Ok(()),
+        };
+        let mut header = Vec::with_capacity(signature.len());
+        std::fs::File::open(path)?
+            .take(signature.len() as u64)
+            .read_to_end(&mut header)?;
+        if header != signature {
+            return Err(anyhow!(
+                "File contents are not a valid .{} document",
+                extension
+            ));
+        }
+        Ok(())
+    }
+
+    /// Parse the file at `file_path`, judging its length against the experience level
+    /// `thresholds` place it at
+    pub async fn parse_file(
+        file_path: &str,
+        thresholds: &ExperienceLevelThresholds,
+    ) -> Result<DocumentInfo> {
         info!("Parsing document: {}", file_path);
 
         // SECURITY: Validate file path to prevent path traversal attacks
@@ -117,7 +193,14 @@ impl DocumentParser {
         let structure = Self::analyze_document_structure(&cleaned_content);
 
         // Calculate quality metrics
-        let quality_metrics = Self::calculate_quality_metrics(&cleaned_content, &structure);
+        let mut quality_metrics = Self::calculate_quality_metrics(&cleaned_content, &structure);
+        quality_metrics
+            .issues
+            .extend(Self::detect_metadata_leaks(&metadata, &content));
+
+        // Check length against the detected experience level
+        let length_analysis = Self::analyze_length(&cleaned_content, metadata.pages, thresholds);
+        Self::record_length_issue(&length_analysis, &mut quality_metrics);
 
         Ok(DocumentInfo {
             id: uuid::Uuid::new_v4().to_string(),
@@ -130,11 +213,16 @@ impl DocumentParser {
             metadata,
             structure: Some(structure),
             quality_metrics: Some(quality_metrics),
+            length_analysis: Some(length_analysis),
         })
     }
 
     #[allow(dead_code)]
-    pub async fn parse_content(content: &[u8], filename: &str) -> Result<DocumentInfo> {
+    pub async fn parse_content(
+        content: &[u8],
+        filename: &str,
+        thresholds: &ExperienceLevelThresholds,
+    ) -> Result<DocumentInfo> {
         info!("Parsing document content for: {}", filename);
 
         let file_type = Self::determine_file_type_from_filename(filename);
@@ -167,7 +255,14 @@ impl DocumentParser {
         let structure = Self::analyze_document_structure(&cleaned_content);
 
         // Calculate quality metrics
-        let quality_metrics = Self::calculate_quality_metrics(&cleaned_content, &structure);
+        let mut quality_metrics = Self::calculate_quality_metrics(&cleaned_content, &structure);
+        quality_metrics
+            .issues
+            .extend(Self::detect_metadata_leaks(&metadata, &parsed_content));
+
+        // Check length against the detected experience level
+        let length_analysis = Self::analyze_length(&cleaned_content, metadata.pages, thresholds);
+        Self::record_length_issue(&length_analysis, &mut quality_metrics);
 
         Ok(DocumentInfo {
             id: uuid::Uuid::new_v4().to_string(),
@@ -180,6 +275,7 @@ impl DocumentParser {
             metadata,
             structure: Some(structure),
             quality_metrics: Some(quality_metrics),
+            length_analysis: Some(length_analysis),
         })
     }
 
@@ -543,23 +639,43 @@ impl DocumentParser {
         Ok(structure)
     }
 
-    #[allow(dead_code)]
-    fn extract_contact_info(text: &str) -> ContactInfo {
+    pub fn extract_contact_info(text: &str) -> ContactInfo {
         let email_regex =
             Regex::new(r"\b[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Z|a-z]{2,}\b").unwrap();
-        let phone_regex =
-            Regex::new(r"(\+?1[-.\s]?)?\(?([0-9]{3})\)?[-.\s]?([0-9]{3})[-.\s]?([0-9]{4})")
-                .unwrap();
+        let phone_regex = Regex::new(PHONE_PATTERN).unwrap();
         let linkedin_regex =
             Regex::new(r"(?:linkedin\.com/in/|linkedin\.com/pub/)([A-Za-z0-9-]+)").unwrap();
+        let address_regex = Regex::new(ADDRESS_PATTERN).unwrap();
 
         ContactInfo {
+            name: Self::extract_name(text),
             email: email_regex.find(text).map(|m| m.as_str().to_string()),
             phone: phone_regex.find(text).map(|m| m.as_str().to_string()),
             linkedin: linkedin_regex.find(text).map(|m| m.as_str().to_string()),
+            address: address_regex
+                .find(text)
+                .map(|m| m.as_str().trim().to_string()),
         }
     }
 
+    /// The candidate's name is normally the first line: two to four capitalized words with no
+    /// digits or contact details
+    fn extract_name(text: &str) -> Option<String> {
+        let first_line = text.lines().map(str::trim).find(|line| !line.is_empty())?;
+        let words: Vec<&str> = first_line.split_whitespace().collect();
+
+        let looks_like_name = (2..=4).contains(&words.len())
+            && !Self::is_section_header(first_line)
+            && words.iter().all(|word| {
+                word.chars().next().is_some_and(char::is_uppercase)
+                    && word
+                        .chars()
+                        .all(|c| c.is_alphabetic() || matches!(c, '.' | '\'' | '-'))
+            });
+
+        looks_like_name.then(|| first_line.to_string())
+    }
+
     #[allow(dead_code)]
     fn extract_section(content: &str, section_headers: &[&str]) -> Option<String> {
         let _content_lower = content.to_lowercase();
@@ -665,11 +781,12 @@ impl ResumeStructure {
 }
 
 #[derive(Debug, Clone, Default)]
-#[allow(dead_code)]
 pub struct ContactInfo {
+    pub name: Option<String>,
     pub email: Option<String>,
     pub phone: Option<String>,
     pub linkedin: Option<String>,
+    pub address: Option<String>,
 }
 
 impl DocumentParser {
@@ -680,6 +797,137 @@ impl DocumentParser {
             .count()
     }
 
+    /// Compare resume length with the expected page range for the detected experience level.
+    /// Uses the page count from the file metadata when available, otherwise estimates it from
+    /// the word count.
+    pub fn analyze_length(
+        content: &str,
+        pages: Option<u32>,
+        thresholds: &ExperienceLevelThresholds,
+    ) -> LengthAnalysis {
+        let word_count = Self::count_words(content);
+        let (page_count, page_count_estimated) = match pages {
+            Some(pages) if pages > 0 => (pages, false),
+            _ => (
+                (word_count.saturating_add(WORDS_PER_PAGE - 1) / WORDS_PER_PAGE).max(1) as u32,
+                true,
+            ),
+        };
+
+        let experience_level =
+            Self::detect_experience_level(content, Utc::now().date_naive(), thresholds);
+        let (recommended_min_pages, recommended_max_pages) = match experience_level {
+            "entry" => (1, 1),
+            "mid" => (1, 2),
+            _ => (2, 3),
+        };
+
+        let verdict = if page_count > recommended_max_pages {
+            LengthVerdict::TooLong
+        } else if page_count < recommended_min_pages || word_count < MIN_RESUME_WORDS {
+            LengthVerdict::TooShort
+        } else {
+            LengthVerdict::WithinRange
+        };
+
+        let range = if recommended_min_pages == recommended_max_pages {
+            format!("{} page", recommended_min_pages)
+        } else {
+            format!("{}-{} pages", recommended_min_pages, recommended_max_pages)
+        };
+        let message = match verdict {
+            LengthVerdict::TooLong => format!(
+                "Resume is {} pages; {}-level resumes should be {}",
+                page_count, experience_level, range
+            ),
+            LengthVerdict::TooShort => format!(
+                "Resume is too short ({} words, {} page(s)); {}-level resumes should fill {}",
+                word_count, page_count, experience_level, range
+            ),
+            LengthVerdict::WithinRange => format!(
+                "Resume length ({} page(s)) fits the recommended {} for {} level",
+                page_count, range, experience_level
+            ),
+        };
+
+        LengthAnalysis {
+            word_count,
+            page_count,
+            page_count_estimated,
+            experience_level: experience_level.to_string(),
+            recommended_min_pages,
+            recommended_max_pages,
+            verdict,
+            message,
+        }
+    }
+
+    /// Rough experience level from stated years of experience, tenure since the earliest dated
+    /// role and seniority terms in the resume
+    fn detect_experience_level(
+        content: &str,
+        today: NaiveDate,
+        thresholds: &ExperienceLevelThresholds,
+    ) -> &'static str {
+        let content_lower = content.to_lowercase();
+
+        let stated_years = STATED_EXPERIENCE_YEARS
+            .captures_iter(&content_lower)
+            .filter_map(|cap| cap[1].parse::<u32>().ok())
+            .max();
+
+        // Degree dates are not work experience, so skip everything under an education heading
+        let mut in_education = false;
+        let role_lines = content.lines().filter(|line| {
+            if Self::is_section_header(line) {
+                let header = line.to_lowercase();
+                in_education = header.contains("education") || header.contains("academic");
+            }
+            !in_education
+        });
+        let tenure_years = crate::level_fit::career_span_years(role_lines, today);
+
+        let years_of_experience = stated_years.unwrap_or(tenure_years);
+        let years_level = SeniorityLevel::from_years(years_of_experience, thresholds);
+        // Whole-word terms from the shared ladder, so "leadership" or "internal" don't count
+        let term_level = crate::level_fit::term_seniority(&content_lower).map(|(level, _)| level);
+
+        if years_level >= SeniorityLevel::Senior || term_level >= Some(SeniorityLevel::Senior) {
+            "senior"
+        } else if years_level == SeniorityLevel::Mid {
+            "mid"
+        } else if years_of_experience > 0 || term_level == Some(SeniorityLevel::Entry) {
+            "entry"
+        } else {
+            "mid"
+        }
+    }
+
+    /// Surface a length problem alongside the other document issues
+    fn record_length_issue(length: &LengthAnalysis, metrics: &mut DocumentQualityMetrics) {
+        if length.verdict == LengthVerdict::WithinRange {
+            return;
+        }
+
+        let suggestion = match length.verdict {
+            LengthVerdict::TooLong => {
+                "Trim older or less relevant roles and condense bullet points"
+            }
+            _ => "Expand on achievements, projects and skills relevant to the role",
+        };
+        metrics.issues.push(DocumentIssue {
+            issue_type: DocumentIssueType::Content,
+            description: length.message.clone(),
+            severity: IssueSeverity::Medium,
+            location: None,
+            suggestion: Some(suggestion.to_string()),
+        });
+        metrics.recommendations.push(format!(
+            "Aim for {}-{} page(s) at the {} level",
+            length.recommended_min_pages, length.recommended_max_pages, length.experience_level
+        ));
+    }
+
     /// Extract document metadata based on file type
     async fn extract_metadata(
         content: &[u8],
@@ -815,7 +1063,7 @@ impl DocumentParser {
                         "dc:subject" => metadata.subject = Some(text),
                         "dc:description" => {} // Could be used for summary
                         "cp:keywords" => metadata.keywords = Some(text),
-                        "cp:lastModifiedBy" => {} // Additional author info
+                        "cp:lastModifiedBy" => metadata.last_modified_by = Some(text),
                         "dcterms:created" => {
                             if let Ok(date) = dateparser::parse(&text) {
                                 metadata.creation_date = Some(date);
@@ -862,6 +1110,7 @@ impl DocumentParser {
                     let text = e.unescape().unwrap_or_default().to_string();
                     match current_element.as_str() {
                         "Application" => metadata.creator = Some(text),
+                        "Template" => metadata.template = Some(text),
                         "Pages" => {
                             if let Ok(pages) = text.parse::<u32>() {
                                 metadata.pages = Some(pages);
@@ -1591,8 +1840,169 @@ impl DocumentParser {
             creator: None,
             pages: None,
             language: None,
+            last_modified_by: None,
+            template: None,
+        }
+    }
+
+    /// Flag file metadata that names someone other than the candidate or gives away the
+    /// template or site the resume was built with
+    pub fn detect_metadata_leaks(metadata: &DocumentMetadata, text: &str) -> Vec<DocumentIssue> {
+        let mut issues = Vec::new();
+
+        if let Some(candidate) = Self::extract_name(text) {
+            let people = [
+                ("author", &metadata.author),
+                ("last modified by", &metadata.last_modified_by),
+            ];
+            for (field, value) in people {
+                let Some(person) = value.as_deref().map(str::trim).filter(|v| !v.is_empty()) else {
+                    continue;
+                };
+                if !names_match(person, &candidate) {
+                    issues.push(metadata_issue(
+                        field,
+                        format!(
+                            "Document {} '{}' does not match the name on the resume ({})",
+                            field, person, candidate
+                        ),
+                    ));
+                }
+            }
+        }
+
+        let sources = [
+            ("title", &metadata.title),
+            ("template", &metadata.template),
+            ("creator", &metadata.creator),
+            ("producer", &metadata.producer),
+        ];
+        for (field, value) in sources {
+            let Some(value) = value.as_deref().map(str::trim).filter(|v| !v.is_empty()) else {
+                continue;
+            };
+            let is_default_template = field == "template"
+                && value
+                    .to_lowercase()
+                    .trim_end_matches(['m', 'x'])
+                    .ends_with("normal.dot");
+            let reveals_template =
+                !is_default_template && (field == "template" || TEMPLATE_SOURCE.is_match(value));
+            if reveals_template {
+                issues.push(metadata_issue(
+                    field,
+                    format!("Document {} '{}' reveals a template source", field, value),
+                ));
+            }
+        }
+
+        issues
+    }
+
+    /// Copy of a PDF or DOCX with its author, title, edit history and template properties
+    /// removed; the document's content is left untouched
+    pub fn scrub_metadata(content: &[u8], file_type: &str) -> Result<Vec<u8>> {
+        match file_type {
+            "pdf" => Self::scrub_pdf_metadata(content),
+            "docx" => Self::scrub_docx_metadata(content),
+            _ => Err(anyhow!(
+                "Metadata scrubbing is not supported for {} files",
+                file_type
+            )),
         }
     }
+
+    fn scrub_pdf_metadata(content: &[u8]) -> Result<Vec<u8>> {
+        let mut doc =
+            lopdf::Document::load_mem(content).map_err(|e| anyhow!("Failed to open PDF: {}", e))?;
+        doc.trailer.remove(b"Info");
+        if let Ok(catalog) = doc.catalog_mut() {
+            // XMP metadata repeats the document information in XML
+            catalog.remove(b"Metadata");
+        }
+        doc.prune_objects();
+
+        let mut scrubbed = Vec::new();
+        doc.save_to(&mut scrubbed)
+            .map_err(|e| anyhow!("Failed to write scrubbed PDF: {}", e))?;
+        Ok(scrubbed)
+    }
+
+    fn scrub_docx_metadata(content: &[u8]) -> Result<Vec<u8>> {
+        let mut archive = ZipArchive::new(Cursor::new(content))
+            .map_err(|e| anyhow!("Failed to open DOCX file as ZIP archive: {}", e))?;
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+
+        for index in 0..archive.len() {
+            let mut file = archive.by_index(index)?;
+            let replacement = match file.name() {
+                "docProps/core.xml" => Some(EMPTY_DOCX_CORE_PROPERTIES.to_string()),
+                "docProps/app.xml" => {
+                    let mut xml = String::new();
+                    file.read_to_string(&mut xml)?;
+                    Some(
+                        DOCX_IDENTIFYING_APP_PROPERTIES
+                            .replace_all(&xml, "")
+                            .into_owned(),
+                    )
+                }
+                _ => None,
+            };
+            match replacement {
+                Some(xml) => {
+                    let options = FileOptions::default().compression_method(file.compression());
+                    writer.start_file(file.name().to_string(), options)?;
+                    writer.write_all(xml.as_bytes())?;
+                }
+                None => writer.raw_copy_file(file)?,
+            }
+        }
+
+        Ok(writer.finish()?.into_inner())
+    }
+}
+
+/// Producers and titles that give away a resume builder or template
+static TEMPLATE_SOURCE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)\btemplates?\b|\b(?:canva|zety|resume\.io|novoresume|kickresume|enhancv|resume\s*genius|resumelab|livecareer|my\s*perfect\s*resume)\b",
+    )
+    .expect("valid template source pattern")
+});
+
+/// Extended DOCX properties that name a person, organization or template
+static DOCX_IDENTIFYING_APP_PROPERTIES: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"<(?:Template|Company|Manager)\s*/>|<Template>[^<]*</Template>|<Company>[^<]*</Company>|<Manager>[^<]*</Manager>",
+    )
+    .expect("valid DOCX app properties pattern")
+});
+
+const EMPTY_DOCX_CORE_PROPERTIES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<cp:coreProperties xmlns:cp="http://schemas.openxmlformats.org/package/2006/metadata/core-properties" xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:dcterms="http://purl.org/dc/terms/" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance"></cp:coreProperties>"#;
+
+/// Whether two names refer to the same person: every word of the shorter name appears in the
+/// longer one, so "Jane Smith" matches "Jane A. Smith"
+fn names_match(a: &str, b: &str) -> bool {
+    let words = |name: &str| -> Vec<String> {
+        name.split(|c: char| !c.is_alphabetic())
+            .filter(|word| word.chars().count() > 1)
+            .map(str::to_lowercase)
+            .collect()
+    };
+    let (a, b) = (words(a), words(b));
+    let (shorter, longer) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+    !shorter.is_empty() && shorter.iter().all(|word| longer.contains(word))
+}
+
+fn metadata_issue(field: &str, description: String) -> DocumentIssue {
+    DocumentIssue {
+        issue_type: DocumentIssueType::Metadata,
+        description,
+        severity: IssueSeverity::Medium,
+        location: Some(format!("metadata: {}", field)),
+        suggestion: Some("Scrub the document metadata before sending the file".to_string()),
+    }
 }
 
 #[cfg(test)]
@@ -1647,7 +2057,12 @@ Requirements:
     #[tokio::test]
     async fn test_parse_text_content() {
         let content = SAMPLE_RESUME_TEXT.as_bytes();
-        let result = DocumentParser::parse_content(content, "resume.txt").await;
+        let result = DocumentParser::parse_content(
+            content,
+            "resume.txt",
+            &ExperienceLevelThresholds::default(),
+        )
+        .await;
 
         assert!(result.is_ok());
         let doc_info = result.unwrap();
@@ -1845,14 +2260,20 @@ Computer Science Degree
     #[tokio::test]
     async fn test_empty_and_whitespace_content() {
         // Test empty content
-        let empty_result = DocumentParser::parse_content(b"", "empty.txt").await;
+        let empty_result =
+            DocumentParser::parse_content(b"", "empty.txt", &ExperienceLevelThresholds::default())
+                .await;
         assert!(empty_result.is_ok());
         let doc = empty_result.unwrap();
         assert!(doc.content.is_empty());
 
         // Test whitespace-only content
-        let whitespace_result =
-            DocumentParser::parse_content(b"   \n\n   \t  ", "whitespace.txt").await;
+        let whitespace_result = DocumentParser::parse_content(
+            b"   \n\n   \t  ",
+            "whitespace.txt",
+            &ExperienceLevelThresholds::default(),
+        )
+        .await;
         assert!(whitespace_result.is_ok());
         let doc = whitespace_result.unwrap();
         assert!(doc.content.trim().is_empty());
@@ -1863,7 +2284,12 @@ Computer Science Degree
     #[tokio::test]
     async fn test_enhanced_document_info_structure() {
         let content = SAMPLE_RESUME_TEXT.as_bytes();
-        let result = DocumentParser::parse_content(content, "resume.txt").await;
+        let result = DocumentParser::parse_content(
+            content,
+            "resume.txt",
+            &ExperienceLevelThresholds::default(),
+        )
+        .await;
 
         assert!(result.is_ok());
         let doc_info = result.unwrap();
@@ -2099,4 +2525,228 @@ Summary
 
         assert!(bad_score < score);
     }
+
+    fn entry_level_resume(bullet_count: usize) -> String {
+        let mut resume = String::from(
+            "Jane Smith\njane.smith@email.com\n\nSUMMARY\nRecent graduate looking for an entry level software role\n\nEXPERIENCE\nSoftware Engineering Intern - Acme Corp (2024 - 2025)\n",
+        );
+        for i in 0..bullet_count {
+            resume.push_str(&format!(
+                "• Built feature {} for the internal dashboard using Python and React, writing unit tests and documentation for the team\n",
+                i + 1
+            ));
+        }
+        resume.push_str("\nEDUCATION\nBachelor of Computer Science - State University (2025)\n");
+        resume
+    }
+
+    #[test]
+    fn test_length_flags_three_page_entry_level_resume() {
+        let resume = entry_level_resume(70);
+        let analysis =
+            DocumentParser::analyze_length(&resume, None, &ExperienceLevelThresholds::default());
+
+        assert_eq!(analysis.experience_level, "entry");
+        assert!(analysis.page_count_estimated);
+        assert_eq!(analysis.page_count, 3);
+        assert_eq!(analysis.verdict, LengthVerdict::TooLong);
+        assert_eq!(
+            (
+                analysis.recommended_min_pages,
+                analysis.recommended_max_pages
+            ),
+            (1, 1)
+        );
+
+        // A page count from the file metadata takes precedence over the estimate
+        let from_metadata = DocumentParser::analyze_length(
+            &entry_level_resume(20),
+            Some(3),
+            &ExperienceLevelThresholds::default(),
+        );
+        assert!(!from_metadata.page_count_estimated);
+        assert_eq!(from_metadata.verdict, LengthVerdict::TooLong);
+    }
+
+    #[test]
+    fn test_length_accepts_one_page_entry_level_resume() {
+        let resume = entry_level_resume(20);
+        let analysis =
+            DocumentParser::analyze_length(&resume, None, &ExperienceLevelThresholds::default());
+
+        assert_eq!(analysis.experience_level, "entry");
+        assert_eq!(analysis.page_count, 1);
+        assert_eq!(analysis.verdict, LengthVerdict::WithinRange);
+    }
+
+    #[tokio::test]
+    async fn test_long_resume_reported_as_document_issue() {
+        let resume = entry_level_resume(70);
+        let info = DocumentParser::parse_content(
+            resume.as_bytes(),
+            "resume.txt",
+            &ExperienceLevelThresholds::default(),
+        )
+        .await
+        .unwrap();
+
+        let length = info.length_analysis.unwrap();
+        assert_eq!(length.word_count, info.word_count);
+        assert_eq!(length.verdict, LengthVerdict::TooLong);
+        assert!(info
+            .quality_metrics
+            .unwrap()
+            .issues
+            .iter()
+            .any(|issue| issue.description == length.message));
+    }
+
+    #[test]
+    fn test_validate_upload_rejects_oversized_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("resume.txt");
+        std::fs::write(&path, vec![b'a'; 1024 * 1024 + 1]).unwrap();
+
+        let error = DocumentParser::validate_upload(&path, 1).unwrap_err();
+
+        assert!(error.to_string().contains("over the 1 MB upload limit"));
+        assert!(DocumentParser::validate_upload(&path, 2).is_ok());
+    }
+
+    #[test]
+    fn test_validate_upload_rejects_unsupported_or_mislabeled_types() {
+        let dir = tempfile::tempdir().unwrap();
+        let executable = dir.path().join("resume.exe");
+        std::fs::write(&executable, b"MZ").unwrap();
+        let renamed = dir.path().join("resume.pdf");
+        std::fs::write(&renamed, b"plain text pretending to be a PDF").unwrap();
+
+        let error =
+            DocumentParser::validate_upload(&executable, DEFAULT_MAX_UPLOAD_SIZE_MB).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("Unsupported file type 'resume.exe'"));
+        let error =
+            DocumentParser::validate_upload(&renamed, DEFAULT_MAX_UPLOAD_SIZE_MB).unwrap_err();
+        assert!(error.to_string().contains("not a valid .pdf document"));
+    }
+
+    fn docx_fixture(body_lines: &[&str], core_properties: &str) -> Vec<u8> {
+        let paragraphs: String = body_lines
+            .iter()
+            .map(|line| format!("<w:p><w:r><w:t>{}</w:t></w:r></w:p>", line))
+            .collect();
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        writer
+            .start_file("word/document.xml", FileOptions::default())
+            .unwrap();
+        write!(
+            writer,
+            r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>{}</w:body></w:document>"#,
+            paragraphs
+        )
+        .unwrap();
+        writer
+            .start_file("docProps/core.xml", FileOptions::default())
+            .unwrap();
+        write!(
+            writer,
+            r#"<cp:coreProperties xmlns:cp="http://schemas.openxmlformats.org/package/2006/metadata/core-properties" xmlns:dc="http://purl.org/dc/elements/1.1/">{}</cp:coreProperties>"#,
+            core_properties
+        )
+        .unwrap();
+        writer.finish().unwrap().into_inner()
+    }
+
+    #[tokio::test]
+    async fn test_metadata_author_different_from_resume_name_is_flagged() {
+        let docx = docx_fixture(
+            &["Jane Smith", "Software Engineer", "jane.smith@email.com"],
+            "<dc:title>Modern Resume Template</dc:title><dc:creator>Mark Jones</dc:creator>",
+        );
+
+        let info = DocumentParser::parse_content(
+            &docx,
+            "resume.docx",
+            &ExperienceLevelThresholds::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(info.metadata.author.as_deref(), Some("Mark Jones"));
+        let leaks: Vec<&DocumentIssue> = info
+            .quality_metrics
+            .as_ref()
+            .unwrap()
+            .issues
+            .iter()
+            .filter(|issue| matches!(issue.issue_type, DocumentIssueType::Metadata))
+            .collect();
+        assert_eq!(leaks.len(), 2);
+        assert!(leaks[0].description.contains("'Mark Jones'"));
+        assert_eq!(leaks[0].location.as_deref(), Some("metadata: author"));
+        assert_eq!(leaks[1].location.as_deref(), Some("metadata: title"));
+
+        let scrubbed = DocumentParser::scrub_metadata(&docx, "docx").unwrap();
+        let info = DocumentParser::parse_content(
+            &scrubbed,
+            "resume.docx",
+            &ExperienceLevelThresholds::default(),
+        )
+        .await
+        .unwrap();
+        assert!(info.content.starts_with("Jane Smith"));
+        assert_eq!(info.metadata.author, None);
+        assert_eq!(info.metadata.title, None);
+    }
+
+    #[test]
+    fn test_experience_level_matches_whole_words() {
+        let today = NaiveDate::from_ymd_opt(2026, 6, 1).unwrap();
+        let partial_words = "Alex Kim\n\nEXPERIENCE\nAnalyst - Acme Corp\n\u{2022} Showed leadership on internal reporting tools\n";
+        assert_eq!(
+            DocumentParser::detect_experience_level(
+                partial_words,
+                today,
+                &ExperienceLevelThresholds::default()
+            ),
+            "mid"
+        );
+
+        let senior_title = "Alex Kim\n\nEXPERIENCE\nSenior Analyst - Acme Corp\n";
+        assert_eq!(
+            DocumentParser::detect_experience_level(
+                senior_title,
+                today,
+                &ExperienceLevelThresholds::default()
+            ),
+            "senior"
+        );
+
+        let intern_title = "Alex Kim\n\nEXPERIENCE\nData Intern - Acme Corp\n";
+        assert_eq!(
+            DocumentParser::detect_experience_level(
+                intern_title,
+                today,
+                &ExperienceLevelThresholds::default()
+            ),
+            "entry"
+        );
+    }
+
+    #[test]
+    fn test_experience_level_counts_dated_roles_only() {
+        let today = NaiveDate::from_ymd_opt(2026, 6, 1).unwrap();
+        let resume = "Alex Kim\n\nEXPERIENCE\nAnalyst - Acme Corp | Jan 2025 - Present\n\
+                      \u{2022} Gave a presentation on the reporting tools currently in use\n\n\
+                      EDUCATION\nBS Economics, State University, 2005 - 2009\n";
+
+        assert_eq!(
+            DocumentParser::detect_experience_level(
+                resume,
+                today,
+                &ExperienceLevelThresholds::default()
+            ),
+            "entry"
+        );
+    }
 }
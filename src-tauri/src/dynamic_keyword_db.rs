@@ -1,14 +1,17 @@
-use anyhow::Result;
+use anyhow::{bail, Result};
 use chrono::{DateTime, Utc};
 use log::{info, warn};
 use serde::{Deserialize, Serialize};
-use sqlx::Row;
+use sqlx::{Row, SqliteConnection};
 use std::collections::HashMap;
 use tokio::time::{interval, Duration};
 
 use crate::database::Database;
 use crate::ollama::OllamaClient;
 
+/// Days after which a trend score counts for half as much
+pub const DEFAULT_TREND_HALF_LIFE_DAYS: f64 = 30.0;
+
 /// Dynamic keyword database that learns and adapts in real-time
 pub struct DynamicKeywordDatabase {
     database: Database,
@@ -28,6 +31,8 @@ pub struct DynamicKeywordDatabase {
     #[allow(dead_code)]
     max_keywords_per_industry: usize,
     last_full_update: DateTime<Utc>,
+    /// Days after which a trend score counts for half as much
+    trend_half_life_days: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -68,6 +73,34 @@ pub struct TrendingKeywordData {
     pub decline_prediction: Option<DateTime<Utc>>,
     pub adoption_stage: AdoptionStage,
     pub industries: Vec<String>,
+    /// When the trend was last reported or confirmed by user feedback
+    #[serde(default = "Utc::now")]
+    pub last_updated: DateTime<Utc>,
+}
+
+impl TrendingKeywordData {
+    /// Trend score halved for every `half_life_days` since the last update, so stale spikes fade
+    pub fn decayed_trend_score(&self, now: DateTime<Utc>, half_life_days: f64) -> f64 {
+        if half_life_days <= 0.0 {
+            return self.trend_score;
+        }
+        let age_days = (now - self.last_updated).num_seconds().max(0) as f64 / 86_400.0;
+        self.trend_score * 0.5_f64.powf(age_days / half_life_days)
+    }
+}
+
+/// Order trending keywords by decayed score, highest first
+pub fn rank_trending_keywords<'a>(
+    keywords: impl IntoIterator<Item = &'a TrendingKeywordData>,
+    now: DateTime<Utc>,
+    half_life_days: f64,
+) -> Vec<&'a TrendingKeywordData> {
+    let mut ranked: Vec<_> = keywords
+        .into_iter()
+        .map(|keyword| (keyword.decayed_trend_score(now, half_life_days), keyword))
+        .collect();
+    ranked.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+    ranked.into_iter().map(|(_, keyword)| keyword).collect()
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -184,6 +217,7 @@ impl DynamicKeywordDatabase {
             confidence_threshold: 0.6,
             max_keywords_per_industry: 500,
             last_full_update: Utc::now() - chrono::Duration::hours(24), // Force initial update
+            trend_half_life_days: DEFAULT_TREND_HALF_LIFE_DAYS,
         };
 
         // Initialize database tables
@@ -199,6 +233,12 @@ impl DynamicKeywordDatabase {
         Ok(db)
     }
 
+    /// Fade trending keyword scores with a half-life of `days` instead of the default
+    pub fn with_trend_half_life(mut self, days: f64) -> Self {
+        self.trend_half_life_days = days;
+        self
+    }
+
     async fn initialize_database_schema(&self) -> Result<()> {
         info!("Initializing dynamic keyword database schema");
 
@@ -288,6 +328,23 @@ impl DynamicKeywordDatabase {
         .execute(self.database.get_pool())
         .await?;
 
+        // Raw user feedback, kept so imported datasets can be audited and replayed
+        sqlx::query(
+            "
+            CREATE TABLE IF NOT EXISTS keyword_feedback (
+                id TEXT PRIMARY KEY,
+                keyword TEXT NOT NULL,
+                industry TEXT NOT NULL,
+                rating INTEGER NOT NULL,
+                comment TEXT,
+                context TEXT,
+                submitted_at TEXT NOT NULL
+            );
+        ",
+        )
+        .execute(self.database.get_pool())
+        .await?;
+
         // Create indexes for performance
         sqlx::query("CREATE INDEX IF NOT EXISTS idx_dynamic_keywords_industry ON dynamic_keywords(industry);")
             .execute(self.database.get_pool()).await?;
@@ -388,6 +445,10 @@ impl DynamicKeywordDatabase {
                     &row.get::<String, _>("industries"),
                 )
                 .unwrap_or_default(),
+                last_updated: row
+                    .get::<Option<String>, _>("last_updated")
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or_else(Utc::now),
             };
 
             self.trending_keywords.insert(keyword, trending_data);
@@ -814,14 +875,13 @@ Focus on practical, current skill relationships for 2024-2025."#,
             .unwrap_or_default())
     }
 
-    /// Get trending keywords across all industries
+    /// Get trending keywords across all industries, ranked by age-decayed trend score
     pub fn get_trending_keywords(&self, limit: Option<usize>) -> Vec<&TrendingKeywordData> {
-        let mut trending: Vec<_> = self.trending_keywords.values().collect();
-        trending.sort_by(|a, b| {
-            b.trend_score
-                .partial_cmp(&a.trend_score)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
+        let mut trending = rank_trending_keywords(
+            self.trending_keywords.values(),
+            Utc::now(),
+            self.trend_half_life_days,
+        );
 
         if let Some(limit) = limit {
             trending.truncate(limit);
@@ -830,6 +890,24 @@ Focus on practical, current skill relationships for 2024-2025."#,
         trending
     }
 
+    /// Current demand score (0.0 to 1.0) for every skill with market data, keyed by lowercase
+    /// skill name
+    pub async fn load_market_demand_scores(database: &Database) -> Result<HashMap<String, f64>> {
+        let rows = sqlx::query("SELECT skill, demand_score FROM market_demand_data")
+            .fetch_all(database.get_pool())
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                (
+                    row.get::<String, _>("skill").to_lowercase(),
+                    row.get::<f64, _>("demand_score"),
+                )
+            })
+            .collect())
+    }
+
     /// Get market demand data for a specific skill
     pub async fn get_market_demand(&self, skill: &str) -> Result<Option<MarketDemandData>> {
         let rows = sqlx::query(
@@ -900,6 +978,56 @@ Focus on practical, current skill relationships for 2024-2025."#,
             keyword, industry
         );
 
+        let mut conn = self.database.get_pool().acquire().await?;
+        Self::apply_feedback(&mut conn, &feedback).await?;
+        drop(conn);
+
+        // Reload cached data to reflect changes
+        self.load_cached_data().await?;
+
+        Ok(())
+    }
+
+    /// Apply many feedback entries in one transaction. Entries that fail validation are reported
+    /// by position and skipped; a database error rolls back the whole batch.
+    pub async fn add_user_feedback_batch(
+        &mut self,
+        entries: Vec<UserFeedback>,
+    ) -> Result<FeedbackBatchResult> {
+        info!("Adding batch of {} keyword feedback entries", entries.len());
+
+        let mut result = FeedbackBatchResult::default();
+        let mut tx = self.database.get_pool().begin().await?;
+        for (index, feedback) in entries.iter().enumerate() {
+            if let Err(e) = feedback.validate() {
+                result.errors.push(FeedbackItemError {
+                    index,
+                    keyword: feedback.keyword.clone(),
+                    error: e.to_string(),
+                });
+                continue;
+            }
+            Self::apply_feedback(&mut tx, feedback).await?;
+            result.accepted += 1;
+        }
+        tx.commit().await?;
+
+        if !result.errors.is_empty() {
+            warn!(
+                "Skipped {} invalid keyword feedback entries",
+                result.errors.len()
+            );
+        }
+
+        // Reload cached data once for the whole batch
+        self.load_cached_data().await?;
+
+        Ok(result)
+    }
+
+    async fn apply_feedback(conn: &mut SqliteConnection, feedback: &UserFeedback) -> Result<()> {
+        let now = Utc::now().to_rfc3339();
+
         // Update confidence scores based on feedback
         let confidence_adjustment = match feedback.rating {
             5 => 0.1,
@@ -919,14 +1047,35 @@ Focus on practical, current skill relationships for 2024-2025."#,
         ",
         )
         .bind(confidence_adjustment)
-        .bind(Utc::now().to_rfc3339())
-        .bind(keyword)
-        .bind(industry)
-        .execute(self.database.get_pool())
+        .bind(&now)
+        .bind(&feedback.keyword)
+        .bind(&feedback.industry)
+        .execute(&mut *conn)
         .await?;
 
-        // Reload cached data to reflect changes
-        self.load_cached_data().await?;
+        // Fresh feedback shows the keyword is still in use, so restart its trend decay
+        sqlx::query("UPDATE trending_keywords SET last_updated = ? WHERE keyword = ?")
+            .bind(&now)
+            .bind(&feedback.keyword)
+            .execute(&mut *conn)
+            .await?;
+
+        sqlx::query(
+            "
+            INSERT INTO keyword_feedback
+            (id, keyword, industry, rating, comment, context, submitted_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+        ",
+        )
+        .bind(uuid::Uuid::new_v4().to_string())
+        .bind(&feedback.keyword)
+        .bind(&feedback.industry)
+        .bind(feedback.rating as i64)
+        .bind(&feedback.comment)
+        .bind(&feedback.context)
+        .bind(&now)
+        .execute(&mut *conn)
+        .await?;
 
         Ok(())
     }
@@ -960,6 +1109,35 @@ pub struct UserFeedback {
     pub context: Option<String>, // Where this keyword was found
 }
 
+impl UserFeedback {
+    pub fn validate(&self) -> Result<()> {
+        if self.keyword.trim().is_empty() {
+            bail!("keyword must not be empty");
+        }
+        if self.industry.trim().is_empty() {
+            bail!("industry must not be empty");
+        }
+        if !(1..=5).contains(&self.rating) {
+            bail!("rating {} is outside the 1-5 scale", self.rating);
+        }
+        Ok(())
+    }
+}
+
+/// One feedback entry rejected from a batch, identified by its position in the request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeedbackItemError {
+    pub index: usize,
+    pub keyword: String,
+    pub error: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FeedbackBatchResult {
+    pub accepted: usize,
+    pub errors: Vec<FeedbackItemError>,
+}
+
 impl DynamicKeywordDatabase {
     /// Creates a new DynamicKeywordDatabase instance with default configuration
     /// Note: This replaces the Default trait implementation to avoid blocking operations
@@ -986,7 +1164,8 @@ impl DynamicKeywordDatabase {
             // Enhance with trending data
             if let Some(trending) = self.trending_keywords.get(&keyword_match.keyword) {
                 // Boost confidence for trending keywords
-                let trend_boost = trending.trend_score * 0.1;
+                let trend_boost =
+                    trending.decayed_trend_score(Utc::now(), self.trend_half_life_days) * 0.1;
                 keyword_match.confidence_score =
                     (keyword_match.confidence_score + trend_boost).min(1.0);
 
@@ -1020,3 +1199,81 @@ impl DynamicKeywordDatabase {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trending(
+        keyword: &str,
+        trend_score: f64,
+        last_updated: DateTime<Utc>,
+    ) -> TrendingKeywordData {
+        TrendingKeywordData {
+            keyword: keyword.to_string(),
+            trend_score,
+            velocity: 0.0,
+            momentum: 0.0,
+            peak_prediction: None,
+            decline_prediction: None,
+            adoption_stage: AdoptionStage::Growing,
+            industries: vec!["technology".to_string()],
+            last_updated,
+        }
+    }
+
+    #[test]
+    fn test_recent_trend_outranks_stale_spike() {
+        let now = Utc::now();
+        let stale = trending("blockchain", 0.95, now - chrono::Duration::days(120));
+        let recent = trending("rust", 0.6, now - chrono::Duration::days(2));
+
+        // Without decay the stale spike still leads
+        let undecayed = rank_trending_keywords([&stale, &recent], now, 0.0);
+        assert_eq!(undecayed[0].keyword, "blockchain");
+
+        let ranked = rank_trending_keywords([&stale, &recent], now, DEFAULT_TREND_HALF_LIFE_DAYS);
+        assert_eq!(ranked[0].keyword, "rust");
+        // Four half-lives leave a sixteenth of the original score
+        let decayed = stale.decayed_trend_score(now, DEFAULT_TREND_HALF_LIFE_DAYS);
+        assert!((decayed - 0.95 / 16.0).abs() < 1e-3, "{}", decayed);
+    }
+
+    fn feedback(keyword: &str, rating: u8) -> UserFeedback {
+        UserFeedback {
+            keyword: keyword.to_string(),
+            industry: "technology".to_string(),
+            rating,
+            comment: None,
+            context: Some("market dataset".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_feedback_batch_keeps_valid_entries_and_reports_invalid() {
+        let database = Database::new_with_url("sqlite::memory:").await.unwrap();
+        let mut dynamic_db = DynamicKeywordDatabase::new(database.clone()).await.unwrap();
+
+        let result = dynamic_db
+            .add_user_feedback_batch(vec![
+                feedback("Kubernetes", 5),
+                feedback("Terraform", 9),
+                feedback("Rust", 4),
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(result.accepted, 2);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].index, 1);
+        assert_eq!(result.errors[0].keyword, "Terraform");
+        assert!(result.errors[0].error.contains("rating 9"));
+
+        let stored: Vec<String> =
+            sqlx::query_scalar("SELECT keyword FROM keyword_feedback ORDER BY keyword")
+                .fetch_all(database.get_pool())
+                .await
+                .unwrap();
+        assert_eq!(stored, vec!["Kubernetes", "Rust"]);
+    }
+}
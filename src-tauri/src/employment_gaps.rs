@@ -0,0 +1,388 @@
+use chrono::{Datelike, NaiveDate, Utc};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::advanced_scoring::{ExperienceEntry, SectionHeaderSynonyms};
+
+pub const DEFAULT_GAP_THRESHOLD_MONTHS: u32 = 6;
+/// Gaps that ended within this many months of today are treated as recent
+const RECENT_GAP_MONTHS: u32 = 24;
+/// Older gaps at least this long still deserve a mention
+const LONG_GAP_MONTHS: u32 = 24;
+
+static DATE_RANGE: Lazy<Regex> = Lazy::new(|| {
+    let date = r"(?:[A-Za-z]{3,9}\.?\s+\d{4}|\d{1,2}/\d{4}|\d{4})";
+    Regex::new(&format!(
+        r"(?i)\b({date})\s*(?:-|–|—|to)\s*({date}|present|current|now)\b"
+    ))
+    .expect("valid date range pattern")
+});
+
+/// A year, month name or "present", which shows a role was meant to be dated
+static DATE_HINT: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)\b(?:19|20)\d{2}\b|'\d{2}\b|\b(?:january|february|march|april|june|july|august|september|october|november|december|jan|feb|mar|apr|jun|jul|aug|sept?|oct|nov|dec)\.?(?:\s|$)|\bpresent\b",
+    )
+    .expect("valid date hint pattern")
+});
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GapSeverity {
+    Low,
+    Medium,
+    High,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmploymentGap {
+    /// First month without a listed role, as YYYY-MM
+    pub start: String,
+    /// Month the next role started, or the current month when no role follows
+    pub end: String,
+    pub months: u32,
+    pub recent: bool,
+    /// True when the resume lists no role after the gap
+    pub ongoing: bool,
+    pub severity: GapSeverity,
+    pub suggestion: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmploymentGapAnalysis {
+    pub threshold_months: u32,
+    pub gaps: Vec<EmploymentGap>,
+}
+
+/// Why an experience entry's dates cannot be used
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DateIssueKind {
+    /// The role lists no dates at all
+    Missing,
+    /// Dates are written but do not form a readable range, e.g. "Summer '19" or an end before
+    /// the start
+    Malformed,
+}
+
+/// An experience entry without a usable date range
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExperienceDateIssue {
+    pub title: String,
+    pub company: String,
+    /// Date text as the parser found it, empty when there is none
+    pub duration: String,
+    pub kind: DateIssueKind,
+    pub suggestion: String,
+}
+
+/// Flag every experience entry that lacks a date range or whose range cannot be parsed
+pub fn check_experience_dates(experience: &[ExperienceEntry]) -> Vec<ExperienceDateIssue> {
+    let now = month_index(Utc::now().date_naive());
+    experience
+        .iter()
+        .filter_map(|entry| {
+            let text = format!("{} {} {}", entry.title, entry.company, entry.duration);
+            if DATE_RANGE
+                .captures_iter(&text)
+                .any(|cap| parse_period(&cap, now).is_some())
+            {
+                return None;
+            }
+
+            let role = if entry.company.is_empty() || entry.company == "Unknown Company" {
+                entry.title.clone()
+            } else {
+                format!("{} at {}", entry.title, entry.company)
+            };
+            let (kind, suggestion) = if DATE_HINT.is_match(&text) {
+                (
+                    DateIssueKind::Malformed,
+                    format!(
+                        "Rewrite the dates for {} as \"Mon YYYY - Mon YYYY\" or \"Mon YYYY - Present\" so ATS systems can read them",
+                        role
+                    ),
+                )
+            } else {
+                (
+                    DateIssueKind::Missing,
+                    format!(
+                        "Add start and end dates to {}; ATS systems and recruiters discount undated roles",
+                        role
+                    ),
+                )
+            };
+            Some(ExperienceDateIssue {
+                title: entry.title.clone(),
+                company: entry.company.clone(),
+                duration: entry.duration.clone(),
+                kind,
+                suggestion,
+            })
+        })
+        .collect()
+}
+
+/// One dated role, counted in months since year 0
+#[derive(Debug, Clone, Copy)]
+struct Period {
+    start: u32,
+    end: u32,
+}
+
+/// Find gaps of at least `threshold_months` between consecutive roles as of today, reading
+/// the Experience section through `synonyms`
+pub fn analyze_employment_gaps(
+    content: &str,
+    synonyms: &SectionHeaderSynonyms,
+    threshold_months: u32,
+) -> EmploymentGapAnalysis {
+    analyze_employment_gaps_at(content, synonyms, threshold_months, Utc::now().date_naive())
+}
+
+pub fn analyze_employment_gaps_at(
+    content: &str,
+    synonyms: &SectionHeaderSynonyms,
+    threshold_months: u32,
+    today: NaiveDate,
+) -> EmploymentGapAnalysis {
+    let now = month_index(today);
+    let mut periods = extract_periods(content, synonyms, now);
+    periods.sort_by_key(|period| period.start);
+
+    let mut gaps = Vec::new();
+    let mut covered_until: Option<u32> = None;
+    for period in &periods {
+        if let Some(until) = covered_until {
+            if period.start > until {
+                push_gap(&mut gaps, until, period.start, false, threshold_months, now);
+            }
+        }
+        covered_until = Some(covered_until.map_or(period.end, |until| until.max(period.end)));
+    }
+    if let Some(until) = covered_until {
+        if until < now {
+            push_gap(&mut gaps, until, now, true, threshold_months, now);
+        }
+    }
+
+    EmploymentGapAnalysis {
+        threshold_months,
+        gaps,
+    }
+}
+
+fn push_gap(
+    gaps: &mut Vec<EmploymentGap>,
+    from: u32,
+    to: u32,
+    ongoing: bool,
+    threshold_months: u32,
+    now: u32,
+) {
+    let months = to - from;
+    if months < threshold_months.max(1) {
+        return;
+    }
+
+    let recent = ongoing || now.saturating_sub(to) <= RECENT_GAP_MONTHS;
+    let severity = if recent {
+        GapSeverity::High
+    } else if months >= LONG_GAP_MONTHS {
+        GapSeverity::Medium
+    } else {
+        GapSeverity::Low
+    };
+    let suggestion = if ongoing {
+        format!(
+            "No role listed for the last {} months; add current freelance work, study or \
+             projects, or explain the break in your summary",
+            months
+        )
+    } else if recent {
+        format!(
+            "Explain the recent {}-month gap with a one-line entry (caregiving, study, \
+             relocation, contract work) so recruiters are not left guessing",
+            months
+        )
+    } else {
+        format!(
+            "Consider a brief note for the {}-month gap, or list years only for older roles",
+            months
+        )
+    };
+
+    gaps.push(EmploymentGap {
+        start: format_month(from),
+        end: format_month(to),
+        months,
+        recent,
+        ongoing,
+        severity,
+        suggestion,
+    });
+}
+
+/// Date ranges from the Experience section, or from the whole resume when no section header
+/// is recognized
+fn extract_periods(content: &str, synonyms: &SectionHeaderSynonyms, now: u32) -> Vec<Period> {
+    let mut current_section: Option<&str> = None;
+    let mut experience = Vec::new();
+    let mut anywhere = Vec::new();
+
+    for line in content.lines() {
+        if let Some(section) = synonyms.section_for_header(line) {
+            current_section = Some(section);
+            continue;
+        }
+        for period in DATE_RANGE
+            .captures_iter(line)
+            .filter_map(|cap| parse_period(&cap, now))
+        {
+            anywhere.push(period);
+            if current_section == Some("Experience") {
+                experience.push(period);
+            }
+        }
+    }
+
+    if experience.is_empty() {
+        anywhere
+    } else {
+        experience
+    }
+}
+
+/// The role period a date range capture describes, if both ends parse and are in order
+fn parse_period(cap: &regex::Captures, now: u32) -> Option<Period> {
+    let start = parse_month(&cap[1], false).map(month_index)?;
+    let end = match cap[2].to_lowercase().as_str() {
+        "present" | "current" | "now" => Some(now),
+        other => parse_month(other, true).map(month_index),
+    }?;
+    (end >= start).then_some(Period { start, end })
+}
+
+/// The first date range in `text`, such as "Jan 2018 - Present"
+pub fn find_date_range(text: &str) -> Option<&str> {
+    DATE_RANGE.find(text).map(|m| m.as_str())
+}
+
+/// Start of the first date range in `text`
+pub fn date_range_start(text: &str) -> Option<NaiveDate> {
+    DATE_RANGE
+        .captures(text)
+        .and_then(|cap| parse_month(&cap[1], false))
+}
+
+/// Parse "Jan 2018", "03/2018" or "2018"; a bare year means January for a start date and
+/// December for an end date so year-only resumes do not report false gaps
+pub fn parse_month(text: &str, is_end: bool) -> Option<NaiveDate> {
+    let text = text.trim().trim_end_matches('.');
+    let normalized = if let Some((month, year)) = text.split_once('/') {
+        format!("{}-{:0>2}-01", year, month)
+    } else if let Some((month, year)) = text.split_once(char::is_whitespace) {
+        format!("1 {} {}", month.trim_end_matches('.'), year.trim())
+    } else {
+        let month = if is_end { 12 } else { 1 };
+        format!("{}-{:02}-01", text, month)
+    };
+
+    dateparser::parse_with_timezone(&normalized, &Utc)
+        .ok()
+        .map(|date| date.date_naive())
+}
+
+fn month_index(date: NaiveDate) -> u32 {
+    date.year().max(0) as u32 * 12 + date.month0()
+}
+
+fn format_month(index: u32) -> String {
+    format!("{:04}-{:02}", index / 12, index % 12 + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_two_year_gap_between_roles_is_flagged() {
+        let resume = "Jane Doe\n\
+                      jane.doe@example.com\n\n\
+                      Experience\n\
+                      Senior Engineer, Initech, Jan 2020 - Present\n\
+                      • Led the platform team\n\
+                      Engineer, Globex, Mar 2015 - Jan 2018\n\
+                      • Built billing services\n\n\
+                      Education\n\
+                      B.S. Computer Science, State University, 2011 - 2015\n";
+        let today = NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+
+        let synonyms = SectionHeaderSynonyms::default();
+
+        let analysis =
+            analyze_employment_gaps_at(resume, &synonyms, DEFAULT_GAP_THRESHOLD_MONTHS, today);
+
+        assert_eq!(analysis.gaps.len(), 1, "{:?}", analysis.gaps);
+        let gap = &analysis.gaps[0];
+        assert_eq!(gap.start, "2018-01");
+        assert_eq!(gap.end, "2020-01");
+        assert_eq!(gap.months, 24);
+        assert!(!gap.ongoing);
+        assert!(!gap.recent);
+        assert_eq!(gap.severity, GapSeverity::Medium);
+
+        // The same gap is too short to report under a longer threshold
+        assert!(analyze_employment_gaps_at(resume, &synonyms, 30, today)
+            .gaps
+            .is_empty());
+    }
+
+    #[test]
+    fn test_only_the_undated_role_is_flagged() {
+        let resume = "Jane Doe\n\
+                      jane.doe@example.com\n\n\
+                      Experience\n\
+                      Senior Engineer, Initech, Jan 2020 - Present\n\
+                      • Led the platform team\n\
+                      Engineer, Globex\n\
+                      • Built billing services\n\n\
+                      Education\n\
+                      B.S. Computer Science, State University, 2011 - 2015\n";
+        let parsed = crate::advanced_scoring::ATSSimulator::new()
+            .parse_with_multiple_systems(resume)
+            .unwrap();
+        assert_eq!(parsed.experience.len(), 2, "{:?}", parsed.experience);
+
+        let issues = check_experience_dates(&parsed.experience);
+
+        assert_eq!(issues.len(), 1, "{:?}", issues);
+        assert_eq!(issues[0].title, "Engineer", "{:?}", issues[0]);
+        assert_eq!(issues[0].company, "Globex", "{:?}", issues[0]);
+        assert_eq!(issues[0].kind, DateIssueKind::Missing);
+    }
+
+    #[test]
+    fn test_wrapped_bullet_line_is_not_a_role() {
+        let resume = "Jane Doe\n\
+                      jane.doe@example.com\n\n\
+                      Experience\n\
+                      Senior Engineer, Initech, Jan 2020 - Present\n\
+                      • Led the migration of billing to Kubernetes while keeping\n\
+                      uptime above 99.9% for all customers\n\
+                      • Mentored four engineers\n\n\
+                      Education\n\
+                      B.S. Computer Science, State University, 2011 - 2015\n";
+        let parsed = crate::advanced_scoring::ATSSimulator::new()
+            .parse_with_multiple_systems(resume)
+            .unwrap();
+
+        assert_eq!(parsed.experience.len(), 1, "{:?}", parsed.experience);
+        assert_eq!(
+            parsed.experience[0].achievements[0],
+            "Led the migration of billing to Kubernetes while keeping uptime above 99.9% for all customers"
+        );
+        assert!(check_experience_dates(&parsed.experience).is_empty());
+    }
+}
@@ -1,9 +1,15 @@
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use log::{info, warn};
+use once_cell::sync::Lazy;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
 
+use crate::advanced_scoring::KeywordAnalyzer;
 use crate::industry_analyzer::IndustryAnalysisResult;
+use crate::ollama::OllamaClient;
 use crate::semantic_analyzer::SemanticAnalysisResult;
 // Enhanced scoring types imported when needed
 
@@ -51,6 +57,211 @@ pub struct EnhancedPromptResponse {
     pub context_summary: String,
 }
 
+/// Rough token count of a prompt for the given model
+pub fn estimate_prompt_tokens(model_name: &str, text: &str) -> usize {
+    // Rough estimation: 1 token ≈ 4 characters for most models
+    let char_count = text.chars().count();
+    let estimated_tokens = (char_count as f64 / 4.0).ceil() as usize;
+
+    // Apply model-specific adjustments
+    let adjustment_factor = match model_name {
+        name if name.contains("llama") => 1.1, // Llama tends to use slightly more tokens
+        name if name.contains("mistral") => 0.9, // Mistral is more efficient
+        name if name.contains("code") => 1.2,  // Code models use more tokens for symbols
+        _ => 1.0,
+    };
+
+    (estimated_tokens as f64 * adjustment_factor) as usize
+}
+
+/// Placeholders every prompt template must contain
+pub const REQUIRED_PLACEHOLDERS: &[&str] = &["resume_content", "job_description"];
+
+/// Placeholders filled from the prompt request; any other `{name}` in a template is an error
+pub const KNOWN_PLACEHOLDERS: &[&str] = &[
+    "resume_content",
+    "job_description",
+    "industry",
+    "industry_analysis",
+    "semantic_analysis",
+    "analysis_focus",
+    "output_format",
+];
+
+/// Custom template files are `<prompt type>.txt`
+const TEMPLATE_FILE_EXTENSION: &str = "txt";
+
+static PLACEHOLDER: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\{([a-z_]+)\}").expect("valid placeholder pattern"));
+
+/// Load each `<prompt type>.txt` in `dir` as the template for that prompt type. Settings other
+/// than the text come from the built-in template of the same name when there is one.
+pub fn load_prompt_templates(dir: &Path) -> Result<HashMap<String, PromptTemplate>> {
+    let built_in = EnhancedPromptEngine::build_prompt_templates();
+    let mut templates = HashMap::new();
+
+    let entries = fs::read_dir(dir)
+        .with_context(|| format!("Failed to read prompt template directory {}", dir.display()))?;
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some(TEMPLATE_FILE_EXTENSION) {
+            continue;
+        }
+        let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        let text = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read prompt template {}", path.display()))?;
+        let template = prompt_template_from_text(name, &text, built_in.get(name))
+            .with_context(|| format!("Invalid prompt template {}", path.display()))?;
+        templates.insert(name.to_string(), template);
+    }
+
+    info!(
+        "Loaded {} custom prompt templates from {}",
+        templates.len(),
+        dir.display()
+    );
+    Ok(templates)
+}
+
+/// Build a template from raw text, checking its placeholders
+pub fn prompt_template_from_text(
+    name: &str,
+    text: &str,
+    base: Option<&PromptTemplate>,
+) -> Result<PromptTemplate> {
+    let mut variables: Vec<String> = Vec::new();
+    for cap in PLACEHOLDER.captures_iter(text) {
+        let variable = &cap[1];
+        if !KNOWN_PLACEHOLDERS.contains(&variable) {
+            bail!(
+                "unknown placeholder {{{}}}; expected one of {}",
+                variable,
+                KNOWN_PLACEHOLDERS.join(", ")
+            );
+        }
+        if !variables.iter().any(|existing| existing == variable) {
+            variables.push(variable.to_string());
+        }
+    }
+    let missing: Vec<&str> = REQUIRED_PLACEHOLDERS
+        .iter()
+        .copied()
+        .filter(|required| !variables.iter().any(|variable| variable == required))
+        .collect();
+    if !missing.is_empty() {
+        bail!(
+            "missing required placeholders: {{{}}}",
+            missing.join("}, {")
+        );
+    }
+
+    Ok(PromptTemplate {
+        name: base.map_or_else(|| name.to_string(), |base| base.name.clone()),
+        category: base.map_or_else(|| "analysis".to_string(), |base| base.category.clone()),
+        template: text.trim_end().to_string(),
+        variables,
+        // Per-model variants of a built-in prompt would override the custom text
+        model_specific: HashMap::new(),
+        context_window_size: base.map_or(4000, |base| base.context_window_size),
+        temperature: base.map_or(0.1, |base| base.temperature),
+        max_tokens: base.map_or(Some(1024), |base| base.max_tokens),
+    })
+}
+
+/// Heuristic quality signals for one model output
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromptOutputQuality {
+    pub valid_json: bool,
+    pub suggestion_count: usize,
+    /// Share of job description keywords the output mentions, 0.0 to 1.0
+    pub keyword_coverage: f64,
+    /// Combined score used to pick the better template, 0 to 100
+    pub quality_score: f64,
+}
+
+/// One template's prompt and the model's answer to it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromptRun {
+    pub template: String,
+    pub prompt_strategy: String,
+    pub estimated_tokens: usize,
+    pub output: String,
+    pub processing_time_ms: i64,
+    pub quality: PromptOutputQuality,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromptComparison {
+    pub first: PromptRun,
+    pub second: PromptRun,
+    /// Template with the higher quality score; the first wins ties
+    pub better_template: String,
+}
+
+/// Score an output on JSON validity, how many suggestions it makes and how many job keywords it
+/// covers
+pub fn evaluate_prompt_output(output: &str, job_description: &str) -> PromptOutputQuality {
+    let json = parse_json_output(output);
+    let suggestion_count = match &json {
+        Some(serde_json::Value::Object(fields)) => fields
+            .iter()
+            .filter(|(key, _)| key.contains("recommend") || key.contains("suggest"))
+            .filter_map(|(_, value)| value.as_array())
+            .map(Vec::len)
+            .sum(),
+        _ => output
+            .lines()
+            .map(str::trim_start)
+            .filter(|line| {
+                line.starts_with(['-', '*', '•'])
+                    || line.split_once(['.', ')']).is_some_and(|(number, _)| {
+                        !number.is_empty() && number.chars().all(|c| c.is_ascii_digit())
+                    })
+            })
+            .count(),
+    };
+
+    let keywords = KeywordAnalyzer::new()
+        .extract_keywords_from_job_description(job_description)
+        .unwrap_or_default();
+    let output_lower = output.to_lowercase();
+    let keyword_coverage = if keywords.is_empty() {
+        0.0
+    } else {
+        keywords
+            .iter()
+            .filter(|keyword| output_lower.contains(&keyword.to_lowercase()))
+            .count() as f64
+            / keywords.len() as f64
+    };
+
+    let quality_score = if json.is_some() { 50.0 } else { 0.0 }
+        + keyword_coverage * 30.0
+        + suggestion_count.min(10) as f64 * 2.0;
+
+    PromptOutputQuality {
+        valid_json: json.is_some(),
+        suggestion_count,
+        keyword_coverage,
+        quality_score,
+    }
+}
+
+/// The output as JSON, allowing for prose around a single top-level object
+fn parse_json_output(output: &str) -> Option<serde_json::Value> {
+    let trimmed = output.trim();
+    if let Ok(value) = serde_json::from_str(trimmed) {
+        return Some(value);
+    }
+    let start = trimmed.find('{')?;
+    let end = trimmed.rfind('}')?;
+    (start < end)
+        .then(|| serde_json::from_str(&trimmed[start..=end]).ok())
+        .flatten()
+}
+
 pub struct EnhancedPromptEngine {
     model_configs: HashMap<String, ModelConfig>,
     prompt_templates: HashMap<String, PromptTemplate>,
@@ -78,6 +289,12 @@ impl EnhancedPromptEngine {
         }
     }
 
+    /// Layer `templates`, usually loaded from the configured directory, over the built-in ones
+    pub fn with_custom_templates(mut self, templates: HashMap<String, PromptTemplate>) -> Self {
+        self.prompt_templates.extend(templates);
+        self
+    }
+
     pub fn create_enhanced_prompt(
         &self,
         request: EnhancedPromptRequest,
@@ -131,6 +348,61 @@ impl EnhancedPromptEngine {
         })
     }
 
+    /// Run the same input through two templates and compare the model's outputs
+    pub async fn compare_prompts(
+        &self,
+        client: &OllamaClient,
+        request: EnhancedPromptRequest,
+        first_template: &str,
+        second_template: &str,
+    ) -> Result<PromptComparison> {
+        let first = self.run_template(client, &request, first_template).await?;
+        let second = self.run_template(client, &request, second_template).await?;
+
+        let better_template = if second.quality.quality_score > first.quality.quality_score {
+            second.template.clone()
+        } else {
+            first.template.clone()
+        };
+
+        Ok(PromptComparison {
+            first,
+            second,
+            better_template,
+        })
+    }
+
+    async fn run_template(
+        &self,
+        client: &OllamaClient,
+        request: &EnhancedPromptRequest,
+        prompt_type: &str,
+    ) -> Result<PromptRun> {
+        let temperature = self
+            .select_prompt_template(prompt_type, &request.model_name)?
+            .temperature;
+        let prompt = self.create_enhanced_prompt(EnhancedPromptRequest {
+            prompt_type: prompt_type.to_string(),
+            ..request.clone()
+        })?;
+        let (output, processing_time_ms) = client
+            .generate_response(
+                &request.model_name,
+                &prompt.formatted_prompt,
+                Some(temperature),
+            )
+            .await?;
+
+        Ok(PromptRun {
+            template: prompt_type.to_string(),
+            prompt_strategy: prompt.prompt_strategy,
+            estimated_tokens: prompt.estimated_tokens,
+            quality: evaluate_prompt_output(&output, &request.job_description),
+            output,
+            processing_time_ms,
+        })
+    }
+
     fn get_model_config(&self, model_name: &str) -> Result<&ModelConfig> {
         // Try exact match first
         if let Some(config) = self.model_configs.get(model_name) {
@@ -226,6 +498,12 @@ impl EnhancedPromptEngine {
             "job_description".to_string(),
             request.job_description.clone(),
         );
+        if let Some(industry_ctx) = &request.industry_context {
+            context.insert(
+                "industry".to_string(),
+                industry_ctx.detected_industry.clone(),
+            );
+        }
 
         // Calculate remaining space after base content
         let base_tokens = self.estimate_text_tokens(&request.resume_content)
@@ -316,6 +594,12 @@ impl EnhancedPromptEngine {
             let placeholder = format!("{{{}}}", key);
             formatted = formatted.replace(&placeholder, value);
         }
+        // Optional context that wasn't available or didn't fit leaves no placeholder behind
+        for key in KNOWN_PLACEHOLDERS {
+            if !context.contains_key(*key) {
+                formatted = formatted.replace(&format!("{{{}}}", key), "");
+            }
+        }
 
         // Apply model-specific formatting
         let final_prompt = match model_config.instruction_format.as_str() {
@@ -352,19 +636,7 @@ impl EnhancedPromptEngine {
     }
 
     fn estimate_token_count(&self, text: &str, model_config: &ModelConfig) -> usize {
-        // Rough estimation: 1 token ≈ 4 characters for most models
-        let char_count = text.chars().count();
-        let estimated_tokens = (char_count as f64 / 4.0).ceil() as usize;
-
-        // Apply model-specific adjustments
-        let adjustment_factor = match model_config.model_name.as_str() {
-            name if name.contains("llama") => 1.1, // Llama tends to use slightly more tokens
-            name if name.contains("mistral") => 0.9, // Mistral is more efficient
-            name if name.contains("code") => 1.2,  // Code models use more tokens for symbols
-            _ => 1.0,
-        };
-
-        (estimated_tokens as f64 * adjustment_factor) as usize
+        estimate_prompt_tokens(&model_config.model_name, text)
     }
 
     fn estimate_text_tokens(&self, text: &str) -> usize {
@@ -780,6 +1052,7 @@ impl Default for EnhancedPromptEngine {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::test_server::spawn_json_server;
 
     #[test]
     fn test_prompt_template_creation() {
@@ -829,4 +1102,108 @@ mod tests {
         let config = engine.get_model_config("unknown-model").unwrap();
         assert_eq!(config.model_name, "default");
     }
+
+    /// Serve `/api/generate`, answering the skills template with broken JSON and anything
+    /// else with a well-formed analysis
+    async fn spawn_template_sensitive_model() -> String {
+        spawn_json_server(|request| async move {
+            let prompt = request["prompt"].as_str().unwrap_or_default();
+            let answer = if prompt.contains("detailed skills analysis") {
+                r#"Sure! {"recommendations": ["Add Kubernetes", "#.to_string()
+            } else {
+                serde_json::json!({
+                    "overall_score": 74,
+                    "recommendations": [
+                        "Add Kubernetes to your skills section",
+                        "Quantify PostgreSQL tuning results"
+                    ]
+                })
+                .to_string()
+            };
+            serde_json::json!({
+                "model": request["model"],
+                "created_at": "2024-01-01T00:00:00Z",
+                "response": answer,
+                "done": true,
+            })
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_compare_prompts_prefers_valid_json() {
+        let client = OllamaClient::new(Some(spawn_template_sensitive_model().await)).unwrap();
+        let engine = EnhancedPromptEngine::new();
+        let request = EnhancedPromptRequest {
+            prompt_type: "comprehensive_analysis".to_string(),
+            model_name: "llama2".to_string(),
+            resume_content: "Backend engineer. Built Python services and tuned PostgreSQL."
+                .to_string(),
+            job_description: "Looking for a Python engineer with Kubernetes and PostgreSQL."
+                .to_string(),
+            industry_context: None,
+            semantic_context: None,
+            analysis_focus: vec!["skills".to_string()],
+            output_format: "json".to_string(),
+        };
+
+        let comparison = engine
+            .compare_prompts(
+                &client,
+                request,
+                "skills_analysis",
+                "comprehensive_analysis",
+            )
+            .await
+            .unwrap();
+
+        assert!(!comparison.first.quality.valid_json);
+        assert!(comparison.second.quality.valid_json);
+        assert_eq!(comparison.second.quality.suggestion_count, 2);
+        assert!(comparison.second.quality.keyword_coverage > 0.0);
+        assert!(comparison.second.quality.quality_score > comparison.first.quality.quality_score);
+        assert_eq!(comparison.better_template, "comprehensive_analysis");
+    }
+
+    #[test]
+    fn test_custom_template_loaded_from_directory() {
+        let dir = std::env::temp_dir().join(format!("ats-prompts-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("cover_note.txt"),
+            "Industry: {industry}\nResume:\n{resume_content}\nJob:\n{job_description}\n\
+             Answer in {output_format}.\n",
+        )
+        .unwrap();
+        fs::write(dir.join("notes.md"), "ignored, not a template").unwrap();
+
+        let templates = load_prompt_templates(&dir).unwrap();
+        assert_eq!(templates.len(), 1);
+        let engine = EnhancedPromptEngine::new().with_custom_templates(templates);
+        let prompt = engine
+            .create_enhanced_prompt(EnhancedPromptRequest {
+                prompt_type: "cover_note".to_string(),
+                model_name: "unknown-model".to_string(),
+                resume_content: "Python engineer".to_string(),
+                job_description: "Hiring a Rust engineer".to_string(),
+                industry_context: None,
+                semantic_context: None,
+                analysis_focus: Vec::new(),
+                output_format: "json".to_string(),
+            })
+            .unwrap();
+        assert_eq!(
+            prompt.formatted_prompt,
+            "### Instruction:\nIndustry: \nResume:\nPython engineer\nJob:\n\
+             Hiring a Rust engineer\nAnswer in json.\n\n### Response:\n"
+        );
+        // Built-in templates stay available alongside the custom ones
+        assert!(engine.prompt_templates.contains_key("skills_analysis"));
+
+        fs::write(dir.join("broken.txt"), "Resume: {resume_content} {salary}").unwrap();
+        let error = format!("{:#}", load_prompt_templates(&dir).unwrap_err());
+        assert!(error.contains("unknown placeholder {salary}"), "{}", error);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
 }
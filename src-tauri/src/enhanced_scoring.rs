@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use crate::database::Database;
-use crate::models::{AnalysisResult, CategoryScores};
+use crate::models::{AnalysisResult, CategoryScores, IndustryDefinition};
 use crate::semantic_analyzer::{SemanticAnalysisResult, SemanticAnalyzer};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -148,6 +148,8 @@ impl Default for ScoringWeights {
 pub struct EnhancedScoringEngine {
     database: Database,
     semantic_analyzer: SemanticAnalyzer,
+    /// User-defined industries keyed by lowercase name
+    custom_industries: HashMap<String, IndustryDefinition>,
 }
 
 impl EnhancedScoringEngine {
@@ -157,9 +159,19 @@ impl EnhancedScoringEngine {
         EnhancedScoringEngine {
             database,
             semantic_analyzer,
+            custom_industries: HashMap::new(),
         }
     }
 
+    /// Detect `custom_industries` alongside the built-in categories
+    pub fn with_custom_industries(
+        mut self,
+        custom_industries: HashMap<String, IndustryDefinition>,
+    ) -> Self {
+        self.custom_industries = custom_industries;
+        self
+    }
+
     pub async fn comprehensive_analysis(
         &self,
         resume_content: &str,
@@ -748,6 +760,7 @@ impl EnhancedScoringEngine {
                     .get("format")
                     .map(|ws| ws.raw_score)
                     .unwrap_or(0.0),
+                confidence: None,
             },
             detailed_feedback: format!(
                 "Enhanced analysis completed with {} confidence",
@@ -756,6 +769,7 @@ impl EnhancedScoringEngine {
             missing_keywords: Vec::new(),
             recommendations: Vec::new(),
             processing_time_ms: 0, // Will be set by caller
+            model_used: None,
         }
     }
 
@@ -763,6 +777,13 @@ impl EnhancedScoringEngine {
     async fn detect_industry_from_content(&self, resume_content: &str) -> Result<String> {
         use crate::ollama::OllamaClient;
 
+        let mut custom_industries: Vec<&String> = self.custom_industries.keys().collect();
+        custom_industries.sort();
+        let custom_categories: String = custom_industries
+            .iter()
+            .map(|name| format!("\n- {}", name))
+            .collect();
+
         let industry_classification_prompt = format!(
             "Classify this resume content into the most appropriate industry category.
 
@@ -784,7 +805,7 @@ Industry Categories (choose exactly one):
 - Transportation/Logistics
 - Energy/Utilities
 - Legal/Law
-- Construction
+- Construction{}
 
 Analyze the content for:
 1. Technical skills and tools mentioned
@@ -796,7 +817,7 @@ Analyze the content for:
 7. Industry-specific terminology used
 
 Return ONLY the single most appropriate industry category from the list above.",
-            resume_content
+            resume_content, custom_categories
         );
 
         let ollama_client = OllamaClient::new(None)?;
@@ -823,6 +844,16 @@ Return ONLY the single most appropriate industry category from the list above.",
     fn validate_and_normalize_industry(&self, ml_response: &str) -> String {
         let response_lower = ml_response.trim().to_lowercase();
 
+        // User-defined industries take precedence over the built-in categories
+        let mut custom_industries: Vec<&String> = self.custom_industries.keys().collect();
+        custom_industries.sort();
+        if let Some(name) = custom_industries
+            .into_iter()
+            .find(|name| response_lower.contains(name.as_str()))
+        {
+            return name.clone();
+        }
+
         // Map of valid industries with their variations
         let industry_mappings = vec![
             (
@@ -1082,6 +1113,18 @@ Return ONLY the single most appropriate industry category from the list above.",
             }
         }
 
+        for (industry, definition) in &self.custom_industries {
+            let score: f64 = definition
+                .keywords
+                .iter()
+                .filter(|(keyword, _)| content_lower.contains(&keyword.to_lowercase()))
+                .map(|(_, weight)| weight)
+                .sum();
+            if score > 0.0 {
+                *industry_scores.entry(industry.clone()).or_insert(0.0) += score;
+            }
+        }
+
         let detected_industry = industry_scores
             .iter()
             .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
@@ -1,4 +1,5 @@
 use serde::Serialize;
+use std::collections::HashSet;
 use thiserror::Error;
 
 /// Centralized error type for the ATS Scanner application
@@ -349,25 +350,58 @@ impl<T> IntoATSError<T> for std::result::Result<T, anyhow::Error> {
 /// Conversion from anyhow::Error to ATSError
 impl From<anyhow::Error> for ATSError {
     fn from(err: anyhow::Error) -> Self {
-        // Try to determine the error type from the error chain
-        let error_string = err.to_string().to_lowercase();
-
-        if error_string.contains("database") || error_string.contains("sql") {
-            ATSError::database(err.to_string())
-        } else if error_string.contains("file") || error_string.contains("io") {
-            ATSError::file_operation(err.to_string())
-        } else if error_string.contains("security") || error_string.contains("permission") {
-            ATSError::security(err.to_string())
-        } else if error_string.contains("config") {
-            ATSError::configuration(err.to_string())
-        } else if error_string.contains("memory") {
-            ATSError::memory(err.to_string())
+        let err = match err.downcast::<ATSError>() {
+            Ok(ats_error) => return ats_error,
+            Err(err) => err,
+        };
+        let message = format!("{:#}", err);
+
+        // Prefer the concrete source type anywhere in the chain over guessing from the text
+        for cause in err.chain() {
+            if cause.is::<sqlx::Error>() {
+                return ATSError::database(message);
+            }
+            if cause.is::<reqwest::Error>() {
+                return ATSError::ollama_api(message);
+            }
+            if cause.is::<std::io::Error>() {
+                return ATSError::file_operation(message);
+            }
+        }
+
+        // Otherwise fall back to whole words of the root cause only; the caller's context
+        // describes what was being attempted, not what failed ("profile" is not a file error)
+        let root_cause = err.root_cause().to_string().to_lowercase();
+        let words: HashSet<&str> = root_cause
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .collect();
+        let mentions = |terms: &[&str]| terms.iter().any(|term| words.contains(term));
+        if mentions(&["database", "sql", "sqlite"]) {
+            ATSError::database(message)
+        } else if mentions(&["ollama"]) {
+            ATSError::ollama_api(message)
+        } else if mentions(&["security", "permission"]) {
+            ATSError::security(message)
+        } else if mentions(&["config", "configuration"]) {
+            ATSError::configuration(message)
+        } else if mentions(&["file", "directory"]) {
+            ATSError::file_operation(message)
+        } else if mentions(&["memory"]) {
+            ATSError::memory(message)
         } else {
-            ATSError::document_parsing_with_source("Unknown error", err)
+            ATSError::document_parsing_with_source(message, err)
         }
     }
 }
 
+impl ATSError {
+    /// Classify an error and prefix its message with what the caller was doing
+    pub fn with_context(context: &str, err: impl Into<anyhow::Error>) -> Self {
+        Self::from(err.into().context(context.to_string()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -409,4 +443,101 @@ mod tests {
             assert_eq!(ats_error.error_code(), "FILE_ERROR");
         }
     }
+
+    #[tokio::test]
+    async fn test_database_failure_maps_to_database_error() {
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        let err = sqlx::query("SELECT * FROM missing_table")
+            .fetch_all(&pool)
+            .await
+            .err()
+            .expect("query against a missing table should fail");
+
+        let ats_error = ATSError::with_context("Failed to get resumes", err);
+        assert!(matches!(ats_error, ATSError::Database { .. }));
+        assert_eq!(ats_error.error_code(), "DATABASE_ERROR");
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_ollama_maps_to_ollama_error() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+
+        let client = crate::ollama::OllamaClient::new(Some(base_url)).unwrap();
+        let err = client.list_models().await.unwrap_err();
+
+        // The context mentions nothing about Ollama, so this relies on the reqwest source
+        let ats_error = ATSError::with_context("Failed to load", err);
+        assert!(matches!(ats_error, ATSError::OllamaApi { .. }));
+        assert_eq!(ats_error.error_code(), "OLLAMA_ERROR");
+    }
+
+    #[tokio::test]
+    async fn test_missing_file_maps_to_file_error() {
+        let err = tokio::fs::read("/nonexistent/resume.pdf")
+            .await
+            .unwrap_err();
+
+        let ats_error = ATSError::with_context("Export failed", err);
+        assert!(matches!(ats_error, ATSError::FileOperation { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_unparseable_document_maps_to_parsing_error() {
+        let err = crate::document::DocumentParser::parse_content(
+            b"not a zip",
+            "resume.docx",
+            &crate::models::ExperienceLevelThresholds::default(),
+        )
+        .await
+        .unwrap_err();
+
+        let ats_error = ATSError::with_context("Failed to parse document", err);
+        assert!(matches!(ats_error, ATSError::DocumentParsing { .. }));
+        assert_eq!(ats_error.error_code(), "DOCUMENT_ERROR");
+    }
+
+    #[test]
+    fn test_invalid_config_maps_to_configuration_error() {
+        let err = anyhow::anyhow!("Missing config value 'ollama_config.port'");
+
+        let ats_error = ATSError::with_context("Failed to load settings", err);
+        assert!(matches!(ats_error, ATSError::Configuration { .. }));
+        assert!(ats_error.to_string().contains("Failed to load settings"));
+    }
+
+    #[test]
+    fn test_context_words_do_not_classify_the_error() {
+        let err = serde_json::from_str::<serde_json::Value>("{").unwrap_err();
+
+        let ats_error = ATSError::with_context("Failed to update profile in PostgreSQL", err);
+        assert!(!matches!(ats_error, ATSError::FileOperation { .. }));
+        assert!(!matches!(ats_error, ATSError::Database { .. }));
+    }
+
+    #[test]
+    fn test_unknown_scoring_profile_maps_to_validation_error() {
+        let err = crate::advanced_scoring::ScoringSettings::default()
+            .scoring_profile(Some("no-such-profile"))
+            .unwrap_err();
+
+        let ats_error = ATSError::with_context("Invalid scoring profile", err);
+        assert_eq!(ats_error.error_code(), "VALIDATION_ERROR");
+        assert!(ats_error.to_string().contains("no-such-profile"));
+    }
+
+    #[test]
+    fn test_ats_error_survives_anyhow_round_trip() {
+        let err = anyhow::Error::new(ATSError::security("path traversal"));
+
+        assert!(matches!(ATSError::from(err), ATSError::Security { .. }));
+    }
+
+    #[test]
+    fn test_mention_of_a_model_is_not_an_ollama_error() {
+        let err = anyhow::anyhow!("Scoring model weights must sum to 1.0");
+
+        assert!(!matches!(ATSError::from(err), ATSError::OllamaApi { .. }));
+    }
 }
@@ -1,9 +1,65 @@
 use anyhow::Result;
+use once_cell::sync::Lazy;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use crate::format_checker::{FormatCompatibilityReport, FormatIssue};
+use crate::models::ResumeRegion;
+
+/// Consecutive column-aligned lines needed before text reads as a flattened table
+const MIN_TABLE_ROWS: usize = 3;
+/// Longest cell, in words, still counted as a table fragment rather than prose
+const MAX_FRAGMENT_WORDS: usize = 4;
+
+/// Gaps of a tab or three or more spaces, which is how table columns survive text extraction
+static COLUMN_GAP: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\t+| {3,}").expect("valid column gap pattern"));
+
+/// Bullets needed before their punctuation and capitalization read as a deliberate style
+const MIN_STYLE_BULLETS: usize = 3;
+
+const BULLET_MARKERS: &[char] = &['•', '-', '*', '▪', '◦', '●', '–', '‣', '·'];
+
+/// Personal details US employers cannot weigh and ATS fields do not expect:
+/// (issue type, what was found, pattern, removal advice)
+static PERSONAL_DETAILS: Lazy<Vec<(&str, &str, Regex, &str)>> = Lazy::new(|| {
+    vec![
+        (
+            "photo",
+            "A photo or embedded image",
+            Regex::new(r"(?im)<img\b|data:image/|\[(?:photo|image|picture)\]|^\s*photo\s*:")
+                .expect("valid photo pattern"),
+            "Remove the photo; US employers discard resumes with photos to avoid bias claims and ATS systems cannot read them",
+        ),
+        (
+            "date_of_birth",
+            "Date of birth or age",
+            Regex::new(r"(?im)\b(?:date\s+of\s+birth|d\.o\.b\.?|dob|born(?:\s+on)?)\s*[:\-]?\s*\d|^\s*age\s*:\s*\d|\b\d{2}\s+years\s+old\b")
+                .expect("valid date of birth pattern"),
+            "Remove your date of birth and age; they invite age bias and are not asked for in US hiring",
+        ),
+        (
+            "marital_status",
+            "Marital status",
+            Regex::new(r"(?im)\bmarital\s+status\b|^\s*(?:married|single|divorced|widowed)\s*$")
+                .expect("valid marital status pattern"),
+            "Remove your marital status; it has no bearing on the role and can introduce bias",
+        ),
+        (
+            "nationality",
+            "Nationality",
+            Regex::new(r"(?i)\bnationality\s*:|\bi\s+am\s+an?\s+[a-z]+\s+(?:citizen|national)\b")
+                .expect("valid nationality pattern"),
+            "Remove your nationality; state work authorization instead if the posting asks for it",
+        ),
+    ]
+});
+
+/// Irregular past-tense verbs common on resumes that the suffix check misses
+const IRREGULAR_VERBS: &[&str] = &[
+    "built", "drove", "grew", "led", "made", "ran", "won", "wrote", "taught", "sold",
+];
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FormatIssueReport {
@@ -54,6 +110,8 @@ pub struct FormatIssueDetector {
     issue_patterns: HashMap<String, IssuePattern>,
     ats_sensitivities: HashMap<String, ATSSensitivity>,
     recommendation_templates: HashMap<String, RecommendationTemplate>,
+    /// Hiring conventions that decide whether personal details are flagged
+    region: ResumeRegion,
 }
 
 #[derive(Debug, Clone)]
@@ -93,6 +151,7 @@ impl FormatIssueDetector {
             issue_patterns: HashMap::new(),
             ats_sensitivities: HashMap::new(),
             recommendation_templates: HashMap::new(),
+            region: ResumeRegion::default(),
         };
 
         detector.initialize_issue_patterns();
@@ -101,6 +160,12 @@ impl FormatIssueDetector {
         detector
     }
 
+    /// Flag personal details by the hiring conventions of `region`
+    pub fn with_region(mut self, region: ResumeRegion) -> Self {
+        self.region = region;
+        self
+    }
+
     pub fn analyze_format_issues(
         &self,
         content: &str,
@@ -161,6 +226,9 @@ impl FormatIssueDetector {
         issues.extend(self.detect_metadata_issues(content));
         issues.extend(self.detect_style_inconsistencies(content));
         issues.extend(self.detect_section_structure_issues(content));
+        issues.extend(self.detect_table_scramble_issues(content));
+        issues.extend(self.detect_bullet_style_issues(content));
+        issues.extend(personal_detail_issues(content, self.region));
 
         Ok(issues)
     }
@@ -547,6 +615,96 @@ impl FormatIssueDetector {
         issues
     }
 
+    /// Tables without visible borders come out of extraction as rows of short, verbless
+    /// fragments separated by wide gaps, often read across columns in the wrong order
+    fn detect_table_scramble_issues(&self, content: &str) -> Vec<FormatIssue> {
+        let mut table_lines = 0;
+        let mut run_lines = 0;
+        let mut run_columns = 0;
+
+        for line in content.lines() {
+            let columns = table_row_columns(line);
+            if columns >= 2 && columns == run_columns {
+                run_lines += 1;
+            } else {
+                if run_lines >= MIN_TABLE_ROWS {
+                    table_lines += run_lines;
+                }
+                run_lines = usize::from(columns >= 2);
+                run_columns = columns;
+            }
+        }
+        if run_lines >= MIN_TABLE_ROWS {
+            table_lines += run_lines;
+        }
+
+        if table_lines == 0 {
+            return Vec::new();
+        }
+        vec![FormatIssue {
+            issue_type: "table_scramble".to_string(),
+            severity: "high".to_string(),
+            description: format!(
+                "{} lines look like a table flattened into columns of short fragments; ATS systems may read the cells out of order",
+                table_lines
+            ),
+            recommendation: "Delinearize the table: rewrite each row as its own bullet or sentence instead of aligning cells with tabs or spaces".to_string(),
+            section_affected: "structure".to_string(),
+            impact_score: 15.0,
+        }]
+    }
+
+    /// Bullets that break from the style most bullets follow: some ending with a period and
+    /// others not, or some starting lowercase. The minority lines are reported by number.
+    fn detect_bullet_style_issues(&self, content: &str) -> Vec<FormatIssue> {
+        let bullets: Vec<(usize, &str)> = content
+            .lines()
+            .enumerate()
+            .filter_map(|(index, line)| bullet_text(line).map(|text| (index + 1, text)))
+            .collect();
+        if bullets.len() < MIN_STYLE_BULLETS {
+            return Vec::new();
+        }
+
+        let mut issues = Vec::new();
+        let (with_period, without_period): (Vec<_>, Vec<_>) =
+            bullets.iter().partition(|(_, text)| text.ends_with('.'));
+        if let Some(offending) = minority_lines(&with_period, &without_period) {
+            issues.push(FormatIssue {
+                issue_type: "inconsistent_bullet_punctuation".to_string(),
+                severity: "low".to_string(),
+                description: format!(
+                    "Some bullets end with a period and others do not (lines {})",
+                    offending
+                ),
+                recommendation: "End every bullet the same way: either all with a period or none"
+                    .to_string(),
+                section_affected: "formatting".to_string(),
+                impact_score: 3.0,
+            });
+        }
+
+        let (capitalized, lowercase): (Vec<_>, Vec<_>) = bullets
+            .iter()
+            .filter(|(_, text)| text.starts_with(char::is_alphabetic))
+            .partition(|(_, text)| text.starts_with(char::is_uppercase));
+        if let Some(offending) = minority_lines(&capitalized, &lowercase) {
+            issues.push(FormatIssue {
+                issue_type: "inconsistent_bullet_capitalization".to_string(),
+                severity: "low".to_string(),
+                description: format!(
+                    "Some bullets start with a capital letter and others do not (lines {})",
+                    offending
+                ),
+                recommendation: "Start every bullet with a capital letter".to_string(),
+                section_affected: "formatting".to_string(),
+                impact_score: 3.0,
+            });
+        }
+
+        issues
+    }
+
     fn initialize_issue_patterns(&mut self) {
         // Initialize regex patterns for different issue types
         // This would be expanded with more sophisticated patterns
@@ -639,6 +797,21 @@ impl FormatIssueDetector {
             time_estimate: "15 minutes".to_string(),
         });
 
+        self.recommendation_templates.insert("table_scramble".to_string(), RecommendationTemplate {
+            category: "table_scramble".to_string(),
+            title_template: "Delinearize Borderless Tables".to_string(),
+            description_template: "Text aligned into columns with tabs or spaces is read row by row across the page, mixing cells from different columns. Rewrite it as plain lines.".to_string(),
+            steps: vec![
+                "1. Find text aligned into columns with tabs or repeated spaces".to_string(),
+                "2. Rewrite each row as one bullet, e.g. \"Python – 5 years, expert\"".to_string(),
+                "3. Turn column headings into a section heading or drop them".to_string(),
+                "4. Remove the tab stops or spacing used for alignment".to_string(),
+            ],
+            tools: vec!["Word processor".to_string(), "Text editor".to_string()],
+            difficulty: "easy".to_string(),
+            time_estimate: "15 minutes".to_string(),
+        });
+
         self.recommendation_templates.insert("text_in_images".to_string(), RecommendationTemplate {
             category: "text_in_images".to_string(),
             title_template: "Extract Text from Images".to_string(),
@@ -657,6 +830,93 @@ impl FormatIssueDetector {
     }
 }
 
+/// Number of cells when `line` reads as a table row of short verbless fragments, else 0
+fn table_row_columns(line: &str) -> usize {
+    let cells: Vec<&str> = COLUMN_GAP
+        .split(line.trim())
+        .map(str::trim)
+        .filter(|cell| !cell.is_empty())
+        .collect();
+    let is_fragment = |cell: &&str| {
+        let words: Vec<&str> = cell.split_whitespace().collect();
+        words.len() <= MAX_FRAGMENT_WORDS && !words.iter().any(|word| looks_like_verb(word))
+    };
+    if cells.len() >= 2 && cells.iter().all(is_fragment) {
+        cells.len()
+    } else {
+        0
+    }
+}
+
+/// Personal details that should come off a resume written for `region`, one issue per kind of
+/// detail with the lines it appears on
+pub fn personal_detail_issues(content: &str, region: ResumeRegion) -> Vec<FormatIssue> {
+    if !region.flags_personal_details() {
+        return Vec::new();
+    }
+
+    PERSONAL_DETAILS
+        .iter()
+        .filter_map(|(issue_type, found, pattern, recommendation)| {
+            let lines: Vec<String> = content
+                .lines()
+                .enumerate()
+                .filter(|(_, line)| pattern.is_match(line))
+                .map(|(index, _)| (index + 1).to_string())
+                .collect();
+            (!lines.is_empty()).then(|| FormatIssue {
+                issue_type: format!("personal_info_{}", issue_type),
+                severity: "medium".to_string(),
+                description: format!(
+                    "{} should not appear on the resume (lines {})",
+                    found,
+                    lines.join(", ")
+                ),
+                recommendation: recommendation.to_string(),
+                section_affected: "personal_information".to_string(),
+                impact_score: 5.0,
+            })
+        })
+        .collect()
+}
+
+/// The text of a bulleted line, without its marker
+fn bullet_text(line: &str) -> Option<&str> {
+    let trimmed = line.trim();
+    let rest = trimmed.strip_prefix(BULLET_MARKERS)?;
+    rest.starts_with(char::is_whitespace)
+        .then(|| rest.trim())
+        .filter(|text| !text.is_empty())
+}
+
+/// Line numbers of the smaller of two groups of bullets, when both are non-empty; on a tie
+/// the second group is reported
+fn minority_lines(first: &[&(usize, &str)], second: &[&(usize, &str)]) -> Option<String> {
+    if first.is_empty() || second.is_empty() {
+        return None;
+    }
+    let minority = if first.len() < second.len() {
+        first
+    } else {
+        second
+    };
+    Some(
+        minority
+            .iter()
+            .map(|(line, _)| line.to_string())
+            .collect::<Vec<_>>()
+            .join(", "),
+    )
+}
+
+fn looks_like_verb(word: &str) -> bool {
+    let word = word
+        .trim_matches(|c: char| !c.is_alphabetic())
+        .to_lowercase();
+    IRREGULAR_VERBS.contains(&word.as_str())
+        || (word.len() > 4 && (word.ends_with("ed") || word.ends_with("ing")))
+}
+
 impl Default for FormatIssueDetector {
     fn default() -> Self {
         Self::new()
@@ -727,4 +987,92 @@ mod tests {
         assert!(issue_report.overall_format_score <= 100.0);
         assert!(!issue_report.improvement_recommendations.is_empty());
     }
+
+    #[test]
+    fn test_tab_aligned_pseudo_table_flags_scramble() {
+        let detector = FormatIssueDetector::new();
+        let content = "Jane Doe\n\nSKILLS\n\
+                       Language\tYears\tLevel\n\
+                       Python\t5 years\tExpert\n\
+                       Go\t2 years\tIntermediate\n\
+                       SQL\t6 years\tExpert\n\n\
+                       EXPERIENCE\n\
+                       Engineer    Acme    2020 - Present\n\
+                       Led the migration of billing to Kubernetes, cutting costs 30%\n";
+
+        let issues = detector.detect_advanced_issues(content).unwrap();
+        let scramble = issues
+            .iter()
+            .find(|issue| issue.issue_type == "table_scramble")
+            .expect("pseudo-table should be flagged");
+        assert!(scramble.description.starts_with("4 lines"));
+
+        let prose = "EXPERIENCE\nEngineer at Acme\n\
+                     Led the migration of billing to Kubernetes\n\
+                     Designed a caching layer\tserving    2M requests\n";
+        assert!(!detector
+            .detect_advanced_issues(prose)
+            .unwrap()
+            .iter()
+            .any(|issue| issue.issue_type == "table_scramble"));
+    }
+
+    #[test]
+    fn test_mixed_bullet_punctuation_is_flagged() {
+        let detector = FormatIssueDetector::new();
+        let content = "EXPERIENCE\n\
+                       Engineer, Acme\n\
+                       • Led the billing migration to Kubernetes.\n\
+                       • Cut deploy time from 40 to 8 minutes.\n\
+                       • reduced cloud spend 30%\n\
+                       • Mentored four junior engineers.\n";
+
+        let issues = detector.detect_advanced_issues(content).unwrap();
+        let punctuation = issues
+            .iter()
+            .find(|issue| issue.issue_type == "inconsistent_bullet_punctuation")
+            .expect("mixed end punctuation should be flagged");
+        assert_eq!(punctuation.severity, "low");
+        assert!(punctuation.description.ends_with("(lines 5)"));
+        let capitalization = issues
+            .iter()
+            .find(|issue| issue.issue_type == "inconsistent_bullet_capitalization")
+            .expect("lowercase bullet should be flagged");
+        assert!(capitalization.description.ends_with("(lines 5)"));
+
+        let consistent = "• Led the billing migration\n• Cut deploy time\n• Mentored engineers\n";
+        assert!(!detector
+            .detect_advanced_issues(consistent)
+            .unwrap()
+            .iter()
+            .any(|issue| issue.issue_type.starts_with("inconsistent_bullet")));
+    }
+
+    #[test]
+    fn test_birth_date_and_marital_status_flagged_for_us_resumes() {
+        let content = "Maria Rossi\nmaria@example.com\n\
+                       Date of Birth: 01/01/1990\n\
+                       Marital Status: Married\n\n\
+                       EXPERIENCE\n\
+                       • Built a single source of truth for inventory data\n";
+
+        let issues = personal_detail_issues(content, ResumeRegion::Us);
+        let types: Vec<&str> = issues
+            .iter()
+            .map(|issue| issue.issue_type.as_str())
+            .collect();
+        assert_eq!(
+            types,
+            [
+                "personal_info_date_of_birth",
+                "personal_info_marital_status"
+            ]
+        );
+        assert!(issues[0].description.ends_with("(lines 3)"));
+        assert!(issues[1].description.ends_with("(lines 4)"));
+        assert!(issues[1].recommendation.starts_with("Remove"));
+
+        // European conventions expect these details
+        assert!(personal_detail_issues(content, ResumeRegion::Eu).is_empty());
+    }
 }
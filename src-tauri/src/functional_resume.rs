@@ -0,0 +1,197 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::advanced_scoring::{ExperienceEntry, ParsedResume};
+
+/// Skill groups needed before a resume is treated as functional rather than chronological
+const MIN_SKILL_GROUPS: usize = 2;
+
+/// Longest line, in words, still read as a skill-group heading such as "Process Improvement"
+const MAX_GROUP_HEADING_WORDS: usize = 6;
+
+/// Headers of the skill-grouped section functional and combined resumes lead with
+static FUNCTIONAL_HEADER: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)^(?:(?:relevant|key|core|professional|selected|functional)\s+(?:skills|experience|accomplishments|achievements|qualifications|expertise)(?:\s+(?:and|&)\s+(?:accomplishments|achievements|experience|skills))?|(?:skills|expertise)\s+(?:and|&)\s+(?:accomplishments|achievements)|areas\s+of\s+expertise|skills\s+summary)\s*:?$",
+    )
+    .expect("valid functional header pattern")
+});
+
+/// Headers that close the skill-grouped section
+static SECTION_HEADER: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)^(?:(?:employment|work|career|professional)\s+history|(?:work|professional)?\s*experience|employment|education|certifications?|(?:technical\s+)?skills|summary|(?:professional\s+)?summary\s+of\s+qualifications|profile|projects|awards|honors|publications|references|volunteer(?:\s+\w+)?|languages|interests)\s*:?$",
+    )
+    .expect("valid section header pattern")
+});
+
+/// A year or date range, which marks a heading as a chronological role rather than a skill
+static DATED: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)\b(?:19|20)\d{2}\b|\bpresent\b").expect("valid date pattern"));
+
+const BULLETS: [char; 6] = ['•', '-', '*', '◦', '▪', '·'];
+
+/// Accomplishments a functional resume groups under one skill area instead of under a role
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SkillGroup {
+    pub skill_area: String,
+    pub accomplishments: Vec<String>,
+}
+
+/// The skill-grouped accomplishments of a functional or combined resume, in document order
+pub fn functional_skill_groups(content: &str) -> Vec<SkillGroup> {
+    let mut groups: Vec<SkillGroup> = Vec::new();
+    let mut in_section = false;
+
+    for line in content.lines().map(str::trim) {
+        if line.is_empty() {
+            continue;
+        }
+        if FUNCTIONAL_HEADER.is_match(line) {
+            in_section = true;
+            continue;
+        }
+        if !in_section {
+            continue;
+        }
+        if SECTION_HEADER.is_match(line) {
+            in_section = false;
+            continue;
+        }
+        if line.starts_with(BULLETS) {
+            let accomplishment = line.trim_start_matches(BULLETS).trim();
+            if let Some(group) = groups.last_mut().filter(|_| !accomplishment.is_empty()) {
+                group.accomplishments.push(accomplishment.to_string());
+            }
+        } else if is_group_heading(line) {
+            groups.push(SkillGroup {
+                skill_area: line.trim_end_matches(':').trim().to_string(),
+                accomplishments: Vec::new(),
+            });
+        }
+    }
+
+    groups.retain(|group| !group.accomplishments.is_empty());
+    groups
+}
+
+/// Whether the resume organizes its accomplishments by skill rather than by role
+pub fn is_functional_resume(content: &str) -> bool {
+    functional_skill_groups(content).len() >= MIN_SKILL_GROUPS
+}
+
+/// Record a functional resume's skill groups on the parse, and when no role carries
+/// accomplishments of its own, stand each group in for a role so achievement scoring still
+/// sees the candidate's work
+pub fn apply_functional_layout(parsed: &mut ParsedResume, content: &str) {
+    let groups = functional_skill_groups(content);
+    if groups.len() < MIN_SKILL_GROUPS {
+        return;
+    }
+    let roles_have_accomplishments = parsed
+        .experience
+        .iter()
+        .any(|role| !role.achievements.is_empty());
+    if !roles_have_accomplishments {
+        parsed
+            .experience
+            .extend(groups.iter().map(|group| ExperienceEntry {
+                title: group.skill_area.clone(),
+                company: String::new(),
+                duration: String::new(),
+                description: group.accomplishments.join("\n"),
+                achievements: group.accomplishments.clone(),
+            }));
+    }
+    parsed.skill_groups = groups;
+}
+
+fn is_group_heading(line: &str) -> bool {
+    !line.ends_with('.')
+        && !DATED.is_match(line)
+        && line.split_whitespace().count() <= MAX_GROUP_HEADING_WORDS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::advanced_scoring::{ATSSimulator, FormatAnalyzer, FormatIssueType, ScoringSettings};
+
+    const FUNCTIONAL_RESUME: &str = "Alex Morgan\n\
+        alex.morgan@example.com | (555) 123-4567\n\
+        \n\
+        SUMMARY OF QUALIFICATIONS\n\
+        Operations leader with ten years in logistics.\n\
+        \n\
+        RELEVANT SKILLS AND ACCOMPLISHMENTS\n\
+        \n\
+        Project Management\n\
+        • Delivered a warehouse relocation two weeks ahead of schedule\n\
+        • Coordinated 12 vendors across three regions\n\
+        \n\
+        Process Improvement\n\
+        • Reduced order fulfilment time by 30% through workflow redesign\n\
+        • Introduced lean audits adopted by four sites\n\
+        \n\
+        Team Leadership\n\
+        • Trained and mentored 15 new coordinators\n\
+        \n\
+        EMPLOYMENT HISTORY\n\
+        Operations Manager, Northwind Logistics, 2018 - Present\n\
+        Logistics Coordinator, Contoso Freight, 2014 - 2018\n\
+        \n\
+        EDUCATION\n\
+        B.S. Business Administration, State University, 2013\n";
+
+    #[test]
+    fn test_functional_resume_accomplishments_extracted_with_format_warning() {
+        let parsed = ATSSimulator::new()
+            .parse_with_multiple_systems(FUNCTIONAL_RESUME)
+            .unwrap();
+
+        let areas: Vec<&str> = parsed
+            .skill_groups
+            .iter()
+            .map(|group| group.skill_area.as_str())
+            .collect();
+        assert_eq!(
+            areas,
+            [
+                "Project Management",
+                "Process Improvement",
+                "Team Leadership"
+            ]
+        );
+        assert_eq!(
+            parsed.skill_groups[1].accomplishments,
+            [
+                "Reduced order fulfilment time by 30% through workflow redesign",
+                "Introduced lean audits adopted by four sites",
+            ]
+        );
+        let achievements: Vec<&str> = parsed
+            .experience
+            .iter()
+            .flat_map(|role| role.achievements.iter().map(String::as_str))
+            .collect();
+        assert!(achievements.contains(&"Trained and mentored 15 new coordinators"));
+
+        let format = FormatAnalyzer::new()
+            .analyze_with_profile(
+                FUNCTIONAL_RESUME,
+                &parsed,
+                &ScoringSettings::default().default_scoring_profile(),
+            )
+            .unwrap();
+        assert!(format
+            .parsing_issues
+            .iter()
+            .any(|issue| matches!(issue.issue_type, FormatIssueType::FunctionalLayout)));
+
+        assert!(!is_functional_resume(
+            "Jane Doe\n\nPROFESSIONAL EXPERIENCE\nSenior Engineer, Acme, 2020 - Present\n\
+             • Led the billing migration\nEngineer, Globex, 2017 - 2020\n• Built CI tooling\n"
+        ));
+    }
+}
@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use crate::database::Database;
-use crate::models::IndustryKeyword;
+use crate::models::{IndustryDefinition, IndustryKeyword};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IndustryAnalysisResult {
@@ -123,16 +123,166 @@ pub struct IndustryTrend {
     pub keywords: Vec<String>,
 }
 
+/// A skill from the candidate's current industry that carries over to the target one
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransferableSkillMapping {
+    pub source_skill: String,
+    pub target_skill: String,
+    pub confidence: f64,
+    /// Resume line the source skill was found on
+    pub evidence: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransferableSkillsReport {
+    pub source_industry: String,
+    pub target_industry: String,
+    pub switching_industries: bool,
+    pub mappings: Vec<TransferableSkillMapping>,
+    pub strengths_to_emphasize: Vec<String>,
+}
+
+/// (skill as written on the resume, what it maps to, JD terms showing the target wants it, confidence)
+const TRANSFERABLE_SKILLS: &[(&str, &str, &[&str], f64)] = &[
+    ("sql", "SQL", &["sql", "database", "queries"], 0.95),
+    ("python", "Python", &["python"], 0.95),
+    (
+        "excel",
+        "spreadsheet analysis",
+        &["excel", "spreadsheet", "data analysis"],
+        0.8,
+    ),
+    (
+        "vba",
+        "scripting and automation",
+        &["automation", "scripting", "python"],
+        0.6,
+    ),
+    (
+        "risk modeling",
+        "data analysis",
+        &["data analysis", "analytics", "modeling", "statistical"],
+        0.8,
+    ),
+    (
+        "financial modeling",
+        "data modeling",
+        &["data model", "modeling", "analytics"],
+        0.75,
+    ),
+    (
+        "forecasting",
+        "predictive analytics",
+        &["forecast", "predictive", "analytics"],
+        0.75,
+    ),
+    (
+        "statistical analysis",
+        "data analysis",
+        &["data analysis", "statistic", "analytics"],
+        0.9,
+    ),
+    (
+        "variance analysis",
+        "data analysis",
+        &["data analysis", "analytics", "metrics"],
+        0.65,
+    ),
+    (
+        "financial reporting",
+        "dashboards and reporting",
+        &["reporting", "dashboard", "kpi"],
+        0.7,
+    ),
+    (
+        "tableau",
+        "data visualization",
+        &["tableau", "visualization", "dashboard"],
+        0.9,
+    ),
+    (
+        "power bi",
+        "data visualization",
+        &["power bi", "visualization", "dashboard"],
+        0.9,
+    ),
+    (
+        "regulatory compliance",
+        "compliance and governance",
+        &["compliance", "governance", "regulatory"],
+        0.7,
+    ),
+    (
+        "audit",
+        "quality assurance",
+        &["quality assurance", "qa", "testing", "audit"],
+        0.55,
+    ),
+    (
+        "budgeting",
+        "resource planning",
+        &["budget", "planning", "resource"],
+        0.6,
+    ),
+    (
+        "stakeholder management",
+        "stakeholder management",
+        &["stakeholder"],
+        0.85,
+    ),
+    (
+        "project management",
+        "project management",
+        &["project management", "project manager", "deliver"],
+        0.85,
+    ),
+    (
+        "patient care",
+        "customer experience",
+        &["customer", "client", "user experience"],
+        0.5,
+    ),
+    (
+        "clinical research",
+        "user research",
+        &["research", "user research", "experiment"],
+        0.5,
+    ),
+    (
+        "lesson planning",
+        "training and enablement",
+        &["training", "enablement", "onboarding"],
+        0.6,
+    ),
+    (
+        "customer service",
+        "customer success",
+        &["customer success", "customer", "support"],
+        0.7,
+    ),
+];
+
 pub struct IndustryAnalyzer {
     database: Database,
     _industry_rules: HashMap<String, IndustryRules>,
     industry_patterns: HashMap<String, Vec<Regex>>,
     experience_patterns: Vec<Regex>,
     leadership_patterns: Vec<Regex>,
+    /// User-defined industries keyed by lowercase name
+    custom_industries: HashMap<String, IndustryDefinition>,
 }
 
 impl IndustryAnalyzer {
+    #[allow(dead_code)]
     pub fn new(database: Database) -> Self {
+        Self::with_custom_industries(database, HashMap::new())
+    }
+
+    /// Analyzer that also knows `custom_industries`, keyed by lowercase name
+    pub fn with_custom_industries(
+        database: Database,
+        custom_industries: HashMap<String, IndustryDefinition>,
+    ) -> Self {
         let industry_rules = Self::build_industry_rules();
         let industry_patterns = Self::build_industry_patterns();
         let experience_patterns = Self::build_experience_patterns();
@@ -144,6 +294,7 @@ impl IndustryAnalyzer {
             industry_patterns,
             experience_patterns,
             leadership_patterns,
+            custom_industries,
         }
     }
 
@@ -202,6 +353,91 @@ impl IndustryAnalyzer {
         })
     }
 
+    /// Find skills from the resume's industry that carry over to the industry the JD is in,
+    /// so career switchers know which existing strengths to lead with
+    pub async fn identify_transferable_skills(
+        &self,
+        resume_content: &str,
+        job_description: &str,
+    ) -> Result<TransferableSkillsReport> {
+        let source_industry = self.detect_industry_from_content(resume_content).await?;
+        let target_industry = self.detect_industry_from_content(job_description).await?;
+        let switching_industries = !source_industry.eq_ignore_ascii_case(&target_industry);
+
+        let resume_lower = resume_content.to_lowercase();
+        let jd_lower = job_description.to_lowercase();
+
+        // Staying in the same industry, every skill already applies as-is
+        let mut mappings: Vec<TransferableSkillMapping> = TRANSFERABLE_SKILLS
+            .iter()
+            .filter(|(source, _, jd_terms, _)| {
+                switching_industries
+                    && Self::contains_term(&resume_lower, source)
+                    && jd_terms
+                        .iter()
+                        .any(|term| Self::contains_term(&jd_lower, term))
+            })
+            .map(|(source, target, _, confidence)| TransferableSkillMapping {
+                source_skill: source.to_string(),
+                target_skill: target.to_string(),
+                confidence: *confidence,
+                evidence: resume_content
+                    .lines()
+                    .find(|line| Self::contains_term(&line.to_lowercase(), source))
+                    .unwrap_or_default()
+                    .trim()
+                    .to_string(),
+            })
+            .collect();
+
+        mappings.sort_by(|a, b| {
+            b.confidence
+                .partial_cmp(&a.confidence)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let strengths_to_emphasize = mappings
+            .iter()
+            .map(|mapping| {
+                if mapping
+                    .source_skill
+                    .eq_ignore_ascii_case(&mapping.target_skill)
+                {
+                    format!(
+                        "Lead with {}: it applies directly in {}",
+                        mapping.target_skill, target_industry
+                    )
+                } else {
+                    format!(
+                        "Frame your {} experience as {} for {} roles",
+                        mapping.source_skill, mapping.target_skill, target_industry
+                    )
+                }
+            })
+            .collect();
+
+        info!(
+            "Found {} transferable skills from {} to {}",
+            mappings.len(),
+            source_industry,
+            target_industry
+        );
+
+        Ok(TransferableSkillsReport {
+            source_industry,
+            target_industry,
+            switching_industries,
+            mappings,
+            strengths_to_emphasize,
+        })
+    }
+
+    fn contains_term(text_lower: &str, term: &str) -> bool {
+        Regex::new(&format!(r"\b{}", regex::escape(term)))
+            .map(|re| re.is_match(text_lower))
+            .unwrap_or(false)
+    }
+
     async fn detect_industry_from_content(&self, resume_content: &str) -> Result<String> {
         let content_lower = resume_content.to_lowercase();
         let mut industry_scores: HashMap<String, f64> = HashMap::new();
@@ -243,6 +479,19 @@ impl IndustryAnalyzer {
             }
         }
 
+        // User-defined industries score by their own keyword weights
+        for (industry, definition) in &self.custom_industries {
+            let keyword_score: f64 = definition
+                .keywords
+                .iter()
+                .filter(|(keyword, _)| Self::contains_term(&content_lower, &keyword.to_lowercase()))
+                .map(|(_, weight)| weight)
+                .sum();
+            if keyword_score > 0.0 {
+                *industry_scores.entry(industry.clone()).or_insert(0.0) += keyword_score;
+            }
+        }
+
         // Return the industry with the highest score
         let detected_industry = industry_scores
             .iter()
@@ -1141,6 +1390,58 @@ mod tests {
         assert_eq!(detected, "technology");
     }
 
+    #[tokio::test]
+    async fn test_transferable_skills_from_finance_to_data_analysis() {
+        let db = Database::new_with_url("sqlite::memory:").await.unwrap();
+        let analyzer = IndustryAnalyzer::new(db);
+        let resume = "Financial Analyst, First National Banking\n\
+            • Built risk modeling reports for the loan portfolio using SQL and Excel\n\
+            • Supported audit and compliance reviews for investment accounts\n";
+        let job_description = "Data Analyst\n\
+            Write SQL queries against our database, build dashboards, and perform data analysis \
+            with Python to support product decisions.";
+
+        let report = analyzer
+            .identify_transferable_skills(resume, job_description)
+            .await
+            .unwrap();
+
+        assert_eq!(report.source_industry, "finance");
+        assert!(report.switching_industries);
+
+        let sql = report
+            .mappings
+            .iter()
+            .find(|m| m.source_skill == "sql")
+            .expect("SQL should be transferable");
+        assert_eq!(sql.target_skill, "SQL");
+        assert!(sql.confidence > 0.9);
+        assert!(sql.evidence.contains("SQL and Excel"));
+        assert!(report
+            .mappings
+            .iter()
+            .any(|m| m.source_skill == "risk modeling" && m.target_skill == "data analysis"));
+    }
+
+    #[tokio::test]
+    async fn test_no_transferable_skills_within_the_same_industry() {
+        let db = Database::new_with_url("sqlite::memory:").await.unwrap();
+        let analyzer = IndustryAnalyzer::new(db);
+        let resume = "Financial Analyst, First National Banking\n\
+            • Built risk modeling reports for the loan portfolio using SQL and Excel\n";
+        let job_description = "Senior Financial Analyst at an investment bank. \
+            Build risk modeling and financial reporting in SQL and Excel for our banking portfolio.";
+
+        let report = analyzer
+            .identify_transferable_skills(resume, job_description)
+            .await
+            .unwrap();
+
+        assert!(!report.switching_industries);
+        assert!(report.mappings.is_empty());
+        assert!(report.strengths_to_emphasize.is_empty());
+    }
+
     #[tokio::test]
     async fn test_experience_estimation() {
         let analyzer = IndustryAnalyzer::new(Database::new().await.unwrap());
@@ -1160,4 +1461,29 @@ mod tests {
         assert_eq!(assessment.detected_level, "senior");
         assert!(assessment.confidence > 0.5);
     }
+
+    #[tokio::test]
+    async fn test_custom_industry_detected_from_its_keywords() {
+        let db = Database::new_with_url("sqlite::memory:").await.unwrap();
+        let resume = "Litigation associate handling contract drafting and due diligence";
+        let legal = IndustryDefinition {
+            keywords: HashMap::from([
+                ("litigation".to_string(), 3.0),
+                ("contract drafting".to_string(), 2.8),
+                ("due diligence".to_string(), 2.0),
+            ]),
+            skill_requirements: HashMap::new(),
+            experience_keywords: Vec::new(),
+            preferred_degrees: Vec::new(),
+            benchmark: None,
+            scoring_weights: None,
+        };
+
+        let analyzer = IndustryAnalyzer::with_custom_industries(
+            db,
+            HashMap::from([("legal".to_string(), legal)]),
+        );
+        let detected = analyzer.detect_industry_from_content(resume).await.unwrap();
+        assert_eq!(detected, "legal");
+    }
 }
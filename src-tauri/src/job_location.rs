@@ -0,0 +1,135 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::models::{RemoteWorkType, VisaSponsorship};
+
+static LOCATION_LINE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?im)^\s*(?:job\s+)?location\s*:\s*(.+?)\s*$")
+        .expect("valid location line pattern")
+});
+
+/// "City, ST" after a preposition, e.g. "On-site in Austin, TX"
+static CITY_STATE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b(?:[Ii]n|[Aa]t|[Oo]ut of)\s+([A-Z][a-z]+(?:[ .][A-Z][a-z]+)*,\s*[A-Z]{2})\b")
+        .expect("valid city and state pattern")
+});
+
+static HYBRID: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)\bhybrid\b").expect("valid hybrid pattern"));
+
+static REMOTE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\b(?:fully\s+)?remote\b|\bwork\s+from\s+(?:home|anywhere)\b")
+        .expect("valid remote pattern")
+});
+
+static NOT_REMOTE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\b(?:not|no|non)[\s-]+remote\b|\bremote\s+(?:work\s+)?(?:is\s+)?not\s+(?:available|possible|an option)\b")
+        .expect("valid not remote pattern")
+});
+
+static ONSITE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\bon[\s-]?site\b|\bin[\s-](?:office|person)\b").expect("valid onsite pattern")
+});
+
+/// Region a remote role is limited to: "Remote (US only)", "Remote - EU based"
+static REMOTE_REGION: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)\bremote\b\s*[(,:\-–—]?\s*\(?\s*(?:within\s+(?:the\s+)?)?([a-z][a-z.]*(?:\s+[a-z][a-z.]*)?)[\s-]+(?:only|based)\b",
+    )
+    .expect("valid remote region pattern")
+});
+
+static NO_SPONSORSHIP: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)\bno\s+(?:visa\s+|work\s+visa\s+|h-?1b\s+)?sponsorship\b|\b(?:not|unable\s+to|cannot|can't|won't|will\s+not|do\s+not|does\s+not|don't)\s+(?:be\s+)?(?:able\s+to\s+)?(?:provide\s+|offer\s+)?(?:visa\s+)?sponsor|\bwithout\s+(?:the\s+need\s+for\s+|requiring\s+)?(?:current\s+or\s+future\s+)?(?:visa\s+|employer\s+)?sponsorship\b|\bsponsorship\s+(?:is\s+)?not\s+(?:available|offered|provided)\b",
+    )
+    .expect("valid no sponsorship pattern")
+});
+
+static SPONSORSHIP_OFFERED: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)\b(?:visa\s+|h-?1b\s+)?sponsorship\s+(?:is\s+)?(?:available|offered|provided)\b|\bwill\s+sponsor\b|\b(?:we\s+)?sponsor\s+(?:work\s+)?visas\b",
+    )
+    .expect("valid sponsorship offered pattern")
+});
+
+/// Where a job is based and who it is open to, as stated in the posting
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct JobLocationSignals {
+    pub location: Option<String>,
+    /// Work arrangement the posting states, if it states one
+    pub remote_options: Option<RemoteWorkType>,
+    /// Country or region a remote role is restricted to, e.g. "US"
+    pub remote_region: Option<String>,
+    pub visa_sponsorship: VisaSponsorship,
+}
+
+/// Read location, remote/hybrid/on-site arrangement and visa sponsorship language from a
+/// job description
+pub fn extract_location_signals(content: &str) -> JobLocationSignals {
+    let location = LOCATION_LINE
+        .captures(content)
+        .map(|captures| captures[1].to_string())
+        .or_else(|| {
+            CITY_STATE
+                .captures(content)
+                .map(|captures| captures[1].to_string())
+        });
+
+    let remote = REMOTE.is_match(content) && !NOT_REMOTE.is_match(content);
+    let remote_options = if HYBRID.is_match(content) {
+        Some(RemoteWorkType::Hybrid)
+    } else if remote {
+        Some(RemoteWorkType::Remote)
+    } else if ONSITE.is_match(content) || NOT_REMOTE.is_match(content) {
+        Some(RemoteWorkType::OnSite)
+    } else {
+        None
+    };
+
+    let remote_region = REMOTE_REGION
+        .captures(content)
+        .filter(|_| remote)
+        .map(|captures| captures[1].to_string());
+
+    // Refusals are checked first: "we do not sponsor" also contains "sponsor"
+    let visa_sponsorship = if NO_SPONSORSHIP.is_match(content) {
+        VisaSponsorship::NotOffered
+    } else if SPONSORSHIP_OFFERED.is_match(content) {
+        VisaSponsorship::Offered
+    } else {
+        VisaSponsorship::Unspecified
+    };
+
+    JobLocationSignals {
+        location,
+        remote_options,
+        remote_region,
+        visa_sponsorship,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remote_restriction_and_onsite_without_sponsorship() {
+        let remote = extract_location_signals(
+            "Senior Data Engineer\nRemote (US only)\nBuild pipelines in Python and Spark.",
+        );
+        assert_eq!(remote.remote_options, Some(RemoteWorkType::Remote));
+        assert_eq!(remote.remote_region.as_deref(), Some("US"));
+        assert_eq!(remote.location, None);
+        assert_eq!(remote.visa_sponsorship, VisaSponsorship::Unspecified);
+
+        let onsite = extract_location_signals(
+            "Backend Engineer\nOn-site in Austin, TX — no visa sponsorship\nGo and Postgres.",
+        );
+        assert_eq!(onsite.remote_options, Some(RemoteWorkType::OnSite));
+        assert_eq!(onsite.location.as_deref(), Some("Austin, TX"));
+        assert_eq!(onsite.remote_region, None);
+        assert_eq!(onsite.visa_sponsorship, VisaSponsorship::NotOffered);
+    }
+}
@@ -0,0 +1,250 @@
+use once_cell::sync::Lazy;
+use rust_stemmers::{Algorithm, Stemmer};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::utils::tokenize;
+
+/// A non-English guess needs at least this many stopword hits, otherwise short or
+/// keyword-only text would flip language on a single shared word
+const MIN_STOPWORD_HITS: usize = 3;
+
+static STEMMERS: Lazy<HashMap<Language, Stemmer>> = Lazy::new(|| {
+    Language::ALL
+        .into_iter()
+        .map(|language| (language, Stemmer::create(language.stemmer_algorithm())))
+        .collect()
+});
+
+/// Languages with a Snowball stemmer and stopword list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    #[default]
+    English,
+    Spanish,
+    French,
+    German,
+    Portuguese,
+    Italian,
+    Dutch,
+}
+
+impl Language {
+    pub const ALL: [Language; 7] = [
+        Language::English,
+        Language::Spanish,
+        Language::French,
+        Language::German,
+        Language::Portuguese,
+        Language::Italian,
+        Language::Dutch,
+    ];
+
+    pub fn stemmer_algorithm(self) -> Algorithm {
+        match self {
+            Language::English => Algorithm::English,
+            Language::Spanish => Algorithm::Spanish,
+            Language::French => Algorithm::French,
+            Language::German => Algorithm::German,
+            Language::Portuguese => Algorithm::Portuguese,
+            Language::Italian => Algorithm::Italian,
+            Language::Dutch => Algorithm::Dutch,
+        }
+    }
+
+    /// Shared Snowball stemmer for the language, created once per process
+    pub fn stemmer(self) -> &'static Stemmer {
+        &STEMMERS[&self]
+    }
+
+    /// Common function words, used both for detection and to drop noise from keyword lists
+    pub fn stopwords(self) -> &'static [&'static str] {
+        match self {
+            Language::English => &[
+                "the", "and", "of", "to", "in", "for", "with", "on", "is", "are", "was", "were",
+                "by", "as", "at", "from", "this", "that", "be", "have", "has", "an", "or", "it",
+                "our", "you", "will", "we",
+            ],
+            Language::Spanish => &[
+                "el", "la", "los", "las", "de", "del", "y", "en", "con", "para", "por", "que",
+                "un", "una", "es", "son", "se", "al", "como", "más", "sus", "su", "lo", "o",
+                "desde", "sobre", "entre", "nuestro",
+            ],
+            Language::French => &[
+                "le", "la", "les", "des", "de", "du", "et", "en", "un", "une", "pour", "avec",
+                "dans", "sur", "par", "est", "sont", "au", "aux", "que", "qui", "ce", "cette",
+                "ses", "son", "nous", "vous", "plus",
+            ],
+            Language::German => &[
+                "der", "die", "das", "und", "ein", "eine", "mit", "für", "von", "zu", "im", "in",
+                "ist", "sind", "auf", "den", "dem", "des", "bei", "als", "auch", "oder", "wir",
+                "sie", "nicht", "über", "durch",
+            ],
+            Language::Portuguese => &[
+                "o", "a", "os", "as", "de", "do", "da", "dos", "das", "e", "em", "no", "na", "com",
+                "para", "por", "um", "uma", "que", "é", "são", "ao", "como", "mais", "seu", "sua",
+                "nosso",
+            ],
+            Language::Italian => &[
+                "il", "lo", "la", "gli", "le", "di", "del", "della", "e", "in", "con", "per", "da",
+                "un", "una", "che", "è", "sono", "al", "nel", "come", "più", "suo", "sua",
+                "nostro",
+            ],
+            Language::Dutch => &[
+                "de", "het", "een", "en", "van", "in", "met", "voor", "op", "is", "zijn", "te",
+                "aan", "door", "bij", "als", "ook", "of", "wij", "naar", "dat", "die",
+            ],
+        }
+    }
+
+    /// Local-language terms for English keywords, merged into the language-agnostic synonym
+    /// table so a resume in this language still matches an English job description
+    pub fn synonyms(self) -> &'static [(&'static str, &'static [&'static str])] {
+        match self {
+            Language::English => &[],
+            Language::Spanish => &[
+                ("machine learning", &["aprendizaje automático"]),
+                ("artificial intelligence", &["inteligencia artificial"]),
+                ("data science", &["ciencia de datos"]),
+                ("project management", &["gestión de proyectos"]),
+                ("leadership", &["liderazgo"]),
+                ("communication", &["comunicación"]),
+                (
+                    "cybersecurity",
+                    &["ciberseguridad", "seguridad informática"],
+                ),
+                ("software development", &["desarrollo de software"]),
+            ],
+            Language::French => &[
+                ("machine learning", &["apprentissage automatique"]),
+                ("artificial intelligence", &["intelligence artificielle"]),
+                ("data science", &["science des données"]),
+                (
+                    "project management",
+                    &["gestion de projet", "gestion de projets"],
+                ),
+                ("leadership", &["encadrement", "direction d'équipe"]),
+                ("cybersecurity", &["cybersécurité", "sécurité informatique"]),
+                ("software development", &["développement logiciel"]),
+                ("database", &["base de données", "bases de données"]),
+            ],
+            Language::German => &[
+                ("machine learning", &["maschinelles lernen"]),
+                ("artificial intelligence", &["künstliche intelligenz"]),
+                ("data science", &["datenwissenschaft"]),
+                (
+                    "project management",
+                    &["projektmanagement", "projektleitung"],
+                ),
+                ("leadership", &["führung", "teamleitung"]),
+                ("communication", &["kommunikation"]),
+                ("cybersecurity", &["cybersicherheit", "it-sicherheit"]),
+                ("software development", &["softwareentwicklung"]),
+            ],
+            Language::Portuguese => &[
+                (
+                    "machine learning",
+                    &["aprendizado de máquina", "aprendizagem automática"],
+                ),
+                ("artificial intelligence", &["inteligência artificial"]),
+                ("data science", &["ciência de dados"]),
+                (
+                    "project management",
+                    &["gestão de projetos", "gerenciamento de projetos"],
+                ),
+                ("leadership", &["liderança"]),
+                ("communication", &["comunicação"]),
+                ("software development", &["desenvolvimento de software"]),
+            ],
+            Language::Italian => &[
+                ("machine learning", &["apprendimento automatico"]),
+                ("artificial intelligence", &["intelligenza artificiale"]),
+                ("data science", &["scienza dei dati"]),
+                (
+                    "project management",
+                    &["gestione progetti", "gestione dei progetti"],
+                ),
+                ("leadership", &["guida del team"]),
+                ("communication", &["comunicazione"]),
+                ("software development", &["sviluppo software"]),
+            ],
+            Language::Dutch => &[
+                ("machine learning", &["machinaal leren"]),
+                ("artificial intelligence", &["kunstmatige intelligentie"]),
+                (
+                    "project management",
+                    &["projectmanagement", "projectleiding"],
+                ),
+                ("leadership", &["leiderschap"]),
+                ("communication", &["communicatie"]),
+                ("software development", &["softwareontwikkeling"]),
+            ],
+        }
+    }
+
+    pub fn is_stopword(self, word: &str) -> bool {
+        self.stopwords().contains(&word.to_lowercase().as_str())
+    }
+}
+
+/// Guess the language of a resume or job description from stopword frequency, falling back to
+/// English when no other language is clearly ahead
+pub fn detect_language(text: &str) -> Language {
+    let words: Vec<String> = tokenize(text)
+        .into_iter()
+        .map(|token| token.text.to_lowercase())
+        .collect();
+    let hits = |language: Language| {
+        let stopwords = language.stopwords();
+        words
+            .iter()
+            .filter(|word| stopwords.contains(&word.as_str()))
+            .count()
+    };
+
+    let english_hits = hits(Language::English);
+    Language::ALL
+        .into_iter()
+        .filter(|language| *language != Language::English)
+        .map(|language| (language, hits(language)))
+        .filter(|(_, count)| *count >= MIN_STOPWORD_HITS && *count > english_hits)
+        .max_by_key(|(_, count)| *count)
+        .map_or(Language::English, |(language, _)| language)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_common_languages() {
+        assert_eq!(
+            detect_language(
+                "Led the migration of our services to the cloud and mentored engineers"
+            ),
+            Language::English
+        );
+        assert_eq!(
+            detect_language("Desarrollé los sistemas de pagos para la empresa y lideré el equipo"),
+            Language::Spanish
+        );
+        assert_eq!(
+            detect_language(
+                "Responsable des projets et de la migration vers le cloud pour les clients"
+            ),
+            Language::French
+        );
+        assert_eq!(
+            detect_language(
+                "Verantwortlich für die Entwicklung und den Betrieb der Plattform mit dem Team"
+            ),
+            Language::German
+        );
+        // Too little signal to move off the default
+        assert_eq!(
+            detect_language("Python, SQL, Kubernetes"),
+            Language::English
+        );
+    }
+}
@@ -0,0 +1,439 @@
+use anyhow::{bail, Result};
+use chrono::NaiveDate;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::employment_gaps::date_range_start;
+use crate::models::ExperienceLevelThresholds;
+
+/// Levels apart before the resume and the job count as mismatched
+const MISMATCH_LEVELS: u8 = 2;
+/// Years short of a job's stated minimum before the candidate counts as underqualified
+const YEARS_SHORTFALL: u32 = 2;
+/// Scope verbs needed before they lift a resume with some tenure to senior level
+const SENIOR_SCOPE_VERBS: usize = 3;
+/// Years of experience needed before scope verbs count toward seniority
+const SCOPE_VERB_MIN_YEARS: u32 = 5;
+
+static STATED_YEARS: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)(\d{1,2})\+?\s*(?:years?|yrs?)(?:\s+of)?\s+(?:\w+\s+)?experience")
+        .expect("valid stated years pattern")
+});
+
+/// A stated requirement such as "5+ years" or "3-5+ years", capturing its lower bound
+static REQUIRED_YEARS: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)(\d{1,2})\s*(?:\+|(?:-|–|to)\s*\d{1,2}\+?)?\s*(?:years?|yrs?)")
+        .expect("valid required years pattern")
+});
+
+/// Reject thresholds that do not increase from mid to senior to executive
+pub fn validate_experience_level_thresholds(thresholds: &ExperienceLevelThresholds) -> Result<()> {
+    if !(thresholds.mid_years < thresholds.senior_years
+        && thresholds.senior_years < thresholds.executive_years)
+    {
+        bail!(
+            "Experience level thresholds must increase from mid to senior to executive, got {}, {} and {} years",
+            thresholds.mid_years,
+            thresholds.senior_years,
+            thresholds.executive_years
+        );
+    }
+    Ok(())
+}
+
+const EXECUTIVE_TERMS: &[&str] = &[
+    "director",
+    "vice president",
+    "vp of",
+    "head of",
+    "chief",
+    "cto",
+    "ceo",
+];
+const SENIOR_TERMS: &[&str] = &["senior", "sr.", "lead", "principal", "staff", "architect"];
+const ENTRY_TERMS: &[&str] = &[
+    "entry level",
+    "entry-level",
+    "junior",
+    "new grad",
+    "recent graduate",
+    "graduate program",
+    "intern",
+    "no experience required",
+];
+/// Words that turn a seniority term into something else, e.g. "lead generation"
+const NON_TITLE_FOLLOWERS: &[(&str, &[&str])] = &[(
+    "lead",
+    &[
+        "generation",
+        "gen",
+        "scoring",
+        "nurturing",
+        "qualification",
+        "time",
+        "times",
+    ],
+)];
+/// Verbs that describe owning people or direction rather than tasks
+const SCOPE_VERBS: &[&str] = &[
+    "led", "managed", "directed", "oversaw", "mentored", "hired", "founded", "headed",
+];
+
+/// Seniority ladder shared by resumes and job descriptions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SeniorityLevel {
+    Entry,
+    #[default]
+    Mid,
+    Senior,
+    Executive,
+}
+
+impl SeniorityLevel {
+    pub fn from_years(years: u32, thresholds: &ExperienceLevelThresholds) -> Self {
+        if years >= thresholds.executive_years {
+            SeniorityLevel::Executive
+        } else if years >= thresholds.senior_years {
+            SeniorityLevel::Senior
+        } else if years >= thresholds.mid_years {
+            SeniorityLevel::Mid
+        } else {
+            SeniorityLevel::Entry
+        }
+    }
+
+    /// The level an analysis request names, e.g. "senior"
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "entry" => Some(SeniorityLevel::Entry),
+            "mid" => Some(SeniorityLevel::Mid),
+            "senior" => Some(SeniorityLevel::Senior),
+            "executive" => Some(SeniorityLevel::Executive),
+            _ => None,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            SeniorityLevel::Entry => "entry-level",
+            SeniorityLevel::Mid => "mid-level",
+            SeniorityLevel::Senior => "senior-level",
+            SeniorityLevel::Executive => "executive-level",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LevelFitVerdict {
+    #[default]
+    Matched,
+    Overqualified,
+    Underqualified,
+}
+
+/// How the seniority a resume demonstrates compares with the level a job targets
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LevelFit {
+    pub resume_level: SeniorityLevel,
+    pub job_level: SeniorityLevel,
+    pub resume_years: u32,
+    /// Minimum years the job asks for, when it states one
+    pub required_years: Option<u32>,
+    pub verdict: LevelFitVerdict,
+    /// Evidence behind the resume's level: tenure, titles and scope verbs
+    pub signals: Vec<String>,
+    pub explanation: String,
+}
+
+/// Compare the resume's demonstrated seniority as of `today` with the job description's target
+/// level, bucketing years of experience with `thresholds`
+pub fn assess_level_fit_with(
+    resume_content: &str,
+    job_description: &str,
+    today: NaiveDate,
+    thresholds: &ExperienceLevelThresholds,
+) -> LevelFit {
+    let (resume_level, resume_years, signals) = resume_seniority(resume_content, today, thresholds);
+    let (job_level, required_years) = job_seniority(job_description, thresholds);
+
+    let short_on_years =
+        required_years.is_some_and(|required| resume_years + YEARS_SHORTFALL < required);
+    let verdict = if resume_level as u8 >= job_level as u8 + MISMATCH_LEVELS {
+        LevelFitVerdict::Overqualified
+    } else if job_level as u8 >= resume_level as u8 + MISMATCH_LEVELS || short_on_years {
+        LevelFitVerdict::Underqualified
+    } else {
+        LevelFitVerdict::Matched
+    };
+
+    let evidence = if signals.is_empty() {
+        String::new()
+    } else {
+        format!(" ({})", signals.join(", "))
+    };
+    let explanation = match verdict {
+        LevelFitVerdict::Matched => format!(
+            "The resume reads as {}{}, in line with this {} role.",
+            resume_level.label(),
+            evidence,
+            job_level.label()
+        ),
+        LevelFitVerdict::Overqualified => format!(
+            "The resume reads as {}{} but the job targets {} candidates. Recruiters may worry \
+             the role will not hold your interest; lead with the hands-on work this role needs \
+             or target more senior postings.",
+            resume_level.label(),
+            evidence,
+            job_level.label()
+        ),
+        LevelFitVerdict::Underqualified => {
+            let years = required_years
+                .filter(|_| short_on_years)
+                .map(|required| format!(" and asks for {}+ years", required))
+                .unwrap_or_default();
+            format!(
+                "The resume reads as {}{} but the job targets {} candidates{}. Bring forward \
+                 the most senior scope you have held, or target roles closer to your level.",
+                resume_level.label(),
+                evidence,
+                job_level.label(),
+                years
+            )
+        }
+    };
+
+    LevelFit {
+        resume_level,
+        job_level,
+        resume_years,
+        required_years,
+        verdict,
+        signals,
+        explanation,
+    }
+}
+
+fn resume_seniority(
+    content: &str,
+    today: NaiveDate,
+    thresholds: &ExperienceLevelThresholds,
+) -> (SeniorityLevel, u32, Vec<String>) {
+    let lower = content.to_lowercase();
+    let mut signals = Vec::new();
+
+    // Career span from the earliest dated role, or the longest stated tenure if larger
+    let span_years = career_span_years(content.lines(), today);
+    let stated_years = STATED_YEARS
+        .captures_iter(content)
+        .filter_map(|captures| captures[1].parse::<u32>().ok())
+        .max()
+        .unwrap_or(0);
+    let years = span_years.max(stated_years);
+    if years > 0 {
+        signals.push(format!("{} years of experience", years));
+    }
+
+    let mut level = SeniorityLevel::from_years(years, thresholds);
+    // An entry-level term on a resume is usually an early role, so only senior terms count
+    if let Some((term_level, term)) =
+        term_seniority(&lower).filter(|(term_level, _)| *term_level >= SeniorityLevel::Senior)
+    {
+        signals.push(format!("\"{}\" title", term));
+        level = level.max(term_level);
+    }
+
+    let scope_verbs = lower
+        .split(|c: char| !c.is_alphabetic())
+        .filter(|word| SCOPE_VERBS.contains(word))
+        .count();
+    if scope_verbs >= SENIOR_SCOPE_VERBS {
+        signals.push(format!("{} leadership verbs", scope_verbs));
+        if years >= SCOPE_VERB_MIN_YEARS {
+            level = level.max(SeniorityLevel::Senior);
+        }
+    }
+
+    (level, years, signals)
+}
+
+/// Whole years from the earliest date range among `texts` to `today`, 0 when none is dated
+pub fn career_span_years<'a>(texts: impl IntoIterator<Item = &'a str>, today: NaiveDate) -> u32 {
+    texts
+        .into_iter()
+        .filter_map(date_range_start)
+        .min()
+        .map_or(0, |start| {
+            (today.signed_duration_since(start).num_days() / 365).max(0) as u32
+        })
+}
+
+fn job_seniority(
+    job_description: &str,
+    thresholds: &ExperienceLevelThresholds,
+) -> (SeniorityLevel, Option<u32>) {
+    let lower = job_description.to_lowercase();
+    // The strictest stated minimum is the bar; a range counts from its lower end
+    let required_years = REQUIRED_YEARS
+        .captures_iter(job_description)
+        .filter_map(|captures| captures[1].parse::<u32>().ok())
+        .max();
+
+    // A level in the job title outranks one mentioned further down, such as the senior
+    // engineers a junior hire reports to
+    let title = lower
+        .split(['\n', '.'])
+        .find(|line| !line.trim().is_empty())
+        .unwrap_or("");
+    let level = term_seniority(title)
+        .or_else(|| term_seniority(&lower))
+        .map(|(level, _)| level)
+        .unwrap_or_else(|| {
+            required_years.map_or(SeniorityLevel::Mid, |years| {
+                SeniorityLevel::from_years(years, thresholds)
+            })
+        });
+    (level, required_years)
+}
+
+/// The level the seniority terms in `lower` name, most senior first, with the term found
+pub fn term_seniority(lower: &str) -> Option<(SeniorityLevel, &'static str)> {
+    [
+        (SeniorityLevel::Executive, EXECUTIVE_TERMS),
+        (SeniorityLevel::Senior, SENIOR_TERMS),
+        (SeniorityLevel::Entry, ENTRY_TERMS),
+    ]
+    .into_iter()
+    .find_map(|(level, terms)| first_term(lower, terms).map(|term| (level, term)))
+}
+
+/// First of `terms` appearing in `lower` as a whole word or phrase
+fn first_term(lower: &str, terms: &[&'static str]) -> Option<&'static str> {
+    terms.iter().copied().find(|term| {
+        let excluded_followers = NON_TITLE_FOLLOWERS
+            .iter()
+            .find(|(excluded_term, _)| excluded_term == term)
+            .map_or(&[][..], |(_, followers)| *followers);
+        lower.match_indices(term).any(|(start, matched)| {
+            let before = lower[..start].chars().next_back();
+            let rest = &lower[start + matched.len()..];
+            let next_word = rest.split(|c: char| !c.is_alphanumeric()).nth(1);
+            !before.is_some_and(char::is_alphanumeric)
+                && !rest.chars().next().is_some_and(char::is_alphanumeric)
+                && !next_word.is_some_and(|word| excluded_followers.contains(&word))
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_senior_resume_for_entry_level_job_is_overqualified() {
+        let today = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+        let resume = "Alex Kim\n\nEXPERIENCE\n\
+                      Senior Engineering Manager | Acme | Jan 2016 - Present\n\
+                      • Led a platform group of 30 engineers and managed four team leads\n\
+                      • Hired and mentored a dozen senior engineers\n\
+                      Senior Software Engineer | Globex | Mar 2009 - Dec 2015\n\
+                      • Led the rewrite of the billing system\n";
+        let job = "Junior Software Engineer (entry level). 0-2 years of experience. \
+                   You will fix bugs and write tests under the guidance of senior engineers.";
+
+        let fit = assess_level_fit_with(resume, job, today, &ExperienceLevelThresholds::default());
+        assert_eq!(fit.resume_years, 15);
+        assert_eq!(fit.job_level, SeniorityLevel::Entry);
+        assert!(fit.resume_level >= SeniorityLevel::Senior);
+        assert_eq!(fit.verdict, LevelFitVerdict::Overqualified);
+        assert!(fit.explanation.contains("entry-level"));
+
+        let senior_job = "Senior Software Engineer with 10+ years building distributed systems.";
+        assert_eq!(
+            assess_level_fit_with(
+                resume,
+                senior_job,
+                today,
+                &ExperienceLevelThresholds::default()
+            )
+            .verdict,
+            LevelFitVerdict::Matched
+        );
+    }
+
+    #[test]
+    fn test_custom_thresholds_bucket_four_years_differently() {
+        let today = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+        let resume = "Sam Lee\n\nEXPERIENCE\n\
+                      Data Analyst | Initech | Jun 2020 - Present\n\
+                      • Built weekly revenue dashboards in SQL and Tableau\n";
+        let job = "Data Analyst to build reporting in SQL.";
+
+        let default_fit =
+            assess_level_fit_with(resume, job, today, &ExperienceLevelThresholds::default());
+        assert_eq!(default_fit.resume_years, 4);
+        assert_eq!(default_fit.resume_level, SeniorityLevel::Mid);
+
+        // Fields with long training paths count fewer early years as mid-level
+        let strict = ExperienceLevelThresholds {
+            mid_years: 5,
+            senior_years: 10,
+            executive_years: 20,
+        };
+        let strict_fit = assess_level_fit_with(resume, job, today, &strict);
+        assert_eq!(strict_fit.resume_level, SeniorityLevel::Entry);
+
+        let fast = ExperienceLevelThresholds {
+            mid_years: 1,
+            senior_years: 4,
+            executive_years: 10,
+        };
+        assert_eq!(
+            assess_level_fit_with(resume, job, today, &fast).resume_level,
+            SeniorityLevel::Senior
+        );
+    }
+
+    #[test]
+    fn test_non_increasing_thresholds_rejected() {
+        let inverted = ExperienceLevelThresholds {
+            mid_years: 8,
+            senior_years: 3,
+            executive_years: 15,
+        };
+        let error = validate_experience_level_thresholds(&inverted).unwrap_err();
+        assert!(error.to_string().contains("must increase"), "{}", error);
+        assert!(
+            validate_experience_level_thresholds(&ExperienceLevelThresholds::default()).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_job_level_reads_title_and_whole_terms() {
+        let thresholds = ExperienceLevelThresholds::default();
+
+        // Senior terms outrank a degree requirement that merely mentions graduates
+        let (level, _) = job_seniority(
+            "Senior Data Engineer\nGraduate degree required. Build pipelines in Spark.",
+            &thresholds,
+        );
+        assert_eq!(level, SeniorityLevel::Senior);
+
+        // A sales "lead generation" duty is not a lead title
+        let (level, required) = job_seniority(
+            "Marketing Coordinator\nOwn lead generation campaigns. 3-5+ years of experience.",
+            &thresholds,
+        );
+        assert_eq!(required, Some(3));
+        assert_eq!(level, SeniorityLevel::Mid);
+
+        // The strictest stated minimum sets the bar
+        let (_, required) = job_seniority(
+            "Backend Engineer\n2+ years with Go and 5+ years building web services.",
+            &thresholds,
+        );
+        assert_eq!(required, Some(5));
+    }
+}
@@ -1,19 +1,34 @@
 // Library exports for integration testing
 
+pub mod analysis_estimate;
+pub mod checklist;
 pub mod commands;
 pub mod config;
+pub mod contact_confidence;
 pub mod database;
 pub mod document;
+pub mod employment_gaps;
 pub mod errors;
+pub mod functional_resume;
+pub mod job_location;
+pub mod language;
+pub mod level_fit;
+pub mod linkedin_export;
 pub mod memory_manager;
 pub mod migrations;
 pub mod models;
 pub mod ollama;
 pub mod plugin_system;
+pub mod requirement_coverage;
+pub mod resume_export;
 pub mod scoring;
+pub mod section_classifier;
+pub mod system_health;
 pub mod utils;
+pub mod webhook;
 // Advanced Scoring Engine
 pub mod advanced_scoring;
+pub mod cover_letter;
 // Phase 2 Enhanced Analysis Modules
 pub mod ats_simulator;
 pub mod enhanced_prompts;
@@ -26,8 +41,10 @@ pub mod format_issue_detector;
 pub mod testing_framework;
 // Phase 4 Advanced Optimization Modules
 pub mod achievement_analyzer;
+pub mod readability_analyzer;
 pub mod realtime_optimizer;
 pub mod smart_optimizer;
+pub mod tone_analyzer;
 // Phase 5 Competitive Features
 pub mod competitive_analyzer;
 // Phase 6 Advanced AI Integration & Machine Learning
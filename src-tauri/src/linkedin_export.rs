@@ -0,0 +1,327 @@
+use chrono::Utc;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashMap;
+
+use crate::advanced_scoring::{
+    parse_certifications, ContactInfo, EducationEntry, ExperienceEntry, ParsedResume,
+};
+use crate::contact_confidence::ContactConfidence;
+use crate::employment_gaps::find_date_range;
+
+/// Headers of the main column of a LinkedIn "Save to PDF" profile export, with the canonical
+/// section name the parsers report
+const MAIN_SECTIONS: &[(&str, &str)] = &[
+    ("Summary", "Summary"),
+    ("Experience", "Experience"),
+    ("Education", "Education"),
+];
+
+/// Headers of the sidebar, which text extraction places before or after the main column
+const SIDEBAR_SECTIONS: &[(&str, &str)] = &[
+    ("Contact", "Contact"),
+    ("Top Skills", "Skills"),
+    ("Languages", "Languages"),
+    ("Certifications", "Certifications"),
+    ("Honors-Awards", "Awards"),
+    ("Publications", "Publications"),
+    ("Patents", "Patents"),
+];
+
+/// Number of signature markers needed before content is treated as a LinkedIn export
+const MIN_SIGNATURE_MARKERS: usize = 2;
+
+/// Page footer LinkedIn prints on every page of the export
+static PAGE_FOOTER: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^Page \d+ of \d+$").expect("valid page footer pattern"));
+
+/// Total tenure LinkedIn prints under a company that lists several roles, e.g. "4 years 3 months"
+static TENURE_LINE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?:\d+ years?)?(?:\s*\d+ months?)?$").expect("valid tenure pattern")
+});
+
+static EMAIL: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}").expect("valid email pattern")
+});
+
+static PHONE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\+?\d[\d\s().-]{7,}\d").expect("valid phone pattern"));
+
+/// Whether `content` looks like a LinkedIn profile export rather than a resume, judged by the
+/// "Top Skills" sidebar, the profile URL, the "Contact" header and the page footers
+pub fn is_linkedin_export(content: &str) -> bool {
+    let lines: Vec<&str> = content.lines().map(str::trim).collect();
+    let markers = [
+        lines.contains(&"Top Skills"),
+        lines.contains(&"Contact"),
+        content.contains("linkedin.com/in/"),
+        lines.iter().any(|line| PAGE_FOOTER.is_match(line)),
+    ];
+    // "Top Skills" is unique to the export; the rest only count alongside each other
+    markers[0] && markers.iter().filter(|found| **found).count() >= MIN_SIGNATURE_MARKERS
+}
+
+/// Map a LinkedIn profile export onto the sections a resume parser would report
+pub fn parse_linkedin_export(content: &str) -> ParsedResume {
+    let mut preamble = Vec::new();
+    let mut section_lines: HashMap<&str, Vec<&str>> = HashMap::new();
+    let mut current: Option<&str> = None;
+
+    for line in content.lines().map(str::trim) {
+        if line.is_empty() || PAGE_FOOTER.is_match(line) {
+            continue;
+        }
+        if let Some(section) = section_for_header(line) {
+            current = Some(section);
+            section_lines.entry(section).or_default();
+            continue;
+        }
+        match current {
+            Some(section) => section_lines.entry(section).or_default().push(line),
+            // Name, headline and location head the main column
+            None => preamble.push(line),
+        }
+    }
+
+    let lines_of = |section: &str| section_lines.get(section).cloned().unwrap_or_default();
+    let contact_lines = lines_of("Contact");
+    let contact_info = ContactInfo {
+        name: preamble.first().map(|line| line.to_string()),
+        email: contact_lines
+            .iter()
+            .find_map(|line| EMAIL.find(line))
+            .map(|m| m.as_str().to_string()),
+        phone: contact_lines
+            .iter()
+            .filter(|line| !line.contains("linkedin.com"))
+            .find_map(|line| PHONE.find(line))
+            .map(|m| m.as_str().to_string()),
+        location: preamble.get(2).map(|line| line.to_string()),
+        confidence: ContactConfidence::default(),
+        unconfirmed: Vec::new(),
+    };
+    let experience = parse_experience(&lines_of("Experience"));
+    let education = parse_education(&lines_of("Education"));
+    let skills: Vec<String> = lines_of("Skills").into_iter().map(str::to_string).collect();
+    let certifications = parse_certifications(
+        &lines_of("Certifications").join("\n"),
+        Utc::now().date_naive(),
+    );
+
+    let sections: HashMap<String, String> = section_lines
+        .iter()
+        .map(|(section, lines)| (section.to_string(), lines.join("\n")))
+        .collect();
+
+    let found = [
+        contact_info.name.is_some(),
+        contact_info.email.is_some(),
+        !experience.is_empty(),
+        !education.is_empty(),
+        !skills.is_empty(),
+    ];
+    let parsing_confidence =
+        found.iter().filter(|found| **found).count() as f64 / found.len() as f64;
+
+    ParsedResume {
+        sections,
+        contact_info,
+        experience,
+        education,
+        skills,
+        skill_proficiencies: HashMap::new(),
+        certifications,
+        skill_groups: Vec::new(),
+        parsing_confidence,
+    }
+}
+
+fn section_for_header(line: &str) -> Option<&'static str> {
+    MAIN_SECTIONS
+        .iter()
+        .chain(SIDEBAR_SECTIONS)
+        .find(|(header, _)| *header == line)
+        .map(|(_, section)| *section)
+}
+
+/// Roles are listed as company, title, then a dated line such as "January 2020 - Present
+/// (4 years 3 months)"; several roles at one company share a company line followed by the
+/// total tenure
+fn parse_experience(lines: &[&str]) -> Vec<ExperienceEntry> {
+    let date_lines: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(index, line)| {
+            *index > 0 && find_date_range(line).is_some_and(|range| line.starts_with(range))
+        })
+        .map(|(index, _)| index)
+        .collect();
+
+    // Where each role's company (or, within a company, title) line sits
+    let mut company_lines = Vec::with_capacity(date_lines.len());
+    let mut previous_date = None;
+    for &date_index in &date_lines {
+        let company_index = date_index
+            .checked_sub(2)
+            .map(|index| {
+                if index > 0 && TENURE_LINE.is_match(lines[index]) {
+                    index - 1
+                } else {
+                    index
+                }
+            })
+            .filter(|&index| previous_date.map_or(true, |previous| index > previous))
+            .filter(|&index| is_heading_line(lines[index]));
+        company_lines.push(company_index);
+        previous_date = Some(date_index);
+    }
+
+    let mut entries: Vec<ExperienceEntry> = Vec::new();
+    for (position, &date_index) in date_lines.iter().enumerate() {
+        let company = match company_lines[position] {
+            Some(index) => lines[index].to_string(),
+            None => entries
+                .last()
+                .map(|entry| entry.company.clone())
+                .unwrap_or_default(),
+        };
+        let description_end = date_lines.get(position + 1).map_or(lines.len(), |&next| {
+            company_lines[position + 1].unwrap_or(next - 1)
+        });
+        let description = &lines[date_index + 1..description_end.max(date_index + 1)];
+
+        entries.push(ExperienceEntry {
+            title: lines[date_index - 1].to_string(),
+            company,
+            duration: find_date_range(lines[date_index])
+                .unwrap_or(lines[date_index])
+                .to_string(),
+            description: description.join("\n"),
+            achievements: description
+                .iter()
+                .filter(|line| line.starts_with(['•', '-', '*']))
+                .map(|line| line.trim_start_matches(['•', '-', '*']).trim().to_string())
+                .collect(),
+        });
+    }
+    entries
+}
+
+/// Company and title lines are short and unpunctuated, unlike description sentences
+fn is_heading_line(line: &str) -> bool {
+    line.chars().count() <= 60 && !line.ends_with(['.', ',', ';', ':']) && !line.starts_with('•')
+}
+
+/// Schools are followed by a line like "Bachelor of Science - BS, Computer Science · (2012 - 2016)"
+fn parse_education(lines: &[&str]) -> Vec<EducationEntry> {
+    lines
+        .chunks(2)
+        .filter(|pair| pair.len() == 2)
+        .map(|pair| {
+            let (degree, years) = pair[1].split_once('·').unwrap_or((pair[1], ""));
+            EducationEntry {
+                degree: degree.trim().to_string(),
+                institution: pair[0].to_string(),
+                year: find_date_range(years)
+                    .map(|range| range.rsplit(['-', '–']).next().unwrap_or(range).trim())
+                    .map(str::to_string),
+                gpa: None,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::advanced_scoring::ATSSimulator;
+
+    const LINKEDIN_EXPORT: &str = "Jane Doe\n\
+        Senior Software Engineer at Acme\n\
+        San Francisco Bay Area\n\
+        Summary\n\
+        Backend engineer focused on distributed systems.\n\
+        Experience\n\
+        Acme Corp\n\
+        4 years 3 months\n\
+        Senior Software Engineer\n\
+        January 2022 - Present (2 years 9 months)\n\
+        San Francisco, California\n\
+        Led the migration of billing to Kubernetes.\n\
+        Software Engineer\n\
+        July 2020 - January 2022 (1 year 7 months)\n\
+        Built internal Python tooling used by 40 engineers.\n\
+        Globex\n\
+        Junior Developer\n\
+        June 2018 - June 2020 (2 years 1 month)\n\
+        Page 1 of 2\n\
+        Education\n\
+        Stanford University\n\
+        Bachelor of Science - BS, Computer Science · (2014 - 2018)\n\
+        Page 2 of 2\n\
+        Contact\n\
+        jane.doe@example.com\n\
+        www.linkedin.com/in/janedoe (LinkedIn)\n\
+        Top Skills\n\
+        Python\n\
+        Kubernetes\n\
+        PostgreSQL\n\
+        Languages\n\
+        English (Native or Bilingual)\n";
+
+    #[test]
+    fn test_linkedin_export_sections_map_despite_sidebar_order() {
+        assert!(is_linkedin_export(LINKEDIN_EXPORT));
+        assert!(!is_linkedin_export(
+            "Jane Doe\njane@example.com\n\nExperience\nEngineer | Acme | 2020 - Present\n\nSkills\nPython\n"
+        ));
+
+        let parsed = ATSSimulator::new()
+            .parse_with_multiple_systems(LINKEDIN_EXPORT)
+            .unwrap();
+
+        assert_eq!(parsed.contact_info.name.as_deref(), Some("Jane Doe"));
+        assert_eq!(
+            parsed.contact_info.email.as_deref(),
+            Some("jane.doe@example.com")
+        );
+        assert_eq!(parsed.skills, ["Python", "Kubernetes", "PostgreSQL"]);
+
+        let roles: Vec<(&str, &str, &str)> = parsed
+            .experience
+            .iter()
+            .map(|role| {
+                (
+                    role.company.as_str(),
+                    role.title.as_str(),
+                    role.duration.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            roles,
+            [
+                (
+                    "Acme Corp",
+                    "Senior Software Engineer",
+                    "January 2022 - Present"
+                ),
+                ("Acme Corp", "Software Engineer", "July 2020 - January 2022"),
+                ("Globex", "Junior Developer", "June 2018 - June 2020"),
+            ]
+        );
+        assert_eq!(
+            parsed.experience[0].description,
+            "San Francisco, California\nLed the migration of billing to Kubernetes."
+        );
+
+        assert_eq!(parsed.education.len(), 1);
+        assert_eq!(parsed.education[0].institution, "Stanford University");
+        assert_eq!(
+            parsed.education[0].degree,
+            "Bachelor of Science - BS, Computer Science"
+        );
+        assert_eq!(parsed.education[0].year.as_deref(), Some("2018"));
+        assert!(parsed.parsing_confidence > 0.9);
+    }
+}
@@ -1,20 +1,35 @@
 // Prevents additional console window on Windows in release mode
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod analysis_estimate;
+mod checklist;
 mod commands;
 mod config;
+mod contact_confidence;
 mod database;
 mod document;
+mod employment_gaps;
 mod errors;
+mod functional_resume;
+mod job_location;
+mod language;
+mod level_fit;
+mod linkedin_export;
 mod memory_manager;
 mod migrations;
 mod models;
 mod ollama;
 mod plugin_system;
+mod requirement_coverage;
+mod resume_export;
 mod scoring;
+mod section_classifier;
+mod system_health;
 mod utils;
+mod webhook;
 // Advanced Scoring Engine
 mod advanced_scoring;
+mod cover_letter;
 // Phase 2 Enhanced Analysis Modules
 mod ats_simulator;
 mod enhanced_prompts;
@@ -27,8 +42,10 @@ mod format_issue_detector;
 mod testing_framework;
 // Phase 4 Advanced Optimization Engine
 mod achievement_analyzer;
+mod readability_analyzer;
 mod realtime_optimizer;
 mod smart_optimizer;
+mod tone_analyzer;
 // Phase 5 Competitive Features
 mod competitive_analyzer;
 // Phase 6 Advanced AI Integration & Machine Learning
@@ -70,9 +87,37 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
-    // Initialize database with config
-    let database_url = config_manager.get_database_url();
-    let database = Database::new_with_url(database_url).await?;
+    // Apply the Ollama concurrency cap, which environment overrides may have changed, before
+    // any analysis can run
+    config_manager.apply_ollama_request_limit();
+
+    // Check the configured model is installed without blocking startup on Ollama
+    let default_model = config_manager.get_ollama_config().default_model.clone();
+    tokio::spawn(async move {
+        let client = match ollama::OllamaClient::new(None) {
+            Ok(client) => client,
+            Err(e) => {
+                log::warn!("Skipping model check, Ollama client unavailable: {}", e);
+                return;
+            }
+        };
+
+        match client.resolve_model(&default_model).await {
+            Ok(selection) if selection.is_fallback => log::warn!(
+                "Configured model '{}' is not installed; analyses will use '{}'",
+                selection.requested,
+                selection.selected
+            ),
+            Ok(selection) => info!("Configured model '{}' is available", selection.selected),
+            Err(e) => log::warn!("Model availability check failed: {}", e),
+        }
+    });
+
+    // Initialize database in the configured data directory, falling back to the database URL
+    let database = match config_manager.data_directory()? {
+        Some(data_dir) => Database::new_in_directory(&data_dir).await?,
+        None => Database::new_with_url(config_manager.get_database_url()).await?,
+    };
 
     // Perform initial health check
     match database.health_check().await {
@@ -88,6 +133,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
+    // Drop analyses past the retention policy, if one is configured
+    let retention_policy = &config_manager.get_performance_config().retention_policy;
+    if retention_policy.is_enabled() {
+        if let Err(e) = database.prune_old_analyses(retention_policy).await {
+            log::warn!("Pruning old analyses failed: {}", e);
+        }
+    }
+
     let app_state = AppState {
         db: std::sync::Arc::new(tokio::sync::Mutex::new(database)),
         config: std::sync::Arc::new(tokio::sync::Mutex::new(config_manager)),
@@ -99,8 +152,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             commands::get_ollama_models,
             commands::test_ollama_connection,
             commands::ollama_health_check,
+            commands::system_health,
             commands::parse_document,
             commands::parse_document_with_metadata,
+            commands::scrub_document_metadata,
             commands::extract_document_structure,
             commands::analyze_document_quality,
             commands::get_document_metadata,
@@ -111,12 +166,32 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             commands::analyze_resume,
             commands::get_analysis_history,
             commands::delete_analysis,
+            commands::prune_old_analyses,
+            commands::reanalyze_with_params,
+            commands::analyze_saved,
+            commands::analyze_resume_advanced,
+            commands::get_keyword_analysis_detailed,
+            commands::mark_suggestion_resolved,
+            commands::unmark_suggestion_resolved,
             commands::export_results,
+            commands::export_batch_results,
+            commands::export_changes_since,
+            commands::get_resume_timeline,
             commands::optimize_resume,
+            commands::export_optimized_resume,
+            commands::resume_checklist,
+            commands::requirement_coverage,
+            commands::normalize_document,
+            commands::estimate_analysis,
+            commands::employment_gaps,
+            commands::aggregate_job_keywords,
+            commands::similar_roles,
+            commands::ats_system_reports,
             commands::get_model_performance,
             commands::get_analysis_stats,
             commands::get_score_distribution,
             commands::get_improvement_trends,
+            commands::get_keyword_coverage_trend,
             commands::get_user_preferences,
             commands::update_user_preferences,
             commands::reset_user_preferences,
@@ -133,6 +208,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             commands::get_ats_rules,
             commands::save_ats_rule,
             commands::get_scoring_benchmarks,
+            commands::get_industry_benchmark,
             commands::save_scoring_benchmark,
             commands::save_user_feedback,
             commands::get_feedback_by_analysis,
@@ -146,7 +222,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             commands::semantic_analysis,
             commands::comprehensive_analysis,
             commands::industry_analysis,
+            commands::transferable_skills,
             commands::create_enhanced_prompt,
+            commands::compare_prompts,
             commands::simulate_ats_processing,
             // Phase 3 ATS Format Compatibility Commands
             commands::check_format_compatibility,
@@ -158,8 +236,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             commands::analyze_achievements,
             commands::generate_comprehensive_optimization,
             commands::get_realtime_suggestions,
+            commands::rescore_incremental,
             commands::validate_xyz_formula,
             commands::get_achievement_suggestions,
+            commands::analyze_cover_letter,
             // Phase 5 Competitive Features Commands
             commands::generate_competitive_analysis,
             commands::get_market_position_analysis,
@@ -194,10 +274,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             commands::get_market_demand_data,
             commands::get_industry_keywords_dynamic,
             commands::submit_keyword_feedback,
+            commands::submit_keyword_feedback_batch,
             // Database Migration Management Commands
             commands::get_schema_version,
             commands::run_database_migrations,
             commands::rollback_migration,
+            commands::rollback_last_migration,
             commands::verify_migration_integrity,
             commands::cleanup_expired_cache,
         ])
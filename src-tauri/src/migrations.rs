@@ -3,7 +3,7 @@ use chrono::{DateTime, Utc};
 use log::{info, warn};
 use serde::{Deserialize, Serialize};
 use sqlx::{Row, SqlitePool};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 /// Represents a single database migration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -49,7 +49,7 @@ pub struct MigrationRecord {
 /// Migration manager for handling database schema evolution
 pub struct MigrationManager {
     pool: SqlitePool,
-    migrations: HashMap<i32, Migration>,
+    migrations: BTreeMap<i32, Migration>,
 }
 
 impl MigrationManager {
@@ -57,7 +57,7 @@ impl MigrationManager {
     pub fn new(pool: SqlitePool) -> Self {
         Self {
             pool,
-            migrations: HashMap::new(),
+            migrations: BTreeMap::new(),
         }
     }
 
@@ -104,15 +104,15 @@ impl MigrationManager {
             up_sql: r#"
                 CREATE INDEX IF NOT EXISTS idx_job_descriptions_created_at ON job_descriptions(created_at);
                 CREATE INDEX IF NOT EXISTS idx_user_feedback_created_at ON user_feedback(created_at);
-                CREATE INDEX IF NOT EXISTS idx_model_performance_updated_at ON model_performance_metrics(updated_at);
-                CREATE INDEX IF NOT EXISTS idx_ats_rules_category ON ats_compatibility_rules(category);
+                CREATE INDEX IF NOT EXISTS idx_model_performance_created_at ON model_performance_metrics(created_at);
+                CREATE INDEX IF NOT EXISTS idx_ats_rules_rule_type ON ats_compatibility_rules(rule_type);
                 CREATE INDEX IF NOT EXISTS idx_industry_keywords_industry ON industry_keywords(industry);
             "#.to_string(),
             down_sql: r#"
                 DROP INDEX IF EXISTS idx_job_descriptions_created_at;
                 DROP INDEX IF EXISTS idx_user_feedback_created_at;
-                DROP INDEX IF EXISTS idx_model_performance_updated_at;
-                DROP INDEX IF EXISTS idx_ats_rules_category;
+                DROP INDEX IF EXISTS idx_model_performance_created_at;
+                DROP INDEX IF EXISTS idx_ats_rules_rule_type;
                 DROP INDEX IF EXISTS idx_industry_keywords_industry;
             "#.to_string(),
             checksum: "performance_indexes_v1".to_string(),
@@ -123,22 +123,12 @@ impl MigrationManager {
             version: 2,
             name: "add_dynamic_keywords".to_string(),
             description: "Add tables for dynamic keyword tracking and market analysis".to_string(),
+            // dynamic_keywords and skill_relationships are owned and created by
+            // DynamicKeywordDatabase; creating them here first would give them the wrong columns
             up_sql: r#"
-                CREATE TABLE IF NOT EXISTS dynamic_keywords (
-                    id INTEGER PRIMARY KEY AUTOINCREMENT,
-                    keyword TEXT NOT NULL UNIQUE,
-                    industry TEXT NOT NULL,
-                    frequency_score REAL NOT NULL DEFAULT 0.0,
-                    trend_score REAL NOT NULL DEFAULT 0.0,
-                    market_demand REAL NOT NULL DEFAULT 0.0,
-                    salary_correlation REAL NOT NULL DEFAULT 0.0,
-                    created_at TEXT NOT NULL,
-                    updated_at TEXT NOT NULL
-                );
-
                 CREATE TABLE IF NOT EXISTS keyword_trends (
                     id INTEGER PRIMARY KEY AUTOINCREMENT,
-                    keyword_id INTEGER NOT NULL,
+                    keyword_id TEXT NOT NULL,
                     trend_date TEXT NOT NULL,
                     frequency_count INTEGER NOT NULL,
                     job_postings_count INTEGER NOT NULL,
@@ -146,28 +136,14 @@ impl MigrationManager {
                     FOREIGN KEY (keyword_id) REFERENCES dynamic_keywords (id)
                 );
 
-                CREATE TABLE IF NOT EXISTS skill_relationships (
-                    id INTEGER PRIMARY KEY AUTOINCREMENT,
-                    source_skill TEXT NOT NULL,
-                    target_skill TEXT NOT NULL,
-                    relationship_type TEXT NOT NULL,
-                    strength REAL NOT NULL,
-                    industry_context TEXT,
-                    created_at TEXT NOT NULL
-                );
-
-                CREATE INDEX IF NOT EXISTS idx_dynamic_keywords_industry ON dynamic_keywords(industry);
                 CREATE INDEX IF NOT EXISTS idx_keyword_trends_date ON keyword_trends(trend_date);
-                CREATE INDEX IF NOT EXISTS idx_skill_relationships_source ON skill_relationships(source_skill);
-            "#.to_string(),
+            "#
+            .to_string(),
             down_sql: r#"
-                DROP INDEX IF EXISTS idx_skill_relationships_source;
                 DROP INDEX IF EXISTS idx_keyword_trends_date;
-                DROP INDEX IF EXISTS idx_dynamic_keywords_industry;
-                DROP TABLE IF EXISTS skill_relationships;
                 DROP TABLE IF EXISTS keyword_trends;
-                DROP TABLE IF EXISTS dynamic_keywords;
-            "#.to_string(),
+            "#
+            .to_string(),
             checksum: "dynamic_keywords_v1".to_string(),
         });
 
@@ -293,6 +269,133 @@ impl MigrationManager {
             checksum: "document_versioning_v1".to_string(),
         });
 
+        // Migration 6: Track prompt and response token counts per analysis
+        self.register_migration(Migration {
+            version: 6,
+            name: "add_model_performance_tokens".to_string(),
+            description: "Add prompt and response token counts to model performance metrics"
+                .to_string(),
+            up_sql: r#"
+                ALTER TABLE model_performance_metrics ADD COLUMN prompt_tokens INTEGER DEFAULT 0;
+                ALTER TABLE model_performance_metrics ADD COLUMN response_tokens INTEGER DEFAULT 0;
+            "#
+            .to_string(),
+            down_sql: r#"
+                ALTER TABLE model_performance_metrics DROP COLUMN response_tokens;
+                ALTER TABLE model_performance_metrics DROP COLUMN prompt_tokens;
+            "#
+            .to_string(),
+            checksum: "model_performance_tokens_v1".to_string(),
+        });
+
+        // Migration 7: Keep updated_at current so incremental export sees every change.
+        // Analyses predating the column take their creation time.
+        self.register_migration(Migration {
+            version: 7,
+            name: "add_updated_at_tracking".to_string(),
+            description: "Add updated_at to analyses and bump it on every update".to_string(),
+            up_sql: r#"
+                ALTER TABLE analyses ADD COLUMN updated_at TEXT;
+                UPDATE analyses SET updated_at = created_at WHERE updated_at IS NULL;
+
+                CREATE INDEX IF NOT EXISTS idx_resumes_updated_at ON resumes(updated_at);
+                CREATE INDEX IF NOT EXISTS idx_analyses_updated_at ON analyses(updated_at);
+
+                CREATE TRIGGER IF NOT EXISTS trg_resumes_touch_updated_at
+                AFTER UPDATE ON resumes
+                FOR EACH ROW WHEN NEW.updated_at IS OLD.updated_at
+                BEGIN
+                    UPDATE resumes
+                    SET updated_at = strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')
+                    WHERE id = NEW.id;
+                END;
+
+                CREATE TRIGGER IF NOT EXISTS trg_analyses_touch_updated_at
+                AFTER UPDATE ON analyses
+                FOR EACH ROW WHEN NEW.updated_at IS OLD.updated_at
+                BEGIN
+                    UPDATE analyses
+                    SET updated_at = strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')
+                    WHERE id = NEW.id;
+                END;
+            "#
+            .to_string(),
+            down_sql: r#"
+                DROP TRIGGER IF EXISTS trg_analyses_touch_updated_at;
+                DROP TRIGGER IF EXISTS trg_resumes_touch_updated_at;
+                DROP INDEX IF EXISTS idx_analyses_updated_at;
+                DROP INDEX IF EXISTS idx_resumes_updated_at;
+                ALTER TABLE analyses DROP COLUMN updated_at;
+            "#
+            .to_string(),
+            checksum: "updated_at_tracking_v1".to_string(),
+        });
+
+        // Migration 8: Link successive saves of the same logical resume
+        self.register_migration(Migration {
+            version: 8,
+            name: "add_resume_versions".to_string(),
+            description: "Add resume version chains keyed by the first save's id".to_string(),
+            up_sql: r#"
+                CREATE TABLE IF NOT EXISTS resume_versions (
+                    resume_id TEXT PRIMARY KEY,
+                    root_resume_id TEXT NOT NULL,
+                    version INTEGER NOT NULL,
+                    created_at TEXT NOT NULL,
+                    FOREIGN KEY (resume_id) REFERENCES resumes (id)
+                );
+
+                CREATE INDEX IF NOT EXISTS idx_resume_versions_root ON resume_versions(root_resume_id, version);
+            "#
+            .to_string(),
+            down_sql: r#"
+                DROP INDEX IF EXISTS idx_resume_versions_root;
+                DROP TABLE IF EXISTS resume_versions;
+            "#
+            .to_string(),
+            checksum: "resume_versions_v1".to_string(),
+        });
+
+        // Migration 9: Store the remote region and visa sponsorship found in job postings
+        self.register_migration(Migration {
+            version: 9,
+            name: "add_job_location_details".to_string(),
+            description: "Add remote region and visa sponsorship to job descriptions".to_string(),
+            up_sql: r#"
+                ALTER TABLE job_descriptions ADD COLUMN remote_region TEXT;
+                ALTER TABLE job_descriptions ADD COLUMN visa_sponsorship TEXT NOT NULL DEFAULT '"Unspecified"';
+            "#
+            .to_string(),
+            down_sql: r#"
+                ALTER TABLE job_descriptions DROP COLUMN visa_sponsorship;
+                ALTER TABLE job_descriptions DROP COLUMN remote_region;
+            "#
+            .to_string(),
+            checksum: "job_location_details_v1".to_string(),
+        });
+
+        // Migration 10: Answer retried analyses from the result saved under their key
+        self.register_migration(Migration {
+            version: 10,
+            name: "add_analysis_idempotency_keys".to_string(),
+            description: "Add the result saved under each client idempotency key".to_string(),
+            up_sql: r#"
+                CREATE TABLE IF NOT EXISTS analysis_idempotency_keys (
+                    idempotency_key TEXT PRIMARY KEY,
+                    analysis_id TEXT NOT NULL,
+                    result TEXT NOT NULL,
+                    created_at TEXT NOT NULL,
+                    FOREIGN KEY (analysis_id) REFERENCES analyses (id)
+                );
+            "#
+            .to_string(),
+            down_sql: r#"
+                DROP TABLE IF EXISTS analysis_idempotency_keys;
+            "#
+            .to_string(),
+            checksum: "analysis_idempotency_keys_v1".to_string(),
+        });
+
         info!("Registered {} migrations", self.migrations.len());
     }
 
@@ -311,6 +414,28 @@ impl MigrationManager {
         Ok(row.get("version"))
     }
 
+    /// Versions recorded in schema_migrations
+    pub async fn get_applied_versions(&self) -> Result<BTreeSet<i32>> {
+        let rows = sqlx::query("SELECT version FROM schema_migrations")
+            .fetch_all(&self.pool)
+            .await
+            .context("Failed to fetch applied migration versions")?;
+
+        Ok(rows.iter().map(|row| row.get("version")).collect())
+    }
+
+    /// Registered migrations that haven't been applied yet, in version order. Includes gaps
+    /// below the current version left by a partially applied upgrade.
+    async fn get_pending_migrations(&self) -> Result<Vec<&Migration>> {
+        let applied = self.get_applied_versions().await?;
+
+        Ok(self
+            .migrations
+            .values()
+            .filter(|m| !applied.contains(&m.version))
+            .collect())
+    }
+
     /// Get all applied migrations
     pub async fn get_applied_migrations(&self) -> Result<Vec<MigrationRecord>> {
         let rows = sqlx::query(
@@ -349,14 +474,13 @@ impl MigrationManager {
         let applied_migrations = self.get_applied_migrations().await?;
 
         let latest_available = self.migrations.keys().max().copied().unwrap_or(0);
-        let is_up_to_date = current_version == latest_available;
-
-        let pending_migrations = self
-            .migrations
-            .values()
-            .filter(|m| m.version > current_version)
+        let pending_migrations: Vec<Migration> = self
+            .get_pending_migrations()
+            .await?
+            .into_iter()
             .cloned()
             .collect();
+        let is_up_to_date = pending_migrations.is_empty();
 
         Ok(SchemaVersion {
             current_version,
@@ -367,20 +491,13 @@ impl MigrationManager {
         })
     }
 
-    /// Run all pending migrations
+    /// Run all pending migrations in version order. Safe to call repeatedly: applied versions
+    /// are skipped, so only what's missing runs.
     pub async fn migrate(&self) -> Result<Vec<MigrationResult>> {
         info!("Starting database migration");
 
-        let current_version = self.get_current_version().await?;
         let mut results = Vec::new();
-
-        // Get migrations to apply (sorted by version)
-        let mut pending_migrations: Vec<_> = self
-            .migrations
-            .values()
-            .filter(|m| m.version > current_version)
-            .collect();
-        pending_migrations.sort_by_key(|m| m.version);
+        let pending_migrations = self.get_pending_migrations().await?;
 
         if pending_migrations.is_empty() {
             info!("No pending migrations to apply");
@@ -395,7 +512,14 @@ impl MigrationManager {
             let execution_time = chrono::Utc::now().signed_duration_since(start_time);
 
             let migration_result = match result {
-                Ok(()) => {
+                Ok(false) => {
+                    info!(
+                        "Migration {} was applied concurrently, skipping",
+                        migration.version
+                    );
+                    continue;
+                }
+                Ok(true) => {
                     info!("Migration {} applied successfully", migration.version);
                     MigrationResult {
                         version: migration.version,
@@ -430,8 +554,8 @@ impl MigrationManager {
         Ok(results)
     }
 
-    /// Apply a single migration
-    async fn apply_migration(&self, migration: &Migration) -> Result<()> {
+    /// Apply a single migration, returning false if it turned out to be applied already
+    async fn apply_migration(&self, migration: &Migration) -> Result<bool> {
         info!(
             "Applying migration {}: {}",
             migration.version, migration.name
@@ -452,23 +576,31 @@ impl MigrationManager {
             .context("Failed to check existing migration")?;
 
         if existing.is_some() {
-            return Err(anyhow!(
-                "Migration {} is already applied",
-                migration.version
-            ));
+            return Ok(false);
         }
 
         // Execute migration SQL
-        for statement in migration.up_sql.split(';') {
-            let statement = statement.trim();
-            if !statement.is_empty() {
-                sqlx::query(statement)
-                    .execute(&mut *tx)
-                    .await
-                    .with_context(|| {
-                        format!("Failed to execute migration statement: {}", statement)
-                    })?;
+        for statement in sql_statements(&migration.up_sql) {
+            // Databases that gained the column before it was added by a migration already have it
+            if let Some((table, column)) = added_column(statement) {
+                let columns: Vec<String> =
+                    sqlx::query_scalar("SELECT name FROM pragma_table_info(?)")
+                        .bind(table)
+                        .fetch_all(&mut *tx)
+                        .await
+                        .with_context(|| format!("Failed to read columns of {}", table))?;
+                if columns
+                    .iter()
+                    .any(|existing| existing.eq_ignore_ascii_case(column))
+                {
+                    continue;
+                }
             }
+
+            sqlx::query(statement)
+                .execute(&mut *tx)
+                .await
+                .with_context(|| format!("Failed to execute migration statement: {}", statement))?;
         }
 
         // Record migration in schema_migrations table
@@ -492,10 +624,11 @@ impl MigrationManager {
             .await
             .context("Failed to commit migration transaction")?;
 
-        Ok(())
+        Ok(true)
     }
 
-    /// Rollback a migration (if down_sql is provided)
+    /// Rollback a migration (if down_sql is provided). Only the most recently applied migration
+    /// can be rolled back, so older down-migrations never run against a newer schema.
     pub async fn rollback(&self, version: i32) -> Result<MigrationResult> {
         info!("Rolling back migration {}", version);
 
@@ -504,6 +637,19 @@ impl MigrationManager {
             .get(&version)
             .ok_or_else(|| anyhow!("Migration {} not found", version))?;
 
+        let latest_applied = self.get_applied_versions().await?.last().copied();
+        match latest_applied {
+            Some(latest) if latest == version => {}
+            Some(latest) if latest > version => {
+                return Err(anyhow!(
+                    "Migration {} can't be rolled back before the newer migration {}",
+                    version,
+                    latest
+                ));
+            }
+            _ => return Err(anyhow!("Migration {} is not applied", version)),
+        }
+
         if migration.down_sql.trim().is_empty() {
             return Err(anyhow!("Migration {} has no rollback SQL", version));
         }
@@ -538,6 +684,14 @@ impl MigrationManager {
         Ok(migration_result)
     }
 
+    /// Rollback the most recently applied migration, if any
+    pub async fn rollback_last(&self) -> Result<Option<MigrationResult>> {
+        match self.get_applied_versions().await?.last() {
+            Some(&version) => self.rollback(version).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
     /// Apply rollback for a migration
     async fn apply_rollback(&self, migration: &Migration) -> Result<()> {
         // Start transaction for atomic rollback
@@ -548,16 +702,11 @@ impl MigrationManager {
             .context("Failed to start rollback transaction")?;
 
         // Execute rollback SQL
-        for statement in migration.down_sql.split(';') {
-            let statement = statement.trim();
-            if !statement.is_empty() {
-                sqlx::query(statement)
-                    .execute(&mut *tx)
-                    .await
-                    .with_context(|| {
-                        format!("Failed to execute rollback statement: {}", statement)
-                    })?;
-            }
+        for statement in sql_statements(&migration.down_sql) {
+            sqlx::query(statement)
+                .execute(&mut *tx)
+                .await
+                .with_context(|| format!("Failed to execute rollback statement: {}", statement))?;
         }
 
         // Remove migration record
@@ -641,21 +790,82 @@ impl MigrationManager {
     }
 }
 
+/// Statements of a migration script split on `;`, keeping each trigger's `BEGIN ... END` body
+/// in one piece
+fn sql_statements(sql: &str) -> Vec<&str> {
+    let mut statements = Vec::new();
+    let mut start = 0;
+    for (end, _) in sql.match_indices(';') {
+        let statement = sql[start..end].trim();
+        let upper = statement.to_uppercase();
+        if upper.contains("CREATE TRIGGER") && !upper.ends_with("END") {
+            continue;
+        }
+        if !statement.is_empty() {
+            statements.push(statement);
+        }
+        start = end + 1;
+    }
+    let rest = sql[start..].trim();
+    if !rest.is_empty() {
+        statements.push(rest);
+    }
+    statements
+}
+
+/// Table and column named by an `ALTER TABLE <table> ADD COLUMN <column> ...` statement
+fn added_column(statement: &str) -> Option<(&str, &str)> {
+    let words: Vec<&str> = statement.split_whitespace().take(6).collect();
+    match words.as_slice() {
+        [alter, table_keyword, table, add, column_keyword, column]
+            if alter.eq_ignore_ascii_case("ALTER")
+                && table_keyword.eq_ignore_ascii_case("TABLE")
+                && add.eq_ignore_ascii_case("ADD")
+                && column_keyword.eq_ignore_ascii_case("COLUMN") =>
+        {
+            Some((table, column))
+        }
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use sqlx::SqlitePool;
     use tempfile::NamedTempFile;
 
-    async fn create_test_db() -> SqlitePool {
+    // The temp file has to outlive the pool or SQLite reports disk I/O errors
+    async fn create_test_db() -> (SqlitePool, NamedTempFile) {
         let temp_file = NamedTempFile::new().unwrap();
         let database_url = format!("sqlite://{}", temp_file.path().display());
-        SqlitePool::connect(&database_url).await.unwrap()
+        let pool = SqlitePool::connect(&database_url).await.unwrap();
+        (pool, temp_file)
+    }
+
+    fn test_migration(version: i32, table: &str) -> Migration {
+        Migration {
+            version,
+            name: format!("create_{}", table),
+            description: format!("Create {}", table),
+            up_sql: format!("CREATE TABLE {} (id INTEGER PRIMARY KEY)", table),
+            down_sql: format!("DROP TABLE {}", table),
+            checksum: format!("{}_v{}", table, version),
+        }
+    }
+
+    async fn table_exists(pool: &SqlitePool, table: &str) -> bool {
+        sqlx::query("SELECT name FROM sqlite_master WHERE type = 'table' AND name = ?")
+            .bind(table)
+            .fetch_optional(pool)
+            .await
+            .unwrap()
+            .is_some()
     }
 
     #[tokio::test]
     async fn test_migration_system_initialization() {
-        let pool = create_test_db().await;
+        let (pool, _db_file) = create_test_db().await;
         let migration_manager = MigrationManager::new(pool);
 
         let result = migration_manager.initialize().await;
@@ -667,7 +877,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_migration_registration_and_execution() {
-        let pool = create_test_db().await;
+        let (pool, _db_file) = create_test_db().await;
         let mut migration_manager = MigrationManager::new(pool);
 
         migration_manager.initialize().await.unwrap();
@@ -682,4 +892,118 @@ mod tests {
         let final_version = migration_manager.get_current_version().await.unwrap();
         assert!(final_version > 0);
     }
+
+    #[tokio::test]
+    async fn test_migrate_twice_applies_each_version_once() {
+        let (pool, _db_file) = create_test_db().await;
+        let mut migration_manager = MigrationManager::new(pool.clone());
+        migration_manager.initialize().await.unwrap();
+        migration_manager.register_migration(test_migration(2, "second"));
+        migration_manager.register_migration(test_migration(1, "first"));
+
+        let first_run = migration_manager.migrate().await.unwrap();
+        assert_eq!(
+            first_run.iter().map(|r| r.version).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        assert!(first_run.iter().all(|r| r.success));
+
+        // Re-initializing and migrating again must not re-run anything
+        migration_manager.initialize().await.unwrap();
+        let second_run = migration_manager.migrate().await.unwrap();
+        assert!(second_run.is_empty());
+
+        let recorded: i64 = sqlx::query("SELECT COUNT(*) AS count FROM schema_migrations")
+            .fetch_one(&pool)
+            .await
+            .unwrap()
+            .get("count");
+        assert_eq!(recorded, 2);
+
+        let schema_version = migration_manager.get_schema_version().await.unwrap();
+        assert_eq!(schema_version.current_version, 2);
+        assert!(schema_version.is_up_to_date);
+    }
+
+    #[tokio::test]
+    async fn test_migrate_fills_gap_from_partial_upgrade() {
+        let (pool, _db_file) = create_test_db().await;
+        let mut migration_manager = MigrationManager::new(pool.clone());
+        migration_manager.initialize().await.unwrap();
+        migration_manager.register_migration(test_migration(2, "second"));
+        migration_manager.migrate().await.unwrap();
+
+        // An older migration that never got applied still runs even though version 2 is recorded
+        migration_manager.register_migration(test_migration(1, "first"));
+        let results = migration_manager.migrate().await.unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].version, 1);
+        assert!(table_exists(&pool, "first").await);
+    }
+
+    #[tokio::test]
+    async fn test_rollback_only_last_migration() {
+        let (pool, _db_file) = create_test_db().await;
+        let mut migration_manager = MigrationManager::new(pool.clone());
+        migration_manager.initialize().await.unwrap();
+        migration_manager.register_migration(test_migration(1, "first"));
+        migration_manager.register_migration(test_migration(2, "second"));
+        migration_manager.migrate().await.unwrap();
+
+        assert!(migration_manager.rollback(1).await.is_err());
+        assert!(table_exists(&pool, "first").await);
+
+        let result = migration_manager.rollback_last().await.unwrap().unwrap();
+        assert_eq!(result.version, 2);
+        assert!(result.success);
+        assert!(!table_exists(&pool, "second").await);
+        assert_eq!(migration_manager.get_current_version().await.unwrap(), 1);
+
+        // Re-applying after a rollback brings the schema back to the same version
+        migration_manager.migrate().await.unwrap();
+        assert_eq!(migration_manager.get_current_version().await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_migration_skips_column_that_already_exists() {
+        let (pool, _db_file) = create_test_db().await;
+        sqlx::query("CREATE TABLE metrics (id TEXT PRIMARY KEY, prompt_tokens INTEGER)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let mut migration_manager = MigrationManager::new(pool.clone());
+        migration_manager.initialize().await.unwrap();
+        migration_manager.register_migration(Migration {
+            version: 1,
+            name: "add_token_columns".to_string(),
+            description: "Add token columns".to_string(),
+            up_sql: "ALTER TABLE metrics ADD COLUMN prompt_tokens INTEGER; \
+                     ALTER TABLE metrics ADD COLUMN response_tokens INTEGER"
+                .to_string(),
+            down_sql: "ALTER TABLE metrics DROP COLUMN response_tokens".to_string(),
+            checksum: "token_columns_v1".to_string(),
+        });
+
+        let results = migration_manager.migrate().await.unwrap();
+        assert!(results[0].success, "{:?}", results[0].error_message);
+        let columns: Vec<String> =
+            sqlx::query_scalar("SELECT name FROM pragma_table_info('metrics')")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(columns, ["id", "prompt_tokens", "response_tokens"]);
+    }
+
+    #[test]
+    fn test_sql_statements_keep_trigger_bodies_whole() {
+        let sql = "CREATE INDEX idx ON t(a);
+                   CREATE TRIGGER trg AFTER UPDATE ON t BEGIN UPDATE t SET a = 1; END;
+                   DROP TABLE old";
+
+        let statements = sql_statements(sql);
+        assert_eq!(statements.len(), 3);
+        assert!(statements[1].ends_with("UPDATE t SET a = 1; END"));
+        assert_eq!(statements[2], "DROP TABLE old");
+    }
 }
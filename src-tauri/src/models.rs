@@ -1,6 +1,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
+use std::collections::{BTreeMap, HashMap};
 use uuid::Uuid;
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
@@ -32,6 +33,59 @@ pub struct Analysis {
     pub created_at: DateTime<Utc>,
 }
 
+/// Resumes and analyses added or changed after `since`, for incremental sync to external storage
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncrementalExport {
+    pub since: DateTime<Utc>,
+    /// Pass as `since` on the next export to pick up where this one ended
+    pub exported_at: DateTime<Utc>,
+    pub resumes: Vec<Resume>,
+    pub analyses: Vec<Analysis>,
+}
+
+/// One saved version of a resume and how it scored against the job used for the timeline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResumeTimelineEntry {
+    pub resume_id: String,
+    /// Position in the resume's history, starting at 1 for the first save
+    pub version: i64,
+    pub saved_at: DateTime<Utc>,
+    /// Latest overall score against the job, if this version has been analyzed for it
+    pub overall_score: Option<f64>,
+}
+
+/// Average keyword score of the analyses run in one calendar month
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeywordCoveragePoint {
+    /// Month as `YYYY-MM`
+    pub period: String,
+    pub average_keywords_score: f64,
+    pub analysis_count: usize,
+}
+
+/// A keyword the job descriptions keep asking for that the user's analyses keep missing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersistentKeywordGap {
+    pub keyword: String,
+    /// Analyses that reported the keyword missing
+    pub missed_count: usize,
+    /// Share of the industry's analyses that reported it missing, 0.0 to 1.0
+    pub miss_rate: f64,
+}
+
+/// How keyword coverage across every analysis for one industry has moved over time
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeywordCoverageTrend {
+    pub industry: String,
+    pub analysis_count: usize,
+    /// Monthly averages, oldest first
+    pub points: Vec<KeywordCoveragePoint>,
+    /// Latest month's average minus the first month's; positive means coverage improved
+    pub change: f64,
+    /// Most frequently missed first
+    pub persistent_gaps: Vec<PersistentKeywordGap>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OllamaModel {
     pub name: String,
@@ -45,6 +99,14 @@ pub struct AnalysisRequest {
     pub resume_content: String,
     pub job_description: String,
     pub model_name: String,
+    /// Client-chosen key that makes retries of the same request return the first result
+    /// instead of saving another analysis
+    #[serde(default)]
+    pub idempotency_key: Option<String>,
+    /// Industry of the pasted job, saved with it so the analysis counts toward that
+    /// industry's benchmark distribution
+    #[serde(default)]
+    pub industry: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -55,6 +117,9 @@ pub struct AnalysisResult {
     pub missing_keywords: Vec<String>,
     pub recommendations: Vec<String>,
     pub processing_time_ms: i64,
+    /// Ollama model that actually produced the analysis, if one was used
+    #[serde(default)]
+    pub model_used: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -64,6 +129,19 @@ pub struct CategoryScores {
     pub education: f64,
     pub keywords: f64,
     pub format: f64,
+    /// How well parsed source text backs each score; `None` when the engine did not assess it
+    #[serde(default)]
+    pub confidence: Option<CategoryConfidence>,
+}
+
+/// Per-category confidence from 0 (the score is a neutral guess) to 1 (fully backed by text)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CategoryConfidence {
+    pub skills: f64,
+    pub experience: f64,
+    pub education: f64,
+    pub keywords: f64,
+    pub format: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -78,6 +156,29 @@ pub struct DocumentInfo {
     pub metadata: DocumentMetadata,
     pub structure: Option<DocumentStructure>,
     pub quality_metrics: Option<DocumentQualityMetrics>,
+    #[serde(default)]
+    pub length_analysis: Option<LengthAnalysis>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LengthVerdict {
+    TooShort,
+    WithinRange,
+    TooLong,
+}
+
+/// Resume length compared with what recruiters expect for the detected experience level
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LengthAnalysis {
+    pub word_count: usize,
+    pub page_count: u32,
+    /// True when the page count was estimated from the word count rather than read from the file
+    pub page_count_estimated: bool,
+    pub experience_level: String,
+    pub recommended_min_pages: u32,
+    pub recommended_max_pages: u32,
+    pub verdict: LengthVerdict,
+    pub message: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -92,6 +193,12 @@ pub struct DocumentMetadata {
     pub creator: Option<String>,
     pub pages: Option<u32>,
     pub language: Option<String>,
+    /// Whoever saved the document last, which can differ from its author
+    #[serde(default)]
+    pub last_modified_by: Option<String>,
+    /// Word template the document was created from
+    #[serde(default)]
+    pub template: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -167,6 +274,8 @@ pub enum DocumentIssueType {
     ContactInfo,
     Spelling,
     Grammar,
+    /// File properties that reveal more than the resume itself, such as another author
+    Metadata,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -199,6 +308,9 @@ pub struct OptimizationResult {
     pub before_score: f64,
     pub after_score: f64,
     pub improvement_percentage: f64,
+    /// Set when Ollama was unreachable and the changes are rule-based suggestions only
+    #[serde(default)]
+    pub offline_mode: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -495,6 +607,37 @@ pub struct ModelPerformanceMetrics {
     pub user_satisfaction: Option<f64>,
     pub error_count: i32,
     pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    #[sqlx(default)]
+    pub prompt_tokens: i64,
+    #[serde(default)]
+    #[sqlx(default)]
+    pub response_tokens: i64,
+}
+
+impl ModelPerformanceMetrics {
+    /// Metrics for a single model generation; a failed generation counts as one error
+    pub fn for_generation(
+        model_name: &str,
+        processing_time_ms: i64,
+        prompt_tokens: i64,
+        response_tokens: i64,
+        success: bool,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            model_name: model_name.to_string(),
+            analysis_id: String::new(),
+            processing_time_ms,
+            memory_usage_mb: 0.0,
+            accuracy_score: 0.0,
+            user_satisfaction: None,
+            error_count: i32::from(!success),
+            created_at: Utc::now(),
+            prompt_tokens,
+            response_tokens,
+        }
+    }
 }
 
 // Enhanced Analysis Result with Phase 1 features
@@ -567,6 +710,13 @@ pub struct OptimizationSuggestion {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppConfig {
     pub database_url: String,
+    /// Directory for the database, used instead of `database_url` when set
+    #[serde(default)]
+    pub data_dir: Option<String>,
+    /// Keep the database and config in a `data` directory beside the executable, for running
+    /// from a USB stick; takes precedence over `data_dir`
+    #[serde(default)]
+    pub portable: bool,
     pub ollama_config: OllamaConfig,
     pub analysis_config: AnalysisConfig,
     pub performance_config: PerformanceConfig,
@@ -581,6 +731,12 @@ pub struct OllamaConfig {
     pub max_retries: u32,
     pub default_model: String,
     pub models: Vec<String>,
+    #[serde(default = "default_max_concurrent_requests")]
+    pub max_concurrent_requests: usize,
+}
+
+fn default_max_concurrent_requests() -> usize {
+    crate::ollama::DEFAULT_MAX_CONCURRENT_REQUESTS
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -591,6 +747,490 @@ pub struct AnalysisConfig {
     pub default_optimization_level: OptimizationLevel,
     pub max_suggestions: usize,
     pub confidence_threshold: f64,
+    /// Pin the Ollama seed and use temperature 0 so repeated analyses return identical text
+    #[serde(default)]
+    pub deterministic_mode: bool,
+    #[serde(default = "default_deterministic_seed")]
+    pub deterministic_seed: u64,
+    /// Extra section headers keyed by canonical section (e.g. "Experience"), added to the
+    /// parsers' built-in synonyms
+    #[serde(default)]
+    pub section_header_synonyms: HashMap<String, Vec<String>>,
+    #[serde(default)]
+    pub keyword_position_weighting: KeywordPositionWeighting,
+    /// Shortest break between roles, in months, reported as an employment gap
+    #[serde(default = "default_employment_gap_threshold_months")]
+    pub employment_gap_threshold_months: u32,
+    /// Named scoring profiles; the built-in presets are always available alongside these
+    #[serde(default = "ScoringProfile::presets")]
+    pub scoring_profiles: HashMap<String, ScoringProfile>,
+    /// Profile used when an analysis call does not name one
+    #[serde(default = "default_scoring_profile_name")]
+    pub default_scoring_profile: String,
+    #[serde(default)]
+    pub match_type_weights: MatchTypeWeights,
+    /// URL that receives each completed analysis as a JSON POST
+    #[serde(default)]
+    pub completion_webhook_url: Option<String>,
+    /// Days after which a trending keyword's score counts for half as much
+    #[serde(default = "default_trend_half_life_days")]
+    pub trend_half_life_days: f64,
+    /// Directory of `<prompt type>.txt` files that replace or add to the built-in prompts
+    #[serde(default)]
+    pub prompt_template_dir: Option<String>,
+    /// Industries beyond the built-in ones, keyed by the name passed to analyses
+    #[serde(default)]
+    pub custom_industries: HashMap<String, IndustryDefinition>,
+    /// Words shown either side of each keyword match in its context snippet
+    #[serde(default = "default_context_window_words")]
+    pub context_window_words: usize,
+    /// Context score a contextual keyword match must exceed to count; raise it to cut false
+    /// positives
+    #[serde(default = "default_min_contextual_confidence")]
+    pub min_contextual_confidence: f64,
+    /// How much less skills from older roles count than those from current ones
+    #[serde(default)]
+    pub recency_decay: RecencyDecay,
+    /// Sections each role or industry expects, keyed by the name passed to analyses
+    #[serde(default = "SectionPolicy::presets")]
+    pub section_policies: HashMap<String, SectionPolicy>,
+    /// Points the ATS compatibility check deducts per formatting problem, and their cap
+    #[serde(default)]
+    pub format_penalties: FormatPenalties,
+    /// Canonical skill names and the variants folded into them, e.g. "JavaScript" for "JS"
+    #[serde(default = "default_skill_taxonomy")]
+    pub skill_taxonomy: HashMap<String, Vec<String>>,
+    /// Years of experience at which a resume moves up to each seniority level
+    #[serde(default)]
+    pub experience_level_thresholds: ExperienceLevelThresholds,
+    /// Hiring conventions resumes are checked against; decides whether personal details such
+    /// as date of birth or a photo are flagged
+    #[serde(default)]
+    pub resume_region: ResumeRegion,
+    /// User-defined regex rules that detect phrases the built-in keyword extraction misses
+    #[serde(default)]
+    pub keyword_rules: Vec<KeywordRule>,
+    /// Share of the overall quality blend taken from the ATS score, 0.0-1.0; the rest comes
+    /// from human readability
+    #[serde(default = "default_ats_score_weight")]
+    pub ats_score_weight: f64,
+    /// Overall score advanced analyses plan toward when the caller does not pass one
+    #[serde(default)]
+    pub target_score: Option<f64>,
+    /// Hedging and confident phrases the tone analysis looks for
+    #[serde(default)]
+    pub tone_phrases: TonePhrases,
+    /// Confidence an extracted contact field must reach to be reported rather than left for
+    /// the user to confirm
+    #[serde(default = "default_min_contact_confidence")]
+    pub min_contact_confidence: f64,
+    /// Days ahead of a certification's expiry that the suggestions start asking for renewal
+    #[serde(default = "default_cert_expiry_warning_days")]
+    pub cert_expiry_warning_days: u32,
+    /// Ollama embedding model, such as `nomic-embed-text`, used to place resume blocks under
+    /// headings no header pattern knows; without one they are matched by vocabulary
+    #[serde(default)]
+    pub section_embedding_model: Option<String>,
+}
+
+fn default_deterministic_seed() -> u64 {
+    42
+}
+
+fn default_scoring_profile_name() -> String {
+    ScoringProfile::DEFAULT_NAME.to_string()
+}
+
+fn default_employment_gap_threshold_months() -> u32 {
+    crate::employment_gaps::DEFAULT_GAP_THRESHOLD_MONTHS
+}
+
+fn default_trend_half_life_days() -> f64 {
+    crate::dynamic_keyword_db::DEFAULT_TREND_HALF_LIFE_DAYS
+}
+
+fn default_context_window_words() -> usize {
+    crate::advanced_scoring::DEFAULT_CONTEXT_WINDOW_WORDS
+}
+
+fn default_ats_score_weight() -> f64 {
+    crate::advanced_scoring::DEFAULT_ATS_SCORE_WEIGHT
+}
+
+fn default_min_contextual_confidence() -> f64 {
+    crate::advanced_scoring::DEFAULT_MIN_CONTEXTUAL_CONFIDENCE
+}
+
+fn default_min_contact_confidence() -> f64 {
+    crate::contact_confidence::DEFAULT_MIN_CONTACT_CONFIDENCE
+}
+
+fn default_cert_expiry_warning_days() -> u32 {
+    crate::advanced_scoring::DEFAULT_CERT_EXPIRY_WARNING_DAYS
+}
+
+fn default_skill_taxonomy() -> HashMap<String, Vec<String>> {
+    crate::advanced_scoring::default_skill_taxonomy()
+}
+
+/// Boost for keyword matches by where they appear: near the top of the resume and in the
+/// sections recruiters skim first
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeywordPositionWeighting {
+    /// Multiplier for a match on the first line, decaying to 1.0 at the end of the resume
+    pub top_boost: f64,
+    /// Shape of the decay: 1.0 is linear, larger values keep the boost closer to the top
+    pub decay_exponent: f64,
+    /// Extra multiplier for matches in these sections, keyed case-insensitively
+    pub section_boosts: HashMap<String, f64>,
+}
+
+impl Default for KeywordPositionWeighting {
+    fn default() -> Self {
+        Self {
+            top_boost: 1.5,
+            decay_exponent: 2.0,
+            section_boosts: HashMap::from([
+                ("Summary".to_string(), 1.25),
+                ("Skills".to_string(), 1.25),
+            ]),
+        }
+    }
+}
+
+impl KeywordPositionWeighting {
+    /// Score multiplier for a match at `relative_position` (0.0 top, 1.0 bottom) in `section`
+    pub fn factor(&self, relative_position: f64, section: &str) -> f64 {
+        let remaining = 1.0 - relative_position.clamp(0.0, 1.0);
+        let position_boost =
+            1.0 + (self.top_boost - 1.0).max(0.0) * remaining.powf(self.decay_exponent.max(0.0));
+        let section_boost = self
+            .section_boosts
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(section))
+            .map_or(1.0, |(_, boost)| boost.max(0.0));
+
+        position_boost * section_boost
+    }
+}
+
+/// Share of a full keyword hit each kind of match earns in the keyword score. Sensible values
+/// lie between 0.0 (the match type is ignored) and 1.0 (as good as an exact match), with exact
+/// matches usually weighted highest.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MatchTypeWeights {
+    pub exact: f64,
+    pub stemmed: f64,
+    pub contextual: f64,
+    pub synonym: f64,
+    pub fuzzy: f64,
+}
+
+impl Default for MatchTypeWeights {
+    fn default() -> Self {
+        Self {
+            exact: 1.0,
+            stemmed: 0.85,
+            contextual: 0.6,
+            synonym: 0.7,
+            fuzzy: 0.5,
+        }
+    }
+}
+
+/// Sections a role or industry expects; a missing required section is flagged more strongly
+/// than a missing recommended one
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SectionPolicy {
+    pub required: Vec<String>,
+    pub recommended: Vec<String>,
+}
+
+impl SectionPolicy {
+    /// Built-in expectations, keyed by lowercase industry name
+    pub fn presets() -> HashMap<String, SectionPolicy> {
+        HashMap::from([
+            (
+                "technology".to_string(),
+                SectionPolicy {
+                    required: Vec::new(),
+                    recommended: vec!["Projects".to_string()],
+                },
+            ),
+            (
+                "finance".to_string(),
+                SectionPolicy {
+                    required: Vec::new(),
+                    recommended: vec!["Certifications".to_string()],
+                },
+            ),
+        ])
+    }
+}
+
+/// Points deducted from the 100-point ATS compatibility score. Symbol penalties apply per match,
+/// up to twice the listed amount; the rest apply once. However many issues a resume has, the
+/// deductions together never exceed `max_total_penalty`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FormatPenalties {
+    /// Box-drawing table borders (default 15)
+    pub table_borders: f64,
+    /// Decorative symbol bullets such as ★ or ■ (default 10)
+    pub special_symbols: f64,
+    /// Circled numbers such as ① (default 8)
+    pub numbered_circles: f64,
+    /// Arrow bullets such as ➤ (default 8)
+    pub arrow_symbols: f64,
+    /// Checkmarks and crosses (default 5)
+    pub checkmarks: f64,
+    /// Fewer than three recognizable section headers (default 20)
+    pub missing_sections: f64,
+    /// Three or more bullet styles mixed together (default 10)
+    pub inconsistent_bullets: f64,
+    /// No email address in the first ten lines (default 10)
+    pub missing_email: f64,
+    /// No phone number in the first ten lines (default 5)
+    pub missing_phone: f64,
+    /// More than three HTML tags, braces or brackets, per kind (default 5)
+    pub markup: f64,
+    /// Over a fifth of lines longer than 150 characters (default 10)
+    pub long_lines: f64,
+    /// Three or more date formats mixed together (default 5)
+    pub inconsistent_dates: f64,
+    /// Most the deductions above can remove in total (default 60)
+    pub max_total_penalty: f64,
+}
+
+impl Default for FormatPenalties {
+    fn default() -> Self {
+        Self {
+            table_borders: 15.0,
+            special_symbols: 10.0,
+            numbered_circles: 8.0,
+            arrow_symbols: 8.0,
+            checkmarks: 5.0,
+            missing_sections: 20.0,
+            inconsistent_bullets: 10.0,
+            missing_email: 10.0,
+            missing_phone: 5.0,
+            markup: 5.0,
+            long_lines: 10.0,
+            inconsistent_dates: 5.0,
+            max_total_penalty: 60.0,
+        }
+    }
+}
+
+/// Hiring conventions a resume is written for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ResumeRegion {
+    /// Photos, age, marital status and nationality are left off to avoid bias
+    #[default]
+    Us,
+    /// A photo and personal details are customary in many European countries
+    Eu,
+}
+
+impl ResumeRegion {
+    pub fn flags_personal_details(self) -> bool {
+        matches!(self, ResumeRegion::Us)
+    }
+}
+
+/// Phrase detected by a regex rather than the job description's keywords, e.g. a security
+/// clearance level or a git URL
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeywordRule {
+    pub name: String,
+    pub pattern: String,
+    /// Group the rule's matches are reported under
+    pub category: String,
+    /// Keyword score weight of a match, on the same scale as an exact keyword match
+    #[serde(default = "default_keyword_rule_weight")]
+    pub weight: f64,
+}
+
+fn default_keyword_rule_weight() -> f64 {
+    1.0
+}
+
+/// Phrases that undercut or assert ownership of the work a resume describes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TonePhrases {
+    /// Hedging phrases keyed to the stronger wording suggested in their place
+    pub hedging: BTreeMap<String, String>,
+    /// Ownership verbs and phrases, e.g. "led" or "delivered"
+    pub confident: Vec<String>,
+}
+
+impl Default for TonePhrases {
+    fn default() -> Self {
+        let hedging = [
+            ("assisted with", "delivered"),
+            ("assisted in", "delivered"),
+            ("helped to", "drove efforts to"),
+            ("helped with", "contributed"),
+            ("tried to", "worked to"),
+            ("attempted to", "worked to"),
+            ("was responsible for", "owned"),
+            ("responsible for", "owned"),
+            ("participated in", "contributed to"),
+            ("was involved in", "delivered"),
+            ("worked on", "built"),
+            ("exposure to", "hands-on experience with"),
+            ("familiar with", "proficient in"),
+            ("duties included", "delivered"),
+        ];
+        let confident = [
+            "led",
+            "owned",
+            "delivered",
+            "built",
+            "launched",
+            "drove",
+            "designed",
+            "spearheaded",
+            "achieved",
+            "increased",
+            "reduced",
+            "architected",
+            "established",
+            "managed",
+        ];
+        Self {
+            hedging: hedging
+                .into_iter()
+                .map(|(phrase, rewrite)| (phrase.to_string(), rewrite.to_string()))
+                .collect(),
+            confident: confident.into_iter().map(str::to_string).collect(),
+        }
+    }
+}
+
+/// Fewest years of experience that put a resume at each seniority level; below `mid_years` it
+/// reads as entry level
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExperienceLevelThresholds {
+    pub mid_years: u32,
+    pub senior_years: u32,
+    pub executive_years: u32,
+}
+
+impl Default for ExperienceLevelThresholds {
+    fn default() -> Self {
+        Self {
+            mid_years: 3,
+            senior_years: 8,
+            executive_years: 15,
+        }
+    }
+}
+
+/// Weight of experience by how long ago the role ended: 1.0 for a current role, halving every
+/// `half_life_years` but never below `floor`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RecencyDecay {
+    pub half_life_years: f64,
+    pub floor: f64,
+}
+
+impl Default for RecencyDecay {
+    fn default() -> Self {
+        Self {
+            half_life_years: 5.0,
+            floor: 0.25,
+        }
+    }
+}
+
+impl RecencyDecay {
+    /// Weight for a role that ended `years_ago` years ago; a non-positive half-life turns the
+    /// decay off
+    pub fn weight(&self, years_ago: f64) -> f64 {
+        if self.half_life_years <= 0.0 {
+            return 1.0;
+        }
+        0.5_f64
+            .powf(years_ago.max(0.0) / self.half_life_years)
+            .max(self.floor.clamp(0.0, 1.0))
+    }
+}
+
+/// Strictness applied uniformly to format penalties and keyword thresholds
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScoringProfile {
+    /// Scale for every format deduction; above 1.0 penalizes harder
+    pub penalty_multiplier: f64,
+    /// Scale for the keyword match weight needed for full keyword credit; the keyword density
+    /// that reads as stuffing scales inversely
+    pub threshold_multiplier: f64,
+}
+
+impl Default for ScoringProfile {
+    fn default() -> Self {
+        Self {
+            penalty_multiplier: 1.0,
+            threshold_multiplier: 1.0,
+        }
+    }
+}
+
+impl ScoringProfile {
+    pub const DEFAULT_NAME: &'static str = "balanced";
+
+    pub fn presets() -> HashMap<String, ScoringProfile> {
+        HashMap::from([
+            (
+                "strict".to_string(),
+                ScoringProfile {
+                    penalty_multiplier: 1.5,
+                    threshold_multiplier: 1.25,
+                },
+            ),
+            (Self::DEFAULT_NAME.to_string(), ScoringProfile::default()),
+            (
+                "lenient".to_string(),
+                ScoringProfile {
+                    penalty_multiplier: 0.5,
+                    threshold_multiplier: 0.8,
+                },
+            ),
+        ])
+    }
+
+    /// Rescale the deductions in a 0-100 score that starts from 100
+    pub fn apply_penalty(&self, score: f64) -> f64 {
+        (100.0 - (100.0 - score) * self.penalty_multiplier.max(0.0)).clamp(0.0, 100.0)
+    }
+}
+
+/// Keywords, skills and benchmarks for an industry the scoring engine does not ship with
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IndustryDefinition {
+    /// Keyword weights on the built-in scale, 1.0 for nice-to-have up to 3.0 for core terms
+    pub keywords: HashMap<String, f64>,
+    /// Skills grouped by category, each with an importance weight
+    #[serde(default)]
+    pub skill_requirements: HashMap<String, Vec<(String, f64)>>,
+    /// Words in a role's title or description that mark it as in-industry experience
+    #[serde(default)]
+    pub experience_keywords: Vec<String>,
+    /// Degree names and the alignment bonus each earns
+    #[serde(default)]
+    pub preferred_degrees: Vec<(String, f64)>,
+    /// Score benchmarks; the generic ones apply when absent
+    #[serde(default)]
+    pub benchmark: Option<crate::advanced_scoring::IndustryBenchmark>,
+    /// Score component weights; the general weights apply when absent
+    #[serde(default)]
+    pub scoring_weights: Option<crate::advanced_scoring::ScoringWeights>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -600,6 +1240,33 @@ pub struct PerformanceConfig {
     pub enable_gpu_acceleration: bool,
     pub memory_limit_mb: usize,
     pub timeout_seconds: u64,
+    /// Largest document accepted for parsing
+    #[serde(default = "default_max_upload_size_mb")]
+    pub max_upload_size_mb: u64,
+    /// Which stored analyses to prune; keeps everything by default
+    #[serde(default)]
+    pub retention_policy: RetentionPolicy,
+}
+
+/// Limits on how many stored analyses are kept; each limit is off when unset
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RetentionPolicy {
+    /// Most recent analyses kept for each resume
+    pub keep_last_per_resume: Option<u32>,
+    /// Analyses older than this many days are removed
+    pub max_age_days: Option<u32>,
+}
+
+impl RetentionPolicy {
+    /// Whether the policy removes anything at all
+    pub fn is_enabled(&self) -> bool {
+        self.keep_last_per_resume.is_some() || self.max_age_days.is_some()
+    }
+}
+
+fn default_max_upload_size_mb() -> u64 {
+    crate::document::DEFAULT_MAX_UPLOAD_SIZE_MB
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -624,6 +1291,11 @@ pub struct JobDescription {
     pub salary_currency: Option<String>,
     pub location: String,
     pub remote_options: RemoteWorkType,
+    /// Country or region a remote role is limited to, e.g. "US"
+    #[serde(default)]
+    pub remote_region: Option<String>,
+    #[serde(default)]
+    pub visa_sponsorship: VisaSponsorship,
     pub employment_type: EmploymentType,
     pub experience_level: ExperienceLevel,
     pub posted_date: Option<DateTime<Utc>>,
@@ -648,7 +1320,7 @@ pub struct JobDescription {
     pub updated_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub enum RemoteWorkType {
     #[default]
     OnSite,
@@ -657,6 +1329,15 @@ pub enum RemoteWorkType {
     Flexible,
 }
 
+/// Whether a posting says it will sponsor work visas
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum VisaSponsorship {
+    #[default]
+    Unspecified,
+    Offered,
+    NotOffered,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub enum EmploymentType {
     #[default]
@@ -680,6 +1361,20 @@ pub enum ExperienceLevel {
     Executive,
 }
 
+impl ExperienceLevel {
+    /// Level name the scoring engine expects: "entry", "mid" or "senior"
+    pub fn scoring_level(&self) -> &'static str {
+        match self {
+            ExperienceLevel::EntryLevel | ExperienceLevel::Junior => "entry",
+            ExperienceLevel::MidLevel => "mid",
+            ExperienceLevel::Senior
+            | ExperienceLevel::Lead
+            | ExperienceLevel::Principal
+            | ExperienceLevel::Executive => "senior",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub enum JobStatus {
     #[default]
@@ -735,7 +1430,7 @@ pub enum JobSource {
 impl JobDescription {
     pub fn new(title: String, company: String, content: String) -> Self {
         let now = Utc::now();
-        Self {
+        let mut job = Self {
             id: Uuid::new_v4().to_string(),
             title,
             company,
@@ -747,6 +1442,8 @@ impl JobDescription {
             salary_currency: Some("USD".to_string()),
             location: "".to_string(),
             remote_options: RemoteWorkType::OnSite,
+            remote_region: None,
+            visa_sponsorship: VisaSponsorship::Unspecified,
             employment_type: EmploymentType::FullTime,
             experience_level: ExperienceLevel::MidLevel,
             posted_date: None,
@@ -769,7 +1466,9 @@ impl JobDescription {
             is_archived: false,
             created_at: now,
             updated_at: now,
-        }
+        };
+        job.apply_location_signals();
+        job
     }
 
     pub fn from_url(url: String, title: String, company: String, content: String) -> Self {
@@ -778,6 +1477,31 @@ impl JobDescription {
         job.source = JobSource::Url;
         job
     }
+
+    /// Fill location, work arrangement and sponsorship from what the posting states. Fields
+    /// the posting says nothing about, and a location already entered, are left alone.
+    pub fn apply_location_signals(&mut self) {
+        let signals = crate::job_location::extract_location_signals(&self.content);
+        if self.location.trim().is_empty() {
+            if let Some(location) = signals.location {
+                self.location = location;
+            }
+        }
+        if let Some(remote_options) = signals.remote_options {
+            self.remote_options = remote_options;
+        }
+        if signals.remote_region.is_some() {
+            self.remote_region = signals.remote_region;
+        }
+        if signals.visa_sponsorship != VisaSponsorship::Unspecified {
+            self.visa_sponsorship = signals.visa_sponsorship;
+        }
+    }
+
+    /// True when the posting rules out visa sponsorship
+    pub fn excludes_sponsorship(&self) -> bool {
+        self.visa_sponsorship == VisaSponsorship::NotOffered
+    }
 }
 
 // Job search and filter models
@@ -787,6 +1511,9 @@ pub struct JobSearchRequest {
     pub company: Option<String>,
     pub location: Option<String>,
     pub remote_options: Option<Vec<RemoteWorkType>>,
+    /// Leave out postings that say they will not sponsor visas
+    #[serde(default)]
+    pub exclude_no_sponsorship: Option<bool>,
     pub employment_type: Option<Vec<EmploymentType>>,
     pub experience_level: Option<Vec<ExperienceLevel>>,
     pub salary_min: Option<i64>,
@@ -1,16 +1,171 @@
 use anyhow::{anyhow, Result};
 use chrono::{DateTime, Utc};
 use log::{error, info, warn};
+use once_cell::sync::Lazy;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
+use std::sync::{Arc, PoisonError, RwLock};
 use std::time::{Duration, Instant};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 
-use crate::models::OllamaModel;
+use crate::database::Database;
+use crate::enhanced_prompts::estimate_prompt_tokens;
+use crate::models::{ModelPerformanceMetrics, OllamaModel};
+
+/// Default cap on generation requests in flight against the local Ollama server
+pub const DEFAULT_MAX_CONCURRENT_REQUESTS: usize = 2;
+
+/// Process-wide limiter shared by every `OllamaClient`
+static REQUEST_LIMITER: Lazy<RwLock<RequestLimiter>> =
+    Lazy::new(|| RwLock::new(RequestLimiter::new(DEFAULT_MAX_CONCURRENT_REQUESTS)));
+
+/// Caps concurrent Ollama requests; callers over the limit queue for a permit instead of failing
+#[derive(Debug, Clone)]
+pub struct RequestLimiter {
+    semaphore: Arc<Semaphore>,
+    limit: usize,
+}
+
+impl RequestLimiter {
+    pub fn new(limit: usize) -> Self {
+        let limit = limit.max(1);
+        Self {
+            semaphore: Arc::new(Semaphore::new(limit)),
+            limit,
+        }
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Wait until a request slot is free; the slot is released when the permit is dropped
+    pub async fn acquire(&self) -> Result<OwnedSemaphorePermit> {
+        self.semaphore
+            .clone()
+            .acquire_owned()
+            .await
+            .map_err(|e| anyhow!("Ollama request limiter closed: {}", e))
+    }
+}
+
+/// Apply the configured concurrency cap. Requests already holding a permit finish
+/// under the previous limit; new requests queue against the new one.
+pub fn configure_request_limit(limit: usize) {
+    let mut limiter = REQUEST_LIMITER
+        .write()
+        .unwrap_or_else(PoisonError::into_inner);
+
+    if limiter.limit() != limit.max(1) {
+        *limiter = RequestLimiter::new(limit);
+        info!(
+            "Ollama request concurrency limit set to {}",
+            limiter.limit()
+        );
+    }
+}
+
+/// Model families tried, in order, when the requested model isn't installed
+const FALLBACK_MODEL_FAMILIES: &[&str] = &["qwen", "mistral", "llama", "gemma", "phi"];
+
+/// Outcome of matching a requested model against the models installed in Ollama
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModelSelection {
+    pub requested: String,
+    pub selected: String,
+    pub is_fallback: bool,
+}
+
+/// Pick the model to run for `requested` from the installed models.
+///
+/// Prefers the exact model (treating a missing tag as `:latest`), then another tag of the
+/// same model, then the first installed model from a known instruction-following family.
+/// Embedding-only models are never chosen since they cannot generate text.
+pub fn select_model(requested: &str, available: &[OllamaModel]) -> Result<ModelSelection> {
+    let normalize = |name: &str| -> String {
+        let name = name.trim().to_lowercase();
+        if name.contains(':') {
+            name
+        } else {
+            format!("{}:latest", name)
+        }
+    };
+    let family =
+        |name: &str| -> String { name.split(':').next().unwrap_or(name).trim().to_lowercase() };
+
+    let requested_normalized = normalize(requested);
+    let requested_family = family(requested);
+
+    let candidates: Vec<&OllamaModel> = available
+        .iter()
+        .filter(|m| !m.name.to_lowercase().contains("embed"))
+        .collect();
+
+    let selection = |model: &OllamaModel, is_fallback: bool| ModelSelection {
+        requested: requested.to_string(),
+        selected: model.name.clone(),
+        is_fallback,
+    };
+
+    if let Some(model) = candidates
+        .iter()
+        .find(|m| normalize(&m.name) == requested_normalized)
+    {
+        return Ok(selection(model, false));
+    }
+
+    if let Some(model) = candidates
+        .iter()
+        .find(|m| family(&m.name) == requested_family)
+    {
+        return Ok(selection(model, true));
+    }
+
+    for preferred in FALLBACK_MODEL_FAMILIES {
+        if let Some(model) = candidates
+            .iter()
+            .find(|m| family(&m.name).contains(preferred))
+        {
+            return Ok(selection(model, true));
+        }
+    }
+
+    if let Some(model) = candidates.first() {
+        return Ok(selection(model, true));
+    }
+
+    Err(anyhow!(
+        "Model '{}' is not installed in Ollama and no other text generation model is available. \
+         Install it with `ollama pull {}`",
+        requested,
+        requested
+    ))
+}
+
+/// Get the limiter currently guarding Ollama requests
+pub fn request_limiter() -> RequestLimiter {
+    REQUEST_LIMITER
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
+/// Whether an error means Ollama itself couldn't be reached, as opposed to a bad response
+pub fn is_unavailable_error(error: &anyhow::Error) -> bool {
+    error.chain().any(|cause| {
+        cause
+            .downcast_ref::<reqwest::Error>()
+            .is_some_and(|e| e.is_connect() || e.is_timeout())
+    })
+}
 
 #[derive(Debug, Clone)]
 pub struct OllamaClient {
     client: Client,
     base_url: String,
+    seed: Option<u64>,
+    /// Where each generation's latency, token counts and outcome are recorded, if anywhere
+    performance_db: Option<Database>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -26,6 +181,8 @@ struct OllamaOptions {
     temperature: Option<f64>,
     top_p: Option<f64>,
     max_tokens: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seed: Option<u64>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -43,6 +200,17 @@ struct OllamaGenerateResponse {
     eval_duration: Option<i64>,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+struct OllamaEmbeddingRequest {
+    model: String,
+    prompt: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct OllamaEmbeddingResponse {
+    embedding: Vec<f64>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct OllamaListResponse {
     models: Vec<OllamaModelInfo>,
@@ -78,7 +246,30 @@ impl OllamaClient {
             .build()
             .map_err(|e| anyhow::anyhow!("Failed to create HTTP client: {}", e))?;
 
-        Ok(Self { client, base_url })
+        Ok(Self {
+            client,
+            base_url,
+            seed: None,
+            performance_db: None,
+        })
+    }
+
+    /// Record every generation made through this client in the model performance table
+    pub fn with_performance_tracking(mut self, database: Database) -> Self {
+        self.performance_db = Some(database);
+        self
+    }
+
+    /// Generate deterministically with `seed`, or normally with `None`.
+    ///
+    /// A seeded client sends a fixed `seed` and `temperature: 0` with every request, so the
+    /// same model, prompt and Ollama build return the same text. What stays nondeterministic:
+    /// `processing_time_ms`, generated IDs and timestamps, and model output across different
+    /// Ollama versions, quantizations or GPU/CPU backends, which can differ numerically even
+    /// with a fixed seed.
+    pub fn with_seed(mut self, seed: Option<u64>) -> Self {
+        self.seed = seed;
+        self
     }
 
     pub async fn test_connection(&self) -> Result<bool> {
@@ -165,23 +356,55 @@ impl OllamaClient {
         temperature: Option<f64>,
     ) -> Result<(String, i64)> {
         info!("Generating response with model: {}", model);
+
+        // Queue behind other in-flight generations so a single local model isn't overloaded
+        let _permit = request_limiter().acquire().await?;
         let start_time = Instant::now();
+        let outcome = self.send_generate_request(model, prompt, temperature).await;
+        let processing_time = start_time.elapsed().as_millis() as i64;
 
-        // Model-specific optimizations
-        let (optimized_temperature, top_p, max_tokens) =
-            self.get_model_optimizations(model, temperature);
+        if let Some(database) = &self.performance_db {
+            let (prompt_tokens, response_tokens) = match &outcome {
+                Ok(response) => (
+                    response
+                        .prompt_eval_count
+                        .map_or_else(|| estimate_prompt_tokens(model, prompt) as i64, i64::from),
+                    response.eval_count.map_or_else(
+                        || estimate_prompt_tokens(model, &response.response) as i64,
+                        i64::from,
+                    ),
+                ),
+                Err(_) => (estimate_prompt_tokens(model, prompt) as i64, 0),
+            };
+            let metrics = ModelPerformanceMetrics::for_generation(
+                model,
+                processing_time,
+                prompt_tokens,
+                response_tokens,
+                outcome.is_ok(),
+            );
+            if let Err(e) = database.save_model_performance(&metrics).await {
+                warn!("Failed to record performance for model {}: {}", model, e);
+            }
+        }
 
-        let options = Some(OllamaOptions {
-            temperature: Some(optimized_temperature),
-            top_p: Some(top_p),
-            max_tokens: Some(max_tokens),
-        });
+        let generate_response = outcome?;
+        info!("Response generated successfully in {}ms", processing_time);
+
+        Ok((generate_response.response, processing_time))
+    }
 
+    async fn send_generate_request(
+        &self,
+        model: &str,
+        prompt: &str,
+        temperature: Option<f64>,
+    ) -> Result<OllamaGenerateResponse> {
         let request = OllamaGenerateRequest {
             model: model.to_string(),
             prompt: prompt.to_string(),
             stream: false,
-            options,
+            options: Some(self.generation_options(model, temperature)),
         };
 
         let response = self
@@ -197,12 +420,35 @@ impl OllamaClient {
             return Err(anyhow!("Ollama API error: {} - {}", status, error_text));
         }
 
-        let generate_response: OllamaGenerateResponse = response.json().await?;
-        let processing_time = start_time.elapsed().as_millis() as i64;
+        Ok(response.json().await?)
+    }
 
-        info!("Response generated successfully in {}ms", processing_time);
+    /// Embedding of `text` from an embedding model such as `nomic-embed-text`
+    pub async fn embed(&self, model: &str, text: &str) -> Result<Vec<f64>> {
+        let _permit = request_limiter().acquire().await?;
+        let request = OllamaEmbeddingRequest {
+            model: model.to_string(),
+            prompt: text.to_string(),
+        };
 
-        Ok((generate_response.response, processing_time))
+        let response = self
+            .client
+            .post(format!("{}/api/embeddings", self.base_url))
+            .json(&request)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(anyhow!("Ollama API error: {} - {}", status, error_text));
+        }
+
+        let embedding = response.json::<OllamaEmbeddingResponse>().await?.embedding;
+        if embedding.is_empty() {
+            return Err(anyhow!("Model {} returned an empty embedding", model));
+        }
+        Ok(embedding)
     }
 
     pub async fn analyze_resume_compatibility(
@@ -247,7 +493,7 @@ impl OllamaClient {
             .await
     }
 
-    fn create_analysis_prompt(
+    pub fn create_analysis_prompt(
         &self,
         model: &str,
         resume_content: &str,
@@ -702,12 +948,46 @@ Provide only the JSON response:"#,
         Ok(serde_json::to_string_pretty(&show_response)?)
     }
 
+    /// Resolve the model to use for `requested` against what's actually installed,
+    /// falling back to a sensible installed model when it hasn't been pulled
+    pub async fn resolve_model(&self, requested: &str) -> Result<ModelSelection> {
+        let models = self.list_models().await?;
+        let selection = select_model(requested, &models)?;
+
+        if selection.is_fallback {
+            warn!(
+                "Model '{}' is not installed; falling back to '{}'. Run `ollama pull {}` to use it",
+                selection.requested, selection.selected, selection.requested
+            );
+        }
+
+        Ok(selection)
+    }
+
     #[allow(dead_code)]
     pub async fn check_model_availability(&self, model_name: &str) -> Result<bool> {
         let models = self.list_models().await?;
         Ok(models.iter().any(|m| m.name == model_name))
     }
 
+    /// Build generation options, pinning seed and temperature in deterministic mode
+    fn generation_options(&self, model: &str, temperature: Option<f64>) -> OllamaOptions {
+        // Model-specific optimizations
+        let (optimized_temperature, top_p, max_tokens) =
+            self.get_model_optimizations(model, temperature);
+
+        OllamaOptions {
+            temperature: Some(if self.seed.is_some() {
+                0.0
+            } else {
+                optimized_temperature
+            }),
+            top_p: Some(top_p),
+            max_tokens: Some(max_tokens),
+            seed: self.seed,
+        }
+    }
+
     /// Get model-specific optimizations for generation parameters
     fn get_model_optimizations(
         &self,
@@ -831,3 +1111,106 @@ Provide only the JSON response:"#,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn test_request_limiter_caps_in_flight_requests() {
+        let limiter = RequestLimiter::new(3);
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let max_in_flight = Arc::new(AtomicUsize::new(0));
+
+        let handles: Vec<_> = (0..12)
+            .map(|_| {
+                let limiter = limiter.clone();
+                let in_flight = in_flight.clone();
+                let max_in_flight = max_in_flight.clone();
+                tokio::spawn(async move {
+                    let _permit = limiter.acquire().await.unwrap();
+                    let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_in_flight.fetch_max(current, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.await.unwrap();
+        }
+
+        // Every task completed (queued rather than failed) without exceeding the cap
+        assert_eq!(in_flight.load(Ordering::SeqCst), 0);
+        assert!(max_in_flight.load(Ordering::SeqCst) <= 3);
+        assert!(max_in_flight.load(Ordering::SeqCst) >= 1);
+    }
+
+    fn installed_models(names: &[&str]) -> Vec<OllamaModel> {
+        names
+            .iter()
+            .map(|name| OllamaModel {
+                name: name.to_string(),
+                size: 0,
+                digest: String::new(),
+                modified_at: Utc::now(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_select_model_exact_match() {
+        let models = installed_models(&["mistral:latest", "llama2:latest"]);
+        let selection = select_model("llama2", &models).unwrap();
+
+        assert_eq!(selection.selected, "llama2:latest");
+        assert!(!selection.is_fallback);
+    }
+
+    #[test]
+    fn test_select_model_falls_back_when_configured_model_missing() {
+        let models = installed_models(&["nomic-embed-text:latest", "mistral:7b", "phi3:mini"]);
+        let selection = select_model("llama2", &models).unwrap();
+
+        assert_eq!(selection.requested, "llama2");
+        assert_eq!(selection.selected, "mistral:7b");
+        assert!(selection.is_fallback);
+
+        // Another tag of the same model wins over other families
+        let models = installed_models(&["mistral:7b", "llama2:13b"]);
+        let selection = select_model("llama2", &models).unwrap();
+        assert_eq!(selection.selected, "llama2:13b");
+        assert!(selection.is_fallback);
+    }
+
+    #[test]
+    fn test_select_model_errors_with_pull_command() {
+        let models = installed_models(&["nomic-embed-text:latest"]);
+        let err = select_model("llama2", &models).unwrap_err().to_string();
+
+        assert!(err.contains("llama2"));
+        assert!(err.contains("ollama pull llama2"));
+    }
+
+    #[test]
+    fn test_deterministic_generation_options() {
+        let client = OllamaClient::new(None).unwrap().with_seed(Some(7));
+        let options = client.generation_options("mistral", Some(0.8));
+
+        assert_eq!(options.seed, Some(7));
+        assert_eq!(options.temperature, Some(0.0));
+
+        let client = client.with_seed(None);
+        let options = client.generation_options("mistral", Some(0.8));
+        assert_eq!(options.seed, None);
+        assert_eq!(options.temperature, Some(0.8));
+        assert!(!serde_json::to_string(&options).unwrap().contains("seed"));
+    }
+
+    #[test]
+    fn test_request_limiter_minimum_of_one() {
+        assert_eq!(RequestLimiter::new(0).limit(), 1);
+    }
+}
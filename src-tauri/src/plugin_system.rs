@@ -1,5 +1,5 @@
 use anyhow::{anyhow, Result};
-use log::info;
+use log::{error, info, warn};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -62,6 +62,14 @@ pub trait Plugin: Send + Sync {
     fn validate_config(&self, config: &serde_json::Value) -> Result<()>;
     #[allow(dead_code)]
     fn get_schema(&self) -> Option<serde_json::Value>;
+    /// Prepare the plugin to run with `config`; an error keeps the plugin from being loaded
+    fn init(&mut self, config: &serde_json::Value) -> Result<()> {
+        self.validate_config(config)
+    }
+    /// Release whatever `init` set up, before the plugin is unloaded or reconfigured
+    fn teardown(&mut self) -> Result<()> {
+        Ok(())
+    }
 }
 
 // Built-in plugin for custom scoring
@@ -102,7 +110,8 @@ impl Plugin for CustomScoringPlugin {
                 "properties": {
                     "industry": {
                         "type": "string",
-                        "enum": ["tech", "finance", "healthcare", "education", "government"]
+                        "enum": ["tech", "finance", "healthcare", "education", "government"],
+                        "default": "tech"
                     },
                     "experience_weight": {
                         "type": "number",
@@ -437,17 +446,78 @@ impl PluginManager {
     }
 
     async fn register_builtin_plugins(&mut self) {
-        let mut plugins = self.plugins.write().await;
+        let builtins: [Box<dyn Plugin>; 2] = [
+            // Register custom scoring plugin
+            Box::new(CustomScoringPlugin::new()),
+            // Register advanced analytics plugin
+            Box::new(AdvancedAnalyticsPlugin::new()),
+        ];
+
+        // Built-ins go through the same init as any other plugin, with their schema defaults
+        for plugin in builtins {
+            let info = plugin.info();
+            let config = schema_defaults(info.config_schema.as_ref());
+            if let Err(e) = self.load_plugin(plugin, config).await {
+                error!("Failed to load built-in plugin {}: {}", info.id, e);
+            }
+        }
 
-        // Register custom scoring plugin
-        let custom_scoring = Box::new(CustomScoringPlugin::new());
-        plugins.insert(custom_scoring.info().id.clone(), custom_scoring);
+        info!(
+            "Registered {} built-in plugins",
+            self.plugins.read().await.len()
+        );
+    }
 
-        // Register advanced analytics plugin
-        let analytics = Box::new(AdvancedAnalyticsPlugin::new());
-        plugins.insert(analytics.info().id.clone(), analytics);
+    /// Initialize `plugin` with `config` and register it, replacing any loaded plugin with the
+    /// same id; a plugin whose config fails `init` is rejected and never registered
+    pub async fn load_plugin(
+        &self,
+        mut plugin: Box<dyn Plugin>,
+        config: serde_json::Value,
+    ) -> Result<()> {
+        let plugin_id = plugin.info().id;
+        plugin
+            .init(&config)
+            .map_err(|e| anyhow!("Plugin {} rejected during init: {}", plugin_id, e))?;
+
+        // Register the new plugin with its config before tearing down the one it replaces, so
+        // a failed teardown cannot leave the new plugin running on the old config
+        let previous = self.plugins.write().await.insert(plugin_id.clone(), plugin);
+        self.plugin_configs.write().await.insert(
+            plugin_id.clone(),
+            PluginConfig {
+                plugin_id: plugin_id.clone(),
+                config,
+                updated_at: chrono::Utc::now(),
+            },
+        );
+        info!("Loaded plugin: {}", plugin_id);
+
+        if let Some(mut previous) = previous {
+            previous.teardown().map_err(|e| {
+                anyhow!(
+                    "Plugin {} was replaced but the previous instance failed to tear down: {}",
+                    plugin_id,
+                    e
+                )
+            })?;
+        }
+        Ok(())
+    }
 
-        info!("Registered {} built-in plugins", plugins.len());
+    /// Tear down and unregister a plugin along with its config
+    #[allow(dead_code)]
+    pub async fn unload_plugin(&self, plugin_id: &str) -> Result<()> {
+        let mut plugin = self
+            .plugins
+            .write()
+            .await
+            .remove(plugin_id)
+            .ok_or_else(|| anyhow!("Plugin not found: {}", plugin_id))?;
+        self.plugin_configs.write().await.remove(plugin_id);
+        plugin.teardown()?;
+        info!("Unloaded plugin: {}", plugin_id);
+        Ok(())
     }
 
     pub async fn list_plugins(&self) -> Vec<PluginInfo> {
@@ -490,18 +560,35 @@ impl PluginManager {
         plugin_id: &str,
         config: serde_json::Value,
     ) -> Result<()> {
-        let plugins = self.plugins.read().await;
+        let mut plugins = self.plugins.write().await;
         let plugin = plugins
-            .get(plugin_id)
+            .get_mut(plugin_id)
             .ok_or_else(|| anyhow!("Plugin not found: {}", plugin_id))?;
 
-        // Validate config
+        // Validate before tearing down, so a bad config leaves the plugin running as it was
         plugin.validate_config(&config)?;
+        plugin.teardown()?;
 
+        let mut configs = self.plugin_configs.write().await;
+        if let Err(e) = plugin.init(&config) {
+            // Bring the plugin back up on the config it was running with, and unregister it
+            // rather than leave it torn down if even that fails
+            let previous = configs
+                .get(plugin_id)
+                .map_or_else(|| serde_json::json!({}), |c| c.config.clone());
+            if let Err(restore_error) = plugin.init(&previous) {
+                warn!(
+                    "Plugin {} could not be restored after a failed reconfigure: {}",
+                    plugin_id, restore_error
+                );
+                plugins.remove(plugin_id);
+                configs.remove(plugin_id);
+            }
+            return Err(anyhow!("Plugin {} rejected during init: {}", plugin_id, e));
+        }
         drop(plugins);
 
         // Update config
-        let mut configs = self.plugin_configs.write().await;
         let plugin_config = PluginConfig {
             plugin_id: plugin_id.to_string(),
             config,
@@ -539,6 +626,20 @@ impl PluginManager {
     }
 }
 
+/// A config made of each property's schema default
+fn schema_defaults(schema: Option<&serde_json::Value>) -> serde_json::Value {
+    let defaults = schema
+        .and_then(|schema| schema["properties"].as_object())
+        .map(|properties| {
+            properties
+                .iter()
+                .filter_map(|(key, property)| Some((key.clone(), property.get("default")?.clone())))
+                .collect()
+        })
+        .unwrap_or_default();
+    serde_json::Value::Object(defaults)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -585,4 +686,151 @@ mod tests {
         assert!(result.success);
         assert!(result.output_data.is_some());
     }
+
+    #[tokio::test]
+    async fn test_plugin_with_invalid_config_is_rejected_during_init() {
+        let temp_dir = tempdir().unwrap();
+        let manager = PluginManager::new(temp_dir.path().to_path_buf()).await;
+        manager.unload_plugin("custom-scoring-v1").await.unwrap();
+
+        let error = manager
+            .load_plugin(
+                Box::new(CustomScoringPlugin::new()),
+                serde_json::json!({ "industry": "astrology" }),
+            )
+            .await
+            .unwrap_err();
+
+        assert!(
+            error.to_string().contains("rejected during init"),
+            "{}",
+            error
+        );
+        assert!(manager.get_plugin_info("custom-scoring-v1").await.is_none());
+
+        manager
+            .load_plugin(
+                Box::new(CustomScoringPlugin::new()),
+                serde_json::json!({ "industry": "tech", "skills_weight": 0.5 }),
+            )
+            .await
+            .unwrap();
+        assert!(manager.get_plugin_info("custom-scoring-v1").await.is_some());
+    }
+
+    /// Validates like the custom scoring plugin but fails `init` for the "finance" industry
+    struct FlakyInitPlugin(CustomScoringPlugin);
+
+    impl Plugin for FlakyInitPlugin {
+        fn info(&self) -> PluginInfo {
+            self.0.info()
+        }
+        fn execute(&self, context: PluginExecutionContext) -> Result<PluginExecutionResult> {
+            self.0.execute(context)
+        }
+        fn validate_config(&self, config: &serde_json::Value) -> Result<()> {
+            self.0.validate_config(config)
+        }
+        fn get_schema(&self) -> Option<serde_json::Value> {
+            self.0.get_schema()
+        }
+        fn init(&mut self, config: &serde_json::Value) -> Result<()> {
+            if config["industry"] == "finance" {
+                return Err(anyhow!("finance models are not installed"));
+            }
+            self.validate_config(config)
+        }
+    }
+
+    /// The custom scoring plugin, except that tearing it down always fails
+    struct StuckTeardownPlugin(CustomScoringPlugin);
+
+    impl Plugin for StuckTeardownPlugin {
+        fn info(&self) -> PluginInfo {
+            self.0.info()
+        }
+        fn execute(&self, context: PluginExecutionContext) -> Result<PluginExecutionResult> {
+            self.0.execute(context)
+        }
+        fn validate_config(&self, config: &serde_json::Value) -> Result<()> {
+            self.0.validate_config(config)
+        }
+        fn get_schema(&self) -> Option<serde_json::Value> {
+            self.0.get_schema()
+        }
+        fn teardown(&mut self) -> Result<()> {
+            Err(anyhow!("scoring cache is still locked"))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_failed_teardown_on_reload_keeps_new_plugin_config() {
+        let temp_dir = tempdir().unwrap();
+        let manager = PluginManager::new(temp_dir.path().to_path_buf()).await;
+        manager
+            .load_plugin(
+                Box::new(StuckTeardownPlugin(CustomScoringPlugin::new())),
+                serde_json::json!({ "industry": "healthcare" }),
+            )
+            .await
+            .unwrap();
+
+        let error = manager
+            .load_plugin(
+                Box::new(CustomScoringPlugin::new()),
+                serde_json::json!({ "industry": "finance" }),
+            )
+            .await
+            .unwrap_err();
+
+        assert!(
+            error.to_string().contains("failed to tear down"),
+            "{}",
+            error
+        );
+        assert!(manager.get_plugin_info("custom-scoring-v1").await.is_some());
+        assert_eq!(
+            manager.plugin_configs.read().await["custom-scoring-v1"].config["industry"],
+            "finance"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_builtins_load_with_schema_defaults_and_failed_reinit_restores_config() {
+        let temp_dir = tempdir().unwrap();
+        let manager = PluginManager::new(temp_dir.path().to_path_buf()).await;
+        let configs = manager.plugin_configs.read().await;
+        assert_eq!(configs["custom-scoring-v1"].config["industry"], "tech");
+        assert_eq!(
+            configs["advanced-analytics-v1"].config["analysis_depth"],
+            "detailed"
+        );
+        drop(configs);
+
+        manager
+            .load_plugin(
+                Box::new(FlakyInitPlugin(CustomScoringPlugin::new())),
+                serde_json::json!({ "industry": "healthcare" }),
+            )
+            .await
+            .unwrap();
+        let error = manager
+            .update_plugin_config(
+                "custom-scoring-v1",
+                serde_json::json!({ "industry": "finance" }),
+            )
+            .await
+            .unwrap_err();
+
+        assert!(
+            error.to_string().contains("rejected during init"),
+            "{}",
+            error
+        );
+        assert!(manager.get_plugin_info("custom-scoring-v1").await.is_some());
+        assert_eq!(
+            manager.plugin_configs.read().await["custom-scoring-v1"].config["industry"],
+            "healthcare"
+        );
+    }
 }
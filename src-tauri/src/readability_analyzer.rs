@@ -0,0 +1,281 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// Average words per sentence/bullet above which text reads as dense
+pub const MAX_AVERAGE_SENTENCE_WORDS: f64 = 22.0;
+/// Individual sentences/bullets longer than this are called out
+pub const LONG_SENTENCE_WORDS: usize = 30;
+/// Flesch-Kincaid grade above which a resume is harder to skim than it needs to be
+pub const MAX_GRADE_LEVEL: f64 = 14.0;
+/// Share of sentences in passive voice above which a suggestion is raised
+pub const MAX_PASSIVE_VOICE_RATIO: f64 = 0.2;
+
+/// Readability points lost per grade level above `MAX_GRADE_LEVEL`
+const GRADE_PENALTY: f64 = 5.0;
+/// Readability points lost per word the average sentence runs over `MAX_AVERAGE_SENTENCE_WORDS`
+const SENTENCE_LENGTH_PENALTY: f64 = 2.0;
+/// Readability points lost per 1% of passive sentences above `MAX_PASSIVE_VOICE_RATIO`
+const PASSIVE_VOICE_PENALTY: f64 = 1.0;
+/// Readability points lost per 1% of sentences over `LONG_SENTENCE_WORDS`
+const LONG_SENTENCE_PENALTY: f64 = 0.5;
+
+/// Lines shorter than this are treated as names, headers or skill lists rather than prose
+const MIN_PROSE_WORDS: usize = 4;
+
+static CONTACT_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)[\w.+-]+@[\w-]+\.[\w.]+|https?://|www\.|linkedin\.com|github\.com|\(?\d{3}\)?[\s.-]\d{3}[\s.-]\d{4}")
+        .unwrap()
+});
+
+static PASSIVE_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\b(?:am|is|are|was|were|be|been|being)\s+(?:\w+ly\s+)?(?:\w+ed|built|made|done|given|taken|written|led|run|seen|shown|driven|grown|chosen|known|held|kept|sent|set|won)\b")
+        .unwrap()
+});
+
+static SENTENCE_END: Lazy<Regex> = Lazy::new(|| Regex::new(r"[.!?]+(?:\s+|$)").unwrap());
+
+const SECTION_HEADERS: &[&str] = &[
+    "summary",
+    "professional summary",
+    "objective",
+    "profile",
+    "experience",
+    "work experience",
+    "professional experience",
+    "employment history",
+    "education",
+    "skills",
+    "technical skills",
+    "projects",
+    "certifications",
+    "awards",
+    "publications",
+    "volunteer experience",
+    "languages",
+    "interests",
+];
+
+/// Human-reader metrics for the resume body
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReadabilityMetrics {
+    pub sentence_count: usize,
+    pub word_count: usize,
+    pub average_sentence_length: f64,
+    pub flesch_kincaid_grade: f64,
+    pub passive_voice_ratio: f64,
+    pub long_sentences: Vec<String>,
+    pub suggestions: Vec<String>,
+    /// 0-100, where 100 means every metric is within its limit
+    #[serde(default)]
+    pub score: f64,
+}
+
+/// Measures how easy the resume is for a person to read, independent of ATS parsing
+#[derive(Debug, Default)]
+pub struct ReadabilityAnalyzer;
+
+impl ReadabilityAnalyzer {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn analyze(&self, resume_content: &str) -> ReadabilityMetrics {
+        let sentences = Self::body_sentences(resume_content);
+        if sentences.is_empty() {
+            return ReadabilityMetrics::default();
+        }
+
+        let mut word_count = 0;
+        let mut syllable_count = 0;
+        let mut passive_count = 0;
+        let mut long_sentences = Vec::new();
+
+        for sentence in &sentences {
+            let words: Vec<&str> = sentence
+                .split_whitespace()
+                .filter(|w| w.chars().any(|c| c.is_alphanumeric()))
+                .collect();
+            word_count += words.len();
+            syllable_count += words
+                .iter()
+                .map(|w| Self::count_syllables(w))
+                .sum::<usize>();
+
+            if PASSIVE_PATTERN.is_match(sentence) {
+                passive_count += 1;
+            }
+            if words.len() > LONG_SENTENCE_WORDS {
+                long_sentences.push(sentence.clone());
+            }
+        }
+
+        let sentence_count = sentences.len();
+        let average_sentence_length = word_count as f64 / sentence_count as f64;
+        let flesch_kincaid_grade = if word_count == 0 {
+            0.0
+        } else {
+            (0.39 * average_sentence_length + 11.8 * (syllable_count as f64 / word_count as f64)
+                - 15.59)
+                .max(0.0)
+        };
+        let passive_voice_ratio = passive_count as f64 / sentence_count as f64;
+
+        let mut suggestions = Vec::new();
+        if average_sentence_length > MAX_AVERAGE_SENTENCE_WORDS {
+            suggestions.push(format!(
+                "Bullets average {:.0} words; aim for under {:.0} so each one can be read at a glance",
+                average_sentence_length, MAX_AVERAGE_SENTENCE_WORDS
+            ));
+        }
+        if !long_sentences.is_empty() {
+            suggestions.push(format!(
+                "Split {} bullet(s) longer than {} words into separate achievements",
+                long_sentences.len(),
+                LONG_SENTENCE_WORDS
+            ));
+        }
+        if flesch_kincaid_grade > MAX_GRADE_LEVEL {
+            suggestions.push(format!(
+                "Reading level is around grade {:.1}; prefer shorter words and plainer phrasing",
+                flesch_kincaid_grade
+            ));
+        }
+        if passive_voice_ratio > MAX_PASSIVE_VOICE_RATIO {
+            suggestions.push(format!(
+                "{:.0}% of sentences use passive voice; start bullets with an action verb instead",
+                passive_voice_ratio * 100.0
+            ));
+        }
+
+        let long_sentence_ratio = long_sentences.len() as f64 / sentence_count as f64;
+        let score = (100.0
+            - (flesch_kincaid_grade - MAX_GRADE_LEVEL).max(0.0) * GRADE_PENALTY
+            - (average_sentence_length - MAX_AVERAGE_SENTENCE_WORDS).max(0.0)
+                * SENTENCE_LENGTH_PENALTY
+            - (passive_voice_ratio - MAX_PASSIVE_VOICE_RATIO).max(0.0)
+                * 100.0
+                * PASSIVE_VOICE_PENALTY
+            - long_sentence_ratio * 100.0 * LONG_SENTENCE_PENALTY)
+            .clamp(0.0, 100.0);
+
+        ReadabilityMetrics {
+            sentence_count,
+            word_count,
+            average_sentence_length,
+            flesch_kincaid_grade,
+            passive_voice_ratio,
+            long_sentences,
+            suggestions,
+            score,
+        }
+    }
+
+    /// Sentences and bullets from the resume body, skipping headers, contact details and
+    /// short fragments such as the candidate's name
+    fn body_sentences(resume_content: &str) -> Vec<String> {
+        resume_content
+            .lines()
+            .map(|line| {
+                line.trim()
+                    .trim_start_matches(['•', '-', '*', '◦', '▪', '·'])
+                    .trim()
+            })
+            .filter(|line| !Self::is_header(line) && !CONTACT_PATTERN.is_match(line))
+            .flat_map(|line| {
+                SENTENCE_END
+                    .split(line)
+                    .map(str::trim)
+                    .filter(|sentence| sentence.split_whitespace().count() >= MIN_PROSE_WORDS)
+                    .map(str::to_string)
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    fn is_header(line: &str) -> bool {
+        let normalized = line.trim_end_matches(':').trim().to_lowercase();
+        if normalized.is_empty() || SECTION_HEADERS.contains(&normalized.as_str()) {
+            return true;
+        }
+
+        let has_letters = line.chars().any(|c| c.is_alphabetic());
+        has_letters
+            && line.split_whitespace().count() <= 5
+            && line
+                .chars()
+                .filter(|c| c.is_alphabetic())
+                .all(|c| c.is_uppercase())
+    }
+
+    /// Approximate syllables by counting vowel groups, ignoring a trailing silent "e"
+    fn count_syllables(word: &str) -> usize {
+        let word: String = word
+            .chars()
+            .filter(|c| c.is_alphabetic())
+            .flat_map(char::to_lowercase)
+            .collect();
+        if word.is_empty() {
+            return 0;
+        }
+
+        let is_vowel = |c: char| matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y');
+        let mut count = 0;
+        let mut previous_vowel = false;
+        for c in word.chars() {
+            let vowel = is_vowel(c);
+            if vowel && !previous_vowel {
+                count += 1;
+            }
+            previous_vowel = vowel;
+        }
+
+        if word.ends_with('e') && !word.ends_with("le") && count > 1 {
+            count -= 1;
+        }
+
+        count.max(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_long_winded_bullet_reads_at_higher_grade() {
+        let analyzer = ReadabilityAnalyzer::new();
+        let long_winded = "Jordan Lee\njordan@example.com | (555) 010-2233\n\nEXPERIENCE\n\
+            • Was responsible for the comprehensive administration and continuous optimization of \
+            organizational infrastructure initiatives, which were subsequently institutionalized \
+            across multiple interdepartmental operational environments throughout the enterprise \
+            in order to deliver measurable value to stakeholders\n";
+        let concise = "Jordan Lee\njordan@example.com | (555) 010-2233\n\nEXPERIENCE\n\
+            • Cut build times by half with a new cache\n\
+            • Led a team of five to ship the app\n";
+
+        let dense = analyzer.analyze(long_winded);
+        let clear = analyzer.analyze(concise);
+
+        assert_eq!(clear.sentence_count, 2);
+        assert!(dense.flesch_kincaid_grade > clear.flesch_kincaid_grade + 10.0);
+        assert!(dense.flesch_kincaid_grade > MAX_GRADE_LEVEL);
+        assert!(dense.passive_voice_ratio > 0.0);
+        assert_eq!(dense.long_sentences.len(), 1);
+        assert!(!dense.suggestions.is_empty());
+        assert!(clear.suggestions.is_empty());
+        assert!(dense.score < clear.score);
+        assert_eq!(clear.score, 100.0);
+    }
+
+    #[test]
+    fn test_headers_and_contact_lines_are_skipped() {
+        let analyzer = ReadabilityAnalyzer::new();
+        let metrics = analyzer.analyze(
+            "JORDAN LEE\nSeattle, WA | linkedin.com/in/jordanlee\nSKILLS\nPython, Go, AWS\n",
+        );
+
+        assert_eq!(metrics.sentence_count, 0);
+        assert_eq!(metrics.word_count, 0);
+    }
+}
@@ -1,11 +1,14 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use log::info;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
 
 use crate::achievement_analyzer::{AchievementAnalyzer, BulletAnalysis};
+use crate::advanced_scoring::{KeywordAnalyzer, ScoringSettings};
 use crate::database::Database;
 use crate::semantic_analyzer::SemanticAnalyzer;
+use crate::utils::{find_whole_words, tokenize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LiveSuggestions {
@@ -91,6 +94,76 @@ pub enum Section {
     Unknown,
 }
 
+/// Edit to the resume text: lines `start_line..end_line` of the previous text are replaced by
+/// the lines of `new_text`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TextDelta {
+    pub start_line: usize,
+    pub end_line: usize,
+    pub new_text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SectionScore {
+    pub section: String,
+    /// Line of the section header
+    pub start_line: usize,
+    /// One past the last line of the section
+    pub end_line: usize,
+    pub strength: f64,
+}
+
+/// Live editor score plus the per-line state needed to rescore only what the next edit touches
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncrementalScore {
+    pub overall_score: f64,
+    pub keyword_score: f64,
+    pub format_score: f64,
+    pub section_scores: Vec<SectionScore>,
+    pub matched_keywords: Vec<String>,
+    pub missing_keywords: Vec<String>,
+    /// Lines whose keyword matches were recomputed for this score
+    pub rescored_lines: usize,
+    /// Sections whose strength was recomputed for this score
+    pub rescored_sections: usize,
+    job_keywords: Vec<String>,
+    lines: Vec<String>,
+    /// Canonical section name for header lines
+    line_headers: Vec<Option<String>>,
+    /// Indexes into `job_keywords` found on each line
+    line_keywords: Vec<Vec<usize>>,
+}
+
+impl IncrementalScore {
+    /// The per-line state comes back from the frontend, so check it still lines up before
+    /// indexing into it
+    fn validate(&self) -> Result<()> {
+        if self.line_headers.len() != self.lines.len()
+            || self.line_keywords.len() != self.lines.len()
+        {
+            return Err(anyhow!(
+                "Previous score is inconsistent: {} lines, {} header entries, {} keyword entries",
+                self.lines.len(),
+                self.line_headers.len(),
+                self.line_keywords.len()
+            ));
+        }
+        if let Some(index) = self
+            .line_keywords
+            .iter()
+            .flatten()
+            .find(|index| **index >= self.job_keywords.len())
+        {
+            return Err(anyhow!(
+                "Previous score references keyword {} of {}",
+                index,
+                self.job_keywords.len()
+            ));
+        }
+        Ok(())
+    }
+}
+
 pub struct RealtimeOptimizer {
     achievement_analyzer: AchievementAnalyzer,
     #[allow(dead_code)]
@@ -98,6 +171,7 @@ pub struct RealtimeOptimizer {
     #[allow(dead_code)]
     suggestion_cache: HashMap<String, Vec<ContextualSuggestion>>,
     change_tracker: ChangeTracker,
+    settings: Arc<ScoringSettings>,
 }
 
 #[derive(Debug, Clone)]
@@ -144,9 +218,16 @@ impl RealtimeOptimizer {
                     session_duration: std::time::Duration::from_secs(0),
                 },
             },
+            settings: Arc::new(ScoringSettings::default()),
         }
     }
 
+    /// Score with configured keyword rules and section headers instead of the defaults
+    pub fn with_settings(mut self, settings: Arc<ScoringSettings>) -> Self {
+        self.settings = settings;
+        self
+    }
+
     pub async fn get_live_suggestions(
         &mut self,
         current_content: &str,
@@ -819,6 +900,197 @@ impl RealtimeOptimizer {
         current_score - previous_score
     }
 
+    /// Score a resume from scratch; the result can be passed to `rescore_incremental`
+    pub fn score_full(&self, content: &str, job_description: &str) -> Result<IncrementalScore> {
+        let empty = self.empty_incremental_score(job_description)?;
+        self.rescore_incremental(
+            &empty,
+            &TextDelta {
+                start_line: 0,
+                end_line: 0,
+                new_text: content.to_string(),
+            },
+        )
+    }
+
+    /// Starting state for a document with no text yet
+    fn empty_incremental_score(&self, job_description: &str) -> Result<IncrementalScore> {
+        let job_keywords = KeywordAnalyzer::with_settings(Arc::clone(&self.settings))
+            .extract_keywords_from_job_description(job_description)?;
+        Ok(IncrementalScore {
+            overall_score: 0.0,
+            keyword_score: 0.0,
+            format_score: 0.0,
+            section_scores: Vec::new(),
+            matched_keywords: Vec::new(),
+            missing_keywords: job_keywords.clone(),
+            rescored_lines: 0,
+            rescored_sections: 0,
+            job_keywords,
+            lines: Vec::new(),
+            line_headers: Vec::new(),
+            line_keywords: Vec::new(),
+        })
+    }
+
+    /// Apply an edit to a previous score, re-matching keywords only on the edited lines and
+    /// recomputing strength only for sections the edit touched or moved the boundaries of.
+    /// The job keywords are carried over, so start again from `score_full` when the job
+    /// description changes.
+    pub fn rescore_incremental(
+        &self,
+        previous: &IncrementalScore,
+        delta: &TextDelta,
+    ) -> Result<IncrementalScore> {
+        if delta.start_line > delta.end_line || delta.end_line > previous.lines.len() {
+            return Err(anyhow!(
+                "Edit range {}..{} is outside the {} line document",
+                delta.start_line,
+                delta.end_line,
+                previous.lines.len()
+            ));
+        }
+        previous.validate()?;
+
+        let synonyms = &self.settings.section_header_synonyms;
+        let new_lines: Vec<String> = delta.new_text.lines().map(str::to_string).collect();
+        let new_headers: Vec<Option<String>> = new_lines
+            .iter()
+            .map(|line| synonyms.section_for_header(line).map(str::to_string))
+            .collect();
+        let new_keywords: Vec<Vec<usize>> = new_lines
+            .iter()
+            .map(|line| Self::keywords_on_line(line, &previous.job_keywords))
+            .collect();
+        let rescored_lines = new_lines.len();
+        let shift = new_lines.len() as isize - (delta.end_line - delta.start_line) as isize;
+
+        let mut lines = previous.lines.clone();
+        lines.splice(delta.start_line..delta.end_line, new_lines);
+        let mut line_headers = previous.line_headers.clone();
+        line_headers.splice(delta.start_line..delta.end_line, new_headers);
+        let mut line_keywords = previous.line_keywords.clone();
+        line_keywords.splice(delta.start_line..delta.end_line, new_keywords);
+
+        // Keyword coverage from the cached per-line matches
+        let mut found = vec![false; previous.job_keywords.len()];
+        for index in line_keywords.iter().flatten() {
+            found[*index] = true;
+        }
+        let (matched_keywords, missing_keywords): (Vec<_>, Vec<_>) = previous
+            .job_keywords
+            .iter()
+            .zip(&found)
+            .partition(|(_, found)| **found);
+        let keyword_score = if previous.job_keywords.is_empty() {
+            0.0
+        } else {
+            matched_keywords.len() as f64 / previous.job_keywords.len() as f64 * 100.0
+        };
+
+        // Sections entirely outside the edit keep their strength once shifted to the new line
+        // numbers, as long as the edit didn't move their boundaries
+        let reusable: Vec<SectionScore> = previous
+            .section_scores
+            .iter()
+            .filter_map(|section| {
+                if section.end_line <= delta.start_line {
+                    Some(section.clone())
+                } else if section.start_line >= delta.end_line {
+                    Some(SectionScore {
+                        start_line: (section.start_line as isize + shift) as usize,
+                        end_line: (section.end_line as isize + shift) as usize,
+                        ..section.clone()
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        let mut section_scores = Vec::new();
+        let mut rescored_sections = 0;
+        for (start_line, end_line, section) in Self::section_spans(&line_headers) {
+            let cached = reusable.iter().find(|s| {
+                s.section == section && s.start_line == start_line && s.end_line == end_line
+            });
+            let strength = match cached {
+                Some(cached) => cached.strength,
+                None => {
+                    rescored_sections += 1;
+                    self.calculate_section_strength(&lines[start_line + 1..end_line].join("\n"))
+                }
+            };
+            section_scores.push(SectionScore {
+                section,
+                start_line,
+                end_line,
+                strength,
+            });
+        }
+
+        let format_score = self.quick_format_score(&lines.join("\n")).min(100.0);
+        let section_average = if section_scores.is_empty() {
+            0.0
+        } else {
+            section_scores.iter().map(|s| s.strength).sum::<f64>() / section_scores.len() as f64
+        };
+        let overall_score = keyword_score * 0.5 + section_average * 0.3 + format_score * 0.2;
+
+        Ok(IncrementalScore {
+            overall_score,
+            keyword_score,
+            format_score,
+            section_scores,
+            matched_keywords: matched_keywords
+                .into_iter()
+                .map(|(k, _)| k.clone())
+                .collect(),
+            missing_keywords: missing_keywords
+                .into_iter()
+                .map(|(k, _)| k.clone())
+                .collect(),
+            rescored_lines,
+            rescored_sections,
+            job_keywords: previous.job_keywords.clone(),
+            lines,
+            line_headers,
+            line_keywords,
+        })
+    }
+
+    /// (header line, end line, section) for every recognized section header
+    fn section_spans(line_headers: &[Option<String>]) -> Vec<(usize, usize, String)> {
+        let starts: Vec<(usize, &String)> = line_headers
+            .iter()
+            .enumerate()
+            .filter_map(|(index, header)| header.as_ref().map(|h| (index, h)))
+            .collect();
+
+        starts
+            .iter()
+            .enumerate()
+            .map(|(i, (start, section))| {
+                let end = starts
+                    .get(i + 1)
+                    .map_or(line_headers.len(), |(next, _)| *next);
+                (*start, end, (*section).clone())
+            })
+            .collect()
+    }
+
+    /// Whole-word, case-insensitive keyword matches on one line
+    fn keywords_on_line(line: &str, keywords: &[String]) -> Vec<usize> {
+        let tokens = tokenize(line);
+
+        keywords
+            .iter()
+            .enumerate()
+            .filter(|(_, keyword)| !find_whole_words(line, &tokens, keyword).is_empty())
+            .map(|(index, _)| index)
+            .collect()
+    }
+
     // Placeholder implementations for helper methods
     fn extract_current_bullet(&self, content: &str, cursor_position: usize) -> Option<String> {
         // Find the bullet point that contains the cursor position
@@ -2866,4 +3138,102 @@ mod tests {
         assert!(score > 0.0);
         assert!(score <= 100.0);
     }
+
+    #[tokio::test]
+    async fn test_incremental_rescore_matches_full_rescore() {
+        let db = Database::new_with_url("sqlite::memory:").await.unwrap();
+        let optimizer = RealtimeOptimizer::new(db);
+
+        let job_description =
+            "Senior engineer with Python, Kubernetes and AWS experience. Leadership required.";
+        let original = "Jane Doe\n\
+            Summary\n\
+            Backend engineer focused on reliable services.\n\
+            Experience\n\
+            • Built Python services handling 2 million requests per day\n\
+            • Improved deployment time by 40%\n\
+            Education\n\
+            B.S. Computer Science\n\
+            Skills\n\
+            Python, SQL, Git";
+
+        let before = optimizer.score_full(original, job_description).unwrap();
+
+        // Rewrite the second experience bullet
+        let edit = TextDelta {
+            start_line: 5,
+            end_line: 6,
+            new_text: "• Led migration of 30 services to Kubernetes on AWS".to_string(),
+        };
+        let incremental = optimizer.rescore_incremental(&before, &edit).unwrap();
+        let edited = original
+            .lines()
+            .enumerate()
+            .map(|(i, line)| if i == 5 { edit.new_text.as_str() } else { line })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let full = optimizer.score_full(&edited, job_description).unwrap();
+
+        assert_eq!(incremental.rescored_lines, 1);
+        assert_eq!(incremental.rescored_sections, 1);
+        assert!((incremental.overall_score - full.overall_score).abs() < 1e-6);
+        assert!((incremental.keyword_score - full.keyword_score).abs() < 1e-6);
+        assert_eq!(incremental.section_scores, full.section_scores);
+        assert_eq!(incremental.matched_keywords, full.matched_keywords);
+        assert!(incremental.keyword_score > before.keyword_score);
+
+        // Deleting a header merges two sections and shifts the ones below
+        let merge = TextDelta {
+            start_line: 6,
+            end_line: 7,
+            new_text: String::new(),
+        };
+        let merged = optimizer.rescore_incremental(&incremental, &merge).unwrap();
+        let merged_text = edited
+            .lines()
+            .enumerate()
+            .filter(|(i, _)| *i != 6)
+            .map(|(_, line)| line)
+            .collect::<Vec<_>>()
+            .join("\n");
+        let full_merged = optimizer.score_full(&merged_text, job_description).unwrap();
+
+        assert_eq!(merged.section_scores, full_merged.section_scores);
+        assert!((merged.overall_score - full_merged.overall_score).abs() < 1e-6);
+        assert!(merged.rescored_sections < full_merged.rescored_sections);
+
+        let out_of_range = TextDelta {
+            start_line: 20,
+            end_line: 21,
+            new_text: String::new(),
+        };
+        assert!(optimizer
+            .rescore_incremental(&merged, &out_of_range)
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_incremental_rescore_rejects_inconsistent_previous_state() {
+        let db = Database::new_with_url("sqlite::memory:").await.unwrap();
+        let optimizer = RealtimeOptimizer::new(db);
+        let job_description = "Python and AWS experience required.";
+        let resume = "Experience\n• Built Python services on AWS\nSkills\nPython";
+        let edit = TextDelta {
+            start_line: 1,
+            end_line: 2,
+            new_text: "• Built Go services".to_string(),
+        };
+
+        let mut stale_keywords = optimizer.score_full(resume, job_description).unwrap();
+        stale_keywords.line_keywords[1].push(stale_keywords.job_keywords.len() + 5);
+        assert!(optimizer
+            .rescore_incremental(&stale_keywords, &edit)
+            .is_err());
+
+        let mut short_headers = optimizer.score_full(resume, job_description).unwrap();
+        short_headers.line_headers.truncate(1);
+        assert!(optimizer
+            .rescore_incremental(&short_headers, &edit)
+            .is_err());
+    }
 }
@@ -0,0 +1,291 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use crate::advanced_scoring::SectionHeaderSynonyms;
+
+/// Share of a requirement's key terms a resume line must contain to count as evidence for it
+const REQUIREMENT_MET_THRESHOLD: f64 = 0.5;
+/// Longest evidence snippet returned, in characters
+const MAX_SNIPPET_CHARS: usize = 160;
+/// Section reported for resume lines above the first recognized header
+const UNSECTIONED: &str = "General";
+
+/// Words that say how a requirement is phrased rather than what it asks for
+const FILLER_WORDS: &[&str] = &[
+    "a",
+    "ability",
+    "able",
+    "an",
+    "and",
+    "any",
+    "are",
+    "as",
+    "at",
+    "be",
+    "by",
+    "demonstrated",
+    "deep",
+    "excellent",
+    "experience",
+    "experienced",
+    "familiarity",
+    "familiar",
+    "for",
+    "good",
+    "have",
+    "hands",
+    "in",
+    "including",
+    "is",
+    "knowledge",
+    "minimum",
+    "must",
+    "of",
+    "on",
+    "or",
+    "plus",
+    "preferred",
+    "proficiency",
+    "proficient",
+    "proven",
+    "related",
+    "required",
+    "skills",
+    "solid",
+    "strong",
+    "the",
+    "to",
+    "understanding",
+    "using",
+    "with",
+    "working",
+    "year",
+    "years",
+    "you",
+    "your",
+];
+
+/// Words that mark a JD sentence as a requirement when the JD has no bullet list
+const REQUIREMENT_CUES: &[&str] = &[
+    "experience",
+    "knowledge",
+    "proficien",
+    "degree",
+    "required",
+    "must",
+    "familiar",
+    "years",
+];
+
+/// Where in the resume a requirement is backed up
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RequirementEvidence {
+    pub section: String,
+    pub snippet: String,
+}
+
+/// One row of the coverage matrix
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequirementCoverage {
+    pub requirement: String,
+    pub met: bool,
+    /// Share of the requirement's key terms found in the best matching resume line
+    pub match_score: f64,
+    /// Best matching resume line, present even for unmet requirements with a partial match
+    pub evidence: Option<RequirementEvidence>,
+}
+
+/// Each job description requirement paired with the resume line that best satisfies it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoverageMatrix {
+    pub requirements: Vec<RequirementCoverage>,
+    /// Met requirements as a percentage of all extracted requirements
+    pub coverage_percentage: f64,
+}
+
+pub fn build_coverage_matrix(
+    resume_content: &str,
+    job_description: &str,
+    synonyms: &SectionHeaderSynonyms,
+) -> CoverageMatrix {
+    let resume_lines = sectioned_lines(resume_content, synonyms);
+    let requirements: Vec<RequirementCoverage> = extract_requirements(job_description)
+        .into_iter()
+        .map(|requirement| cover_requirement(requirement, &resume_lines))
+        .collect();
+
+    let met = requirements.iter().filter(|r| r.met).count();
+    let coverage_percentage = if requirements.is_empty() {
+        0.0
+    } else {
+        met as f64 / requirements.len() as f64 * 100.0
+    };
+    CoverageMatrix {
+        requirements,
+        coverage_percentage,
+    }
+}
+
+/// Bulleted JD lines, or requirement-like sentences when the JD has no bullets
+fn extract_requirements(job_description: &str) -> Vec<String> {
+    let bullets: Vec<String> = job_description
+        .lines()
+        .filter_map(strip_bullet)
+        .filter(|line| !key_terms(line).is_empty())
+        .map(str::to_string)
+        .collect();
+    if !bullets.is_empty() {
+        return bullets;
+    }
+
+    job_description
+        .split(['.', ';', '\n'])
+        .map(str::trim)
+        .filter(|sentence| {
+            let lower = sentence.to_lowercase();
+            REQUIREMENT_CUES.iter().any(|cue| lower.contains(cue))
+        })
+        .filter(|sentence| !key_terms(sentence).is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// The text of a bulleted or numbered line, without its marker
+fn strip_bullet(line: &str) -> Option<&str> {
+    let trimmed = line.trim();
+    let text = if let Some(rest) = trimmed.strip_prefix(['-', '*', '•', '·', '▪']) {
+        rest
+    } else {
+        let digits = trimmed.len()
+            - trimmed
+                .trim_start_matches(|c: char| c.is_ascii_digit())
+                .len();
+        trimmed[digits..]
+            .strip_prefix(['.', ')'])
+            .filter(|_| digits > 0)?
+    };
+    Some(text.trim()).filter(|text| !text.is_empty())
+}
+
+/// Resume content lines with the section each falls under
+fn sectioned_lines(
+    resume_content: &str,
+    synonyms: &SectionHeaderSynonyms,
+) -> Vec<(String, String)> {
+    let mut section = UNSECTIONED.to_string();
+    let mut lines = Vec::new();
+    for line in resume_content.lines() {
+        if let Some(header) = synonyms.section_for_header(line) {
+            section = header.to_string();
+        } else if !line.trim().is_empty() {
+            let text = strip_bullet(line).unwrap_or(line.trim());
+            lines.push((section.clone(), text.to_string()));
+        }
+    }
+    lines
+}
+
+fn cover_requirement(
+    requirement: String,
+    resume_lines: &[(String, String)],
+) -> RequirementCoverage {
+    let terms = key_terms(&requirement);
+    let best = resume_lines
+        .iter()
+        .map(|(section, line)| {
+            let line_terms = key_terms(line);
+            let found = terms
+                .iter()
+                .filter(|term| line_terms.contains(*term))
+                .count();
+            (found as f64 / terms.len().max(1) as f64, section, line)
+        })
+        .filter(|(score, _, _)| *score > 0.0)
+        // Ties go to the earlier line, which is usually experience rather than a skills list
+        .reduce(|best, candidate| {
+            if candidate.0 > best.0 {
+                candidate
+            } else {
+                best
+            }
+        });
+
+    match best {
+        Some((match_score, section, line)) => RequirementCoverage {
+            requirement,
+            met: match_score >= REQUIREMENT_MET_THRESHOLD,
+            match_score,
+            evidence: Some(RequirementEvidence {
+                section: section.clone(),
+                snippet: line.chars().take(MAX_SNIPPET_CHARS).collect(),
+            }),
+        },
+        None => RequirementCoverage {
+            requirement,
+            met: false,
+            match_score: 0.0,
+            evidence: None,
+        },
+    }
+}
+
+/// Lowercase content words with a trailing plural "s" dropped, ignoring numbers and filler
+fn key_terms(text: &str) -> HashSet<String> {
+    text.to_lowercase()
+        .split(|c: char| !(c.is_alphanumeric() || matches!(c, '+' | '#' | '.')))
+        .map(|word| word.trim_matches('.'))
+        .filter(|word| word.chars().any(char::is_alphabetic))
+        .filter(|word| !FILLER_WORDS.contains(word))
+        .map(|word| {
+            word.strip_suffix('s')
+                .filter(|stem| stem.len() > 2 && !stem.ends_with('s'))
+                .unwrap_or(word)
+                .to_string()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coverage_matrix_pairs_requirements_with_evidence() {
+        let job_description = "Backend Engineer\n\n\
+                               Requirements:\n\
+                               - 3+ years of Python development\n\
+                               - Experience running Kubernetes in production\n\
+                               - Familiarity with Salesforce administration\n";
+        let resume = "Jane Doe\njane@example.com\n\n\
+                      Experience\n\
+                      Software Engineer, Acme, 2020 - Present\n\
+                      • Led Python development of the billing service\n\
+                      • Ran Kubernetes clusters in production for 40 services\n\n\
+                      Skills\nPython, Kubernetes, Docker\n";
+
+        let matrix =
+            build_coverage_matrix(resume, job_description, &SectionHeaderSynonyms::default());
+
+        assert_eq!(matrix.requirements.len(), 3);
+        assert_eq!(matrix.coverage_percentage.floor(), 66.0);
+
+        let python = &matrix.requirements[0];
+        assert!(python.met);
+        assert_eq!(
+            python.evidence,
+            Some(RequirementEvidence {
+                section: "Experience".to_string(),
+                snippet: "Led Python development of the billing service".to_string(),
+            })
+        );
+        let kubernetes = &matrix.requirements[1];
+        assert!(kubernetes.met);
+        assert_eq!(
+            kubernetes.evidence.as_ref().unwrap().snippet,
+            "Ran Kubernetes clusters in production for 40 services"
+        );
+        let salesforce = &matrix.requirements[2];
+        assert!(!salesforce.met);
+        assert!(salesforce.evidence.is_none());
+    }
+}
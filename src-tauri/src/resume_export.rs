@@ -0,0 +1,574 @@
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use docx_rs::{Docx, Paragraph, Run, RunFonts, SpecialIndentType};
+use lopdf::content::{Content, Operation};
+use lopdf::{dictionary, Document, Object, Stream, StringFormat};
+use std::io::Cursor;
+
+use crate::advanced_scoring::{normalize_resume_text, SectionHeaderSynonyms};
+use crate::models::OptimizationResult;
+use crate::utils::write_export_file;
+
+/// US Letter in points
+const PAGE_WIDTH: f32 = 612.0;
+const PAGE_HEIGHT: f32 = 792.0;
+const PAGE_MARGIN: f32 = 54.0;
+
+const NAME_FONT_SIZE: f32 = 16.0;
+const HEADING_FONT_SIZE: f32 = 12.0;
+const BODY_FONT_SIZE: f32 = 10.5;
+const LINE_SPACING: f32 = 1.35;
+const BULLET_INDENT: f32 = 12.0;
+
+/// Average Helvetica glyph width as a fraction of the font size, used for line wrapping
+const AVG_CHAR_WIDTH: f32 = 0.5;
+
+const BULLET_MARKERS: &[char] = &['•', '-', '*', '▪', '◦', '●', '–', '‣'];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResumeExportFormat {
+    Pdf,
+    Docx,
+    Markdown,
+    /// ASCII-safe single-column text for pasting into ATS web forms
+    PlainText,
+}
+
+impl ResumeExportFormat {
+    pub fn parse(format: &str) -> Result<Self> {
+        match format.trim().to_lowercase().as_str() {
+            "pdf" => Ok(Self::Pdf),
+            "docx" => Ok(Self::Docx),
+            "markdown" | "md" => Ok(Self::Markdown),
+            "txt" | "text" | "plain" => Ok(Self::PlainText),
+            other => Err(anyhow!("Unsupported resume export format: {}", other)),
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Pdf => "pdf",
+            Self::Docx => "docx",
+            Self::Markdown => "md",
+            Self::PlainText => "txt",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResumeLine {
+    Text(String),
+    Bullet(String),
+}
+
+/// A resume section; the heading is `None` for the contact block above the first section
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResumeSection {
+    pub heading: Option<String>,
+    pub lines: Vec<ResumeLine>,
+}
+
+/// Resume content split into sections and bullets, in the order they appear
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResumeDocument {
+    pub sections: Vec<ResumeSection>,
+}
+
+impl ResumeDocument {
+    pub fn from_optimization(
+        result: &OptimizationResult,
+        synonyms: &SectionHeaderSynonyms,
+    ) -> Self {
+        Self::parse(&result.optimized_content, synonyms)
+    }
+
+    /// Recognized headers are rewritten to their standard section name so every ATS finds
+    /// them; unrecognized all-caps headers are kept as written
+    pub fn parse(content: &str, synonyms: &SectionHeaderSynonyms) -> Self {
+        let mut sections = vec![ResumeSection {
+            heading: None,
+            lines: Vec::new(),
+        }];
+
+        for line in content.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            let heading = synonyms
+                .section_for_header(line)
+                .map(str::to_uppercase)
+                .or_else(|| Self::is_caps_header(line).then(|| line.to_string()));
+
+            if let Some(heading) = heading {
+                sections.push(ResumeSection {
+                    heading: Some(heading),
+                    lines: Vec::new(),
+                });
+                continue;
+            }
+
+            let parsed = match Self::strip_bullet(line) {
+                Some(text) => ResumeLine::Bullet(text.to_string()),
+                None => ResumeLine::Text(line.to_string()),
+            };
+            if let Some(section) = sections.last_mut() {
+                section.lines.push(parsed);
+            }
+        }
+
+        sections.retain(|s| s.heading.is_some() || !s.lines.is_empty());
+        Self { sections }
+    }
+
+    fn strip_bullet(line: &str) -> Option<&str> {
+        let mut chars = line.chars();
+        let marker = chars.next()?;
+        let rest = chars.as_str();
+        (BULLET_MARKERS.contains(&marker) && rest.starts_with(char::is_whitespace))
+            .then(|| rest.trim())
+    }
+
+    fn is_caps_header(line: &str) -> bool {
+        let letters: Vec<char> = line.chars().filter(|c| c.is_alphabetic()).collect();
+        line.len() <= 40
+            && letters.len() >= 3
+            && letters.iter().all(|c| c.is_uppercase())
+            && !line.contains('@')
+            && !line.chars().any(|c| c.is_ascii_digit())
+            && Self::strip_bullet(line).is_none()
+    }
+}
+
+/// Render a single-column PDF using the standard Helvetica fonts
+pub fn render_pdf(resume: &ResumeDocument) -> Result<Vec<u8>> {
+    let mut layout = PdfLayout::new();
+
+    for (index, section) in resume.sections.iter().enumerate() {
+        let mut lines = section.lines.iter();
+        match &section.heading {
+            Some(heading) => {
+                if index > 0 {
+                    layout.gap(BODY_FONT_SIZE * 0.6);
+                }
+                layout.write_wrapped(heading, PdfFont::Bold, HEADING_FONT_SIZE, 0.0, "");
+            }
+            None => {
+                // The first line of the contact block is the candidate's name
+                if let Some(ResumeLine::Text(name)) = section.lines.first() {
+                    lines.next();
+                    layout.write_wrapped(name, PdfFont::Bold, NAME_FONT_SIZE, 0.0, "");
+                }
+            }
+        }
+
+        for line in lines {
+            match line {
+                ResumeLine::Text(text) => {
+                    layout.write_wrapped(text, PdfFont::Regular, BODY_FONT_SIZE, 0.0, "")
+                }
+                ResumeLine::Bullet(text) => layout.write_wrapped(
+                    text,
+                    PdfFont::Regular,
+                    BODY_FONT_SIZE,
+                    BULLET_INDENT,
+                    "\u{2022}",
+                ),
+            }
+        }
+    }
+
+    layout.finish()
+}
+
+/// Render a DOCX with Arial runs and hanging-indent bullets
+pub fn render_docx(resume: &ResumeDocument) -> Result<Vec<u8>> {
+    let fonts = || RunFonts::new().ascii("Arial").hi_ansi("Arial").cs("Arial");
+    // docx sizes are in half-points
+    let run = |text: &str, size: f32| {
+        Run::new()
+            .add_text(text)
+            .size((size * 2.0).round() as usize)
+            .fonts(fonts())
+    };
+
+    let mut docx = Docx::new();
+    for section in &resume.sections {
+        let mut lines = section.lines.iter();
+        match &section.heading {
+            Some(heading) => {
+                docx = docx.add_paragraph(
+                    Paragraph::new().add_run(run(heading, HEADING_FONT_SIZE).bold()),
+                );
+            }
+            None => {
+                if let Some(ResumeLine::Text(name)) = section.lines.first() {
+                    lines.next();
+                    docx = docx
+                        .add_paragraph(Paragraph::new().add_run(run(name, NAME_FONT_SIZE).bold()));
+                }
+            }
+        }
+
+        for line in lines {
+            let paragraph = match line {
+                ResumeLine::Text(text) => Paragraph::new().add_run(run(text, BODY_FONT_SIZE)),
+                ResumeLine::Bullet(text) => Paragraph::new()
+                    .add_run(run(&format!("\u{2022}\t{}", text), BODY_FONT_SIZE))
+                    .indent(Some(360), Some(SpecialIndentType::Hanging(360)), None, None),
+            };
+            docx = docx.add_paragraph(paragraph);
+        }
+    }
+
+    let mut buffer = Cursor::new(Vec::new());
+    docx.build().pack(&mut buffer)?;
+    Ok(buffer.into_inner())
+}
+
+/// Render Markdown with the name as the title and each section as a second-level heading
+pub fn render_markdown(resume: &ResumeDocument) -> String {
+    let mut blocks = Vec::new();
+
+    for section in &resume.sections {
+        let mut lines = section.lines.iter();
+        match &section.heading {
+            Some(heading) => blocks.push(format!("## {}", heading)),
+            None => {
+                if let Some(ResumeLine::Text(name)) = section.lines.first() {
+                    lines.next();
+                    blocks.push(format!("# {}", name));
+                }
+            }
+        }
+
+        // Consecutive bullets form one list; each text line stays its own paragraph
+        let mut bullets = Vec::new();
+        for line in lines {
+            match line {
+                ResumeLine::Bullet(text) => bullets.push(format!("- {}", text)),
+                ResumeLine::Text(text) => {
+                    if !bullets.is_empty() {
+                        blocks.push(std::mem::take(&mut bullets).join("\n"));
+                    }
+                    blocks.push(text.clone());
+                }
+            }
+        }
+        if !bullets.is_empty() {
+            blocks.push(bullets.join("\n"));
+        }
+    }
+
+    blocks.join("\n\n") + "\n"
+}
+
+/// Render ASCII-safe text: straight quotes, hyphen bullets and one column, with garbled
+/// characters fixed rather than only flagged
+pub fn render_plain_text(resume: &ResumeDocument) -> String {
+    // Collapsing whitespace also removes tab gutters left over from multi-column layouts
+    let clean = |text: &str| {
+        normalize_resume_text(text)
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    let mut lines = Vec::new();
+
+    for section in &resume.sections {
+        if let Some(heading) = &section.heading {
+            if !lines.is_empty() {
+                lines.push(String::new());
+            }
+            lines.push(clean(heading));
+        }
+        for line in &section.lines {
+            lines.push(match line {
+                ResumeLine::Text(text) => clean(text),
+                ResumeLine::Bullet(text) => format!("- {}", clean(text)),
+            });
+        }
+    }
+
+    lines.join("\n") + "\n"
+}
+
+pub fn render_resume(resume: &ResumeDocument, format: ResumeExportFormat) -> Result<Vec<u8>> {
+    match format {
+        ResumeExportFormat::Pdf => render_pdf(resume),
+        ResumeExportFormat::Docx => render_docx(resume),
+        ResumeExportFormat::Markdown => Ok(render_markdown(resume).into_bytes()),
+        ResumeExportFormat::PlainText => Ok(render_plain_text(resume).into_bytes()),
+    }
+}
+
+/// Render an optimization result and write it to the exports directory
+pub async fn export_optimized_resume(
+    result: &OptimizationResult,
+    format: &str,
+    synonyms: &SectionHeaderSynonyms,
+) -> Result<String> {
+    let format = ResumeExportFormat::parse(format)?;
+    let bytes = render_resume(&ResumeDocument::from_optimization(result, synonyms), format)?;
+
+    let filename = format!(
+        "optimized_resume_{}.{}",
+        Utc::now().format("%Y%m%d_%H%M%S"),
+        format.extension()
+    );
+    let file_path = write_export_file(&filename, &bytes).await?;
+    Ok(file_path.to_string_lossy().to_string())
+}
+
+#[derive(Debug, Clone, Copy)]
+enum PdfFont {
+    Regular,
+    Bold,
+}
+
+impl PdfFont {
+    fn resource_name(self) -> &'static str {
+        match self {
+            Self::Regular => "F1",
+            Self::Bold => "F2",
+        }
+    }
+}
+
+/// Top-to-bottom text layout that starts a new page when the current one fills up
+struct PdfLayout {
+    pages: Vec<Vec<Operation>>,
+    current: Vec<Operation>,
+    cursor_y: f32,
+}
+
+impl PdfLayout {
+    fn new() -> Self {
+        Self {
+            pages: Vec::new(),
+            current: Vec::new(),
+            cursor_y: PAGE_HEIGHT - PAGE_MARGIN,
+        }
+    }
+
+    fn gap(&mut self, height: f32) {
+        self.cursor_y -= height;
+    }
+
+    /// Wrap text to the usable width; `marker` is drawn in the indent on the first line only
+    fn write_wrapped(&mut self, text: &str, font: PdfFont, size: f32, indent: f32, marker: &str) {
+        let usable_width = PAGE_WIDTH - 2.0 * PAGE_MARGIN - indent;
+        let max_chars = (usable_width / (size * AVG_CHAR_WIDTH)).floor().max(1.0) as usize;
+
+        for (index, line) in wrap_text(text, max_chars).iter().enumerate() {
+            let leading = size * LINE_SPACING;
+            if self.cursor_y - leading < PAGE_MARGIN {
+                self.new_page();
+            }
+            self.cursor_y -= leading;
+
+            if index == 0 && !marker.is_empty() {
+                self.show_text(marker, font, size, PAGE_MARGIN);
+            }
+            self.show_text(line, font, size, PAGE_MARGIN + indent);
+        }
+    }
+
+    fn show_text(&mut self, text: &str, font: PdfFont, size: f32, x: f32) {
+        let encoded = Document::encode_text(Some("WinAnsiEncoding"), text);
+        self.current.extend([
+            Operation::new("BT", vec![]),
+            Operation::new("Tf", vec![font.resource_name().into(), size.into()]),
+            Operation::new("Td", vec![x.into(), self.cursor_y.into()]),
+            Operation::new("Tj", vec![Object::String(encoded, StringFormat::Literal)]),
+            Operation::new("ET", vec![]),
+        ]);
+    }
+
+    fn new_page(&mut self) {
+        self.pages.push(std::mem::take(&mut self.current));
+        self.cursor_y = PAGE_HEIGHT - PAGE_MARGIN;
+    }
+
+    fn finish(mut self) -> Result<Vec<u8>> {
+        if !self.current.is_empty() || self.pages.is_empty() {
+            self.new_page();
+        }
+
+        let mut doc = Document::with_version("1.5");
+        let pages_id = doc.new_object_id();
+        let regular_id = doc.add_object(dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type1",
+            "BaseFont" => "Helvetica",
+            "Encoding" => "WinAnsiEncoding",
+        });
+        let bold_id = doc.add_object(dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type1",
+            "BaseFont" => "Helvetica-Bold",
+            "Encoding" => "WinAnsiEncoding",
+        });
+        let resources_id = doc.add_object(dictionary! {
+            "Font" => dictionary! {
+                "F1" => regular_id,
+                "F2" => bold_id,
+            },
+        });
+
+        let mut kids = Vec::with_capacity(self.pages.len());
+        for operations in self.pages {
+            let content = Content { operations };
+            let content_id = doc.add_object(Stream::new(dictionary! {}, content.encode()?));
+            let page_id = doc.add_object(dictionary! {
+                "Type" => "Page",
+                "Parent" => pages_id,
+                "Contents" => content_id,
+            });
+            kids.push(Object::from(page_id));
+        }
+
+        let page_count = kids.len() as i64;
+        doc.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Kids" => kids,
+                "Count" => page_count,
+                "Resources" => resources_id,
+                "MediaBox" => vec![0.into(), 0.into(), PAGE_WIDTH.into(), PAGE_HEIGHT.into()],
+            }),
+        );
+        let catalog_id = doc.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => pages_id,
+        });
+        doc.trailer.set("Root", catalog_id);
+        doc.compress();
+
+        let mut buffer = Vec::new();
+        doc.save_to(&mut buffer)?;
+        Ok(buffer)
+    }
+}
+
+fn wrap_text(text: &str, max_chars: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+
+    for word in text.split_whitespace() {
+        let needed = if current.is_empty() {
+            word.chars().count()
+        } else {
+            current.chars().count() + 1 + word.chars().count()
+        };
+        if needed > max_chars && !current.is_empty() {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+    }
+
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OPTIMIZED: &str = "Jane Doe\n\
+        jane.doe@example.com | (555) 123-4567\n\
+        \n\
+        Professional Summary\n\
+        Backend engineer with eight years building distributed systems.\n\
+        Professional Experience:\n\
+        Senior Engineer, Acme Corp (2019 - Present)\n\
+        • Led migration of 40 services to Kubernetes, cutting deploy time by 60%\n\
+        - Mentored five engineers on Rust and Go\n\
+        Education\n\
+        B.S. Computer Science, State University\n\
+        Technical Skills\n\
+        Rust, Go, Python, AWS, Kubernetes";
+
+    fn optimization() -> OptimizationResult {
+        OptimizationResult {
+            optimized_content: OPTIMIZED.to_string(),
+            changes_made: Vec::new(),
+            before_score: 62.0,
+            after_score: 81.0,
+            improvement_percentage: 30.6,
+            offline_mode: false,
+        }
+    }
+
+    #[test]
+    fn test_pdf_export_contains_section_headers() {
+        let resume =
+            ResumeDocument::from_optimization(&optimization(), &SectionHeaderSynonyms::default());
+
+        let headings: Vec<_> = resume
+            .sections
+            .iter()
+            .filter_map(|s| s.heading.as_deref())
+            .collect();
+        assert_eq!(headings, ["SUMMARY", "EXPERIENCE", "EDUCATION", "SKILLS"]);
+        assert_eq!(
+            resume.sections[2].lines[1..],
+            [
+                ResumeLine::Bullet(
+                    "Led migration of 40 services to Kubernetes, cutting deploy time by 60%"
+                        .to_string()
+                ),
+                ResumeLine::Bullet("Mentored five engineers on Rust and Go".to_string()),
+            ]
+        );
+
+        let pdf = render_pdf(&resume).unwrap();
+        let text = pdf_extract::extract_text_from_mem(&pdf).unwrap();
+
+        let positions: Vec<usize> = headings
+            .iter()
+            .map(|h| {
+                text.find(h)
+                    .unwrap_or_else(|| panic!("{h} missing from {text}"))
+            })
+            .collect();
+        assert!(positions.windows(2).all(|w| w[0] < w[1]), "{text}");
+        assert!(text.contains("Jane Doe"));
+        assert!(text.contains("Mentored five engineers"));
+
+        let docx = render_docx(&resume).unwrap();
+        assert!(docx.starts_with(b"PK"));
+    }
+
+    #[test]
+    fn test_plain_text_export_fixes_typographic_characters() {
+        let resume = ResumeDocument::parse(
+            "Jane Doe\n\
+             Experience\n\
+             Senior Engineer \u{2014} Acme Corp\t\t\t2019 \u{2013} Present\n\
+             \u{25AA} Rebuilt the \u{201C}orders\u{201D} service\u{2019}s cache layer\n",
+            &SectionHeaderSynonyms::default(),
+        );
+
+        let text = render_plain_text(&resume);
+        assert_eq!(
+            text,
+            "Jane Doe\n\
+             \n\
+             EXPERIENCE\n\
+             Senior Engineer - Acme Corp 2019 - Present\n\
+             - Rebuilt the \"orders\" service's cache layer\n"
+        );
+        assert!(text.is_ascii());
+
+        let markdown = render_markdown(&resume);
+        assert!(markdown.starts_with("# Jane Doe\n\n## EXPERIENCE\n"));
+        assert!(markdown.contains("\n- Rebuilt the \u{201C}orders\u{201D}"));
+        assert_eq!(
+            ResumeExportFormat::parse("md").unwrap(),
+            ResumeExportFormat::Markdown
+        );
+    }
+}
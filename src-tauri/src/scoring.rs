@@ -1,12 +1,13 @@
 use anyhow::{anyhow, Result};
-use log::info;
+use log::{info, warn};
 use regex::Regex;
 use serde_json::Value;
 use std::collections::HashMap;
 
+use crate::advanced_scoring::AdvancedScoringEngine;
 use crate::models::{AnalysisResult, CategoryScores, OptimizationChange, OptimizationResult};
 use crate::modern_keyword_extractor::{ExtractionResult, ModernKeywordExtractor};
-use crate::ollama::OllamaClient;
+use crate::ollama::{is_unavailable_error, OllamaClient};
 
 pub struct AnalysisEngine {
     ollama_client: OllamaClient,
@@ -49,6 +50,7 @@ impl AnalysisEngine {
         // Parse AI response
         let mut analysis_result = self.parse_ai_analysis(&ai_response)?;
         analysis_result.processing_time_ms = processing_time;
+        analysis_result.model_used = Some(model_name.to_string());
 
         // Enhance with our own scoring algorithms
         self.enhance_analysis(&mut analysis_result, resume_content, job_description)?;
@@ -147,9 +149,42 @@ impl AnalysisEngine {
             before_score: original_analysis.overall_score,
             after_score: optimized_analysis.overall_score,
             improvement_percentage,
+            offline_mode: false,
         })
     }
 
+    /// Optimize with the LLM, degrading to rule-based suggestions from the advanced scoring
+    /// engine when Ollama can't be reached
+    pub async fn optimize_resume_or_offline(
+        &self,
+        resume_content: &str,
+        job_description: &str,
+        model_name: &str,
+        optimization_level: &str,
+        fallback: &AdvancedScoringEngine,
+    ) -> Result<OptimizationResult> {
+        match self
+            .optimize_resume(
+                resume_content,
+                job_description,
+                model_name,
+                optimization_level,
+            )
+            .await
+        {
+            Err(e) if is_unavailable_error(&e) => {
+                warn!(
+                    "Ollama unavailable ({}), falling back to offline suggestions",
+                    e
+                );
+                fallback
+                    .offline_optimization(resume_content, job_description)
+                    .await
+            }
+            result => result,
+        }
+    }
+
     fn parse_ai_analysis(&self, response: &str) -> Result<AnalysisResult> {
         // Try to extract JSON from the response
         let json_str = self.extract_json_from_response(response)?;
@@ -171,6 +206,7 @@ impl AnalysisEngine {
                 .as_f64()
                 .unwrap_or(50.0),
             format: parsed["category_scores"]["format"].as_f64().unwrap_or(50.0),
+            confidence: None,
         };
 
         let detailed_feedback = parsed["detailed_feedback"]
@@ -205,6 +241,7 @@ impl AnalysisEngine {
             missing_keywords,
             recommendations,
             processing_time_ms: 0, // Will be set by caller
+            model_used: None,
         })
     }
 
@@ -585,6 +622,7 @@ struct OptimizationData {
 mod tests {
     use super::*;
     use crate::ollama::OllamaClient;
+    use crate::utils::test_server::spawn_json_server;
 
     const SAMPLE_RESUME: &str = r#"
 John Doe
@@ -629,6 +667,120 @@ Requirements:
             .expect("Failed to create test Ollama client")
     }
 
+    /// Serve `/api/generate` with an analysis whose recommendation echoes the request's
+    /// seed and temperature, standing in for a model that is deterministic per seed.
+    async fn spawn_seed_echo_model() -> String {
+        spawn_json_server(|request| async move {
+            // Take a moment like a real model would, so recorded latency is measurable
+            tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+            let analysis = serde_json::json!({
+                "overall_score": 72.0,
+                "detailed_feedback": "Solid match",
+                "missing_keywords": ["Azure"],
+                "recommendations": [format!(
+                    "Suggestion for seed {} at temperature {}",
+                    request["options"]["seed"], request["options"]["temperature"]
+                )],
+            });
+            serde_json::json!({
+                "model": request["model"],
+                "created_at": "2024-01-01T00:00:00Z",
+                "response": analysis.to_string(),
+                "done": true,
+            })
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_optimize_falls_back_offline_when_ollama_unreachable() {
+        // Grab a free port and close it again so nothing is listening there
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+
+        let engine = AnalysisEngine::new(OllamaClient::new(Some(base_url)).unwrap());
+        let db = crate::database::Database::new_with_url("sqlite::memory:")
+            .await
+            .unwrap();
+        let fallback = AdvancedScoringEngine::new(std::sync::Arc::new(tokio::sync::Mutex::new(db)));
+
+        let result = engine
+            .optimize_resume_or_offline(
+                SAMPLE_RESUME,
+                SAMPLE_JOB_DESCRIPTION,
+                "mistral",
+                "balanced",
+                &fallback,
+            )
+            .await
+            .unwrap();
+
+        assert!(result.offline_mode);
+        assert!(!result.changes_made.is_empty());
+        assert_eq!(result.optimized_content, SAMPLE_RESUME);
+        assert_eq!(result.before_score, result.after_score);
+    }
+
+    #[tokio::test]
+    async fn test_deterministic_mode_repeats_suggestions() {
+        let base_url = spawn_seed_echo_model().await;
+        let client = OllamaClient::new(Some(base_url))
+            .unwrap()
+            .with_seed(Some(42));
+        let engine = AnalysisEngine::new(client);
+
+        let first = engine
+            .analyze_resume(SAMPLE_RESUME, SAMPLE_JOB_DESCRIPTION, "mistral")
+            .await
+            .unwrap();
+        let second = engine
+            .analyze_resume(SAMPLE_RESUME, SAMPLE_JOB_DESCRIPTION, "mistral")
+            .await
+            .unwrap();
+
+        assert_eq!(first.recommendations, second.recommendations);
+        assert_eq!(first.missing_keywords, second.missing_keywords);
+        assert_eq!(first.overall_score, second.overall_score);
+        assert!(first
+            .recommendations
+            .iter()
+            .any(|r| r == "Suggestion for seed 42 at temperature 0.0"));
+    }
+
+    #[tokio::test]
+    async fn test_analysis_records_model_performance() {
+        let base_url = spawn_seed_echo_model().await;
+        let db = crate::database::Database::new_with_url("sqlite::memory:")
+            .await
+            .unwrap();
+        let client = OllamaClient::new(Some(base_url))
+            .unwrap()
+            .with_performance_tracking(db.clone());
+        let engine = AnalysisEngine::new(client);
+
+        engine
+            .analyze_resume(SAMPLE_RESUME, SAMPLE_JOB_DESCRIPTION, "mistral")
+            .await
+            .unwrap();
+
+        let (model_name, processing_time_ms, prompt_tokens, error_count): (String, i64, i64, i32) =
+            sqlx::query_as(
+                "SELECT model_name, processing_time_ms, prompt_tokens, error_count \
+                 FROM model_performance_metrics",
+            )
+            .fetch_one(db.get_pool())
+            .await
+            .unwrap();
+        assert_eq!(model_name, "mistral");
+        assert!(processing_time_ms > 0);
+        assert!(prompt_tokens > 0);
+        assert_eq!(error_count, 0);
+
+        let stats = db.get_model_performance_stats("mistral").await.unwrap();
+        assert_eq!(stats["analysis_count"], 1);
+    }
+
     #[test]
     fn test_extract_keywords() {
         let engine = AnalysisEngine::new(create_mock_ollama_client());
@@ -685,6 +837,7 @@ Requirements:
             education: 75.0,
             keywords: 90.0,
             format: 95.0,
+            confidence: None,
         };
 
         let weighted_score = engine.calculate_weighted_score(&scores);
@@ -773,11 +926,13 @@ Software Engineer
                 education: 80.0,
                 keywords: 70.0,
                 format: 85.0,
+                confidence: None,
             },
             detailed_feedback: "Initial feedback".to_string(),
             missing_keywords: vec!["kubernetes".to_string(), "microservices".to_string()],
             recommendations: vec![],
             processing_time_ms: 1000,
+            model_used: None,
         };
 
         let keyword_analysis = KeywordAnalysis {
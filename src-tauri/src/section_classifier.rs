@@ -0,0 +1,484 @@
+//! Section labels for blocks under headings no header pattern knows. With an embedding model
+//! configured, each block is embedded through Ollama and labelled by the nearest canonical
+//! section embedding; the canonical embeddings are computed once per model and cached. Without
+//! one, or when Ollama cannot be reached, blocks are compared by cosine similarity of stemmed
+//! term-frequency vectors against the same per-section descriptions, which only places a block
+//! that shares words with them. Real ATS systems do not do this, so it feeds the consolidated
+//! parse and never the per-system simulations.
+
+use anyhow::Result;
+use log::warn;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::advanced_scoring::SectionHeaderSynonyms;
+use crate::language::Language;
+use crate::ollama::OllamaClient;
+use crate::utils::tokenize;
+
+/// Label for blocks that are not close enough to any canonical section
+pub const GENERAL_SECTION: &str = "General";
+
+/// Cosine similarity a block needs with its nearest canonical section to take that label
+pub const SECTION_CONFIDENCE_THRESHOLD: f64 = 0.2;
+
+/// The same, for embedding similarity, which runs higher than term overlap for unrelated text
+pub const EMBEDDING_CONFIDENCE_THRESHOLD: f64 = 0.5;
+
+/// Headings longer than this are treated as body text
+const MAX_HEADING_WORDS: usize = 4;
+
+/// Description of each canonical section, covering both the headers people use and the words
+/// typically found under them
+const SECTION_PROTOTYPES: &[(&str, &str)] = &[
+    (
+        "Summary",
+        "summary profile about me objective overview introduction personal statement \
+         passionate motivated driven results-oriented professional seeking looking opportunity \
+         years background focused dedicated",
+    ),
+    (
+        "Experience",
+        "experience work history employment career professional background roles positions \
+         company employer present current engineer \
+         developer manager analyst consultant intern senior lead led managed built building \
+         developed delivered designed implemented launched improved reduced increased owned \
+         responsible team teams clients customers services",
+    ),
+    (
+        "Education",
+        "education academic background studies schooling learning university college school \
+         institute academy degree bachelor master masters phd doctorate diploma bsc msc mba gpa \
+         graduated graduation major minor coursework thesis honors cum laude studied",
+    ),
+    (
+        "Skills",
+        "skills toolkit toolbox tools technologies tech stack competencies expertise abilities \
+         strengths proficient proficiency familiar languages frameworks libraries platforms \
+         software python java javascript sql excel git docker kubernetes aws linux",
+    ),
+    (
+        "Projects",
+        "projects portfolio side projects personal project open source \
+         github hackathon prototype demo app application built created repository",
+    ),
+    (
+        "Certifications",
+        "certifications certificates licenses credentials certified certificate accreditation \
+         accredited license issued expires expiry exam aws pmp cissp comptia",
+    ),
+    (
+        "Achievements",
+        "achievements accomplishments awards honors honours recognition wins milestones \
+         awarded won prize winner recognized nominated distinction",
+    ),
+];
+
+/// Term-frequency vector over stemmed words
+type TermVector = HashMap<String, f64>;
+
+static CANONICAL_VECTORS: Lazy<Vec<(&'static str, TermVector)>> = Lazy::new(|| {
+    SECTION_PROTOTYPES
+        .iter()
+        .map(|(section, vocabulary)| (*section, term_vector(vocabulary)))
+        .collect()
+});
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SectionClassification {
+    pub section: String,
+    /// Cosine similarity with the chosen section, 0.0 to 1.0
+    pub confidence: f64,
+}
+
+/// A run of lines under one heading
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResumeBlock {
+    pub heading: String,
+    pub body: String,
+    /// Section named by a recognized header, if the heading is one
+    pub known_section: Option<String>,
+}
+
+/// Canonical section embeddings per embedding model
+static CANONICAL_EMBEDDINGS: Lazy<Mutex<HashMap<String, CanonicalEmbeddings>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+type CanonicalEmbeddings = Arc<[(&'static str, Vec<f64>)]>;
+
+/// Labels blocks by their nearest canonical section embedding from an Ollama embedding model
+#[derive(Debug, Clone)]
+pub struct EmbeddingSectionClassifier {
+    client: OllamaClient,
+    model: String,
+}
+
+impl EmbeddingSectionClassifier {
+    pub fn new(client: OllamaClient, model: impl Into<String>) -> Self {
+        Self {
+            client,
+            model: model.into(),
+        }
+    }
+
+    /// Sections found only by classification, as `classify_unrecognized_sections` but by
+    /// embedding. Once Ollama fails, the remaining blocks are classified lexically.
+    pub async fn classify_unrecognized_sections(
+        &self,
+        content: &str,
+        synonyms: &SectionHeaderSynonyms,
+    ) -> HashMap<String, String> {
+        let mut sections: HashMap<String, String> = HashMap::new();
+        let mut ollama_available = true;
+
+        for (text, body) in unrecognized_blocks(content, synonyms) {
+            let classification = if ollama_available {
+                match self.classify_block(&text).await {
+                    Ok(classification) => classification,
+                    Err(e) => {
+                        warn!(
+                            "Embedding section classification with {} failed, using vocabulary matching: {}",
+                            self.model, e
+                        );
+                        ollama_available = false;
+                        classify_block(&text)
+                    }
+                }
+            } else {
+                classify_block(&text)
+            };
+            if classification.section != GENERAL_SECTION {
+                sections.entry(classification.section).or_insert(body);
+            }
+        }
+
+        sections
+    }
+
+    /// Label a block by its nearest canonical section embedding, or "General" below the
+    /// embedding threshold
+    async fn classify_block(&self, text: &str) -> Result<SectionClassification> {
+        let canonical = self.canonical_embeddings().await?;
+        let embedding = self.client.embed(&self.model, text).await?;
+
+        Ok(nearest_section(
+            canonical
+                .iter()
+                .map(|(section, vector)| (*section, dense_cosine_similarity(&embedding, vector))),
+            EMBEDDING_CONFIDENCE_THRESHOLD,
+        ))
+    }
+
+    async fn canonical_embeddings(&self) -> Result<CanonicalEmbeddings> {
+        let cached = CANONICAL_EMBEDDINGS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&self.model)
+            .cloned();
+        if let Some(embeddings) = cached {
+            return Ok(embeddings);
+        }
+
+        let mut embeddings = Vec::with_capacity(SECTION_PROTOTYPES.len());
+        for (section, description) in SECTION_PROTOTYPES {
+            embeddings.push((*section, self.client.embed(&self.model, description).await?));
+        }
+        let embeddings: CanonicalEmbeddings = embeddings.into();
+
+        CANONICAL_EMBEDDINGS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(self.model.clone(), Arc::clone(&embeddings));
+        Ok(embeddings)
+    }
+}
+
+/// Label a block by its nearest canonical section, or "General" below the threshold
+pub fn classify_block(text: &str) -> SectionClassification {
+    let vector = term_vector(text);
+    nearest_section(
+        CANONICAL_VECTORS
+            .iter()
+            .map(|(section, canonical)| (*section, cosine_similarity(&vector, canonical))),
+        SECTION_CONFIDENCE_THRESHOLD,
+    )
+}
+
+fn nearest_section<'a>(
+    similarities: impl Iterator<Item = (&'a str, f64)>,
+    threshold: f64,
+) -> SectionClassification {
+    let best =
+        similarities.max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+
+    match best {
+        Some((section, confidence)) if confidence >= threshold => SectionClassification {
+            section: section.to_string(),
+            confidence,
+        },
+        Some((_, confidence)) => SectionClassification {
+            section: GENERAL_SECTION.to_string(),
+            confidence,
+        },
+        None => SectionClassification {
+            section: GENERAL_SECTION.to_string(),
+            confidence: 0.0,
+        },
+    }
+}
+
+/// Split a resume into blocks at headers `synonyms` recognize and at short standalone lines
+/// that read like headings
+pub fn split_blocks(content: &str, synonyms: &SectionHeaderSynonyms) -> Vec<ResumeBlock> {
+    let lines: Vec<&str> = content.lines().map(str::trim).collect();
+    let mut blocks = Vec::new();
+    let mut current: Option<(ResumeBlock, Vec<&str>)> = None;
+
+    for (index, line) in lines.iter().enumerate() {
+        let known_section = synonyms.section_for_header(line).map(str::to_string);
+        let after_break = index == 0 || lines[index - 1].is_empty();
+        let before_text = lines.get(index + 1).is_some_and(|next| !next.is_empty());
+
+        if known_section.is_some() || (after_break && before_text && looks_like_heading(line)) {
+            if let Some((block, body)) = current.take() {
+                blocks.push(finish_block(block, body));
+            }
+            current = Some((
+                ResumeBlock {
+                    heading: line.trim_end_matches(':').to_string(),
+                    body: String::new(),
+                    known_section,
+                },
+                Vec::new(),
+            ));
+        } else if let Some((_, body)) = current.as_mut() {
+            body.push(line);
+        }
+    }
+    if let Some((block, body)) = current {
+        blocks.push(finish_block(block, body));
+    }
+
+    blocks
+}
+
+/// Sections found only by classification, for blocks whose heading no header pattern knows
+pub fn classify_unrecognized_sections(
+    content: &str,
+    synonyms: &SectionHeaderSynonyms,
+) -> HashMap<String, String> {
+    let mut sections: HashMap<String, String> = HashMap::new();
+
+    for (text, body) in unrecognized_blocks(content, synonyms) {
+        let classification = classify_block(&text);
+        if classification.section != GENERAL_SECTION {
+            sections.entry(classification.section).or_insert(body);
+        }
+    }
+
+    sections
+}
+
+/// Text to classify and body of each non-empty block whose heading no header pattern knows
+fn unrecognized_blocks(content: &str, synonyms: &SectionHeaderSynonyms) -> Vec<(String, String)> {
+    split_blocks(content, synonyms)
+        .into_iter()
+        .filter(|block| block.known_section.is_none() && !block.body.is_empty())
+        .map(|block| (format!("{}\n{}", block.heading, block.body), block.body))
+        .collect()
+}
+
+fn finish_block(mut block: ResumeBlock, body: Vec<&str>) -> ResumeBlock {
+    block.body = body.join("\n").trim().to_string();
+    block
+}
+
+fn looks_like_heading(line: &str) -> bool {
+    let words = line.split_whitespace().count();
+    (1..=MAX_HEADING_WORDS).contains(&words)
+        && line.chars().count() <= 40
+        && line.chars().any(char::is_alphabetic)
+        && !line.chars().any(|c| c.is_ascii_digit() || c == '@')
+        && !line.ends_with(['.', ','])
+        && !line.starts_with(['•', '-', '*', '▪', '◦', '●', '–'])
+}
+
+fn term_vector(text: &str) -> TermVector {
+    let language = Language::English;
+    let stemmer = language.stemmer();
+    let mut vector = TermVector::new();
+
+    for token in tokenize(text) {
+        let word = token.text.to_lowercase();
+        if word.chars().count() < 2 || language.is_stopword(&word) {
+            continue;
+        }
+        *vector.entry(stemmer.stem(&word).into_owned()).or_default() += 1.0;
+    }
+
+    vector
+}
+
+fn cosine_similarity(a: &TermVector, b: &TermVector) -> f64 {
+    let dot: f64 = a
+        .iter()
+        .filter_map(|(term, weight)| b.get(term).map(|other| weight * other))
+        .sum();
+    let norm = |vector: &TermVector| vector.values().map(|w| w * w).sum::<f64>().sqrt();
+    let denominator = norm(a) * norm(b);
+
+    if denominator == 0.0 {
+        0.0
+    } else {
+        dot / denominator
+    }
+}
+
+fn dense_cosine_similarity(a: &[f64], b: &[f64]) -> f64 {
+    if a.len() != b.len() {
+        return 0.0;
+    }
+    let dot: f64 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm = |vector: &[f64]| vector.iter().map(|w| w * w).sum::<f64>().sqrt();
+    let denominator = norm(a) * norm(b);
+
+    if denominator == 0.0 {
+        0.0
+    } else {
+        dot / denominator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_server::spawn_json_server;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    const CREATIVE_RESUME: &str = "Jane Doe\n\
+        jane.doe@example.com\n\n\
+        My Journey\n\
+        Senior Engineer, Acme Corp, 2019 - Present\n\
+        • Led a team of five engineers building payment services\n\
+        • Reduced checkout latency by 40%\n\n\
+        Beyond Work\n\
+        Sailing, chess and baking sourdough on weekends\n\n\
+        Skills\n\
+        Rust, Go, PostgreSQL\n";
+
+    #[test]
+    fn test_my_journey_heading_classified_as_experience() {
+        let blocks = split_blocks(CREATIVE_RESUME, &SectionHeaderSynonyms::default());
+        let journey = blocks.iter().find(|b| b.heading == "My Journey").unwrap();
+        assert_eq!(journey.known_section, None);
+
+        // The heading shares no words with any section description, so the body decides
+        assert!(classify_block(&journey.heading).confidence < SECTION_CONFIDENCE_THRESHOLD);
+        let classification = classify_block(&journey.body);
+        assert_eq!(classification.section, "Experience");
+        assert!(classification.confidence >= SECTION_CONFIDENCE_THRESHOLD);
+
+        let hobbies = blocks.iter().find(|b| b.heading == "Beyond Work").unwrap();
+        assert_eq!(
+            classify_block(&format!("{}\n{}", hobbies.heading, hobbies.body)).section,
+            GENERAL_SECTION
+        );
+
+        let sections =
+            classify_unrecognized_sections(CREATIVE_RESUME, &SectionHeaderSynonyms::default());
+        assert!(sections["Experience"].starts_with("Senior Engineer, Acme Corp"));
+        // Blocks under recognized headers are left to the header patterns
+        assert!(!sections.contains_key("Skills"));
+    }
+
+    #[test]
+    fn test_unseen_heading_classified_from_its_body() {
+        // Neither heading word appears in any section vocabulary, so only the body decides
+        let block = "Chapters So Far
+                     Staff Engineer at Initech, 2017 - 2021
+                     • Managed a platform team and delivered billing services to customers";
+        assert!(classify_block("Chapters So Far").confidence < SECTION_CONFIDENCE_THRESHOLD);
+        assert_eq!(classify_block(block).section, "Experience");
+    }
+
+    /// Embedding server that places each section description on its own axis and the
+    /// "My Journey" block next to Experience. Counts requests so caching can be checked.
+    async fn spawn_embedding_server(requests: Arc<AtomicUsize>) -> String {
+        spawn_json_server(move |request: serde_json::Value| {
+            let requests = Arc::clone(&requests);
+            async move {
+                requests.fetch_add(1, Ordering::SeqCst);
+                let prompt = request["prompt"].as_str().unwrap_or_default();
+                let embedding: Vec<f64> = match SECTION_PROTOTYPES
+                    .iter()
+                    .position(|(_, description)| *description == prompt)
+                {
+                    Some(axis) => (0..SECTION_PROTOTYPES.len())
+                        .map(|i| if i == axis { 1.0 } else { 0.0 })
+                        .collect(),
+                    None if prompt.contains("Acme Corp") => (0..SECTION_PROTOTYPES.len())
+                        .map(|i| {
+                            if SECTION_PROTOTYPES[i].0 == "Experience" {
+                                0.9
+                            } else {
+                                0.1
+                            }
+                        })
+                        .collect(),
+                    None => vec![1.0; SECTION_PROTOTYPES.len()],
+                };
+                serde_json::json!({ "embedding": embedding })
+            }
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_my_journey_heading_classified_as_experience_via_embeddings() {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let base_url = spawn_embedding_server(Arc::clone(&requests)).await;
+        let classifier = EmbeddingSectionClassifier::new(
+            OllamaClient::new(Some(base_url)).unwrap(),
+            "test-embed-my-journey",
+        );
+        let synonyms = SectionHeaderSynonyms::default();
+
+        let sections = classifier
+            .classify_unrecognized_sections(CREATIVE_RESUME, &synonyms)
+            .await;
+        assert!(sections["Experience"].starts_with("Senior Engineer, Acme Corp"));
+        // "Beyond Work" is equally close to every section, so it stays General
+        assert_eq!(sections.len(), 1);
+
+        // Canonical embeddings are fetched once; later runs embed only the two blocks
+        let first_run = requests.load(Ordering::SeqCst);
+        assert_eq!(first_run, SECTION_PROTOTYPES.len() + 2);
+        classifier
+            .classify_unrecognized_sections(CREATIVE_RESUME, &synonyms)
+            .await;
+        assert_eq!(requests.load(Ordering::SeqCst), first_run + 2);
+    }
+
+    #[tokio::test]
+    async fn test_embedding_classifier_falls_back_to_vocabulary_when_ollama_unreachable() {
+        // Grab a free port and close it again so nothing is listening there
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+
+        let classifier = EmbeddingSectionClassifier::new(
+            OllamaClient::new(Some(base_url)).unwrap(),
+            "test-embed-unreachable",
+        );
+        let synonyms = SectionHeaderSynonyms::default();
+
+        assert_eq!(
+            classifier
+                .classify_unrecognized_sections(CREATIVE_RESUME, &synonyms)
+                .await,
+            classify_unrecognized_sections(CREATIVE_RESUME, &synonyms)
+        );
+    }
+}
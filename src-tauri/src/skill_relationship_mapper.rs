@@ -42,8 +42,91 @@ pub struct SkillRelationshipResult {
     pub market_positioning: MarketPositioning,
     pub relationship_insights: Vec<RelationshipInsight>,
     pub confidence_metrics: RelationshipConfidenceMetrics,
+    #[serde(default)]
+    pub skill_gap_clusters: Vec<SkillGapCluster>,
 }
 
+/// Missing skills that belong to the same family, so advice can target the family as a whole
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillGapCluster {
+    pub family: String,
+    pub missing_skills: Vec<String>,
+    /// Best first skill to learn: the most connected one in the relationship graph
+    pub representative_skill: String,
+    pub hint: String,
+}
+
+/// Built-in skill families used alongside the relationship graph, which starts out empty
+/// until the background updates have populated it
+const SKILL_FAMILIES: &[(&str, &[&str])] = &[
+    (
+        "container ecosystem",
+        &[
+            "docker",
+            "kubernetes",
+            "helm",
+            "podman",
+            "containerd",
+            "openshift",
+            "docker compose",
+        ],
+    ),
+    ("cloud platforms", &["aws", "azure", "gcp", "google cloud"]),
+    (
+        "infrastructure as code",
+        &[
+            "terraform",
+            "ansible",
+            "pulumi",
+            "cloudformation",
+            "chef",
+            "puppet",
+        ],
+    ),
+    (
+        "CI/CD tooling",
+        &[
+            "jenkins",
+            "github actions",
+            "gitlab ci",
+            "circleci",
+            "travis ci",
+            "argo cd",
+        ],
+    ),
+    (
+        "frontend frameworks",
+        &["react", "vue", "angular", "svelte", "next.js", "nuxt"],
+    ),
+    (
+        "relational databases",
+        &[
+            "sql",
+            "postgresql",
+            "mysql",
+            "sql server",
+            "oracle",
+            "sqlite",
+        ],
+    ),
+    (
+        "NoSQL databases",
+        &["mongodb", "cassandra", "dynamodb", "redis", "couchbase"],
+    ),
+    (
+        "machine learning frameworks",
+        &["tensorflow", "pytorch", "scikit-learn", "keras", "xgboost"],
+    ),
+    (
+        "observability",
+        &["prometheus", "grafana", "datadog", "splunk", "new relic"],
+    ),
+    (
+        "messaging & streaming",
+        &["kafka", "rabbitmq", "kinesis", "pulsar", "sqs"],
+    ),
+];
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SkillNetwork {
     pub core_skills: Vec<CoreSkill>,
@@ -773,6 +856,158 @@ pub struct TechnologyEcosystem {
     pub adoption_rate: f64,
 }
 
+impl SkillGraph {
+    /// Cluster the job requirements missing from the resume into related skill families,
+    /// using graph edges, known technology ecosystems and the built-in families
+    pub fn cluster_skill_gaps(
+        &self,
+        ecosystems: &HashMap<String, TechnologyEcosystem>,
+        resume_skills: &[String],
+        job_requirements: &[String],
+    ) -> Vec<SkillGapCluster> {
+        let have: HashSet<String> = resume_skills
+            .iter()
+            .map(|s| s.trim().to_lowercase())
+            .collect();
+
+        let mut seen = HashSet::new();
+        let missing: Vec<&String> = job_requirements
+            .iter()
+            .filter(|skill| {
+                let key = skill.trim().to_lowercase();
+                !key.is_empty() && !have.contains(&key) && seen.insert(key)
+            })
+            .collect();
+        let keys: Vec<String> = missing.iter().map(|s| s.trim().to_lowercase()).collect();
+        let families: Vec<Option<String>> =
+            keys.iter().map(|k| skill_family(ecosystems, k)).collect();
+
+        // Union-find over the missing skills
+        let mut parent: Vec<usize> = (0..keys.len()).collect();
+        fn find(parent: &mut [usize], i: usize) -> usize {
+            let mut root = i;
+            while parent[root] != root {
+                root = parent[root];
+            }
+            parent[i] = root;
+            root
+        }
+
+        for i in 0..keys.len() {
+            for j in (i + 1)..keys.len() {
+                let same_family = families[i].is_some() && families[i] == families[j];
+                if same_family || self.skills_connected(&keys[i], &keys[j]) {
+                    let (a, b) = (find(&mut parent, i), find(&mut parent, j));
+                    parent[b] = a;
+                }
+            }
+        }
+
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        let mut group_of_root: HashMap<usize, usize> = HashMap::new();
+        for i in 0..keys.len() {
+            let root = find(&mut parent, i);
+            let group = *group_of_root.entry(root).or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+            groups[group].push(i);
+        }
+
+        let mut clusters: Vec<SkillGapCluster> = groups
+            .into_iter()
+            .map(|members| {
+                // Most connected skill first; ties go to whichever the job lists first
+                let representative = members
+                    .iter()
+                    .copied()
+                    .max_by(|&a, &b| {
+                        self.skill_degree(&keys[a])
+                            .cmp(&self.skill_degree(&keys[b]))
+                            .then(b.cmp(&a))
+                    })
+                    .unwrap_or(members[0]);
+                let missing_skills: Vec<String> = members
+                    .iter()
+                    .map(|&i| missing[i].trim().to_string())
+                    .collect();
+                let representative_skill = missing[representative].trim().to_string();
+                let family = members
+                    .iter()
+                    .find_map(|&i| families[i].clone())
+                    .unwrap_or_else(|| {
+                        if members.len() > 1 {
+                            format!("{} and related skills", representative_skill)
+                        } else {
+                            representative_skill.clone()
+                        }
+                    });
+
+                let hint = if missing_skills.len() > 1 {
+                    format!(
+                        "You're missing the {} ({}). Learn one of these, starting with {}",
+                        family,
+                        missing_skills.join(", "),
+                        representative_skill
+                    )
+                } else {
+                    format!("Add {} to close this gap", representative_skill)
+                };
+
+                SkillGapCluster {
+                    family,
+                    missing_skills,
+                    representative_skill,
+                    hint,
+                }
+            })
+            .collect();
+
+        // Biggest gaps first; the sort is stable so ties keep the job's ordering
+        clusters.sort_by_key(|cluster| std::cmp::Reverse(cluster.missing_skills.len()));
+        clusters
+    }
+
+    fn skills_connected(&self, a: &str, b: &str) -> bool {
+        self.edges.iter().any(|edge| {
+            let source = edge.source.to_lowercase();
+            let target = edge.target.to_lowercase();
+            (source == a && target == b) || (source == b && target == a)
+        })
+    }
+
+    fn skill_degree(&self, skill: &str) -> usize {
+        self.edges
+            .iter()
+            .filter(|edge| {
+                edge.source.eq_ignore_ascii_case(skill) || edge.target.eq_ignore_ascii_case(skill)
+            })
+            .count()
+    }
+}
+
+fn skill_family(ecosystems: &HashMap<String, TechnologyEcosystem>, skill: &str) -> Option<String> {
+    let mut ecosystems: Vec<&TechnologyEcosystem> = ecosystems.values().collect();
+    ecosystems.sort_by(|a, b| a.name.cmp(&b.name));
+
+    ecosystems
+        .into_iter()
+        .find(|ecosystem| {
+            ecosystem
+                .core_technologies
+                .iter()
+                .chain(&ecosystem.supporting_technologies)
+                .any(|tech| tech.eq_ignore_ascii_case(skill))
+        })
+        .map(|ecosystem| ecosystem.name.clone())
+        .or_else(|| {
+            SKILL_FAMILIES
+                .iter()
+                .find(|(_, skills)| skills.contains(&skill))
+                .map(|(family, _)| family.to_string())
+        })
+}
+
 impl SkillRelationshipMapper {
     pub async fn new(database: Database) -> Result<Self> {
         let ollama_client = OllamaClient::new(None)?;
@@ -1504,6 +1739,13 @@ Include 4-6 realistic progression steps with specific skills, experiences, and t
             &skill_network,
         )?;
 
+        // Group missing skills by family so gaps read as themes rather than a flat list
+        let skill_gap_clusters = self.skill_graph.cluster_skill_gaps(
+            &self.technology_ecosystems,
+            resume_skills,
+            job_requirements,
+        );
+
         let result = SkillRelationshipResult {
             skill_network,
             career_progression_paths,
@@ -1513,6 +1755,7 @@ Include 4-6 realistic progression steps with specific skills, experiences, and t
             market_positioning,
             relationship_insights,
             confidence_metrics,
+            skill_gap_clusters,
         };
 
         info!(
@@ -2007,3 +2250,51 @@ struct StoredSkillRelationship {
     pub market_synergy: f64,
     pub learning_difficulty: f64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edge(source: &str, target: &str) -> SkillEdge {
+        SkillEdge {
+            source: source.to_string(),
+            target: target.to_string(),
+            weight: 0.9,
+            edge_type: ConnectionType::Complementary,
+            metadata: HashMap::new(),
+        }
+    }
+
+    fn skills(names: &[&str]) -> Vec<String> {
+        names.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_missing_container_tools_grouped_into_one_cluster() {
+        let graph = SkillGraph {
+            nodes: HashMap::new(),
+            edges: vec![
+                edge("Docker", "Kubernetes"),
+                edge("Kubernetes", "Helm"),
+                edge("Kubernetes", "Istio"),
+            ],
+            clusters: HashMap::new(),
+        };
+
+        let clusters = graph.cluster_skill_gaps(
+            &HashMap::new(),
+            &skills(&["Python", "AWS"]),
+            &skills(&["Python", "Docker", "Excel", "Kubernetes", "Helm", "Istio"]),
+        );
+
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(clusters[0].family, "container ecosystem");
+        assert_eq!(
+            clusters[0].missing_skills,
+            skills(&["Docker", "Kubernetes", "Helm", "Istio"])
+        );
+        assert_eq!(clusters[0].representative_skill, "Kubernetes");
+        assert!(clusters[0].hint.contains("Learn one of these"));
+        assert_eq!(clusters[1].missing_skills, skills(&["Excel"]));
+    }
+}
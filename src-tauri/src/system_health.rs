@@ -0,0 +1,175 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::config::ConfigManager;
+use crate::database::Database;
+use crate::ollama::{select_model, OllamaClient};
+
+/// How well a subsystem is working
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    Ok,
+    /// Usable, but something needs attention
+    Degraded,
+    Down,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubsystemHealth {
+    pub status: HealthStatus,
+    pub message: String,
+}
+
+impl SubsystemHealth {
+    fn new(status: HealthStatus, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            message: message.into(),
+        }
+    }
+}
+
+/// Status of every subsystem the app depends on, for the diagnostics screen
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SystemHealth {
+    /// Worst status across the subsystems
+    pub status: HealthStatus,
+    pub ollama: SubsystemHealth,
+    pub database: SubsystemHealth,
+    pub config: SubsystemHealth,
+    pub checked_at: DateTime<Utc>,
+}
+
+/// Probe Ollama and the database; the config is checked by the caller so no config lock is held
+/// across the network call
+pub async fn check_system_health(
+    ollama: &OllamaClient,
+    model: &str,
+    db: &Database,
+    config: SubsystemHealth,
+) -> SystemHealth {
+    let ollama = check_ollama(ollama, model).await;
+    let database = check_database(db).await;
+    SystemHealth {
+        status: ollama.status.max(database.status).max(config.status),
+        ollama,
+        database,
+        config,
+        checked_at: Utc::now(),
+    }
+}
+
+/// Down when Ollama cannot be reached, degraded when the configured model is not installed
+pub async fn check_ollama(client: &OllamaClient, model: &str) -> SubsystemHealth {
+    let models = match client.list_models().await {
+        Ok(models) => models,
+        Err(e) => {
+            return SubsystemHealth::new(
+                HealthStatus::Down,
+                format!("Ollama is not reachable: {}", e),
+            )
+        }
+    };
+
+    match select_model(model, &models) {
+        Ok(selection) if selection.is_fallback => SubsystemHealth::new(
+            HealthStatus::Degraded,
+            format!(
+                "Model '{}' is not installed; analyses will use '{}'",
+                selection.requested, selection.selected
+            ),
+        ),
+        Ok(selection) => SubsystemHealth::new(
+            HealthStatus::Ok,
+            format!("Ollama is running with '{}'", selection.selected),
+        ),
+        Err(e) => SubsystemHealth::new(
+            HealthStatus::Degraded,
+            format!("Ollama is running but no usable model is installed: {}", e),
+        ),
+    }
+}
+
+/// Down when the database does not answer, degraded when migrations are pending
+pub async fn check_database(db: &Database) -> SubsystemHealth {
+    match db.health_check().await {
+        Ok(true) => {}
+        Ok(false) => {
+            return SubsystemHealth::new(HealthStatus::Down, "Database returned an invalid result")
+        }
+        Err(e) => {
+            return SubsystemHealth::new(
+                HealthStatus::Down,
+                format!("Database is not connectable: {}", e),
+            )
+        }
+    }
+
+    match db.schema_version().await {
+        Ok(version) if version.is_up_to_date => SubsystemHealth::new(
+            HealthStatus::Ok,
+            format!("Database schema is at version {}", version.current_version),
+        ),
+        Ok(version) => SubsystemHealth::new(
+            HealthStatus::Degraded,
+            format!(
+                "Database schema is at version {} with {} pending migrations",
+                version.current_version,
+                version.pending_migrations.len()
+            ),
+        ),
+        Err(e) => SubsystemHealth::new(
+            HealthStatus::Degraded,
+            format!("Could not read the database schema version: {}", e),
+        ),
+    }
+}
+
+/// Degraded when validation raises warnings, down when the config cannot be validated at all
+pub fn check_config(config: &ConfigManager) -> SubsystemHealth {
+    match config.validate_config() {
+        Ok(warnings) if warnings.is_empty() => {
+            SubsystemHealth::new(HealthStatus::Ok, "Configuration is valid")
+        }
+        Ok(warnings) => SubsystemHealth::new(HealthStatus::Degraded, warnings.join("; ")),
+        Err(e) => SubsystemHealth::new(
+            HealthStatus::Down,
+            format!("Configuration could not be validated: {}", e),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn test_system_health_reports_ollama_down_and_database_up() {
+        // Nothing listens on the discard port, so every request is refused
+        let ollama = OllamaClient::new(Some("http://127.0.0.1:9".to_string())).unwrap();
+        let db = Database::new_with_url("sqlite::memory:").await.unwrap();
+        let temp_dir = tempdir().unwrap();
+        let config = ConfigManager::new_with_path(temp_dir.path().join("config.json")).unwrap();
+
+        let health = check_system_health(
+            &ollama,
+            &config.get_ollama_config().default_model,
+            &db,
+            check_config(&config),
+        )
+        .await;
+
+        assert_eq!(health.ollama.status, HealthStatus::Down);
+        assert!(health.ollama.message.contains("not reachable"));
+        assert_eq!(
+            health.database.status,
+            HealthStatus::Ok,
+            "{}",
+            health.database.message
+        );
+        assert_eq!(health.config.status, HealthStatus::Ok);
+        assert_eq!(health.status, HealthStatus::Down);
+    }
+}
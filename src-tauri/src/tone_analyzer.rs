@@ -0,0 +1,203 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+use std::sync::Arc;
+
+use crate::models::TonePhrases;
+
+/// Tone score when the resume has neither hedging nor confident phrasing to judge by
+const NEUTRAL_TONE_SCORE: f64 = 50.0;
+
+/// Phrase lists compiled into whole-word, case-insensitive patterns
+#[derive(Debug)]
+struct TonePatterns {
+    hedging: Vec<(String, String, Regex)>,
+    confident: Vec<Regex>,
+}
+
+impl TonePatterns {
+    fn compile(phrases: &TonePhrases) -> Self {
+        let pattern = |phrase: &str| {
+            Regex::new(&format!(r"(?i)\b{}\b", regex::escape(phrase.trim())))
+                .expect("escaped phrase is a valid pattern")
+        };
+        let mut hedging: Vec<_> = phrases
+            .hedging
+            .iter()
+            .filter(|(phrase, _)| !phrase.trim().is_empty())
+            .map(|(phrase, rewrite)| (phrase.clone(), rewrite.clone(), pattern(phrase)))
+            .collect();
+        // Longest first, so "was responsible for" claims its words before "responsible for"
+        hedging.sort_by(|a, b| b.0.len().cmp(&a.0.len()).then_with(|| a.0.cmp(&b.0)));
+        Self {
+            hedging,
+            confident: phrases
+                .confident
+                .iter()
+                .filter(|phrase| !phrase.trim().is_empty())
+                .map(|phrase| pattern(phrase))
+                .collect(),
+        }
+    }
+}
+
+/// The built-in phrase lists, compiled once
+static DEFAULT_TONE_PATTERNS: Lazy<Arc<TonePatterns>> =
+    Lazy::new(|| Arc::new(TonePatterns::compile(&TonePhrases::default())));
+
+/// A hedging phrase found in the resume and a stronger way to say it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HedgingPhrase {
+    pub phrase: String,
+    /// The line the phrase appears on
+    pub line: String,
+    pub rewrite: String,
+    /// `line` with the phrase replaced by `rewrite`
+    pub suggested_line: String,
+}
+
+/// How confidently the resume describes the candidate's work
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ToneAnalysis {
+    /// 0-100: the share of tone signals that are confident rather than hedging
+    pub confidence_score: f64,
+    pub hedging_count: usize,
+    pub confident_count: usize,
+    pub hedging_phrases: Vec<HedgingPhrase>,
+}
+
+/// Counts hedging phrases such as "helped to" against ownership language such as "led"
+#[derive(Debug)]
+pub struct ToneAnalyzer {
+    patterns: Arc<TonePatterns>,
+}
+
+impl Default for ToneAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ToneAnalyzer {
+    /// Analyzer looking for the built-in hedging and confident phrases
+    pub fn new() -> Self {
+        Self {
+            patterns: Arc::clone(&DEFAULT_TONE_PATTERNS),
+        }
+    }
+
+    /// Analyzer looking for `phrases` instead of the built-in ones
+    pub fn with_phrases(phrases: &TonePhrases) -> Self {
+        Self {
+            patterns: Arc::new(TonePatterns::compile(phrases)),
+        }
+    }
+
+    pub fn analyze(&self, resume_content: &str) -> ToneAnalysis {
+        let patterns = &self.patterns;
+        let mut hedging_phrases = Vec::new();
+        let mut confident_count = 0;
+
+        for line in resume_content.lines().map(str::trim) {
+            let line_text = line
+                .trim_start_matches(['•', '-', '*', '◦', '▪', '·'])
+                .trim();
+            let mut line_hedges: Vec<(Range<usize>, HedgingPhrase)> = Vec::new();
+            for (phrase, rewrite, pattern) in &patterns.hedging {
+                for found in pattern.find_iter(line_text) {
+                    let overlaps = line_hedges
+                        .iter()
+                        .any(|(span, _)| found.start() < span.end && span.start < found.end());
+                    if overlaps {
+                        continue;
+                    }
+                    line_hedges.push((
+                        found.range(),
+                        HedgingPhrase {
+                            phrase: phrase.clone(),
+                            line: line_text.to_string(),
+                            rewrite: rewrite.clone(),
+                            suggested_line: Self::rewrite_line(
+                                line_text,
+                                found.start(),
+                                found.end(),
+                                rewrite,
+                            ),
+                        },
+                    ));
+                }
+            }
+            line_hedges.sort_by_key(|(span, _)| span.start);
+            hedging_phrases.extend(line_hedges.into_iter().map(|(_, hedge)| hedge));
+            confident_count += patterns
+                .confident
+                .iter()
+                .map(|pattern| pattern.find_iter(line_text).count())
+                .sum::<usize>();
+        }
+
+        let hedging_count = hedging_phrases.len();
+        let signals = hedging_count + confident_count;
+        let confidence_score = if signals == 0 {
+            NEUTRAL_TONE_SCORE
+        } else {
+            confident_count as f64 / signals as f64 * 100.0
+        };
+
+        ToneAnalysis {
+            confidence_score,
+            hedging_count,
+            confident_count,
+            hedging_phrases,
+        }
+    }
+
+    /// Swap the byte span `start..end` of `line` for `rewrite`, capitalized when it starts
+    /// the line
+    fn rewrite_line(line: &str, start: usize, end: usize, rewrite: &str) -> String {
+        let rewrite = if start == 0 {
+            let mut chars = rewrite.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_uppercase().chain(chars).collect()
+            })
+        } else {
+            rewrite.to_lowercase()
+        };
+        format!("{}{}{}", &line[..start], rewrite, &line[end..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hedging_heavy_resume_scores_low_with_rewrites() {
+        let analysis = ToneAnalyzer::new().analyze(
+            "Jordan Lee\nEXPERIENCE\n\
+             • Assisted with the migration of billing services to AWS\n\
+             • Helped to reduce page load times for the checkout flow\n\
+             • Tried to improve test coverage across the team\n\
+             • Was responsible for weekly release notes\n\
+             • Led the on-call rotation\n",
+        );
+
+        assert_eq!(analysis.hedging_count, 4);
+        assert_eq!(analysis.confident_count, 1);
+        assert!(analysis.confidence_score < 40.0);
+
+        let first = &analysis.hedging_phrases[0];
+        assert_eq!(first.phrase, "assisted with");
+        assert_eq!(
+            first.suggested_line,
+            "Delivered the migration of billing services to AWS"
+        );
+        let rewrites: Vec<&str> = analysis
+            .hedging_phrases
+            .iter()
+            .map(|h| h.suggested_line.as_str())
+            .collect();
+        assert!(rewrites.contains(&"Owned weekly release notes"));
+    }
+}
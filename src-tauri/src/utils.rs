@@ -3,8 +3,62 @@ use chrono::Utc;
 use log::{info, warn};
 use serde_json::json;
 use std::path::{Component, Path, PathBuf};
+use unicode_segmentation::UnicodeSegmentation;
 
-use crate::models::Analysis;
+use crate::advanced_scoring::EnhancedAnalysisResult;
+use crate::models::{Analysis, CategoryScores};
+
+/// A word of some text and its span in characters (not bytes) of that text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token<'a> {
+    pub text: &'a str,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Split `text` into Unicode words with character spans. Matchers, context snippets and
+/// density calculations all count words with this so their positions and totals agree.
+pub fn tokenize(text: &str) -> Vec<Token<'_>> {
+    let mut chars_seen = 0;
+    let mut bytes_seen = 0;
+    text.unicode_word_indices()
+        .map(|(byte_offset, word)| {
+            chars_seen += text[bytes_seen..byte_offset].chars().count();
+            bytes_seen = byte_offset;
+            let start = chars_seen;
+            Token {
+                text: word,
+                start,
+                end: start + word.chars().count(),
+            }
+        })
+        .collect()
+}
+
+/// Character spans of case-insensitive occurrences of `phrase` that neither start nor end
+/// inside one of `tokens`, the text's [`tokenize`] output. "java" does not match inside
+/// "javascript", while phrases with symbols such as "c++" or ".net" still match.
+pub fn find_whole_words(text: &str, tokens: &[Token], phrase: &str) -> Vec<(usize, usize)> {
+    let fold = |c: char| c.to_lowercase().next().unwrap_or(c);
+    let phrase: Vec<char> = phrase.chars().map(fold).collect();
+    let chars: Vec<char> = text.chars().map(fold).collect();
+    if phrase.is_empty() || phrase.len() > chars.len() {
+        return Vec::new();
+    }
+
+    let mut can_start = vec![true; chars.len() + 1];
+    let mut can_end = vec![true; chars.len() + 1];
+    for token in tokens {
+        can_start[token.start + 1..=token.end].fill(false);
+        can_end[token.start..token.end].fill(false);
+    }
+
+    (0..=chars.len() - phrase.len())
+        .filter(|&start| can_start[start] && can_end[start + phrase.len()])
+        .filter(|&start| chars[start..start + phrase.len()] == phrase[..])
+        .map(|start| (start, start + phrase.len()))
+        .collect()
+}
 
 /// Security module for path validation and safe file operations
 pub mod security {
@@ -164,6 +218,148 @@ pub mod security {
     }
 }
 
+/// PII redaction for exported reports. Redaction happens on a copy of the analyses at export
+/// time; the original values are never written anywhere, so only the user holding the source
+/// resume can map placeholders back
+pub mod redaction {
+    use super::*;
+    use crate::document::{DocumentParser, ADDRESS_PATTERN, PHONE_PATTERN};
+    use regex::Regex;
+
+    pub const NAME_PLACEHOLDER: &str = "[NAME]";
+    pub const EMAIL_PLACEHOLDER: &str = "[EMAIL]";
+    pub const PHONE_PLACEHOLDER: &str = "[PHONE]";
+    pub const ADDRESS_PLACEHOLDER: &str = "[ADDRESS]";
+    pub const LINKEDIN_PLACEHOLDER: &str = "[LINKEDIN]";
+
+    const EMAIL_PATTERN: &str = r"\b[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}\b";
+    /// Profile URLs with or without scheme and subdomain; the slug is usually the person's name
+    const LINKEDIN_PATTERN: &str =
+        r"(?i)(?:https?://)?(?:[a-z]{2,3}\.)?linkedin\.com/(?:in|pub)/[A-Za-z0-9_%-]+/?";
+
+    /// Trailing digits compared when deciding whether a number is the resume's phone
+    const PHONE_DIGITS: usize = 10;
+
+    pub struct Redactor {
+        /// Exact values detected in the source resume, replaced before the generic patterns
+        known_values: Vec<(String, &'static str)>,
+        /// Digits of the resume's phone number, so other spellings of it are caught too
+        known_phone_digits: Option<String>,
+        email_regex: Regex,
+        linkedin_regex: Regex,
+        phone_regex: Regex,
+        address_regex: Regex,
+    }
+
+    impl Default for Redactor {
+        fn default() -> Self {
+            Self {
+                known_values: Vec::new(),
+                known_phone_digits: None,
+                email_regex: Regex::new(EMAIL_PATTERN).unwrap(),
+                linkedin_regex: Regex::new(LINKEDIN_PATTERN).unwrap(),
+                phone_regex: Regex::new(PHONE_PATTERN).unwrap(),
+                address_regex: Regex::new(ADDRESS_PATTERN).unwrap(),
+            }
+        }
+    }
+
+    impl Redactor {
+        /// Build a redactor that also knows the contact details extracted from a resume
+        pub fn from_resume(resume_content: &str) -> Self {
+            let contact = DocumentParser::extract_contact_info(resume_content);
+            let known_phone_digits = contact.phone.as_deref().map(phone_digits);
+            let known_values = [
+                (contact.name, NAME_PLACEHOLDER),
+                (contact.email, EMAIL_PLACEHOLDER),
+                (contact.phone, PHONE_PLACEHOLDER),
+                (contact.address, ADDRESS_PLACEHOLDER),
+                (contact.linkedin, LINKEDIN_PLACEHOLDER),
+            ]
+            .into_iter()
+            .filter_map(|(value, placeholder)| {
+                value
+                    .map(|v| v.trim().to_string())
+                    .filter(|v| !v.is_empty())
+                    .map(|v| (v, placeholder))
+            })
+            .collect();
+
+            Self {
+                known_values,
+                known_phone_digits,
+                ..Self::default()
+            }
+        }
+
+        /// Replace the resume's contact details wherever they appear. The generic phone and
+        /// address patterns also match ordinary numbers and place names, so they only apply to
+        /// lines that already carry contact details.
+        pub fn redact(&self, text: &str) -> String {
+            text.split_inclusive('\n')
+                .map(|line| self.redact_line(line))
+                .collect()
+        }
+
+        fn redact_line(&self, line: &str) -> String {
+            let contact_line = self.email_regex.is_match(line)
+                || self
+                    .known_values
+                    .iter()
+                    .any(|(value, _)| line.contains(value.as_str()));
+
+            // Whole profile URLs go first, so a known value cannot leave the scheme behind
+            let mut redacted = self
+                .linkedin_regex
+                .replace_all(line, LINKEDIN_PLACEHOLDER)
+                .into_owned();
+            for (value, placeholder) in &self.known_values {
+                redacted = redacted.replace(value.as_str(), placeholder);
+            }
+
+            let redacted = self.email_regex.replace_all(&redacted, EMAIL_PLACEHOLDER);
+            let redacted = if contact_line {
+                self.address_regex
+                    .replace_all(&redacted, ADDRESS_PLACEHOLDER)
+            } else {
+                redacted
+            };
+            let redacted = self
+                .phone_regex
+                .replace_all(&redacted, |captures: &regex::Captures| {
+                    let number = &captures[0];
+                    let is_known_phone = self
+                        .known_phone_digits
+                        .as_deref()
+                        .is_some_and(|known| known == phone_digits(number));
+                    if contact_line || is_known_phone {
+                        PHONE_PLACEHOLDER.to_string()
+                    } else {
+                        number.to_string()
+                    }
+                });
+            redacted.into_owned()
+        }
+
+        pub fn redact_analysis(&self, analysis: &Analysis) -> Analysis {
+            Analysis {
+                detailed_feedback: self.redact(&analysis.detailed_feedback),
+                missing_keywords: self.redact(&analysis.missing_keywords),
+                recommendations: self.redact(&analysis.recommendations),
+                ..analysis.clone()
+            }
+        }
+    }
+
+    /// Last [`PHONE_DIGITS`] digits of a phone number, ignoring punctuation and country code
+    fn phone_digits(number: &str) -> String {
+        let digits: Vec<char> = number.chars().filter(char::is_ascii_digit).collect();
+        digits[digits.len().saturating_sub(PHONE_DIGITS)..]
+            .iter()
+            .collect()
+    }
+}
+
 pub async fn export_data(analyses: &[Analysis], format: &str) -> Result<String> {
     let timestamp = Utc::now().format("%Y%m%d_%H%M%S");
 
@@ -180,43 +376,52 @@ pub async fn export_data(analyses: &[Analysis], format: &str) -> Result<String>
         safe_format
     );
 
+    let content = render_export(analyses, format)?;
+    let file_path = write_export_file(&filename, content.as_bytes()).await?;
+
+    info!(
+        "Exported {} analyses to {}",
+        analyses.len(),
+        file_path.display()
+    );
+    Ok(file_path.to_string_lossy().to_string())
+}
+
+/// Write a generated export into the exports directory, returning its path
+pub async fn write_export_file(filename: &str, content: &[u8]) -> Result<PathBuf> {
     // Validate the generated filename is safe
-    security::validate_file_path(&filename, Some("./exports"))?;
+    security::validate_file_path(filename, Some("./exports"))?;
 
-    let file_path = Path::new("./exports").join(&filename);
+    let file_path = Path::new("./exports").join(filename);
 
     // Create exports directory if it doesn't exist
     tokio::fs::create_dir_all("./exports").await?;
+    tokio::fs::write(&file_path, content).await?;
 
+    Ok(file_path)
+}
+
+/// Render analyses in the given export format without touching the filesystem
+pub fn render_export(analyses: &[Analysis], format: &str) -> Result<String> {
     match format.to_lowercase().as_str() {
-        "json" => export_json(analyses, &file_path).await?,
-        "csv" => export_csv(analyses, &file_path).await?,
-        "txt" => export_txt(analyses, &file_path).await?,
-        _ => return Err(anyhow!("Unsupported export format: {}", format)),
+        "json" => render_json(analyses),
+        "csv" => Ok(render_csv(analyses)),
+        "txt" => Ok(render_txt(analyses)),
+        _ => Err(anyhow!("Unsupported export format: {}", format)),
     }
-
-    info!(
-        "Exported {} analyses to {}",
-        analyses.len(),
-        file_path.display()
-    );
-    Ok(file_path.to_string_lossy().to_string())
 }
 
-async fn export_json(analyses: &[Analysis], file_path: &Path) -> Result<()> {
+fn render_json(analyses: &[Analysis]) -> Result<String> {
     let export_data = json!({
         "export_timestamp": Utc::now().to_rfc3339(),
         "total_analyses": analyses.len(),
         "analyses": analyses
     });
 
-    let json_string = serde_json::to_string_pretty(&export_data)?;
-    tokio::fs::write(file_path, json_string).await?;
-
-    Ok(())
+    Ok(serde_json::to_string_pretty(&export_data)?)
 }
 
-async fn export_csv(analyses: &[Analysis], file_path: &Path) -> Result<()> {
+fn render_csv(analyses: &[Analysis]) -> String {
     let mut csv_content = String::new();
 
     // Header
@@ -241,11 +446,100 @@ async fn export_csv(analyses: &[Analysis], file_path: &Path) -> Result<()> {
         ));
     }
 
-    tokio::fs::write(file_path, csv_content).await?;
-    Ok(())
+    csv_content
+}
+
+/// Missing keywords listed per resume in a batch export, most important first
+const BATCH_EXPORT_MISSING_KEYWORDS: usize = 5;
+
+/// One resume's line in a batch analysis export
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct BatchResultRow {
+    pub resume_name: String,
+    pub overall_score: f64,
+    pub category_scores: CategoryScores,
+    pub matched_keywords: usize,
+    pub top_missing_keywords: Vec<String>,
 }
 
-async fn export_txt(analyses: &[Analysis], file_path: &Path) -> Result<()> {
+impl BatchResultRow {
+    pub fn from_result(resume_name: &str, result: &EnhancedAnalysisResult) -> Self {
+        Self {
+            resume_name: resume_name.to_string(),
+            overall_score: result.base_analysis.overall_score,
+            category_scores: result.base_analysis.category_scores.clone(),
+            matched_keywords: result.keyword_analysis.matched_keywords().len(),
+            top_missing_keywords: result
+                .base_analysis
+                .missing_keywords
+                .iter()
+                .take(BATCH_EXPORT_MISSING_KEYWORDS)
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+/// Write one row per resume of a batch analysis to the exports directory
+pub async fn export_batch_data(rows: &[BatchResultRow], format: &str) -> Result<String> {
+    let content = render_batch_export(rows, format)?;
+    let filename = format!(
+        "ats_batch_export_{}_{}.{}",
+        Utc::now().format("%Y%m%d_%H%M%S"),
+        rows.len(),
+        format.to_lowercase()
+    );
+    let file_path = write_export_file(&filename, content.as_bytes()).await?;
+
+    info!(
+        "Exported batch results for {} resumes to {}",
+        rows.len(),
+        file_path.display()
+    );
+    Ok(file_path.to_string_lossy().to_string())
+}
+
+/// Render batch rows in the given export format without touching the filesystem
+pub fn render_batch_export(rows: &[BatchResultRow], format: &str) -> Result<String> {
+    match format.to_lowercase().as_str() {
+        "json" => Ok(serde_json::to_string_pretty(rows)?),
+        "csv" => Ok(render_batch_csv(rows)),
+        _ => Err(anyhow!("Unsupported batch export format: {}", format)),
+    }
+}
+
+fn render_batch_csv(rows: &[BatchResultRow]) -> String {
+    let mut csv_content = String::from("Resume,Overall Score,Skills Score,Experience Score,Education Score,Keywords Score,Format Score,Matched Keywords,Top Missing Keywords\n");
+
+    for row in rows {
+        let scores = &row.category_scores;
+        csv_content.push_str(&format!(
+            "{},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{},{}\n",
+            csv_field(&row.resume_name),
+            row.overall_score,
+            scores.skills,
+            scores.experience,
+            scores.education,
+            scores.keywords,
+            scores.format,
+            row.matched_keywords,
+            csv_field(&row.top_missing_keywords.join("; "))
+        ));
+    }
+
+    csv_content
+}
+
+/// Quote a CSV field when it holds a delimiter, quote or line break, doubling inner quotes
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn render_txt(analyses: &[Analysis]) -> String {
     let mut txt_content = String::new();
 
     txt_content.push_str("ATS Analysis Export Report\n");
@@ -301,6 +595,214 @@ async fn export_txt(analyses: &[Analysis], file_path: &Path) -> Result<()> {
         txt_content.push_str("\n\n");
     }
 
-    tokio::fs::write(file_path, txt_content).await?;
-    Ok(())
+    txt_content
+}
+
+/// Local HTTP server standing in for Ollama or a webhook endpoint in tests
+#[cfg(test)]
+pub mod test_server {
+    use std::future::Future;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::{TcpListener, TcpStream};
+
+    /// Read one HTTP request and return its body, or None if the client hangs up first
+    pub async fn read_request_body(stream: &mut TcpStream) -> Option<Vec<u8>> {
+        let mut buffer = Vec::new();
+        let mut chunk = [0u8; 4096];
+        loop {
+            let read = stream.read(&mut chunk).await.ok()?;
+            if read == 0 {
+                return None;
+            }
+            buffer.extend_from_slice(&chunk[..read]);
+            let Some(header_end) = buffer.windows(4).position(|w| w == b"\r\n\r\n") else {
+                continue;
+            };
+            let headers = String::from_utf8_lossy(&buffer[..header_end]).to_lowercase();
+            let length = headers
+                .lines()
+                .find_map(|line| line.strip_prefix("content-length:"))
+                .and_then(|value| value.trim().parse::<usize>().ok())
+                .unwrap_or(0);
+            let body_start = header_end + 4;
+            if buffer.len() >= body_start + length {
+                return Some(buffer[body_start..body_start + length].to_vec());
+            }
+        }
+    }
+
+    /// Answer with `status` (such as "200 OK") and a JSON `body`, then close the connection
+    pub async fn write_json_response(stream: &mut TcpStream, status: &str, body: &str) {
+        let response = format!(
+            "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        );
+        let _ = stream.write_all(response.as_bytes()).await;
+    }
+
+    /// Answer every request with what `respond` returns for its JSON body. Returns the base
+    /// URL of the server, which runs until the test ends.
+    pub async fn spawn_json_server<F, Fut>(respond: F) -> String
+    where
+        F: Fn(serde_json::Value) -> Fut + Clone + Send + 'static,
+        Fut: Future<Output = serde_json::Value> + Send,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let respond = respond.clone();
+                tokio::spawn(async move {
+                    let Some(body) = read_request_body(&mut stream).await else {
+                        return;
+                    };
+                    let request = serde_json::from_slice(&body).unwrap();
+                    let response = respond(request).await;
+                    write_json_response(&mut stream, "200 OK", &response.to_string()).await;
+                });
+            }
+        });
+
+        format!("http://{}", addr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::redaction::*;
+    use super::*;
+
+    #[test]
+    fn test_find_whole_words_uses_token_boundaries() {
+        let text = "JavaScript and Java, C++ and C#; .NET 8 but not python3";
+        let tokens = tokenize(text);
+        let find = |phrase| find_whole_words(text, &tokens, phrase);
+
+        assert_eq!(find("java"), vec![(15, 19)]);
+        assert_eq!(find("c++").len(), 1);
+        assert_eq!(find("c#").len(), 1);
+        assert_eq!(find(".net").len(), 1);
+        assert_eq!(find("and java").len(), 1);
+        assert!(find("python").is_empty());
+        assert!(find("script").is_empty());
+    }
+
+    const RESUME: &str = "Jane Q. Doe\n\
+        jane.doe@example.com | (555) 123-4567 | linkedin.com/in/jane-doe-42\n\
+        42 Elm Street, Springfield, IL 62704\n\n\
+        EXPERIENCE\nSoftware Engineer at Acme";
+
+    fn analysis_with_pii() -> Analysis {
+        Analysis {
+            id: "analysis-1".to_string(),
+            resume_id: "resume-1".to_string(),
+            job_description_id: "job-1".to_string(),
+            model_used: "llama3".to_string(),
+            overall_score: 72.5,
+            skills_score: 70.0,
+            experience_score: 75.0,
+            education_score: 80.0,
+            keywords_score: 65.0,
+            format_score: 90.0,
+            detailed_feedback: "Jane Q. Doe lists jane.doe@example.com and (555) 123-4567 \
+                                in the header, with address 42 Elm Street, Springfield, IL 62704."
+                .to_string(),
+            missing_keywords: "kubernetes".to_string(),
+            recommendations: "Reach out via 555.123.4567 or \
+                              https://www.linkedin.com/in/jane-doe-42/ for a follow-up."
+                .to_string(),
+            processing_time_ms: 1200,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_redacted_export_omits_email_and_phone() {
+        let redactor = Redactor::from_resume(RESUME);
+        let redacted = vec![redactor.redact_analysis(&analysis_with_pii())];
+
+        for format in ["txt", "json"] {
+            let output = render_export(&redacted, format).unwrap();
+
+            assert!(
+                !output.contains("jane.doe@example.com"),
+                "{format}: {output}"
+            );
+            assert!(!output.contains("123-4567"), "{format}: {output}");
+            assert!(!output.contains("555.123.4567"), "{format}: {output}");
+            assert!(!output.contains("Jane Q. Doe"), "{format}: {output}");
+            assert!(!output.contains("Elm Street"), "{format}: {output}");
+            assert!(!output.contains("linkedin.com"), "{format}: {output}");
+            assert!(!output.contains("jane-doe-42"), "{format}: {output}");
+            assert!(output.contains(EMAIL_PLACEHOLDER));
+            assert!(output.contains(PHONE_PLACEHOLDER));
+            assert!(output.contains(NAME_PLACEHOLDER));
+            assert!(output.contains(ADDRESS_PLACEHOLDER));
+            assert!(output.contains(LINKEDIN_PLACEHOLDER));
+            // Non-PII content survives
+            assert!(output.contains("for a follow-up"));
+        }
+
+        // Without redaction the contact details are exported as-is
+        let plain = render_export(&[analysis_with_pii()], "txt").unwrap();
+        assert!(plain.contains("jane.doe@example.com"));
+    }
+
+    #[test]
+    fn test_redaction_leaves_ordinary_feedback_unchanged() {
+        let redactor = Redactor::from_resume(RESUME);
+        let feedback = "Mention leading the 120 Main Street office move and processing \
+                        4155550199 records a day.\nQuantify the 2019 - 2023 migration results.";
+
+        assert_eq!(redactor.redact(feedback), feedback);
+    }
+
+    fn batch_row(resume_name: &str, overall_score: f64, missing: &[&str]) -> BatchResultRow {
+        BatchResultRow {
+            resume_name: resume_name.to_string(),
+            overall_score,
+            category_scores: CategoryScores {
+                skills: 80.0,
+                experience: 70.0,
+                education: 90.0,
+                keywords: 65.0,
+                format: 85.0,
+                confidence: None,
+            },
+            matched_keywords: 12,
+            top_missing_keywords: missing.iter().map(|keyword| keyword.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_batch_csv_has_row_per_resume_and_escapes_fields() {
+        let rows = [
+            batch_row("Doe, Jane.pdf", 82.5, &["kubernetes", "terraform"]),
+            batch_row("john_smith.docx", 74.0, &["go"]),
+            batch_row("Alex \"AJ\" Kim.pdf", 68.25, &[]),
+        ];
+
+        let csv = render_batch_export(&rows, "csv").unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(
+            lines[0],
+            "Resume,Overall Score,Skills Score,Experience Score,Education Score,Keywords Score,Format Score,Matched Keywords,Top Missing Keywords"
+        );
+        assert_eq!(
+            lines[1],
+            "\"Doe, Jane.pdf\",82.50,80.00,70.00,90.00,65.00,85.00,12,kubernetes; terraform"
+        );
+        assert!(lines[2].starts_with("john_smith.docx,74.00,"));
+        assert!(lines[3].starts_with("\"Alex \"\"AJ\"\" Kim.pdf\",68.25,"));
+        assert!(lines
+            .iter()
+            .skip(1)
+            .all(|line| line.matches(',').count() >= 8));
+
+        assert!(render_batch_export(&rows, "pdf").is_err());
+    }
 }
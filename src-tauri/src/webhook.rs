@@ -0,0 +1,189 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use log::{info, warn};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::models::{Analysis, AnalysisResult};
+
+pub const ANALYSIS_COMPLETED_EVENT: &str = "analysis.completed";
+
+/// Delivery attempts per event, including the first
+pub const MAX_DELIVERY_ATTEMPTS: u32 = 3;
+/// Wait before the first retry; doubles on each further retry
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// JSON body POSTed to the completion webhook once an analysis has been saved
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnalysisCompletedPayload {
+    pub event: String,
+    pub analysis_id: String,
+    pub resume_id: String,
+    /// None when the analysis was saved without a job description
+    pub job_description_id: Option<String>,
+    pub model_used: String,
+    pub completed_at: DateTime<Utc>,
+    pub result: AnalysisResult,
+}
+
+impl AnalysisCompletedPayload {
+    pub fn new(analysis: &Analysis, result: &AnalysisResult) -> Self {
+        Self {
+            event: ANALYSIS_COMPLETED_EVENT.to_string(),
+            analysis_id: analysis.id.clone(),
+            resume_id: analysis.resume_id.clone(),
+            job_description_id: Some(analysis.job_description_id.clone())
+                .filter(|id| !id.is_empty()),
+            model_used: analysis.model_used.clone(),
+            completed_at: analysis.created_at,
+            result: result.clone(),
+        }
+    }
+}
+
+/// Send the payload in the background; delivery failures are logged and never reach the caller
+pub fn notify_analysis_completed(url: String, payload: AnalysisCompletedPayload) {
+    tokio::spawn(async move {
+        match deliver(&url, &payload, RETRY_BASE_DELAY).await {
+            Ok(()) => info!(
+                "Delivered {} webhook for analysis {}",
+                payload.event, payload.analysis_id
+            ),
+            Err(e) => warn!(
+                "Giving up on {} webhook for analysis {}: {}",
+                payload.event, payload.analysis_id, e
+            ),
+        }
+    });
+}
+
+/// POST the payload, retrying with exponential backoff on connection errors and non-2xx replies
+pub async fn deliver(
+    url: &str,
+    payload: &AnalysisCompletedPayload,
+    retry_delay: Duration,
+) -> Result<()> {
+    let client = Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+    let mut delay = retry_delay;
+    let mut last_error = anyhow!("no delivery attempted");
+
+    for attempt in 1..=MAX_DELIVERY_ATTEMPTS {
+        match client.post(url).json(payload).send().await {
+            Ok(response) if response.status().is_success() => return Ok(()),
+            Ok(response) => last_error = anyhow!("webhook returned {}", response.status()),
+            Err(e) => last_error = e.into(),
+        }
+
+        if attempt < MAX_DELIVERY_ATTEMPTS {
+            warn!(
+                "Webhook attempt {}/{} failed: {}",
+                attempt, MAX_DELIVERY_ATTEMPTS, last_error
+            );
+            tokio::time::sleep(delay).await;
+            delay *= 2;
+        }
+    }
+
+    Err(last_error.context(format!(
+        "webhook delivery failed after {} attempts",
+        MAX_DELIVERY_ATTEMPTS
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::CategoryScores;
+    use crate::utils::test_server::{read_request_body, write_json_response};
+    use tokio::net::TcpListener;
+
+    #[tokio::test]
+    async fn test_webhook_retries_and_delivers_payload() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hooks/ats", listener.local_addr().unwrap());
+
+        // Fail the first attempt so the retry path is exercised
+        let server = tokio::spawn(async move {
+            let mut bodies = Vec::new();
+            for status in ["500 Internal Server Error", "200 OK"] {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                bodies.push(read_request_body(&mut stream).await.unwrap());
+                write_json_response(&mut stream, status, "").await;
+            }
+            bodies
+        });
+
+        let result = AnalysisResult {
+            overall_score: 82.5,
+            category_scores: CategoryScores {
+                skills: 80.0,
+                experience: 85.0,
+                education: 70.0,
+                keywords: 90.0,
+                format: 88.0,
+                confidence: None,
+            },
+            detailed_feedback: "Strong match".to_string(),
+            missing_keywords: vec!["Terraform".to_string()],
+            recommendations: vec!["Add Terraform experience".to_string()],
+            processing_time_ms: 1200,
+            model_used: Some("llama2".to_string()),
+        };
+        let analysis = Analysis::new(
+            "resume-1".to_string(),
+            "job-1".to_string(),
+            "llama2".to_string(),
+            &result,
+        );
+        let payload = AnalysisCompletedPayload::new(&analysis, &result);
+
+        deliver(&url, &payload, Duration::from_millis(10))
+            .await
+            .unwrap();
+
+        let bodies = server.await.unwrap();
+        assert_eq!(bodies.len(), 2);
+        let delivered: serde_json::Value = serde_json::from_slice(&bodies[1]).unwrap();
+        assert_eq!(delivered["event"], ANALYSIS_COMPLETED_EVENT);
+        assert_eq!(delivered["analysis_id"], analysis.id.as_str());
+        assert_eq!(delivered["resume_id"], "resume-1");
+        assert_eq!(delivered["job_description_id"], "job-1");
+        assert_eq!(delivered["model_used"], "llama2");
+        assert!(delivered["completed_at"].is_string());
+        assert_eq!(delivered["result"]["overall_score"], 82.5);
+        assert_eq!(delivered["result"]["category_scores"]["keywords"], 90.0);
+        assert_eq!(delivered["result"]["missing_keywords"][0], "Terraform");
+    }
+
+    #[test]
+    fn test_payload_without_saved_job_sends_null_job_id() {
+        let result = AnalysisResult {
+            overall_score: 70.0,
+            category_scores: CategoryScores {
+                skills: 70.0,
+                experience: 70.0,
+                education: 70.0,
+                keywords: 70.0,
+                format: 70.0,
+                confidence: None,
+            },
+            detailed_feedback: String::new(),
+            missing_keywords: Vec::new(),
+            recommendations: Vec::new(),
+            processing_time_ms: 0,
+            model_used: None,
+        };
+        let analysis = Analysis::new(
+            "resume-1".to_string(),
+            String::new(),
+            "llama2".to_string(),
+            &result,
+        );
+
+        let payload =
+            serde_json::to_value(AnalysisCompletedPayload::new(&analysis, &result)).unwrap();
+        assert!(payload["job_description_id"].is_null());
+    }
+}
@@ -0,0 +1,340 @@
+// Golden corpus regression test: scores a directory of resume/JD fixtures and compares them
+// with stored baselines, so scoring changes can't drift silently
+use anyhow::{anyhow, Context, Result};
+use ats_scanner::{
+    advanced_scoring::{AdvancedScoringEngine, EnhancedAnalysisResult},
+    database::Database,
+};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+/// Set to `1` to rewrite golden baselines from the current scoring output instead of comparing.
+///
+/// `cargo test` rejects unknown flags, so this is the `--update-baselines` switch:
+/// `ATS_UPDATE_BASELINES=1 cargo test golden_corpus`
+pub const UPDATE_BASELINES_ENV: &str = "ATS_UPDATE_BASELINES";
+
+/// Allowed drift per score before a golden fixture counts as a regression
+pub const DEFAULT_GOLDEN_TOLERANCE: f64 = 0.5;
+
+const BASELINE_SUFFIX: &str = ".baseline.json";
+
+/// Resume/job description pair from the golden corpus
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoldenFixture {
+    #[serde(skip)]
+    pub id: String,
+    pub industry: String,
+    pub experience_level: String,
+    pub resume: String,
+    pub job_description: String,
+}
+
+/// Scores locked in for a golden fixture
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GoldenBaseline {
+    pub scores: BTreeMap<String, f64>,
+}
+
+/// A single score that drifted outside the tolerance, or is missing on one side
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoldenScoreDiff {
+    pub fixture_id: String,
+    pub score: String,
+    pub expected: Option<f64>,
+    pub actual: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GoldenCorpusReport {
+    pub fixtures_checked: usize,
+    pub tolerance: f64,
+    pub baselines_updated: Vec<String>,
+    pub missing_baselines: Vec<String>,
+    pub mismatches: Vec<GoldenScoreDiff>,
+}
+
+impl GoldenCorpusReport {
+    pub fn passed(&self) -> bool {
+        self.mismatches.is_empty() && self.missing_baselines.is_empty()
+    }
+
+    /// Human-readable diff of every regression, suitable for a failing assertion message
+    pub fn render_diff(&self) -> String {
+        let mut out = String::new();
+        for id in &self.missing_baselines {
+            let _ = writeln!(
+                out,
+                "{}: no baseline (run with {}=1 to create it)",
+                id, UPDATE_BASELINES_ENV
+            );
+        }
+        for diff in &self.mismatches {
+            let format_score = |score: Option<f64>| {
+                score.map_or_else(|| "missing".to_string(), |s| format!("{:.2}", s))
+            };
+            let delta = match (diff.expected, diff.actual) {
+                (Some(expected), Some(actual)) => format!(" ({:+.2})", actual - expected),
+                _ => String::new(),
+            };
+            let _ = writeln!(
+                out,
+                "{} / {}: expected {}, got {}{}",
+                diff.fixture_id,
+                diff.score,
+                format_score(diff.expected),
+                format_score(diff.actual),
+                delta
+            );
+        }
+        if !out.is_empty() {
+            let _ = writeln!(
+                out,
+                "{} fixture(s) checked with tolerance {:.2}; if the change is intentional, rerun with {}=1",
+                self.fixtures_checked, self.tolerance, UPDATE_BASELINES_ENV
+            );
+        }
+        out
+    }
+}
+
+/// Runs a directory of resume/JD fixtures through `analyze_comprehensive` and compares the
+/// resulting scores with stored baselines, so scoring changes can't drift silently.
+///
+/// Each fixture is `<id>.json` (a [`GoldenFixture`]) with its baseline next to it in
+/// `<id>.baseline.json`.
+pub struct GoldenCorpusHarness {
+    fixtures_dir: PathBuf,
+    tolerance: f64,
+    update_baselines: bool,
+}
+
+impl GoldenCorpusHarness {
+    pub fn new(fixtures_dir: impl Into<PathBuf>) -> Self {
+        let update_baselines = std::env::var(UPDATE_BASELINES_ENV)
+            .map(|value| value == "1" || value.eq_ignore_ascii_case("true"))
+            .unwrap_or(false);
+
+        Self {
+            fixtures_dir: fixtures_dir.into(),
+            tolerance: DEFAULT_GOLDEN_TOLERANCE,
+            update_baselines,
+        }
+    }
+
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance.abs();
+        self
+    }
+
+    pub fn update_baselines(mut self, update: bool) -> Self {
+        self.update_baselines = update;
+        self
+    }
+
+    /// Load every fixture in the directory, ordered by id
+    pub fn load_fixtures(&self) -> Result<Vec<GoldenFixture>> {
+        let entries = std::fs::read_dir(&self.fixtures_dir).with_context(|| {
+            format!(
+                "Failed to read golden fixtures from {}",
+                self.fixtures_dir.display()
+            )
+        })?;
+
+        let mut fixtures = Vec::new();
+        for entry in entries {
+            let path = entry?.path();
+            let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            if !file_name.ends_with(".json") || file_name.ends_with(BASELINE_SUFFIX) {
+                continue;
+            }
+
+            let mut fixture: GoldenFixture = Self::read_json(&path)?;
+            fixture.id = file_name.trim_end_matches(".json").to_string();
+            fixtures.push(fixture);
+        }
+
+        fixtures.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(fixtures)
+    }
+
+    pub async fn run(&self, engine: &AdvancedScoringEngine) -> Result<GoldenCorpusReport> {
+        let fixtures = self.load_fixtures()?;
+        if fixtures.is_empty() {
+            return Err(anyhow!(
+                "No golden fixtures found in {}",
+                self.fixtures_dir.display()
+            ));
+        }
+
+        let mut report = GoldenCorpusReport {
+            tolerance: self.tolerance,
+            ..Default::default()
+        };
+
+        for fixture in &fixtures {
+            let result = engine
+                .analyze_comprehensive(
+                    &fixture.resume,
+                    &fixture.job_description,
+                    &fixture.industry,
+                    &fixture.experience_level,
+                )
+                .await
+                .with_context(|| format!("Golden fixture {} failed to analyze", fixture.id))?;
+            let actual = Self::baseline_from(&result);
+            let baseline_path = self.baseline_path(&fixture.id);
+            report.fixtures_checked += 1;
+
+            if self.update_baselines {
+                std::fs::write(
+                    &baseline_path,
+                    serde_json::to_string_pretty(&actual)? + "\n",
+                )
+                .with_context(|| format!("Failed to write baseline {}", baseline_path.display()))?;
+                report.baselines_updated.push(fixture.id.clone());
+                continue;
+            }
+
+            if !baseline_path.exists() {
+                report.missing_baselines.push(fixture.id.clone());
+                continue;
+            }
+
+            let expected: GoldenBaseline = Self::read_json(&baseline_path)?;
+            report
+                .mismatches
+                .extend(self.compare(&fixture.id, &expected, &actual));
+        }
+
+        if report.passed() {
+            info!(
+                "Golden corpus: {} fixture(s) checked, {} baseline(s) updated",
+                report.fixtures_checked,
+                report.baselines_updated.len()
+            );
+        } else {
+            warn!("Golden corpus regressions:\n{}", report.render_diff());
+        }
+
+        Ok(report)
+    }
+
+    fn compare(
+        &self,
+        fixture_id: &str,
+        expected: &GoldenBaseline,
+        actual: &GoldenBaseline,
+    ) -> Vec<GoldenScoreDiff> {
+        let mut names: Vec<&String> = expected.scores.keys().chain(actual.scores.keys()).collect();
+        names.sort();
+        names.dedup();
+
+        names
+            .into_iter()
+            .filter_map(|name| {
+                let expected_score = expected.scores.get(name).copied();
+                let actual_score = actual.scores.get(name).copied();
+                let within_tolerance = matches!(
+                    (expected_score, actual_score),
+                    (Some(e), Some(a)) if (e - a).abs() <= self.tolerance
+                );
+
+                (!within_tolerance).then(|| GoldenScoreDiff {
+                    fixture_id: fixture_id.to_string(),
+                    score: name.clone(),
+                    expected: expected_score,
+                    actual: actual_score,
+                })
+            })
+            .collect()
+    }
+
+    fn baseline_from(result: &EnhancedAnalysisResult) -> GoldenBaseline {
+        let analysis = &result.base_analysis;
+        let round = |score: f64| (score * 100.0).round() / 100.0;
+
+        GoldenBaseline {
+            scores: [
+                ("overall", analysis.overall_score),
+                ("skills", analysis.category_scores.skills),
+                ("experience", analysis.category_scores.experience),
+                ("education", analysis.category_scores.education),
+                ("keywords", analysis.category_scores.keywords),
+                ("format", analysis.category_scores.format),
+                ("industry_alignment", result.industry_alignment),
+            ]
+            .into_iter()
+            .map(|(name, score)| (name.to_string(), round(score)))
+            .collect(),
+        }
+    }
+
+    fn baseline_path(&self, fixture_id: &str) -> PathBuf {
+        self.fixtures_dir
+            .join(format!("{}{}", fixture_id, BASELINE_SUFFIX))
+    }
+
+    fn read_json<T: serde::de::DeserializeOwned>(path: &Path) -> Result<T> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Invalid JSON in {}", path.display()))
+    }
+}
+
+fn golden_fixtures_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/golden")
+}
+
+async fn golden_engine() -> AdvancedScoringEngine {
+    let db = Database::new_with_url("sqlite::memory:").await.unwrap();
+    AdvancedScoringEngine::new(std::sync::Arc::new(tokio::sync::Mutex::new(db)))
+}
+
+#[tokio::test]
+async fn test_golden_corpus_matches_baselines() {
+    let engine = golden_engine().await;
+    let report = GoldenCorpusHarness::new(golden_fixtures_dir())
+        .run(&engine)
+        .await
+        .unwrap();
+
+    assert!(report.fixtures_checked >= 2);
+    assert!(report.passed(), "{}", report.render_diff());
+}
+
+#[test]
+fn test_golden_compare_reports_drift() {
+    let harness = GoldenCorpusHarness::new(golden_fixtures_dir()).with_tolerance(0.5);
+    let baseline = |overall: f64| GoldenBaseline {
+        scores: BTreeMap::from([
+            ("overall".to_string(), overall),
+            ("skills".to_string(), 70.0),
+        ]),
+    };
+
+    assert!(harness
+        .compare("fixture", &baseline(80.0), &baseline(80.4))
+        .is_empty());
+
+    let diffs = harness.compare("fixture", &baseline(80.0), &baseline(83.0));
+    assert_eq!(diffs.len(), 1);
+    assert_eq!(diffs[0].score, "overall");
+
+    let report = GoldenCorpusReport {
+        fixtures_checked: 1,
+        tolerance: 0.5,
+        mismatches: diffs,
+        ..Default::default()
+    };
+    assert!(!report.passed());
+    assert!(report
+        .render_diff()
+        .contains("fixture / overall: expected 80.00, got 83.00 (+3.00)"));
+}
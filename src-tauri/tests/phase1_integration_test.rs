@@ -121,6 +121,8 @@ async fn test_phase1_database_functionality() {
         user_satisfaction: Some(4.2),
         error_count: 0,
         created_at: Utc::now(),
+        prompt_tokens: 1800,
+        response_tokens: 450,
     };
 
     db.save_model_performance(&metrics)